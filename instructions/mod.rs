@@ -1,10 +0,0 @@
-
-pub mod initialize_collection;
-pub mod mint_nft;
-pub mod update_nft_metadata;
-pub mod transfer_nft;
-
-pub use initialize_collection::*;
-pub use mint_nft::*;
-pub use update_nft_metadata::*;
-pub use transfer_nft::*;
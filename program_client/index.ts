@@ -1,2 +1,3 @@
 export * from "./pda"
-export * from "./rpc"
\ No newline at end of file
+export * from "./rpc"
+export * from "./reveal"
\ No newline at end of file
@@ -35,6 +35,342 @@ export const deriveNftMetadataPDA = (
     )
 };
 
+export type ListingSeeds = {
+    mint: PublicKey, 
+};
+
+export const deriveListingPDA = (
+    seeds: ListingSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("listing"),
+            seeds.mint.toBuffer(),
+        ],
+        programId,
+    )
+};
+
+export const deriveFreezeAuthorityPDA = (
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("freeze_authority"),
+        ],
+        programId,
+    )
+};
+
+export type BidSeeds = {
+    bidder: PublicKey, 
+    target: PublicKey, 
+};
+
+export const deriveBidPDA = (
+    seeds: BidSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("bid"),
+            seeds.bidder.toBuffer(),
+            seeds.target.toBuffer(),
+        ],
+        programId,
+    )
+};
+
+export type AuctionSeeds = {
+    mint: PublicKey, 
+};
+
+export const deriveAuctionPDA = (
+    seeds: AuctionSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("auction"),
+            seeds.mint.toBuffer(),
+        ],
+        programId,
+    )
+};
+
+export type MintConfigSeeds = {
+    collection: PublicKey, 
+};
+
+export const deriveMintConfigPDA = (
+    seeds: MintConfigSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("mint_config"),
+            seeds.collection.toBuffer(),
+        ],
+        programId,
+    )
+};
+
+export type MintReceiptSeeds = {
+    mint: PublicKey, 
+};
+
+export const deriveMintReceiptPDA = (
+    seeds: MintReceiptSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("mint_receipt"),
+            seeds.mint.toBuffer(),
+        ],
+        programId,
+    )
+};
+
+export type RentalOfferSeeds = {
+    mint: PublicKey, 
+    owner: PublicKey, 
+};
+
+export const deriveRentalOfferPDA = (
+    seeds: RentalOfferSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("rental"),
+            seeds.mint.toBuffer(),
+            seeds.owner.toBuffer(),
+        ],
+        programId,
+    )
+};
+
+export type StakePoolSeeds = {
+    collection: PublicKey, 
+};
+
+export const deriveStakePoolPDA = (
+    seeds: StakePoolSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("stake_pool"),
+            seeds.collection.toBuffer(),
+        ],
+        programId,
+    )
+};
+
+export type StakeEntrySeeds = {
+    mint: PublicKey, 
+};
+
+export const deriveStakeEntryPDA = (
+    seeds: StakeEntrySeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("stake_entry"),
+            seeds.mint.toBuffer(),
+        ],
+        programId,
+    )
+};
+
+export type VaultSeeds = {
+    mint: PublicKey, 
+};
+
+export const deriveVaultPDA = (
+    seeds: VaultSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("vault"),
+            seeds.mint.toBuffer(),
+        ],
+        programId,
+    )
+};
+
+export type ShareMintSeeds = {
+    vault: PublicKey, 
+};
+
+export const deriveShareMintPDA = (
+    seeds: ShareMintSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("shares"),
+            seeds.vault.toBuffer(),
+        ],
+        programId,
+    )
+};
+
+export type LoanSeeds = {
+    lender: PublicKey, 
+    offerId: BN, 
+};
+
+export const deriveLoanPDA = (
+    seeds: LoanSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("loan"),
+            seeds.lender.toBuffer(),
+            seeds.offerId.toArrayLike(Buffer, "le", 8),
+        ],
+        programId,
+    )
+};
+
+export type RuleSetSeeds = {
+    collection: PublicKey, 
+};
+
+export const deriveRuleSetPDA = (
+    seeds: RuleSetSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("rule_set"),
+            seeds.collection.toBuffer(),
+        ],
+        programId,
+    )
+};
+
+export const deriveProgramConfigPDA = (
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("program_config"),
+        ],
+        programId,
+    )
+};
+
+export const deriveTreasuryPDA = (
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("treasury"),
+        ],
+        programId,
+    )
+};
+
+export type MultisigSeeds = {
+    creator: PublicKey, 
+    nonce: BN, 
+};
+
+export const deriveMultisigPDA = (
+    seeds: MultisigSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("multisig"),
+            seeds.creator.toBuffer(),
+            seeds.nonce.toArrayLike(Buffer, "le", 8),
+        ],
+        programId,
+    )
+};
+
+export type ProposalSeeds = {
+    multisig: PublicKey, 
+    index: BN, 
+};
+
+export const deriveProposalPDA = (
+    seeds: ProposalSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("proposal"),
+            seeds.multisig.toBuffer(),
+            seeds.index.toArrayLike(Buffer, "le", 8),
+        ],
+        programId,
+    )
+};
+
+export type MetadataHistorySeeds = {
+    mint: PublicKey, 
+};
+
+export const deriveMetadataHistoryPDA = (
+    seeds: MetadataHistorySeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("history"),
+            seeds.mint.toBuffer(),
+        ],
+        programId,
+    )
+};
+
+export type ItemIndexSeeds = {
+    collection: PublicKey, 
+    itemNumber: BN, 
+};
+
+export const deriveItemIndexPDA = (
+    seeds: ItemIndexSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("item"),
+            seeds.collection.toBuffer(),
+            seeds.itemNumber.toArrayLike(Buffer, "le", 8),
+        ],
+        programId,
+    )
+};
+
+export type BidRefundSeeds = {
+    auction: PublicKey, 
+    bidder: PublicKey, 
+};
+
+export const deriveBidRefundPDA = (
+    seeds: BidRefundSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("bid_refund"),
+            seeds.auction.toBuffer(),
+            seeds.bidder.toBuffer(),
+        ],
+        programId,
+    )
+};
+
 export module CslSplTokenPDAs {
     export type AccountSeeds = {
         wallet: PublicKey, 
@@ -0,0 +1,83 @@
+import { createHash } from "crypto";
+
+export type RevealMetadata = {
+    name: string,
+    uri: string,
+    contentHash: number[] | null,
+};
+
+/**
+ * Leaf committing to the final metadata of the NFT minted at `index`, as
+ * computed by the program.
+ */
+export const revealLeaf = (
+    index: number | bigint,
+    metadata: RevealMetadata
+): Buffer => {
+    const indexBuffer = Buffer.alloc(8);
+    indexBuffer.writeBigUInt64LE(BigInt(index));
+    const name = Buffer.from(metadata.name, "utf8");
+    const nameLength = Buffer.alloc(4);
+    nameLength.writeUInt32LE(name.length);
+    const uri = Buffer.from(metadata.uri, "utf8");
+    const uriLength = Buffer.alloc(4);
+    uriLength.writeUInt32LE(uri.length);
+    const contentHash = Buffer.from(metadata.contentHash ?? []);
+
+    return createHash("sha256")
+        .update(Buffer.from([0]))
+        .update(indexBuffer)
+        .update(nameLength)
+        .update(name)
+        .update(uriLength)
+        .update(uri)
+        .update(Buffer.from([metadata.contentHash ? 1 : 0]))
+        .update(contentHash)
+        .digest();
+};
+
+const hashPair = (a: Buffer, b: Buffer): Buffer => {
+    const [left, right] = Buffer.compare(a, b) <= 0 ? [a, b] : [b, a];
+    return createHash("sha256")
+        .update(Buffer.from([1]))
+        .update(left)
+        .update(right)
+        .digest();
+};
+
+/**
+ * Builds the reveal commitment of a placeholder launch from the final
+ * metadata, in mint order: `root` is passed to `initializeCollection` and
+ * `proofs[i]` to `reveal` for the NFT minted at index `i`.
+ */
+export const buildRevealTree = (
+    items: RevealMetadata[]
+): { root: number[], proofs: number[][][] } => {
+    if (items.length === 0) {
+        throw new Error("The reveal list is empty");
+    }
+
+    let level = items.map((item, index) => revealLeaf(index, item));
+    const positions = items.map((_, index) => index);
+    const proofs: Buffer[][] = items.map(() => []);
+    while (level.length > 1) {
+        const next: Buffer[] = [];
+        for (let i = 0; i < level.length; i += 2) {
+            // An unpaired node moves up unchanged.
+            next.push(i + 1 < level.length ? hashPair(level[i], level[i + 1]) : level[i]);
+        }
+        positions.forEach((position, item) => {
+            const sibling = position ^ 1;
+            if (sibling < level.length) {
+                proofs[item].push(level[sibling]);
+            }
+            positions[item] = position >> 1;
+        });
+        level = next;
+    }
+
+    return {
+        root: [...level[0]],
+        proofs: proofs.map((proof) => proof.map((node) => [...node])),
+    };
+};
@@ -2,6 +2,7 @@ import {
   AnchorProvider,
   BN,
   IdlAccounts,
+  IdlTypes,
   Program,
   web3,
 } from "@coral-xyz/anchor";
@@ -52,6 +53,8 @@ export type InitializeCollectionArgs = {
   name: string;
   symbol: string;
   uri: string;
+  contentHash: number[] | null;
+  revealRoot: number[] | null;
 };
 
 /**
@@ -68,6 +71,8 @@ export type InitializeCollectionArgs = {
  * - name: {@link string} Name of the collection
  * - symbol: {@link string} Symbol of the collection
  * - uri: {@link string} URI to the collection metadata
+ * - content_hash: {@link number[] | null} SHA-256 hash of the collection metadata JSON, if committed
+ * - reveal_root: {@link number[] | null} Merkle root of the final NFT metadata for a placeholder launch, revealed later with `reveal`
  */
 export const initializeCollectionBuilder = (
 	args: InitializeCollectionArgs,
@@ -83,6 +88,8 @@ export const initializeCollectionBuilder = (
       args.name,
       args.symbol,
       args.uri,
+      args.contentHash,
+      args.revealRoot,
     )
     .accountsStrict({
       feePayer: args.feePayer,
@@ -107,6 +114,8 @@ export const initializeCollectionBuilder = (
  * - name: {@link string} Name of the collection
  * - symbol: {@link string} Symbol of the collection
  * - uri: {@link string} URI to the collection metadata
+ * - content_hash: {@link number[] | null} SHA-256 hash of the collection metadata JSON, if committed
+ * - reveal_root: {@link number[] | null} Merkle root of the final NFT metadata for a placeholder launch, revealed later with `reveal`
  */
 export const initializeCollection = (
 	args: InitializeCollectionArgs,
@@ -128,6 +137,8 @@ export const initializeCollection = (
  * - name: {@link string} Name of the collection
  * - symbol: {@link string} Symbol of the collection
  * - uri: {@link string} URI to the collection metadata
+ * - content_hash: {@link number[] | null} SHA-256 hash of the collection metadata JSON, if committed
+ * - reveal_root: {@link number[] | null} Merkle root of the final NFT metadata for a placeholder launch, revealed later with `reveal`
  */
 export const initializeCollectionSendAndConfirm = async (
   args: Omit<InitializeCollectionArgs, "feePayer" | "authority"> & {
@@ -158,9 +169,13 @@ export type MintNftArgs = {
   owner: web3.PublicKey;
   funding: web3.PublicKey;
   wallet: web3.PublicKey;
+  itemIndex: web3.PublicKey;
+  seedAuthority: web3.PublicKey;
+  withReceipt?: boolean;
   name: string;
   symbol: string;
   uri: string;
+  contentHash: number[] | null;
 };
 
 /**
@@ -180,19 +195,26 @@ export type MintNftArgs = {
  * 9. `[]` wallet: {@link PublicKey} Wallet address for the new associated token account
  * 10. `[]` token_program: {@link PublicKey} SPL Token program
  * 11. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
- * 12. `[]` csl_spl_assoc_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplAssocTokenProgram v0.0.0
+ * 12. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 13. `[writable]` mint_config: {@link MintConfig} The collection's mint pricing; may be uninitialized for free mints
+ * 14. `[writable]` mint_receipt: {@link MintReceipt} (optional) Records the price paid; required when the collection has mint pricing
+ * 15. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 16. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ * 17. `[writable]` treasury: {@link PublicKey} The fee treasury, credited with the protocol fee once the program config exists
+ * 18. `[writable]` item_index: {@link ItemIndex} The collection's item index entry for the new NFT, at the collection's current nft_count
  *
  * Data:
  * - name: {@link string} Name of the NFT
  * - symbol: {@link string} Symbol of the NFT
- * - uri: {@link string} URI to the NFT metadata
+ * - uri: {@link string} URI to the NFT metadata, or its suffix when the collection has a base URI
+ * - content_hash: {@link number[] | null} SHA-256 hash of the NFT metadata JSON, if committed
  */
 export const mintNftBuilder = (
 	args: MintNftArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): MethodsBuilder<MetaplexNftProgram, never> => {
     const [collectionPubkey] = pda.deriveCollectionPDA({
-        authority: args.authority,
+        authority: args.seedAuthority,
     }, _program.programId);
     const [metadataPubkey] = pda.deriveNftMetadataPDA({
         mint: args.mint,
@@ -202,6 +224,15 @@ export const mintNftBuilder = (
         tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
         mint: args.mint,
     }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [mintConfigPubkey] = pda.deriveMintConfigPDA({
+        collection: collectionPubkey,
+    }, _program.programId);
+    const [mintReceiptPubkey] = pda.deriveMintReceiptPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [freezeAuthorityPubkey] = pda.deriveFreezeAuthorityPDA(_program.programId);
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+    const [treasuryPubkey] = pda.deriveTreasuryPDA(_program.programId);
 
   return _program
     .methods
@@ -209,6 +240,7 @@ export const mintNftBuilder = (
       args.name,
       args.symbol,
       args.uri,
+      args.contentHash,
     )
     .accountsStrict({
       feePayer: args.feePayer,
@@ -223,7 +255,13 @@ export const mintNftBuilder = (
       wallet: args.wallet,
       tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
       cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
-      cslSplAssocTokenV000: new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+      associatedTokenProgram: new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+      mintConfig: mintConfigPubkey,
+      mintReceipt: args.withReceipt ? mintReceiptPubkey : null,
+      freezeAuthority: freezeAuthorityPubkey,
+      programConfig: programConfigPubkey,
+      treasury: treasuryPubkey,
+      itemIndex: args.itemIndex,
     })
     .remainingAccounts(remainingAccounts);
 };
@@ -245,12 +283,19 @@ export const mintNftBuilder = (
  * 9. `[]` wallet: {@link PublicKey} Wallet address for the new associated token account
  * 10. `[]` token_program: {@link PublicKey} SPL Token program
  * 11. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
- * 12. `[]` csl_spl_assoc_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplAssocTokenProgram v0.0.0
+ * 12. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 13. `[writable]` mint_config: {@link MintConfig} The collection's mint pricing; may be uninitialized for free mints
+ * 14. `[writable]` mint_receipt: {@link MintReceipt} (optional) Records the price paid; required when the collection has mint pricing
+ * 15. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 16. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ * 17. `[writable]` treasury: {@link PublicKey} The fee treasury, credited with the protocol fee once the program config exists
+ * 18. `[writable]` item_index: {@link ItemIndex} The collection's item index entry for the new NFT, at the collection's current nft_count
  *
  * Data:
  * - name: {@link string} Name of the NFT
  * - symbol: {@link string} Symbol of the NFT
- * - uri: {@link string} URI to the NFT metadata
+ * - uri: {@link string} URI to the NFT metadata, or its suffix when the collection has a base URI
+ * - content_hash: {@link number[] | null} SHA-256 hash of the NFT metadata JSON, if committed
  */
 export const mintNft = (
 	args: MintNftArgs,
@@ -275,12 +320,19 @@ export const mintNft = (
  * 9. `[]` wallet: {@link PublicKey} Wallet address for the new associated token account
  * 10. `[]` token_program: {@link PublicKey} SPL Token program
  * 11. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
- * 12. `[]` csl_spl_assoc_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplAssocTokenProgram v0.0.0
+ * 12. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 13. `[writable]` mint_config: {@link MintConfig} The collection's mint pricing; may be uninitialized for free mints
+ * 14. `[writable]` mint_receipt: {@link MintReceipt} (optional) Records the price paid; required when the collection has mint pricing
+ * 15. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 16. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ * 17. `[writable]` treasury: {@link PublicKey} The fee treasury, credited with the protocol fee once the program config exists
+ * 18. `[writable]` item_index: {@link ItemIndex} The collection's item index entry for the new NFT, at the collection's current nft_count
  *
  * Data:
  * - name: {@link string} Name of the NFT
  * - symbol: {@link string} Symbol of the NFT
- * - uri: {@link string} URI to the NFT metadata
+ * - uri: {@link string} URI to the NFT metadata, or its suffix when the collection has a base URI
+ * - content_hash: {@link number[] | null} SHA-256 hash of the NFT metadata JSON, if committed
  */
 export const mintNftSendAndConfirm = async (
   args: Omit<MintNftArgs, "feePayer" | "mint" | "authority" | "owner" | "funding"> & {
@@ -314,8 +366,10 @@ export type UpdateNftMetadataArgs = {
   feePayer: web3.PublicKey;
   mint: web3.PublicKey;
   authority: web3.PublicKey;
+  seedAuthority: web3.PublicKey;
   name: string;
   uri: string;
+  contentHash: number[] | null;
 };
 
 /**
@@ -328,27 +382,36 @@ export type UpdateNftMetadataArgs = {
  * 2. `[writable]` metadata: {@link NftMint} The metadata account to update
  * 3. `[]` mint: {@link Mint} The mint account of the NFT
  * 4. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 5. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ * 6. `[writable]` history: {@link MetadataHistory} The NFT's revision history, created if needed
+ * 7. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
  *
  * Data:
  * - name: {@link string} New name of the NFT
- * - uri: {@link string} New URI to the NFT metadata
+ * - uri: {@link string} New URI to the NFT metadata, or its suffix when the collection has a base URI
+ * - content_hash: {@link number[] | null} SHA-256 hash of the new NFT metadata JSON, if committed
  */
 export const updateNftMetadataBuilder = (
 	args: UpdateNftMetadataArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): MethodsBuilder<MetaplexNftProgram, never> => {
     const [collectionPubkey] = pda.deriveCollectionPDA({
-        authority: args.authority,
+        authority: args.seedAuthority,
     }, _program.programId);
     const [metadataPubkey] = pda.deriveNftMetadataPDA({
         mint: args.mint,
     }, _program.programId);
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+    const [historyPubkey] = pda.deriveMetadataHistoryPDA({
+        mint: args.mint,
+    }, _program.programId);
 
   return _program
     .methods
     .updateNftMetadata(
       args.name,
       args.uri,
+      args.contentHash,
     )
     .accountsStrict({
       feePayer: args.feePayer,
@@ -356,6 +419,9 @@ export const updateNftMetadataBuilder = (
       metadata: metadataPubkey,
       mint: args.mint,
       authority: args.authority,
+      programConfig: programConfigPubkey,
+      history: historyPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
     })
     .remainingAccounts(remainingAccounts);
 };
@@ -370,10 +436,14 @@ export const updateNftMetadataBuilder = (
  * 2. `[writable]` metadata: {@link NftMint} The metadata account to update
  * 3. `[]` mint: {@link Mint} The mint account of the NFT
  * 4. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 5. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ * 6. `[writable]` history: {@link MetadataHistory} The NFT's revision history, created if needed
+ * 7. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
  *
  * Data:
  * - name: {@link string} New name of the NFT
- * - uri: {@link string} New URI to the NFT metadata
+ * - uri: {@link string} New URI to the NFT metadata, or its suffix when the collection has a base URI
+ * - content_hash: {@link number[] | null} SHA-256 hash of the new NFT metadata JSON, if committed
  */
 export const updateNftMetadata = (
 	args: UpdateNftMetadataArgs,
@@ -391,10 +461,14 @@ export const updateNftMetadata = (
  * 2. `[writable]` metadata: {@link NftMint} The metadata account to update
  * 3. `[]` mint: {@link Mint} The mint account of the NFT
  * 4. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 5. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ * 6. `[writable]` history: {@link MetadataHistory} The NFT's revision history, created if needed
+ * 7. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
  *
  * Data:
  * - name: {@link string} New name of the NFT
- * - uri: {@link string} New URI to the NFT metadata
+ * - uri: {@link string} New URI to the NFT metadata, or its suffix when the collection has a base URI
+ * - content_hash: {@link number[] | null} SHA-256 hash of the new NFT metadata JSON, if committed
  */
 export const updateNftMetadataSendAndConfirm = async (
   args: Omit<UpdateNftMetadataArgs, "feePayer" | "authority"> & {
@@ -428,6 +502,8 @@ export type TransferNftArgs = {
   source: web3.PublicKey;
   destination: web3.PublicKey;
   authority: web3.PublicKey;
+  collection: web3.PublicKey;
+  price: BN;
 };
 
 /**
@@ -438,18 +514,29 @@ export type TransferNftArgs = {
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
  * 1. `[]` mint: {@link Mint} The mint account of the NFT
  * 2. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
- * 3. `[signer]` owner: {@link PublicKey} The current owner of the NFT
- * 4. `[]` new_owner: {@link PublicKey} The new owner of the NFT
+ * 3. `[]` owner: {@link PublicKey} The current owner of the NFT
+ * 4. `[]` new_owner: {@link PublicKey} The new owner of the NFT; must sign zero-price transfers of royalty-enforced NFTs
  * 5. `[writable, signer]` funding: {@link PublicKey} Funding account (must be a system account)
  * 6. `[writable]` assoc_token_account: {@link PublicKey} Associated token account address to be created
  * 7. `[]` wallet: {@link PublicKey} Wallet address for the new associated token account
  * 8. `[]` system_program: {@link PublicKey} System program
  * 9. `[]` token_program: {@link PublicKey} SPL Token program
- * 10. `[writable]` source: {@link PublicKey} The source account.
- * 11. `[writable]` destination: {@link PublicKey} The destination account.
- * 12. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
- * 13. `[]` csl_spl_assoc_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplAssocTokenProgram v0.0.0
+ * 10. `[writable]` source: {@link TokenAccount} The source account.
+ * 11. `[writable]` destination: {@link TokenAccount} The destination account.
+ * 12. `[signer]` authority: {@link PublicKey} The owner of the NFT, or its approved delegate
+ * 13. `[]` associated_token_program: {@link PublicKey} Associated Token program
  * 14. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 15. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 16. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 17. `[]` rule_set: {@link PublicKey} The rule set of the NFT's collection
+ * 18. `[]` instructions: {@link PublicKey} Instructions sysvar, to find the calling program
+ * 19. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Remaining accounts:
+ * - `[writable]` creators: the collection creators, in the order stored on the collection; required when a price is declared for a royalty-enforced collection
+ *
+ * Data:
+ * - price: {@link BN} Sale price in lamports the transfer settles, or 0 for a gift or a move between own wallets co-signed by the new owner; royalty-enforced collections charge the royalty on it to the funding account
  */
 export const transferNftBuilder = (
 	args: TransferNftArgs,
@@ -463,11 +550,16 @@ export const transferNftBuilder = (
         tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
         mint: args.mint,
     }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [freezeAuthorityPubkey] = pda.deriveFreezeAuthorityPDA(_program.programId);
+    const [ruleSetPubkey] = pda.deriveRuleSetPDA({
+        collection: args.collection,
+    }, _program.programId);
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
 
   return _program
     .methods
     .transferNft(
-
+      args.price,
     )
     .accountsStrict({
       feePayer: args.feePayer,
@@ -483,8 +575,13 @@ export const transferNftBuilder = (
       source: args.source,
       destination: args.destination,
       authority: args.authority,
-      cslSplAssocTokenV000: new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+      associatedTokenProgram: new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
       cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      collection: args.collection,
+      freezeAuthority: freezeAuthorityPubkey,
+      ruleSet: ruleSetPubkey,
+      instructions: new web3.PublicKey("Sysvar1nstructions1111111111111111111111111"),
+      programConfig: programConfigPubkey,
     })
     .remainingAccounts(remainingAccounts);
 };
@@ -497,18 +594,29 @@ export const transferNftBuilder = (
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
  * 1. `[]` mint: {@link Mint} The mint account of the NFT
  * 2. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
- * 3. `[signer]` owner: {@link PublicKey} The current owner of the NFT
- * 4. `[]` new_owner: {@link PublicKey} The new owner of the NFT
+ * 3. `[]` owner: {@link PublicKey} The current owner of the NFT
+ * 4. `[]` new_owner: {@link PublicKey} The new owner of the NFT; must sign zero-price transfers of royalty-enforced NFTs
  * 5. `[writable, signer]` funding: {@link PublicKey} Funding account (must be a system account)
  * 6. `[writable]` assoc_token_account: {@link PublicKey} Associated token account address to be created
  * 7. `[]` wallet: {@link PublicKey} Wallet address for the new associated token account
  * 8. `[]` system_program: {@link PublicKey} System program
  * 9. `[]` token_program: {@link PublicKey} SPL Token program
- * 10. `[writable]` source: {@link PublicKey} The source account.
- * 11. `[writable]` destination: {@link PublicKey} The destination account.
- * 12. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
- * 13. `[]` csl_spl_assoc_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplAssocTokenProgram v0.0.0
+ * 10. `[writable]` source: {@link TokenAccount} The source account.
+ * 11. `[writable]` destination: {@link TokenAccount} The destination account.
+ * 12. `[signer]` authority: {@link PublicKey} The owner of the NFT, or its approved delegate
+ * 13. `[]` associated_token_program: {@link PublicKey} Associated Token program
  * 14. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 15. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 16. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 17. `[]` rule_set: {@link PublicKey} The rule set of the NFT's collection
+ * 18. `[]` instructions: {@link PublicKey} Instructions sysvar, to find the calling program
+ * 19. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Remaining accounts:
+ * - `[writable]` creators: the collection creators, in the order stored on the collection; required when a price is declared for a royalty-enforced collection
+ *
+ * Data:
+ * - price: {@link BN} Sale price in lamports the transfer settles, or 0 for a gift or a move between own wallets co-signed by the new owner; royalty-enforced collections charge the royalty on it to the funding account
  */
 export const transferNft = (
 	args: TransferNftArgs,
@@ -524,24 +632,34 @@ export const transferNft = (
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
  * 1. `[]` mint: {@link Mint} The mint account of the NFT
  * 2. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
- * 3. `[signer]` owner: {@link PublicKey} The current owner of the NFT
- * 4. `[]` new_owner: {@link PublicKey} The new owner of the NFT
+ * 3. `[]` owner: {@link PublicKey} The current owner of the NFT
+ * 4. `[]` new_owner: {@link PublicKey} The new owner of the NFT; must sign zero-price transfers of royalty-enforced NFTs
  * 5. `[writable, signer]` funding: {@link PublicKey} Funding account (must be a system account)
  * 6. `[writable]` assoc_token_account: {@link PublicKey} Associated token account address to be created
  * 7. `[]` wallet: {@link PublicKey} Wallet address for the new associated token account
  * 8. `[]` system_program: {@link PublicKey} System program
  * 9. `[]` token_program: {@link PublicKey} SPL Token program
- * 10. `[writable]` source: {@link PublicKey} The source account.
- * 11. `[writable]` destination: {@link PublicKey} The destination account.
- * 12. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
- * 13. `[]` csl_spl_assoc_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplAssocTokenProgram v0.0.0
+ * 10. `[writable]` source: {@link TokenAccount} The source account.
+ * 11. `[writable]` destination: {@link TokenAccount} The destination account.
+ * 12. `[signer]` authority: {@link PublicKey} The owner of the NFT, or its approved delegate
+ * 13. `[]` associated_token_program: {@link PublicKey} Associated Token program
  * 14. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 15. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 16. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 17. `[]` rule_set: {@link PublicKey} The rule set of the NFT's collection
+ * 18. `[]` instructions: {@link PublicKey} Instructions sysvar, to find the calling program
+ * 19. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Remaining accounts:
+ * - `[writable]` creators: the collection creators, in the order stored on the collection; required when a price is declared for a royalty-enforced collection
+ *
+ * Data:
+ * - price: {@link BN} Sale price in lamports the transfer settles, or 0 for a gift or a move between own wallets co-signed by the new owner; royalty-enforced collections charge the royalty on it to the funding account
  */
 export const transferNftSendAndConfirm = async (
-  args: Omit<TransferNftArgs, "feePayer" | "owner" | "funding" | "authority"> & {
+  args: Omit<TransferNftArgs, "feePayer" | "funding" | "authority"> & {
     signers: {
       feePayer: web3.Signer,
-      owner: web3.Signer,
       funding: web3.Signer,
       authority: web3.Signer,
     },
@@ -554,26 +672,6733 @@ export const transferNftSendAndConfirm = async (
   return transferNftBuilder({
       ...args,
       feePayer: args.signers.feePayer.publicKey,
-      owner: args.signers.owner.publicKey,
       funding: args.signers.funding.publicKey,
       authority: args.signers.authority.publicKey,
     }, remainingAccounts)
     .preInstructions(preInstructions)
-    .signers([args.signers.feePayer, args.signers.owner, args.signers.funding, args.signers.authority])
+    .signers([args.signers.feePayer, args.signers.funding, args.signers.authority])
     .rpc();
 }
 
-// Getters
+export type MintBatchArgs = {
+  feePayer: web3.PublicKey;
+  authority: web3.PublicKey;
+  seedAuthority: web3.PublicKey;
+  entries: IdlTypes<MetaplexNftProgram>["mintBatchEntry"][];
+};
 
-export const getNftCollection = (
-    publicKey: web3.PublicKey,
-    commitment?: web3.Commitment
-): Promise<IdlAccounts<MetaplexNftProgram>["nftCollection"]> => _program.account.nftCollection.fetch(publicKey, commitment);
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Mints several NFTs to a collection in a single instruction
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to mint the NFTs to
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 5. `[]` token_program: {@link PublicKey} SPL Token program
+ * 6. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 7. `[]` csl_spl_assoc_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplAssocTokenProgram v0.0.0
+ * 8. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ * 9. `[writable]` treasury: {@link PublicKey} The fee treasury, credited with the protocol fee once the program config exists
+ * 10. `[writable]` mint_config: {@link MintConfig} The collection's mint pricing, whose supply cap the batch counts against; may be uninitialized for free mints
+ *
+ * Data:
+ * - entries: {@link MintBatchEntry[]} One entry per NFT; each consumes mint, metadata, assoc_token_account, wallet and item_index from the remaining accounts, in that order
+ */
+export const mintBatchBuilder = (
+	args: MintBatchArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [collectionPubkey] = pda.deriveCollectionPDA({
+        authority: args.seedAuthority,
+    }, _program.programId);
+    const [freezeAuthorityPubkey] = pda.deriveFreezeAuthorityPDA(_program.programId);
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+    const [treasuryPubkey] = pda.deriveTreasuryPDA(_program.programId);
+    const [mintConfigPubkey] = pda.deriveMintConfigPDA({
+        collection: collectionPubkey,
+    }, _program.programId);
 
-export const getNftMint = (
-    publicKey: web3.PublicKey,
-    commitment?: web3.Commitment
-): Promise<IdlAccounts<MetaplexNftProgram>["nftMint"]> => _program.account.nftMint.fetch(publicKey, commitment);
+  return _program
+    .methods
+    .mintBatch(
+      args.entries,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      collection: collectionPubkey,
+      authority: args.authority,
+      freezeAuthority: freezeAuthorityPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplAssocTokenV000: new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+      programConfig: programConfigPubkey,
+      treasury: treasuryPubkey,
+      mintConfig: mintConfigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Mints several NFTs to a collection in a single instruction
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to mint the NFTs to
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 5. `[]` token_program: {@link PublicKey} SPL Token program
+ * 6. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 7. `[]` csl_spl_assoc_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplAssocTokenProgram v0.0.0
+ * 8. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ * 9. `[writable]` treasury: {@link PublicKey} The fee treasury, credited with the protocol fee once the program config exists
+ * 10. `[writable]` mint_config: {@link MintConfig} The collection's mint pricing, whose supply cap the batch counts against; may be uninitialized for free mints
+ *
+ * Data:
+ * - entries: {@link MintBatchEntry[]} One entry per NFT; each consumes mint, metadata, assoc_token_account, wallet and item_index from the remaining accounts, in that order
+ */
+export const mintBatch = (
+	args: MintBatchArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    mintBatchBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Mints several NFTs to a collection in a single instruction
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to mint the NFTs to
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 5. `[]` token_program: {@link PublicKey} SPL Token program
+ * 6. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 7. `[]` csl_spl_assoc_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplAssocTokenProgram v0.0.0
+ * 8. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ * 9. `[writable]` treasury: {@link PublicKey} The fee treasury, credited with the protocol fee once the program config exists
+ * 10. `[writable]` mint_config: {@link MintConfig} The collection's mint pricing, whose supply cap the batch counts against; may be uninitialized for free mints
+ *
+ * Data:
+ * - entries: {@link MintBatchEntry[]} One entry per NFT; each consumes mint, metadata, assoc_token_account, wallet and item_index from the remaining accounts, in that order
+ */
+export const mintBatchSendAndConfirm = async (
+  args: Omit<MintBatchArgs, "feePayer" | "authority"> & {
+    signers: {
+      feePayer: web3.Signer,
+      authority: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return mintBatchBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      authority: args.signers.authority.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.authority])
+    .rpc();
+}
+
+export type SetCollectionRoyaltiesArgs = {
+  feePayer: web3.PublicKey;
+  authority: web3.PublicKey;
+  seedAuthority: web3.PublicKey;
+  sellerFeeBasisPoints: number;
+  creators: IdlTypes<MetaplexNftProgram>["collectionCreator"][];
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Sets the royalty paid to the collection creators on marketplace sales
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to configure
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ *
+ * Data:
+ * - seller_fee_basis_points: {@link number} Royalty in basis points of the sale price
+ * - creators: {@link CollectionCreator[]} Creators sharing the royalty; shares must add up to 100
+ */
+export const setCollectionRoyaltiesBuilder = (
+	args: SetCollectionRoyaltiesArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [collectionPubkey] = pda.deriveCollectionPDA({
+        authority: args.seedAuthority,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .setCollectionRoyalties(
+      args.sellerFeeBasisPoints,
+      args.creators,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      collection: collectionPubkey,
+      authority: args.authority,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Sets the royalty paid to the collection creators on marketplace sales
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to configure
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ *
+ * Data:
+ * - seller_fee_basis_points: {@link number} Royalty in basis points of the sale price
+ * - creators: {@link CollectionCreator[]} Creators sharing the royalty; shares must add up to 100
+ */
+export const setCollectionRoyalties = (
+	args: SetCollectionRoyaltiesArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    setCollectionRoyaltiesBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Sets the royalty paid to the collection creators on marketplace sales
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to configure
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ *
+ * Data:
+ * - seller_fee_basis_points: {@link number} Royalty in basis points of the sale price
+ * - creators: {@link CollectionCreator[]} Creators sharing the royalty; shares must add up to 100
+ */
+export const setCollectionRoyaltiesSendAndConfirm = async (
+  args: Omit<SetCollectionRoyaltiesArgs, "feePayer" | "authority"> & {
+    signers: {
+      feePayer: web3.Signer,
+      authority: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return setCollectionRoyaltiesBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      authority: args.signers.authority.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.authority])
+    .rpc();
+}
+
+export type ListNftArgs = {
+  feePayer: web3.PublicKey;
+  seller: web3.PublicKey;
+  mint: web3.PublicKey;
+  collection: web3.PublicKey;
+  price: BN;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Lists an NFT for sale at a fixed price, moving it into an escrow owned by the listing
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` seller: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` listing: {@link Listing} The listing account to initialize
+ * 6. `[writable]` seller_token_account: {@link TokenAccount} The seller's token account holding the NFT
+ * 7. `[writable]` escrow_token_account: {@link TokenAccount} Escrow token account owned by the listing
+ * 8. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 9. `[]` system_program: {@link PublicKey} System program
+ * 10. `[]` token_program: {@link PublicKey} SPL Token program
+ * 11. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 12. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 13. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - price: {@link BN} Sale price in lamports
+ */
+export const listNftBuilder = (
+	args: ListNftArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [metadataPubkey] = pda.deriveNftMetadataPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [listingPubkey] = pda.deriveListingPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [sellerTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.seller,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [escrowTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: listingPubkey,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [freezeAuthorityPubkey] = pda.deriveFreezeAuthorityPDA(_program.programId);
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .listNft(
+      args.price,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      seller: args.seller,
+      mint: args.mint,
+      metadata: metadataPubkey,
+      collection: args.collection,
+      listing: listingPubkey,
+      sellerTokenAccount: sellerTokenAccountPubkey,
+      escrowTokenAccount: escrowTokenAccountPubkey,
+      freezeAuthority: freezeAuthorityPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      associatedTokenProgram: new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+      programConfig: programConfigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Lists an NFT for sale at a fixed price, moving it into an escrow owned by the listing
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` seller: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` listing: {@link Listing} The listing account to initialize
+ * 6. `[writable]` seller_token_account: {@link TokenAccount} The seller's token account holding the NFT
+ * 7. `[writable]` escrow_token_account: {@link TokenAccount} Escrow token account owned by the listing
+ * 8. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 9. `[]` system_program: {@link PublicKey} System program
+ * 10. `[]` token_program: {@link PublicKey} SPL Token program
+ * 11. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 12. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 13. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - price: {@link BN} Sale price in lamports
+ */
+export const listNft = (
+	args: ListNftArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    listNftBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Lists an NFT for sale at a fixed price, moving it into an escrow owned by the listing
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` seller: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` listing: {@link Listing} The listing account to initialize
+ * 6. `[writable]` seller_token_account: {@link TokenAccount} The seller's token account holding the NFT
+ * 7. `[writable]` escrow_token_account: {@link TokenAccount} Escrow token account owned by the listing
+ * 8. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 9. `[]` system_program: {@link PublicKey} System program
+ * 10. `[]` token_program: {@link PublicKey} SPL Token program
+ * 11. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 12. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 13. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - price: {@link BN} Sale price in lamports
+ */
+export const listNftSendAndConfirm = async (
+  args: Omit<ListNftArgs, "feePayer" | "seller"> & {
+    signers: {
+      feePayer: web3.Signer,
+      seller: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return listNftBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      seller: args.signers.seller.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.seller])
+    .rpc();
+}
+
+export type DelistNftArgs = {
+  feePayer: web3.PublicKey;
+  seller: web3.PublicKey;
+  mint: web3.PublicKey;
+  collection: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Cancels a listing and returns the escrowed NFT to the seller
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` seller: {@link PublicKey} The seller that created the listing
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` listing: {@link Listing} The listing to close
+ * 6. `[writable]` seller_token_account: {@link TokenAccount} The seller's token account
+ * 7. `[writable]` escrow_token_account: {@link TokenAccount} Escrow token account owned by the listing
+ * 8. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 9. `[]` token_program: {@link PublicKey} SPL Token program
+ * 10. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ */
+export const delistNftBuilder = (
+	args: DelistNftArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [metadataPubkey] = pda.deriveNftMetadataPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [listingPubkey] = pda.deriveListingPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [sellerTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.seller,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [escrowTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: listingPubkey,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [freezeAuthorityPubkey] = pda.deriveFreezeAuthorityPDA(_program.programId);
+
+  return _program
+    .methods
+    .delistNft(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      seller: args.seller,
+      mint: args.mint,
+      metadata: metadataPubkey,
+      collection: args.collection,
+      listing: listingPubkey,
+      sellerTokenAccount: sellerTokenAccountPubkey,
+      escrowTokenAccount: escrowTokenAccountPubkey,
+      freezeAuthority: freezeAuthorityPubkey,
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Cancels a listing and returns the escrowed NFT to the seller
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` seller: {@link PublicKey} The seller that created the listing
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` listing: {@link Listing} The listing to close
+ * 6. `[writable]` seller_token_account: {@link TokenAccount} The seller's token account
+ * 7. `[writable]` escrow_token_account: {@link TokenAccount} Escrow token account owned by the listing
+ * 8. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 9. `[]` token_program: {@link PublicKey} SPL Token program
+ * 10. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ */
+export const delistNft = (
+	args: DelistNftArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    delistNftBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Cancels a listing and returns the escrowed NFT to the seller
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` seller: {@link PublicKey} The seller that created the listing
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` listing: {@link Listing} The listing to close
+ * 6. `[writable]` seller_token_account: {@link TokenAccount} The seller's token account
+ * 7. `[writable]` escrow_token_account: {@link TokenAccount} Escrow token account owned by the listing
+ * 8. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 9. `[]` token_program: {@link PublicKey} SPL Token program
+ * 10. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ */
+export const delistNftSendAndConfirm = async (
+  args: Omit<DelistNftArgs, "feePayer" | "seller"> & {
+    signers: {
+      feePayer: web3.Signer,
+      seller: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return delistNftBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      seller: args.signers.seller.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.seller])
+    .rpc();
+}
+
+export type BuyNftArgs = {
+  feePayer: web3.PublicKey;
+  buyer: web3.PublicKey;
+  seller: web3.PublicKey;
+  mint: web3.PublicKey;
+  collection: web3.PublicKey;
+  listingTokenAccount: web3.PublicKey;
+  maxPrice: BN;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Buys a listed NFT, paying the seller and the collection royalties
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` buyer: {@link PublicKey} The buyer of the NFT
+ * 2. `[writable]` seller: {@link PublicKey} The seller recorded on the listing
+ * 3. `[]` mint: {@link Mint} The mint account of the NFT
+ * 4. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 5. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 6. `[writable]` listing: {@link Listing} The listing to fill
+ * 7. `[writable]` listing_token_account: {@link TokenAccount} The token account recorded on the listing: the escrow, or the seller's account for in-place listings
+ * 8. `[writable]` buyer_token_account: {@link TokenAccount} The buyer's token account, created if needed
+ * 9. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 10. `[]` system_program: {@link PublicKey} System program
+ * 11. `[]` token_program: {@link PublicKey} SPL Token program
+ * 12. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 13. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 14. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Remaining accounts:
+ * - `[writable]` creators: the collection creators, in the order stored on the collection
+ *
+ * Data:
+ * - max_price: {@link BN} Highest price in lamports the buyer accepts; guards against the seller raising the price
+ */
+export const buyNftBuilder = (
+	args: BuyNftArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [metadataPubkey] = pda.deriveNftMetadataPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [listingPubkey] = pda.deriveListingPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [buyerTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.buyer,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [freezeAuthorityPubkey] = pda.deriveFreezeAuthorityPDA(_program.programId);
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .buyNft(
+      args.maxPrice,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      buyer: args.buyer,
+      seller: args.seller,
+      mint: args.mint,
+      metadata: metadataPubkey,
+      collection: args.collection,
+      listing: listingPubkey,
+      listingTokenAccount: args.listingTokenAccount,
+      buyerTokenAccount: buyerTokenAccountPubkey,
+      freezeAuthority: freezeAuthorityPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      associatedTokenProgram: new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+      programConfig: programConfigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Buys a listed NFT, paying the seller and the collection royalties
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` buyer: {@link PublicKey} The buyer of the NFT
+ * 2. `[writable]` seller: {@link PublicKey} The seller recorded on the listing
+ * 3. `[]` mint: {@link Mint} The mint account of the NFT
+ * 4. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 5. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 6. `[writable]` listing: {@link Listing} The listing to fill
+ * 7. `[writable]` listing_token_account: {@link TokenAccount} The token account recorded on the listing: the escrow, or the seller's account for in-place listings
+ * 8. `[writable]` buyer_token_account: {@link TokenAccount} The buyer's token account, created if needed
+ * 9. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 10. `[]` system_program: {@link PublicKey} System program
+ * 11. `[]` token_program: {@link PublicKey} SPL Token program
+ * 12. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 13. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 14. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Remaining accounts:
+ * - `[writable]` creators: the collection creators, in the order stored on the collection
+ *
+ * Data:
+ * - max_price: {@link BN} Highest price in lamports the buyer accepts; guards against the seller raising the price
+ */
+export const buyNft = (
+	args: BuyNftArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    buyNftBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Buys a listed NFT, paying the seller and the collection royalties
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` buyer: {@link PublicKey} The buyer of the NFT
+ * 2. `[writable]` seller: {@link PublicKey} The seller recorded on the listing
+ * 3. `[]` mint: {@link Mint} The mint account of the NFT
+ * 4. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 5. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 6. `[writable]` listing: {@link Listing} The listing to fill
+ * 7. `[writable]` listing_token_account: {@link TokenAccount} The token account recorded on the listing: the escrow, or the seller's account for in-place listings
+ * 8. `[writable]` buyer_token_account: {@link TokenAccount} The buyer's token account, created if needed
+ * 9. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 10. `[]` system_program: {@link PublicKey} System program
+ * 11. `[]` token_program: {@link PublicKey} SPL Token program
+ * 12. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 13. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 14. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Remaining accounts:
+ * - `[writable]` creators: the collection creators, in the order stored on the collection
+ *
+ * Data:
+ * - max_price: {@link BN} Highest price in lamports the buyer accepts; guards against the seller raising the price
+ */
+export const buyNftSendAndConfirm = async (
+  args: Omit<BuyNftArgs, "feePayer" | "buyer"> & {
+    signers: {
+      feePayer: web3.Signer,
+      buyer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return buyNftBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      buyer: args.signers.buyer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.buyer])
+    .rpc();
+}
+
+export type ListNftInPlaceArgs = {
+  feePayer: web3.PublicKey;
+  seller: web3.PublicKey;
+  mint: web3.PublicKey;
+  collection: web3.PublicKey;
+  collection: web3.PublicKey;
+  price: BN;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Lists an NFT for sale without escrow: the listing becomes delegate of the seller's token account, which is frozen until the NFT is sold or delisted
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` seller: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT, marked as listed
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` listing: {@link Listing} The listing account to initialize
+ * 6. `[writable]` seller_token_account: {@link TokenAccount} The seller's token account holding the NFT
+ * 7. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 8. `[]` rule_set: {@link PublicKey} The rule set of the NFT's collection
+ * 9. `[]` instructions: {@link PublicKey} Instructions sysvar, to find the calling program
+ * 10. `[]` system_program: {@link PublicKey} System program
+ * 11. `[]` token_program: {@link PublicKey} SPL Token program
+ * 12. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 13. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - price: {@link BN} Sale price in lamports
+ */
+export const listNftInPlaceBuilder = (
+	args: ListNftInPlaceArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [metadataPubkey] = pda.deriveNftMetadataPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [listingPubkey] = pda.deriveListingPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [sellerTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.seller,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [freezeAuthorityPubkey] = pda.deriveFreezeAuthorityPDA(_program.programId);
+    const [ruleSetPubkey] = pda.deriveRuleSetPDA({
+        collection: args.collection,
+    }, _program.programId);
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .listNftInPlace(
+      args.price,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      seller: args.seller,
+      mint: args.mint,
+      metadata: metadataPubkey,
+      collection: args.collection,
+      listing: listingPubkey,
+      sellerTokenAccount: sellerTokenAccountPubkey,
+      freezeAuthority: freezeAuthorityPubkey,
+      ruleSet: ruleSetPubkey,
+      instructions: new web3.PublicKey("Sysvar1nstructions1111111111111111111111111"),
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      programConfig: programConfigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Lists an NFT for sale without escrow: the listing becomes delegate of the seller's token account, which is frozen until the NFT is sold or delisted
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` seller: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT, marked as listed
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` listing: {@link Listing} The listing account to initialize
+ * 6. `[writable]` seller_token_account: {@link TokenAccount} The seller's token account holding the NFT
+ * 7. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 8. `[]` rule_set: {@link PublicKey} The rule set of the NFT's collection
+ * 9. `[]` instructions: {@link PublicKey} Instructions sysvar, to find the calling program
+ * 10. `[]` system_program: {@link PublicKey} System program
+ * 11. `[]` token_program: {@link PublicKey} SPL Token program
+ * 12. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 13. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - price: {@link BN} Sale price in lamports
+ */
+export const listNftInPlace = (
+	args: ListNftInPlaceArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    listNftInPlaceBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Lists an NFT for sale without escrow: the listing becomes delegate of the seller's token account, which is frozen until the NFT is sold or delisted
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` seller: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT, marked as listed
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` listing: {@link Listing} The listing account to initialize
+ * 6. `[writable]` seller_token_account: {@link TokenAccount} The seller's token account holding the NFT
+ * 7. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 8. `[]` rule_set: {@link PublicKey} The rule set of the NFT's collection
+ * 9. `[]` instructions: {@link PublicKey} Instructions sysvar, to find the calling program
+ * 10. `[]` system_program: {@link PublicKey} System program
+ * 11. `[]` token_program: {@link PublicKey} SPL Token program
+ * 12. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 13. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - price: {@link BN} Sale price in lamports
+ */
+export const listNftInPlaceSendAndConfirm = async (
+  args: Omit<ListNftInPlaceArgs, "feePayer" | "seller"> & {
+    signers: {
+      feePayer: web3.Signer,
+      seller: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return listNftInPlaceBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      seller: args.signers.seller.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.seller])
+    .rpc();
+}
+
+export type DelistNftInPlaceArgs = {
+  feePayer: web3.PublicKey;
+  seller: web3.PublicKey;
+  mint: web3.PublicKey;
+  collection: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Cancels an in-place listing, thawing the seller's token account and revoking the listing delegate
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` seller: {@link PublicKey} The seller that created the listing
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT, no longer marked as listed
+ * 4. `[writable]` listing: {@link Listing} The listing to close
+ * 5. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 6. `[writable]` seller_token_account: {@link TokenAccount} The seller's token account
+ * 7. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 8. `[]` token_program: {@link PublicKey} SPL Token program
+ * 9. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ */
+export const delistNftInPlaceBuilder = (
+	args: DelistNftInPlaceArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [metadataPubkey] = pda.deriveNftMetadataPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [listingPubkey] = pda.deriveListingPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [sellerTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.seller,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [freezeAuthorityPubkey] = pda.deriveFreezeAuthorityPDA(_program.programId);
+
+  return _program
+    .methods
+    .delistNftInPlace(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      seller: args.seller,
+      mint: args.mint,
+      metadata: metadataPubkey,
+      listing: listingPubkey,
+      collection: args.collection,
+      sellerTokenAccount: sellerTokenAccountPubkey,
+      freezeAuthority: freezeAuthorityPubkey,
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Cancels an in-place listing, thawing the seller's token account and revoking the listing delegate
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` seller: {@link PublicKey} The seller that created the listing
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT, no longer marked as listed
+ * 4. `[writable]` listing: {@link Listing} The listing to close
+ * 5. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 6. `[writable]` seller_token_account: {@link TokenAccount} The seller's token account
+ * 7. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 8. `[]` token_program: {@link PublicKey} SPL Token program
+ * 9. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ */
+export const delistNftInPlace = (
+	args: DelistNftInPlaceArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    delistNftInPlaceBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Cancels an in-place listing, thawing the seller's token account and revoking the listing delegate
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` seller: {@link PublicKey} The seller that created the listing
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT, no longer marked as listed
+ * 4. `[writable]` listing: {@link Listing} The listing to close
+ * 5. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 6. `[writable]` seller_token_account: {@link TokenAccount} The seller's token account
+ * 7. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 8. `[]` token_program: {@link PublicKey} SPL Token program
+ * 9. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ */
+export const delistNftInPlaceSendAndConfirm = async (
+  args: Omit<DelistNftInPlaceArgs, "feePayer" | "seller"> & {
+    signers: {
+      feePayer: web3.Signer,
+      seller: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return delistNftInPlaceBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      seller: args.signers.seller.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.seller])
+    .rpc();
+}
+
+export type PlaceBidArgs = {
+  feePayer: web3.PublicKey;
+  bidder: web3.PublicKey;
+  collection: web3.PublicKey;
+  mint: web3.PublicKey | null;
+  amount: BN;
+  expiresAt: BN;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Places an offer on a specific NFT, or on any NFT of a collection, escrowing the offered lamports in the bid account
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` bidder: {@link PublicKey} The account making the offer
+ * 2. `[]` collection: {@link NftCollection} The collection the offer targets
+ * 3. `[writable]` bid: {@link Bid} The bid account to initialize
+ * 4. `[]` system_program: {@link PublicKey} System program
+ * 5. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - mint: {@link PublicKey | null} The NFT the offer is for, or none for any NFT of the collection
+ * - amount: {@link BN} Offered amount in lamports
+ * - expires_at: {@link BN} Unix timestamp after which the offer can no longer be accepted
+ */
+export const placeBidBuilder = (
+	args: PlaceBidArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [bidPubkey] = pda.deriveBidPDA({
+        bidder: args.bidder,
+        target: args.mint ?? args.collection,
+    }, _program.programId);
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .placeBid(
+      args.mint,
+      args.amount,
+      args.expiresAt,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      bidder: args.bidder,
+      collection: args.collection,
+      bid: bidPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+      programConfig: programConfigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Places an offer on a specific NFT, or on any NFT of a collection, escrowing the offered lamports in the bid account
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` bidder: {@link PublicKey} The account making the offer
+ * 2. `[]` collection: {@link NftCollection} The collection the offer targets
+ * 3. `[writable]` bid: {@link Bid} The bid account to initialize
+ * 4. `[]` system_program: {@link PublicKey} System program
+ * 5. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - mint: {@link PublicKey | null} The NFT the offer is for, or none for any NFT of the collection
+ * - amount: {@link BN} Offered amount in lamports
+ * - expires_at: {@link BN} Unix timestamp after which the offer can no longer be accepted
+ */
+export const placeBid = (
+	args: PlaceBidArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    placeBidBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Places an offer on a specific NFT, or on any NFT of a collection, escrowing the offered lamports in the bid account
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` bidder: {@link PublicKey} The account making the offer
+ * 2. `[]` collection: {@link NftCollection} The collection the offer targets
+ * 3. `[writable]` bid: {@link Bid} The bid account to initialize
+ * 4. `[]` system_program: {@link PublicKey} System program
+ * 5. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - mint: {@link PublicKey | null} The NFT the offer is for, or none for any NFT of the collection
+ * - amount: {@link BN} Offered amount in lamports
+ * - expires_at: {@link BN} Unix timestamp after which the offer can no longer be accepted
+ */
+export const placeBidSendAndConfirm = async (
+  args: Omit<PlaceBidArgs, "feePayer" | "bidder"> & {
+    signers: {
+      feePayer: web3.Signer,
+      bidder: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return placeBidBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      bidder: args.signers.bidder.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.bidder])
+    .rpc();
+}
+
+export type CancelBidArgs = {
+  feePayer: web3.PublicKey;
+  bidder: web3.PublicKey;
+  bid: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Cancels an offer and refunds the escrowed lamports to the bidder
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` bidder: {@link PublicKey} The account that made the offer
+ * 2. `[writable]` bid: {@link Bid} The bid to cancel
+ */
+export const cancelBidBuilder = (
+	args: CancelBidArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+
+  return _program
+    .methods
+    .cancelBid(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      bidder: args.bidder,
+      bid: args.bid,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Cancels an offer and refunds the escrowed lamports to the bidder
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` bidder: {@link PublicKey} The account that made the offer
+ * 2. `[writable]` bid: {@link Bid} The bid to cancel
+ */
+export const cancelBid = (
+	args: CancelBidArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    cancelBidBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Cancels an offer and refunds the escrowed lamports to the bidder
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` bidder: {@link PublicKey} The account that made the offer
+ * 2. `[writable]` bid: {@link Bid} The bid to cancel
+ */
+export const cancelBidSendAndConfirm = async (
+  args: Omit<CancelBidArgs, "feePayer" | "bidder"> & {
+    signers: {
+      feePayer: web3.Signer,
+      bidder: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return cancelBidBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      bidder: args.signers.bidder.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.bidder])
+    .rpc();
+}
+
+export type AcceptBidArgs = {
+  feePayer: web3.PublicKey;
+  seller: web3.PublicKey;
+  bidder: web3.PublicKey;
+  mint: web3.PublicKey;
+  collection: web3.PublicKey;
+  bid: web3.PublicKey;
+  minAmount: BN;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Accepts an offer: the NFT goes to the bidder and the escrowed lamports, minus royalties, go to the seller
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` seller: {@link PublicKey} The current owner of the NFT
+ * 2. `[writable]` bidder: {@link PublicKey} The bidder recorded on the bid
+ * 3. `[]` mint: {@link Mint} The mint account of the NFT
+ * 4. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 5. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 6. `[writable]` bid: {@link Bid} The bid to accept
+ * 7. `[writable]` seller_token_account: {@link TokenAccount} The seller's token account holding the NFT
+ * 8. `[writable]` bidder_token_account: {@link TokenAccount} The bidder's token account, created if needed
+ * 9. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 10. `[]` system_program: {@link PublicKey} System program
+ * 11. `[]` token_program: {@link PublicKey} SPL Token program
+ * 12. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 13. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 14. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Remaining accounts:
+ * - `[writable]` creators: the collection creators, in the order stored on the collection
+ *
+ * Data:
+ * - min_amount: {@link BN} Lowest bid amount in lamports the seller accepts; guards against the bidder lowering the bid
+ */
+export const acceptBidBuilder = (
+	args: AcceptBidArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [metadataPubkey] = pda.deriveNftMetadataPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [sellerTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.seller,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [bidderTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.bidder,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [freezeAuthorityPubkey] = pda.deriveFreezeAuthorityPDA(_program.programId);
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .acceptBid(
+      args.minAmount,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      seller: args.seller,
+      bidder: args.bidder,
+      mint: args.mint,
+      metadata: metadataPubkey,
+      collection: args.collection,
+      bid: args.bid,
+      sellerTokenAccount: sellerTokenAccountPubkey,
+      bidderTokenAccount: bidderTokenAccountPubkey,
+      freezeAuthority: freezeAuthorityPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      associatedTokenProgram: new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+      programConfig: programConfigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Accepts an offer: the NFT goes to the bidder and the escrowed lamports, minus royalties, go to the seller
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` seller: {@link PublicKey} The current owner of the NFT
+ * 2. `[writable]` bidder: {@link PublicKey} The bidder recorded on the bid
+ * 3. `[]` mint: {@link Mint} The mint account of the NFT
+ * 4. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 5. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 6. `[writable]` bid: {@link Bid} The bid to accept
+ * 7. `[writable]` seller_token_account: {@link TokenAccount} The seller's token account holding the NFT
+ * 8. `[writable]` bidder_token_account: {@link TokenAccount} The bidder's token account, created if needed
+ * 9. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 10. `[]` system_program: {@link PublicKey} System program
+ * 11. `[]` token_program: {@link PublicKey} SPL Token program
+ * 12. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 13. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 14. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Remaining accounts:
+ * - `[writable]` creators: the collection creators, in the order stored on the collection
+ *
+ * Data:
+ * - min_amount: {@link BN} Lowest bid amount in lamports the seller accepts; guards against the bidder lowering the bid
+ */
+export const acceptBid = (
+	args: AcceptBidArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    acceptBidBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Accepts an offer: the NFT goes to the bidder and the escrowed lamports, minus royalties, go to the seller
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` seller: {@link PublicKey} The current owner of the NFT
+ * 2. `[writable]` bidder: {@link PublicKey} The bidder recorded on the bid
+ * 3. `[]` mint: {@link Mint} The mint account of the NFT
+ * 4. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 5. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 6. `[writable]` bid: {@link Bid} The bid to accept
+ * 7. `[writable]` seller_token_account: {@link TokenAccount} The seller's token account holding the NFT
+ * 8. `[writable]` bidder_token_account: {@link TokenAccount} The bidder's token account, created if needed
+ * 9. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 10. `[]` system_program: {@link PublicKey} System program
+ * 11. `[]` token_program: {@link PublicKey} SPL Token program
+ * 12. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 13. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 14. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Remaining accounts:
+ * - `[writable]` creators: the collection creators, in the order stored on the collection
+ *
+ * Data:
+ * - min_amount: {@link BN} Lowest bid amount in lamports the seller accepts; guards against the bidder lowering the bid
+ */
+export const acceptBidSendAndConfirm = async (
+  args: Omit<AcceptBidArgs, "feePayer" | "seller"> & {
+    signers: {
+      feePayer: web3.Signer,
+      seller: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return acceptBidBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      seller: args.signers.seller.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.seller])
+    .rpc();
+}
+
+export type CreateAuctionArgs = {
+  feePayer: web3.PublicKey;
+  seller: web3.PublicKey;
+  mint: web3.PublicKey;
+  collection: web3.PublicKey;
+  reservePrice: BN;
+  startTime: BN;
+  endTime: BN;
+  minIncrement: BN;
+  extensionWindow: BN;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Puts an NFT up for an English auction, moving it into an escrow owned by the auction
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` seller: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` auction: {@link Auction} The auction account to initialize
+ * 6. `[writable]` seller_token_account: {@link TokenAccount} The seller's token account holding the NFT
+ * 7. `[writable]` escrow_token_account: {@link TokenAccount} Escrow token account owned by the auction
+ * 8. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 9. `[]` system_program: {@link PublicKey} System program
+ * 10. `[]` token_program: {@link PublicKey} SPL Token program
+ * 11. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 12. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 13. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - reserve_price: {@link BN} Lowest accepted bid in lamports
+ * - start_time: {@link BN} Unix timestamp at which bidding opens
+ * - end_time: {@link BN} Unix timestamp at which bidding closes
+ * - min_increment: {@link BN} Minimum amount in lamports by which a bid must beat the current highest bid
+ * - extension_window: {@link BN} Seconds before the end in which a new bid extends the auction to this many seconds from the bid
+ */
+export const createAuctionBuilder = (
+	args: CreateAuctionArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [metadataPubkey] = pda.deriveNftMetadataPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [auctionPubkey] = pda.deriveAuctionPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [sellerTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.seller,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [escrowTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: auctionPubkey,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [freezeAuthorityPubkey] = pda.deriveFreezeAuthorityPDA(_program.programId);
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .createAuction(
+      args.reservePrice,
+      args.startTime,
+      args.endTime,
+      args.minIncrement,
+      args.extensionWindow,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      seller: args.seller,
+      mint: args.mint,
+      metadata: metadataPubkey,
+      collection: args.collection,
+      auction: auctionPubkey,
+      sellerTokenAccount: sellerTokenAccountPubkey,
+      escrowTokenAccount: escrowTokenAccountPubkey,
+      freezeAuthority: freezeAuthorityPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      associatedTokenProgram: new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+      programConfig: programConfigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Puts an NFT up for an English auction, moving it into an escrow owned by the auction
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` seller: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` auction: {@link Auction} The auction account to initialize
+ * 6. `[writable]` seller_token_account: {@link TokenAccount} The seller's token account holding the NFT
+ * 7. `[writable]` escrow_token_account: {@link TokenAccount} Escrow token account owned by the auction
+ * 8. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 9. `[]` system_program: {@link PublicKey} System program
+ * 10. `[]` token_program: {@link PublicKey} SPL Token program
+ * 11. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 12. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 13. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - reserve_price: {@link BN} Lowest accepted bid in lamports
+ * - start_time: {@link BN} Unix timestamp at which bidding opens
+ * - end_time: {@link BN} Unix timestamp at which bidding closes
+ * - min_increment: {@link BN} Minimum amount in lamports by which a bid must beat the current highest bid
+ * - extension_window: {@link BN} Seconds before the end in which a new bid extends the auction to this many seconds from the bid
+ */
+export const createAuction = (
+	args: CreateAuctionArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    createAuctionBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Puts an NFT up for an English auction, moving it into an escrow owned by the auction
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` seller: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` auction: {@link Auction} The auction account to initialize
+ * 6. `[writable]` seller_token_account: {@link TokenAccount} The seller's token account holding the NFT
+ * 7. `[writable]` escrow_token_account: {@link TokenAccount} Escrow token account owned by the auction
+ * 8. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 9. `[]` system_program: {@link PublicKey} System program
+ * 10. `[]` token_program: {@link PublicKey} SPL Token program
+ * 11. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 12. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 13. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - reserve_price: {@link BN} Lowest accepted bid in lamports
+ * - start_time: {@link BN} Unix timestamp at which bidding opens
+ * - end_time: {@link BN} Unix timestamp at which bidding closes
+ * - min_increment: {@link BN} Minimum amount in lamports by which a bid must beat the current highest bid
+ * - extension_window: {@link BN} Seconds before the end in which a new bid extends the auction to this many seconds from the bid
+ */
+export const createAuctionSendAndConfirm = async (
+  args: Omit<CreateAuctionArgs, "feePayer" | "seller"> & {
+    signers: {
+      feePayer: web3.Signer,
+      seller: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return createAuctionBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      seller: args.signers.seller.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.seller])
+    .rpc();
+}
+
+export type PlaceAuctionBidArgs = {
+  feePayer: web3.PublicKey;
+  bidder: web3.PublicKey;
+  auction: web3.PublicKey;
+  collection: web3.PublicKey;
+  previousBidRefund: web3.PublicKey | null;
+  amount: BN;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Bids on an auction, escrowing the bid and crediting the outbid amount to the previous highest bidder's refund account
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` bidder: {@link PublicKey} The account placing the bid
+ * 2. `[writable]` auction: {@link Auction} The auction to bid on
+ * 3. `[]` collection: {@link NftCollection} The collection of the auctioned NFT
+ * 4. `[writable]` previous_bid_refund: {@link BidRefund} Refund account of the current highest bidder, credited with the outbid amount and created if needed; required once the auction has a bid
+ * 5. `[]` system_program: {@link PublicKey} System program
+ * 6. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - amount: {@link BN} Bid in lamports
+ */
+export const placeAuctionBidBuilder = (
+	args: PlaceAuctionBidArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .placeAuctionBid(
+      args.amount,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      bidder: args.bidder,
+      auction: args.auction,
+      collection: args.collection,
+      previousBidRefund: args.previousBidRefund,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+      programConfig: programConfigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Bids on an auction, escrowing the bid and crediting the outbid amount to the previous highest bidder's refund account
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` bidder: {@link PublicKey} The account placing the bid
+ * 2. `[writable]` auction: {@link Auction} The auction to bid on
+ * 3. `[]` collection: {@link NftCollection} The collection of the auctioned NFT
+ * 4. `[writable]` previous_bid_refund: {@link BidRefund} Refund account of the current highest bidder, credited with the outbid amount and created if needed; required once the auction has a bid
+ * 5. `[]` system_program: {@link PublicKey} System program
+ * 6. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - amount: {@link BN} Bid in lamports
+ */
+export const placeAuctionBid = (
+	args: PlaceAuctionBidArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    placeAuctionBidBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Bids on an auction, escrowing the bid and crediting the outbid amount to the previous highest bidder's refund account
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` bidder: {@link PublicKey} The account placing the bid
+ * 2. `[writable]` auction: {@link Auction} The auction to bid on
+ * 3. `[]` collection: {@link NftCollection} The collection of the auctioned NFT
+ * 4. `[writable]` previous_bid_refund: {@link BidRefund} Refund account of the current highest bidder, credited with the outbid amount and created if needed; required once the auction has a bid
+ * 5. `[]` system_program: {@link PublicKey} System program
+ * 6. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - amount: {@link BN} Bid in lamports
+ */
+export const placeAuctionBidSendAndConfirm = async (
+  args: Omit<PlaceAuctionBidArgs, "feePayer" | "bidder"> & {
+    signers: {
+      feePayer: web3.Signer,
+      bidder: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return placeAuctionBidBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      bidder: args.signers.bidder.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.bidder])
+    .rpc();
+}
+
+export type SettleAuctionArgs = {
+  feePayer: web3.PublicKey;
+  seller: web3.PublicKey;
+  recipient: web3.PublicKey;
+  mint: web3.PublicKey;
+  collection: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Settles an ended auction: the NFT goes to the winner, or back to the seller without bids, and the seller and creators are paid
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} Anyone; pays for the recipient's token account if needed
+ * 1. `[writable]` seller: {@link PublicKey} The seller recorded on the auction
+ * 2. `[]` recipient: {@link PublicKey} The highest bidder, or the seller if there were no bids
+ * 3. `[]` mint: {@link Mint} The mint account of the NFT
+ * 4. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 5. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 6. `[writable]` auction: {@link Auction} The auction to settle
+ * 7. `[writable]` escrow_token_account: {@link TokenAccount} Escrow token account owned by the auction
+ * 8. `[writable]` recipient_token_account: {@link TokenAccount} The recipient's token account, created if needed
+ * 9. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 10. `[]` system_program: {@link PublicKey} System program
+ * 11. `[]` token_program: {@link PublicKey} SPL Token program
+ * 12. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 13. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 14. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Remaining accounts:
+ * - `[writable]` creators: the collection creators, in the order stored on the collection
+ */
+export const settleAuctionBuilder = (
+	args: SettleAuctionArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [metadataPubkey] = pda.deriveNftMetadataPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [auctionPubkey] = pda.deriveAuctionPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [escrowTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: auctionPubkey,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [recipientTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.recipient,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [freezeAuthorityPubkey] = pda.deriveFreezeAuthorityPDA(_program.programId);
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .settleAuction(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      seller: args.seller,
+      recipient: args.recipient,
+      mint: args.mint,
+      metadata: metadataPubkey,
+      collection: args.collection,
+      auction: auctionPubkey,
+      escrowTokenAccount: escrowTokenAccountPubkey,
+      recipientTokenAccount: recipientTokenAccountPubkey,
+      freezeAuthority: freezeAuthorityPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      associatedTokenProgram: new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+      programConfig: programConfigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Settles an ended auction: the NFT goes to the winner, or back to the seller without bids, and the seller and creators are paid
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} Anyone; pays for the recipient's token account if needed
+ * 1. `[writable]` seller: {@link PublicKey} The seller recorded on the auction
+ * 2. `[]` recipient: {@link PublicKey} The highest bidder, or the seller if there were no bids
+ * 3. `[]` mint: {@link Mint} The mint account of the NFT
+ * 4. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 5. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 6. `[writable]` auction: {@link Auction} The auction to settle
+ * 7. `[writable]` escrow_token_account: {@link TokenAccount} Escrow token account owned by the auction
+ * 8. `[writable]` recipient_token_account: {@link TokenAccount} The recipient's token account, created if needed
+ * 9. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 10. `[]` system_program: {@link PublicKey} System program
+ * 11. `[]` token_program: {@link PublicKey} SPL Token program
+ * 12. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 13. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 14. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Remaining accounts:
+ * - `[writable]` creators: the collection creators, in the order stored on the collection
+ */
+export const settleAuction = (
+	args: SettleAuctionArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    settleAuctionBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Settles an ended auction: the NFT goes to the winner, or back to the seller without bids, and the seller and creators are paid
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} Anyone; pays for the recipient's token account if needed
+ * 1. `[writable]` seller: {@link PublicKey} The seller recorded on the auction
+ * 2. `[]` recipient: {@link PublicKey} The highest bidder, or the seller if there were no bids
+ * 3. `[]` mint: {@link Mint} The mint account of the NFT
+ * 4. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 5. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 6. `[writable]` auction: {@link Auction} The auction to settle
+ * 7. `[writable]` escrow_token_account: {@link TokenAccount} Escrow token account owned by the auction
+ * 8. `[writable]` recipient_token_account: {@link TokenAccount} The recipient's token account, created if needed
+ * 9. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 10. `[]` system_program: {@link PublicKey} System program
+ * 11. `[]` token_program: {@link PublicKey} SPL Token program
+ * 12. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 13. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 14. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Remaining accounts:
+ * - `[writable]` creators: the collection creators, in the order stored on the collection
+ */
+export const settleAuctionSendAndConfirm = async (
+  args: Omit<SettleAuctionArgs, "feePayer"> & {
+    signers: {
+      feePayer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return settleAuctionBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer])
+    .rpc();
+}
+
+export type SetMintConfigArgs = {
+  feePayer: web3.PublicKey;
+  authority: web3.PublicKey;
+  seedAuthority: web3.PublicKey;
+  startPrice: BN;
+  endPrice: BN;
+  startTime: BN;
+  decayInterval: BN;
+  priceStep: BN;
+  maxSupply: BN;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Configures a falling mint price for the collection; can only be changed before the first mint
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` collection: {@link NftCollection} The collection to configure
+ * 2. `[writable]` mint_config: {@link MintConfig} The mint pricing account, created if needed
+ * 3. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - start_price: {@link BN} Mint price in lamports when minting opens
+ * - end_price: {@link BN} Floor the price never falls below
+ * - start_time: {@link BN} Unix timestamp at which minting opens
+ * - decay_interval: {@link BN} Seconds between two price decreases
+ * - price_step: {@link BN} Lamports the price falls by every decay interval
+ * - max_supply: {@link BN} Number of NFTs sold through this drop
+ */
+export const setMintConfigBuilder = (
+	args: SetMintConfigArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [collectionPubkey] = pda.deriveCollectionPDA({
+        authority: args.seedAuthority,
+    }, _program.programId);
+    const [mintConfigPubkey] = pda.deriveMintConfigPDA({
+        collection: collectionPubkey,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .setMintConfig(
+      args.startPrice,
+      args.endPrice,
+      args.startTime,
+      args.decayInterval,
+      args.priceStep,
+      args.maxSupply,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      collection: collectionPubkey,
+      mintConfig: mintConfigPubkey,
+      authority: args.authority,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Configures a falling mint price for the collection; can only be changed before the first mint
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` collection: {@link NftCollection} The collection to configure
+ * 2. `[writable]` mint_config: {@link MintConfig} The mint pricing account, created if needed
+ * 3. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - start_price: {@link BN} Mint price in lamports when minting opens
+ * - end_price: {@link BN} Floor the price never falls below
+ * - start_time: {@link BN} Unix timestamp at which minting opens
+ * - decay_interval: {@link BN} Seconds between two price decreases
+ * - price_step: {@link BN} Lamports the price falls by every decay interval
+ * - max_supply: {@link BN} Number of NFTs sold through this drop
+ */
+export const setMintConfig = (
+	args: SetMintConfigArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    setMintConfigBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Configures a falling mint price for the collection; can only be changed before the first mint
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` collection: {@link NftCollection} The collection to configure
+ * 2. `[writable]` mint_config: {@link MintConfig} The mint pricing account, created if needed
+ * 3. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - start_price: {@link BN} Mint price in lamports when minting opens
+ * - end_price: {@link BN} Floor the price never falls below
+ * - start_time: {@link BN} Unix timestamp at which minting opens
+ * - decay_interval: {@link BN} Seconds between two price decreases
+ * - price_step: {@link BN} Lamports the price falls by every decay interval
+ * - max_supply: {@link BN} Number of NFTs sold through this drop
+ */
+export const setMintConfigSendAndConfirm = async (
+  args: Omit<SetMintConfigArgs, "feePayer" | "authority"> & {
+    signers: {
+      feePayer: web3.Signer,
+      authority: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return setMintConfigBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      authority: args.signers.authority.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.authority])
+    .rpc();
+}
+
+export type ClaimMintRebateArgs = {
+  feePayer: web3.PublicKey;
+  minter: web3.PublicKey;
+  mintConfig: web3.PublicKey;
+  mintReceipt: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Refunds a minter the difference between the price they paid and the final clearing price, closing their mint receipt
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} Anyone; the rebate always goes to the minter
+ * 1. `[writable]` minter: {@link PublicKey} The account that paid for the mint
+ * 2. `[writable]` mint_config: {@link MintConfig} The collection's mint pricing
+ * 3. `[writable]` mint_receipt: {@link MintReceipt} The receipt of the mint to refund
+ */
+export const claimMintRebateBuilder = (
+	args: ClaimMintRebateArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+
+  return _program
+    .methods
+    .claimMintRebate(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      minter: args.minter,
+      mintConfig: args.mintConfig,
+      mintReceipt: args.mintReceipt,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Refunds a minter the difference between the price they paid and the final clearing price, closing their mint receipt
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} Anyone; the rebate always goes to the minter
+ * 1. `[writable]` minter: {@link PublicKey} The account that paid for the mint
+ * 2. `[writable]` mint_config: {@link MintConfig} The collection's mint pricing
+ * 3. `[writable]` mint_receipt: {@link MintReceipt} The receipt of the mint to refund
+ */
+export const claimMintRebate = (
+	args: ClaimMintRebateArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    claimMintRebateBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Refunds a minter the difference between the price they paid and the final clearing price, closing their mint receipt
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} Anyone; the rebate always goes to the minter
+ * 1. `[writable]` minter: {@link PublicKey} The account that paid for the mint
+ * 2. `[writable]` mint_config: {@link MintConfig} The collection's mint pricing
+ * 3. `[writable]` mint_receipt: {@link MintReceipt} The receipt of the mint to refund
+ */
+export const claimMintRebateSendAndConfirm = async (
+  args: Omit<ClaimMintRebateArgs, "feePayer"> & {
+    signers: {
+      feePayer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return claimMintRebateBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer])
+    .rpc();
+}
+
+export type WithdrawMintProceedsArgs = {
+  feePayer: web3.PublicKey;
+  authority: web3.PublicKey;
+  destination: web3.PublicKey;
+  seedAuthority: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Withdraws the mint proceeds at the clearing price once the drop has concluded, leaving the rebates for the minters
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` collection: {@link NftCollection} The collection the drop belongs to
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[writable]` mint_config: {@link MintConfig} The collection's mint pricing
+ * 4. `[writable]` destination: {@link PublicKey} Receives the proceeds
+ */
+export const withdrawMintProceedsBuilder = (
+	args: WithdrawMintProceedsArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [collectionPubkey] = pda.deriveCollectionPDA({
+        authority: args.seedAuthority,
+    }, _program.programId);
+    const [mintConfigPubkey] = pda.deriveMintConfigPDA({
+        collection: collectionPubkey,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .withdrawMintProceeds(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      collection: collectionPubkey,
+      authority: args.authority,
+      mintConfig: mintConfigPubkey,
+      destination: args.destination,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Withdraws the mint proceeds at the clearing price once the drop has concluded, leaving the rebates for the minters
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` collection: {@link NftCollection} The collection the drop belongs to
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[writable]` mint_config: {@link MintConfig} The collection's mint pricing
+ * 4. `[writable]` destination: {@link PublicKey} Receives the proceeds
+ */
+export const withdrawMintProceeds = (
+	args: WithdrawMintProceedsArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    withdrawMintProceedsBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Withdraws the mint proceeds at the clearing price once the drop has concluded, leaving the rebates for the minters
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` collection: {@link NftCollection} The collection the drop belongs to
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[writable]` mint_config: {@link MintConfig} The collection's mint pricing
+ * 4. `[writable]` destination: {@link PublicKey} Receives the proceeds
+ */
+export const withdrawMintProceedsSendAndConfirm = async (
+  args: Omit<WithdrawMintProceedsArgs, "feePayer" | "authority"> & {
+    signers: {
+      feePayer: web3.Signer,
+      authority: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return withdrawMintProceedsBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      authority: args.signers.authority.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.authority])
+    .rpc();
+}
+
+export type CreateRentalOfferArgs = {
+  feePayer: web3.PublicKey;
+  owner: web3.PublicKey;
+  mint: web3.PublicKey;
+  collection: web3.PublicKey;
+  pricePerDay: BN;
+  maxDays: number;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Offers the usage rights of an NFT for rent at a daily price, without giving up ownership
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` owner: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` rental_offer: {@link RentalOffer} The rental offer to initialize
+ * 6. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 7. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - price_per_day: {@link BN} Rent in lamports per day
+ * - max_days: {@link number} Longest rental the owner accepts, in days
+ */
+export const createRentalOfferBuilder = (
+	args: CreateRentalOfferArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [metadataPubkey] = pda.deriveNftMetadataPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [rentalOfferPubkey] = pda.deriveRentalOfferPDA({
+        mint: args.mint,
+        owner: args.owner,
+    }, _program.programId);
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .createRentalOffer(
+      args.pricePerDay,
+      args.maxDays,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      owner: args.owner,
+      mint: args.mint,
+      metadata: metadataPubkey,
+      collection: args.collection,
+      rentalOffer: rentalOfferPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+      programConfig: programConfigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Offers the usage rights of an NFT for rent at a daily price, without giving up ownership
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` owner: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` rental_offer: {@link RentalOffer} The rental offer to initialize
+ * 6. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 7. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - price_per_day: {@link BN} Rent in lamports per day
+ * - max_days: {@link number} Longest rental the owner accepts, in days
+ */
+export const createRentalOffer = (
+	args: CreateRentalOfferArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    createRentalOfferBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Offers the usage rights of an NFT for rent at a daily price, without giving up ownership
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` owner: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` rental_offer: {@link RentalOffer} The rental offer to initialize
+ * 6. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 7. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - price_per_day: {@link BN} Rent in lamports per day
+ * - max_days: {@link number} Longest rental the owner accepts, in days
+ */
+export const createRentalOfferSendAndConfirm = async (
+  args: Omit<CreateRentalOfferArgs, "feePayer" | "owner"> & {
+    signers: {
+      feePayer: web3.Signer,
+      owner: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return createRentalOfferBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      owner: args.signers.owner.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.owner])
+    .rpc();
+}
+
+export type RentNftArgs = {
+  feePayer: web3.PublicKey;
+  renter: web3.PublicKey;
+  owner: web3.PublicKey;
+  collection: web3.PublicKey;
+  mint: web3.PublicKey;
+  days: number;
+  expectedPricePerDay: BN;
+  expectedMaxDays: number;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Rents an NFT, paying the owner and becoming its user until the rental expires
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` renter: {@link PublicKey} The account renting the NFT
+ * 2. `[writable]` owner: {@link PublicKey} The owner of the NFT, who receives the rent
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[]` rental_offer: {@link RentalOffer} The rental offer to take
+ * 6. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 7. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - days: {@link number} Rental duration in days
+ * - expected_price_per_day: {@link BN} Daily price the renter agreed to; the rental fails if the offer differs
+ * - expected_max_days: {@link number} Maximum duration the renter saw on the offer; the rental fails if the offer differs
+ */
+export const rentNftBuilder = (
+	args: RentNftArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [metadataPubkey] = pda.deriveNftMetadataPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [rentalOfferPubkey] = pda.deriveRentalOfferPDA({
+        mint: args.mint,
+        owner: args.owner,
+    }, _program.programId);
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .rentNft(
+      args.days,
+      args.expectedPricePerDay,
+      args.expectedMaxDays,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      renter: args.renter,
+      owner: args.owner,
+      metadata: metadataPubkey,
+      collection: args.collection,
+      rentalOffer: rentalOfferPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+      programConfig: programConfigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Rents an NFT, paying the owner and becoming its user until the rental expires
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` renter: {@link PublicKey} The account renting the NFT
+ * 2. `[writable]` owner: {@link PublicKey} The owner of the NFT, who receives the rent
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[]` rental_offer: {@link RentalOffer} The rental offer to take
+ * 6. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 7. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - days: {@link number} Rental duration in days
+ * - expected_price_per_day: {@link BN} Daily price the renter agreed to; the rental fails if the offer differs
+ * - expected_max_days: {@link number} Maximum duration the renter saw on the offer; the rental fails if the offer differs
+ */
+export const rentNft = (
+	args: RentNftArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    rentNftBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Rents an NFT, paying the owner and becoming its user until the rental expires
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` renter: {@link PublicKey} The account renting the NFT
+ * 2. `[writable]` owner: {@link PublicKey} The owner of the NFT, who receives the rent
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[]` rental_offer: {@link RentalOffer} The rental offer to take
+ * 6. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 7. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - days: {@link number} Rental duration in days
+ * - expected_price_per_day: {@link BN} Daily price the renter agreed to; the rental fails if the offer differs
+ * - expected_max_days: {@link number} Maximum duration the renter saw on the offer; the rental fails if the offer differs
+ */
+export const rentNftSendAndConfirm = async (
+  args: Omit<RentNftArgs, "feePayer" | "renter"> & {
+    signers: {
+      feePayer: web3.Signer,
+      renter: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return rentNftBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      renter: args.signers.renter.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.renter])
+    .rpc();
+}
+
+export type EndRentalArgs = {
+  feePayer: web3.PublicKey;
+  mint: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Clears the user of an NFT once its rental has expired; callable by anyone
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` metadata: {@link NftMint} The metadata account of the rented NFT
+ */
+export const endRentalBuilder = (
+	args: EndRentalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [metadataPubkey] = pda.deriveNftMetadataPDA({
+        mint: args.mint,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .endRental(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      metadata: metadataPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Clears the user of an NFT once its rental has expired; callable by anyone
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` metadata: {@link NftMint} The metadata account of the rented NFT
+ */
+export const endRental = (
+	args: EndRentalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    endRentalBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Clears the user of an NFT once its rental has expired; callable by anyone
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` metadata: {@link NftMint} The metadata account of the rented NFT
+ */
+export const endRentalSendAndConfirm = async (
+  args: Omit<EndRentalArgs, "feePayer"> & {
+    signers: {
+      feePayer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return endRentalBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer])
+    .rpc();
+}
+
+export type CloseRentalOfferArgs = {
+  feePayer: web3.PublicKey;
+  owner: web3.PublicKey;
+  mint: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Withdraws a rental offer so the NFT can no longer be rented
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` owner: {@link PublicKey} The owner who created the offer
+ * 2. `[writable]` rental_offer: {@link RentalOffer} The rental offer to close
+ */
+export const closeRentalOfferBuilder = (
+	args: CloseRentalOfferArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [rentalOfferPubkey] = pda.deriveRentalOfferPDA({
+        mint: args.mint,
+        owner: args.owner,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .closeRentalOffer(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      owner: args.owner,
+      rentalOffer: rentalOfferPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Withdraws a rental offer so the NFT can no longer be rented
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` owner: {@link PublicKey} The owner who created the offer
+ * 2. `[writable]` rental_offer: {@link RentalOffer} The rental offer to close
+ */
+export const closeRentalOffer = (
+	args: CloseRentalOfferArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    closeRentalOfferBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Withdraws a rental offer so the NFT can no longer be rented
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` owner: {@link PublicKey} The owner who created the offer
+ * 2. `[writable]` rental_offer: {@link RentalOffer} The rental offer to close
+ */
+export const closeRentalOfferSendAndConfirm = async (
+  args: Omit<CloseRentalOfferArgs, "feePayer" | "owner"> & {
+    signers: {
+      feePayer: web3.Signer,
+      owner: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return closeRentalOfferBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      owner: args.signers.owner.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.owner])
+    .rpc();
+}
+
+export type CreateStakePoolArgs = {
+  feePayer: web3.PublicKey;
+  authority: web3.PublicKey;
+  rewardMint: web3.PublicKey;
+  seedAuthority: web3.PublicKey;
+  rewardRate: BN;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Creates the staking pool of a collection; the reward mint must already have the pool PDA as mint authority
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` collection: {@link NftCollection} The collection whose NFTs can be staked
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[writable]` stake_pool: {@link StakePool} The stake pool to initialize
+ * 4. `[]` reward_mint: {@link Mint} The pool's reward token mint
+ * 5. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - reward_rate: {@link BN} Reward tokens, in base units, earned per staked NFT per second
+ */
+export const createStakePoolBuilder = (
+	args: CreateStakePoolArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [collectionPubkey] = pda.deriveCollectionPDA({
+        authority: args.seedAuthority,
+    }, _program.programId);
+    const [stakePoolPubkey] = pda.deriveStakePoolPDA({
+        collection: collectionPubkey,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .createStakePool(
+      args.rewardRate,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      collection: collectionPubkey,
+      authority: args.authority,
+      stakePool: stakePoolPubkey,
+      rewardMint: args.rewardMint,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Creates the staking pool of a collection; the reward mint must already have the pool PDA as mint authority
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` collection: {@link NftCollection} The collection whose NFTs can be staked
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[writable]` stake_pool: {@link StakePool} The stake pool to initialize
+ * 4. `[]` reward_mint: {@link Mint} The pool's reward token mint
+ * 5. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - reward_rate: {@link BN} Reward tokens, in base units, earned per staked NFT per second
+ */
+export const createStakePool = (
+	args: CreateStakePoolArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    createStakePoolBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Creates the staking pool of a collection; the reward mint must already have the pool PDA as mint authority
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` collection: {@link NftCollection} The collection whose NFTs can be staked
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[writable]` stake_pool: {@link StakePool} The stake pool to initialize
+ * 4. `[]` reward_mint: {@link Mint} The pool's reward token mint
+ * 5. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - reward_rate: {@link BN} Reward tokens, in base units, earned per staked NFT per second
+ */
+export const createStakePoolSendAndConfirm = async (
+  args: Omit<CreateStakePoolArgs, "feePayer" | "authority"> & {
+    signers: {
+      feePayer: web3.Signer,
+      authority: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return createStakePoolBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      authority: args.signers.authority.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.authority])
+    .rpc();
+}
+
+export type StakeNftArgs = {
+  feePayer: web3.PublicKey;
+  owner: web3.PublicKey;
+  mint: web3.PublicKey;
+  collection: web3.PublicKey;
+  collection: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Stakes an NFT without moving it: the stake entry becomes delegate of the owner's token account, which stays frozen until unstaked
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` owner: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[]` stake_pool: {@link StakePool} The stake pool of the NFT's collection
+ * 6. `[writable]` stake_entry: {@link StakeEntry} The stake entry to initialize
+ * 7. `[writable]` owner_token_account: {@link TokenAccount} The owner's token account holding the NFT
+ * 8. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 9. `[]` rule_set: {@link PublicKey} The rule set of the NFT's collection
+ * 10. `[]` instructions: {@link PublicKey} Instructions sysvar, to find the calling program
+ * 11. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 12. `[]` token_program: {@link PublicKey} SPL Token program
+ * 13. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 14. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ */
+export const stakeNftBuilder = (
+	args: StakeNftArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [metadataPubkey] = pda.deriveNftMetadataPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [stakePoolPubkey] = pda.deriveStakePoolPDA({
+        collection: args.collection,
+    }, _program.programId);
+    const [stakeEntryPubkey] = pda.deriveStakeEntryPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [ownerTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.owner,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [freezeAuthorityPubkey] = pda.deriveFreezeAuthorityPDA(_program.programId);
+    const [ruleSetPubkey] = pda.deriveRuleSetPDA({
+        collection: args.collection,
+    }, _program.programId);
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .stakeNft(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      owner: args.owner,
+      mint: args.mint,
+      metadata: metadataPubkey,
+      collection: args.collection,
+      stakePool: stakePoolPubkey,
+      stakeEntry: stakeEntryPubkey,
+      ownerTokenAccount: ownerTokenAccountPubkey,
+      freezeAuthority: freezeAuthorityPubkey,
+      ruleSet: ruleSetPubkey,
+      instructions: new web3.PublicKey("Sysvar1nstructions1111111111111111111111111"),
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      programConfig: programConfigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Stakes an NFT without moving it: the stake entry becomes delegate of the owner's token account, which stays frozen until unstaked
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` owner: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[]` stake_pool: {@link StakePool} The stake pool of the NFT's collection
+ * 6. `[writable]` stake_entry: {@link StakeEntry} The stake entry to initialize
+ * 7. `[writable]` owner_token_account: {@link TokenAccount} The owner's token account holding the NFT
+ * 8. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 9. `[]` rule_set: {@link PublicKey} The rule set of the NFT's collection
+ * 10. `[]` instructions: {@link PublicKey} Instructions sysvar, to find the calling program
+ * 11. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 12. `[]` token_program: {@link PublicKey} SPL Token program
+ * 13. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 14. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ */
+export const stakeNft = (
+	args: StakeNftArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    stakeNftBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Stakes an NFT without moving it: the stake entry becomes delegate of the owner's token account, which stays frozen until unstaked
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` owner: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[]` stake_pool: {@link StakePool} The stake pool of the NFT's collection
+ * 6. `[writable]` stake_entry: {@link StakeEntry} The stake entry to initialize
+ * 7. `[writable]` owner_token_account: {@link TokenAccount} The owner's token account holding the NFT
+ * 8. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 9. `[]` rule_set: {@link PublicKey} The rule set of the NFT's collection
+ * 10. `[]` instructions: {@link PublicKey} Instructions sysvar, to find the calling program
+ * 11. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 12. `[]` token_program: {@link PublicKey} SPL Token program
+ * 13. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 14. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ */
+export const stakeNftSendAndConfirm = async (
+  args: Omit<StakeNftArgs, "feePayer" | "owner"> & {
+    signers: {
+      feePayer: web3.Signer,
+      owner: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return stakeNftBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      owner: args.signers.owner.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.owner])
+    .rpc();
+}
+
+export type ClaimStakeRewardsArgs = {
+  feePayer: web3.PublicKey;
+  owner: web3.PublicKey;
+  collection: web3.PublicKey;
+  rewardMint: web3.PublicKey;
+  collection: web3.PublicKey;
+  mint: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Mints the rewards a staked NFT has accrued since its last claim
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` owner: {@link PublicKey} The owner of the staked NFT
+ * 2. `[]` stake_pool: {@link StakePool} The stake pool of the NFT's collection
+ * 3. `[]` collection: {@link NftCollection} The collection of the stake pool
+ * 4. `[writable]` stake_entry: {@link StakeEntry} The stake entry of the NFT
+ * 5. `[writable]` reward_mint: {@link Mint} The pool's reward token mint
+ * 6. `[writable]` owner_reward_account: {@link TokenAccount} The owner's reward token account, created if needed
+ * 7. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 8. `[]` token_program: {@link PublicKey} SPL Token program
+ * 9. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 10. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 11. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ */
+export const claimStakeRewardsBuilder = (
+	args: ClaimStakeRewardsArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [stakePoolPubkey] = pda.deriveStakePoolPDA({
+        collection: args.collection,
+    }, _program.programId);
+    const [stakeEntryPubkey] = pda.deriveStakeEntryPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [ownerRewardAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.owner,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.reward_mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .claimStakeRewards(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      owner: args.owner,
+      stakePool: stakePoolPubkey,
+      collection: args.collection,
+      stakeEntry: stakeEntryPubkey,
+      rewardMint: args.rewardMint,
+      ownerRewardAccount: ownerRewardAccountPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      associatedTokenProgram: new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+      programConfig: programConfigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Mints the rewards a staked NFT has accrued since its last claim
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` owner: {@link PublicKey} The owner of the staked NFT
+ * 2. `[]` stake_pool: {@link StakePool} The stake pool of the NFT's collection
+ * 3. `[]` collection: {@link NftCollection} The collection of the stake pool
+ * 4. `[writable]` stake_entry: {@link StakeEntry} The stake entry of the NFT
+ * 5. `[writable]` reward_mint: {@link Mint} The pool's reward token mint
+ * 6. `[writable]` owner_reward_account: {@link TokenAccount} The owner's reward token account, created if needed
+ * 7. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 8. `[]` token_program: {@link PublicKey} SPL Token program
+ * 9. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 10. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 11. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ */
+export const claimStakeRewards = (
+	args: ClaimStakeRewardsArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    claimStakeRewardsBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Mints the rewards a staked NFT has accrued since its last claim
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` owner: {@link PublicKey} The owner of the staked NFT
+ * 2. `[]` stake_pool: {@link StakePool} The stake pool of the NFT's collection
+ * 3. `[]` collection: {@link NftCollection} The collection of the stake pool
+ * 4. `[writable]` stake_entry: {@link StakeEntry} The stake entry of the NFT
+ * 5. `[writable]` reward_mint: {@link Mint} The pool's reward token mint
+ * 6. `[writable]` owner_reward_account: {@link TokenAccount} The owner's reward token account, created if needed
+ * 7. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 8. `[]` token_program: {@link PublicKey} SPL Token program
+ * 9. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 10. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 11. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ */
+export const claimStakeRewardsSendAndConfirm = async (
+  args: Omit<ClaimStakeRewardsArgs, "feePayer" | "owner"> & {
+    signers: {
+      feePayer: web3.Signer,
+      owner: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return claimStakeRewardsBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      owner: args.signers.owner.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.owner])
+    .rpc();
+}
+
+export type UnstakeNftArgs = {
+  feePayer: web3.PublicKey;
+  owner: web3.PublicKey;
+  mint: web3.PublicKey;
+  collection: web3.PublicKey;
+  rewardMint: web3.PublicKey;
+  collection: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Pays the pending rewards, thaws the NFT and closes its stake entry
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` owner: {@link PublicKey} The owner of the staked NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[]` stake_pool: {@link StakePool} The stake pool of the NFT's collection
+ * 6. `[writable]` stake_entry: {@link StakeEntry} The stake entry to close
+ * 7. `[writable]` owner_token_account: {@link TokenAccount} The owner's token account holding the NFT
+ * 8. `[writable]` reward_mint: {@link Mint} The pool's reward token mint
+ * 9. `[writable]` owner_reward_account: {@link TokenAccount} The owner's reward token account, created if needed
+ * 10. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 11. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 12. `[]` token_program: {@link PublicKey} SPL Token program
+ * 13. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 14. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 15. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ */
+export const unstakeNftBuilder = (
+	args: UnstakeNftArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [metadataPubkey] = pda.deriveNftMetadataPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [stakePoolPubkey] = pda.deriveStakePoolPDA({
+        collection: args.collection,
+    }, _program.programId);
+    const [stakeEntryPubkey] = pda.deriveStakeEntryPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [ownerTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.owner,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [ownerRewardAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.owner,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.reward_mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [freezeAuthorityPubkey] = pda.deriveFreezeAuthorityPDA(_program.programId);
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .unstakeNft(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      owner: args.owner,
+      mint: args.mint,
+      metadata: metadataPubkey,
+      collection: args.collection,
+      stakePool: stakePoolPubkey,
+      stakeEntry: stakeEntryPubkey,
+      ownerTokenAccount: ownerTokenAccountPubkey,
+      rewardMint: args.rewardMint,
+      ownerRewardAccount: ownerRewardAccountPubkey,
+      freezeAuthority: freezeAuthorityPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      associatedTokenProgram: new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+      programConfig: programConfigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Pays the pending rewards, thaws the NFT and closes its stake entry
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` owner: {@link PublicKey} The owner of the staked NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[]` stake_pool: {@link StakePool} The stake pool of the NFT's collection
+ * 6. `[writable]` stake_entry: {@link StakeEntry} The stake entry to close
+ * 7. `[writable]` owner_token_account: {@link TokenAccount} The owner's token account holding the NFT
+ * 8. `[writable]` reward_mint: {@link Mint} The pool's reward token mint
+ * 9. `[writable]` owner_reward_account: {@link TokenAccount} The owner's reward token account, created if needed
+ * 10. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 11. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 12. `[]` token_program: {@link PublicKey} SPL Token program
+ * 13. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 14. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 15. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ */
+export const unstakeNft = (
+	args: UnstakeNftArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    unstakeNftBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Pays the pending rewards, thaws the NFT and closes its stake entry
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` owner: {@link PublicKey} The owner of the staked NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[]` stake_pool: {@link StakePool} The stake pool of the NFT's collection
+ * 6. `[writable]` stake_entry: {@link StakeEntry} The stake entry to close
+ * 7. `[writable]` owner_token_account: {@link TokenAccount} The owner's token account holding the NFT
+ * 8. `[writable]` reward_mint: {@link Mint} The pool's reward token mint
+ * 9. `[writable]` owner_reward_account: {@link TokenAccount} The owner's reward token account, created if needed
+ * 10. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 11. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 12. `[]` token_program: {@link PublicKey} SPL Token program
+ * 13. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 14. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 15. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ */
+export const unstakeNftSendAndConfirm = async (
+  args: Omit<UnstakeNftArgs, "feePayer" | "owner"> & {
+    signers: {
+      feePayer: web3.Signer,
+      owner: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return unstakeNftBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      owner: args.signers.owner.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.owner])
+    .rpc();
+}
+
+export type FractionalizeNftArgs = {
+  feePayer: web3.PublicKey;
+  owner: web3.PublicKey;
+  mint: web3.PublicKey;
+  collection: web3.PublicKey;
+  totalShares: BN;
+  reservePrice: BN;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Locks an NFT in a vault and mints a fixed supply of fungible shares to the depositor
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` owner: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` vault: {@link Vault} The vault to initialize
+ * 6. `[writable]` share_mint: {@link Mint} The share mint to initialize
+ * 7. `[writable]` owner_token_account: {@link TokenAccount} The owner's token account holding the NFT
+ * 8. `[writable]` vault_token_account: {@link TokenAccount} The vault's token account receiving the NFT
+ * 9. `[writable]` owner_share_account: {@link TokenAccount} The owner's share token account, created if needed
+ * 10. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 11. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 12. `[]` token_program: {@link PublicKey} SPL Token program
+ * 13. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 14. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 15. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - total_shares: {@link BN} Number of shares minted to the depositor; must be greater than zero
+ * - reserve_price: {@link BN} Price in lamports of the whole NFT in a buyout
+ */
+export const fractionalizeNftBuilder = (
+	args: FractionalizeNftArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [metadataPubkey] = pda.deriveNftMetadataPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [vaultPubkey] = pda.deriveVaultPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [shareMintPubkey] = pda.deriveShareMintPDA({
+        vault: vaultPubkey,
+    }, _program.programId);
+    const [ownerTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.owner,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [vaultTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: vaultPubkey,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [ownerShareAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.owner,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: shareMintPubkey,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [freezeAuthorityPubkey] = pda.deriveFreezeAuthorityPDA(_program.programId);
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .fractionalizeNft(
+      args.totalShares,
+      args.reservePrice,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      owner: args.owner,
+      mint: args.mint,
+      metadata: metadataPubkey,
+      collection: args.collection,
+      vault: vaultPubkey,
+      shareMint: shareMintPubkey,
+      ownerTokenAccount: ownerTokenAccountPubkey,
+      vaultTokenAccount: vaultTokenAccountPubkey,
+      ownerShareAccount: ownerShareAccountPubkey,
+      freezeAuthority: freezeAuthorityPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      associatedTokenProgram: new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+      programConfig: programConfigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Locks an NFT in a vault and mints a fixed supply of fungible shares to the depositor
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` owner: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` vault: {@link Vault} The vault to initialize
+ * 6. `[writable]` share_mint: {@link Mint} The share mint to initialize
+ * 7. `[writable]` owner_token_account: {@link TokenAccount} The owner's token account holding the NFT
+ * 8. `[writable]` vault_token_account: {@link TokenAccount} The vault's token account receiving the NFT
+ * 9. `[writable]` owner_share_account: {@link TokenAccount} The owner's share token account, created if needed
+ * 10. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 11. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 12. `[]` token_program: {@link PublicKey} SPL Token program
+ * 13. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 14. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 15. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - total_shares: {@link BN} Number of shares minted to the depositor; must be greater than zero
+ * - reserve_price: {@link BN} Price in lamports of the whole NFT in a buyout
+ */
+export const fractionalizeNft = (
+	args: FractionalizeNftArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    fractionalizeNftBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Locks an NFT in a vault and mints a fixed supply of fungible shares to the depositor
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` owner: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` vault: {@link Vault} The vault to initialize
+ * 6. `[writable]` share_mint: {@link Mint} The share mint to initialize
+ * 7. `[writable]` owner_token_account: {@link TokenAccount} The owner's token account holding the NFT
+ * 8. `[writable]` vault_token_account: {@link TokenAccount} The vault's token account receiving the NFT
+ * 9. `[writable]` owner_share_account: {@link TokenAccount} The owner's share token account, created if needed
+ * 10. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 11. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 12. `[]` token_program: {@link PublicKey} SPL Token program
+ * 13. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 14. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 15. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - total_shares: {@link BN} Number of shares minted to the depositor; must be greater than zero
+ * - reserve_price: {@link BN} Price in lamports of the whole NFT in a buyout
+ */
+export const fractionalizeNftSendAndConfirm = async (
+  args: Omit<FractionalizeNftArgs, "feePayer" | "owner"> & {
+    signers: {
+      feePayer: web3.Signer,
+      owner: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return fractionalizeNftBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      owner: args.signers.owner.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.owner])
+    .rpc();
+}
+
+export type BuyoutVaultArgs = {
+  feePayer: web3.PublicKey;
+  buyer: web3.PublicKey;
+  mint: web3.PublicKey;
+  collection: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Buys the NFT out of a vault: a share holder burns their shares and pays the reserve price for the remaining shares into the vault
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` buyer: {@link PublicKey} A share holder buying the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` vault: {@link Vault} The vault holding the NFT
+ * 6. `[writable]` share_mint: {@link Mint} The vault's share mint
+ * 7. `[writable]` buyer_share_account: {@link TokenAccount} The buyer's share token account
+ * 8. `[writable]` vault_token_account: {@link TokenAccount} The vault's token account holding the NFT
+ * 9. `[writable]` buyer_token_account: {@link TokenAccount} The buyer's token account receiving the NFT, created if needed
+ * 10. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 11. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 12. `[]` token_program: {@link PublicKey} SPL Token program
+ * 13. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 14. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 15. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Remaining accounts:
+ * - `[writable]` creators: the collection creators, in the order stored on the collection; required for royalty-enforced collections
+ */
+export const buyoutVaultBuilder = (
+	args: BuyoutVaultArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [metadataPubkey] = pda.deriveNftMetadataPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [vaultPubkey] = pda.deriveVaultPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [shareMintPubkey] = pda.deriveShareMintPDA({
+        vault: vaultPubkey,
+    }, _program.programId);
+    const [buyerShareAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.buyer,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: shareMintPubkey,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [vaultTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: vaultPubkey,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [buyerTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.buyer,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [freezeAuthorityPubkey] = pda.deriveFreezeAuthorityPDA(_program.programId);
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .buyoutVault(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      buyer: args.buyer,
+      mint: args.mint,
+      metadata: metadataPubkey,
+      collection: args.collection,
+      vault: vaultPubkey,
+      shareMint: shareMintPubkey,
+      buyerShareAccount: buyerShareAccountPubkey,
+      vaultTokenAccount: vaultTokenAccountPubkey,
+      buyerTokenAccount: buyerTokenAccountPubkey,
+      freezeAuthority: freezeAuthorityPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      associatedTokenProgram: new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+      programConfig: programConfigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Buys the NFT out of a vault: a share holder burns their shares and pays the reserve price for the remaining shares into the vault
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` buyer: {@link PublicKey} A share holder buying the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` vault: {@link Vault} The vault holding the NFT
+ * 6. `[writable]` share_mint: {@link Mint} The vault's share mint
+ * 7. `[writable]` buyer_share_account: {@link TokenAccount} The buyer's share token account
+ * 8. `[writable]` vault_token_account: {@link TokenAccount} The vault's token account holding the NFT
+ * 9. `[writable]` buyer_token_account: {@link TokenAccount} The buyer's token account receiving the NFT, created if needed
+ * 10. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 11. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 12. `[]` token_program: {@link PublicKey} SPL Token program
+ * 13. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 14. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 15. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Remaining accounts:
+ * - `[writable]` creators: the collection creators, in the order stored on the collection; required for royalty-enforced collections
+ */
+export const buyoutVault = (
+	args: BuyoutVaultArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    buyoutVaultBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Buys the NFT out of a vault: a share holder burns their shares and pays the reserve price for the remaining shares into the vault
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` buyer: {@link PublicKey} A share holder buying the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` vault: {@link Vault} The vault holding the NFT
+ * 6. `[writable]` share_mint: {@link Mint} The vault's share mint
+ * 7. `[writable]` buyer_share_account: {@link TokenAccount} The buyer's share token account
+ * 8. `[writable]` vault_token_account: {@link TokenAccount} The vault's token account holding the NFT
+ * 9. `[writable]` buyer_token_account: {@link TokenAccount} The buyer's token account receiving the NFT, created if needed
+ * 10. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 11. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 12. `[]` token_program: {@link PublicKey} SPL Token program
+ * 13. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 14. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 15. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Remaining accounts:
+ * - `[writable]` creators: the collection creators, in the order stored on the collection; required for royalty-enforced collections
+ */
+export const buyoutVaultSendAndConfirm = async (
+  args: Omit<BuyoutVaultArgs, "feePayer" | "buyer"> & {
+    signers: {
+      feePayer: web3.Signer,
+      buyer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return buyoutVaultBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      buyer: args.signers.buyer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.buyer])
+    .rpc();
+}
+
+export type RedeemSharesArgs = {
+  feePayer: web3.PublicKey;
+  holder: web3.PublicKey;
+  mint: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Burns a holder's shares of a bought-out vault and pays out their part of the buyout proceeds
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` holder: {@link PublicKey} The share holder
+ * 2. `[writable]` vault: {@link Vault} The bought-out vault
+ * 3. `[writable]` share_mint: {@link Mint} The vault's share mint
+ * 4. `[writable]` holder_share_account: {@link TokenAccount} The holder's share token account
+ * 5. `[]` token_program: {@link PublicKey} SPL Token program
+ * 6. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ */
+export const redeemSharesBuilder = (
+	args: RedeemSharesArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [vaultPubkey] = pda.deriveVaultPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [shareMintPubkey] = pda.deriveShareMintPDA({
+        vault: vaultPubkey,
+    }, _program.programId);
+    const [holderShareAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.holder,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: shareMintPubkey,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+
+  return _program
+    .methods
+    .redeemShares(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      holder: args.holder,
+      vault: vaultPubkey,
+      shareMint: shareMintPubkey,
+      holderShareAccount: holderShareAccountPubkey,
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Burns a holder's shares of a bought-out vault and pays out their part of the buyout proceeds
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` holder: {@link PublicKey} The share holder
+ * 2. `[writable]` vault: {@link Vault} The bought-out vault
+ * 3. `[writable]` share_mint: {@link Mint} The vault's share mint
+ * 4. `[writable]` holder_share_account: {@link TokenAccount} The holder's share token account
+ * 5. `[]` token_program: {@link PublicKey} SPL Token program
+ * 6. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ */
+export const redeemShares = (
+	args: RedeemSharesArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    redeemSharesBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Burns a holder's shares of a bought-out vault and pays out their part of the buyout proceeds
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` holder: {@link PublicKey} The share holder
+ * 2. `[writable]` vault: {@link Vault} The bought-out vault
+ * 3. `[writable]` share_mint: {@link Mint} The vault's share mint
+ * 4. `[writable]` holder_share_account: {@link TokenAccount} The holder's share token account
+ * 5. `[]` token_program: {@link PublicKey} SPL Token program
+ * 6. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ */
+export const redeemSharesSendAndConfirm = async (
+  args: Omit<RedeemSharesArgs, "feePayer" | "holder"> & {
+    signers: {
+      feePayer: web3.Signer,
+      holder: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return redeemSharesBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      holder: args.signers.holder.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.holder])
+    .rpc();
+}
+
+export type CreateLoanOfferArgs = {
+  feePayer: web3.PublicKey;
+  lender: web3.PublicKey;
+  collection: web3.PublicKey;
+  offerId: BN;
+  amount: BN;
+  duration: BN;
+  interest: BN;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Offers a loan against any NFT of a collection, escrowing the principal in the loan account
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` lender: {@link PublicKey} The account lending the SOL
+ * 2. `[]` collection: {@link NftCollection} The collection accepted as collateral
+ * 3. `[writable]` loan: {@link Loan} The loan account to initialize
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 5. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - offer_id: {@link BN} Lender-chosen identifier distinguishing their offers
+ * - amount: {@link BN} Principal in lamports
+ * - duration: {@link BN} Seconds the borrower has to repay once the loan is taken
+ * - interest: {@link BN} Interest in lamports owed on top of the principal
+ */
+export const createLoanOfferBuilder = (
+	args: CreateLoanOfferArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [loanPubkey] = pda.deriveLoanPDA({
+        lender: args.lender,
+        offerId: args.offerId,
+    }, _program.programId);
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .createLoanOffer(
+      args.offerId,
+      args.amount,
+      args.duration,
+      args.interest,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      lender: args.lender,
+      collection: args.collection,
+      loan: loanPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+      programConfig: programConfigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Offers a loan against any NFT of a collection, escrowing the principal in the loan account
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` lender: {@link PublicKey} The account lending the SOL
+ * 2. `[]` collection: {@link NftCollection} The collection accepted as collateral
+ * 3. `[writable]` loan: {@link Loan} The loan account to initialize
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 5. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - offer_id: {@link BN} Lender-chosen identifier distinguishing their offers
+ * - amount: {@link BN} Principal in lamports
+ * - duration: {@link BN} Seconds the borrower has to repay once the loan is taken
+ * - interest: {@link BN} Interest in lamports owed on top of the principal
+ */
+export const createLoanOffer = (
+	args: CreateLoanOfferArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    createLoanOfferBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Offers a loan against any NFT of a collection, escrowing the principal in the loan account
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` lender: {@link PublicKey} The account lending the SOL
+ * 2. `[]` collection: {@link NftCollection} The collection accepted as collateral
+ * 3. `[writable]` loan: {@link Loan} The loan account to initialize
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 5. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - offer_id: {@link BN} Lender-chosen identifier distinguishing their offers
+ * - amount: {@link BN} Principal in lamports
+ * - duration: {@link BN} Seconds the borrower has to repay once the loan is taken
+ * - interest: {@link BN} Interest in lamports owed on top of the principal
+ */
+export const createLoanOfferSendAndConfirm = async (
+  args: Omit<CreateLoanOfferArgs, "feePayer" | "lender"> & {
+    signers: {
+      feePayer: web3.Signer,
+      lender: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return createLoanOfferBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      lender: args.signers.lender.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.lender])
+    .rpc();
+}
+
+export type CancelLoanOfferArgs = {
+  feePayer: web3.PublicKey;
+  lender: web3.PublicKey;
+  loan: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Withdraws a loan offer that has not been taken, refunding the principal to the lender
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` lender: {@link PublicKey} The lender who made the offer
+ * 2. `[writable]` loan: {@link Loan} The loan offer to cancel
+ */
+export const cancelLoanOfferBuilder = (
+	args: CancelLoanOfferArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+
+  return _program
+    .methods
+    .cancelLoanOffer(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      lender: args.lender,
+      loan: args.loan,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Withdraws a loan offer that has not been taken, refunding the principal to the lender
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` lender: {@link PublicKey} The lender who made the offer
+ * 2. `[writable]` loan: {@link Loan} The loan offer to cancel
+ */
+export const cancelLoanOffer = (
+	args: CancelLoanOfferArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    cancelLoanOfferBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Withdraws a loan offer that has not been taken, refunding the principal to the lender
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` lender: {@link PublicKey} The lender who made the offer
+ * 2. `[writable]` loan: {@link Loan} The loan offer to cancel
+ */
+export const cancelLoanOfferSendAndConfirm = async (
+  args: Omit<CancelLoanOfferArgs, "feePayer" | "lender"> & {
+    signers: {
+      feePayer: web3.Signer,
+      lender: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return cancelLoanOfferBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      lender: args.signers.lender.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.lender])
+    .rpc();
+}
+
+export type TakeLoanArgs = {
+  feePayer: web3.PublicKey;
+  borrower: web3.PublicKey;
+  mint: web3.PublicKey;
+  collection: web3.PublicKey;
+  loan: web3.PublicKey;
+  expectedAmount: BN;
+  expectedDuration: BN;
+  expectedInterest: BN;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Accepts a loan offer: the NFT moves into an escrow owned by the loan and the principal goes to the borrower
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` borrower: {@link PublicKey} The owner of the NFT used as collateral
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` loan: {@link Loan} The loan offer to accept
+ * 6. `[writable]` borrower_token_account: {@link TokenAccount} The borrower's token account holding the NFT
+ * 7. `[writable]` escrow_token_account: {@link TokenAccount} Escrow token account owned by the loan
+ * 8. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 9. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 10. `[]` token_program: {@link PublicKey} SPL Token program
+ * 11. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 12. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 13. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - expected_amount: {@link BN} Principal the borrower agreed to; the loan fails if the offer differs
+ * - expected_duration: {@link BN} Repayment period the borrower agreed to; the loan fails if the offer differs
+ * - expected_interest: {@link BN} Interest the borrower agreed to; the loan fails if the offer differs
+ */
+export const takeLoanBuilder = (
+	args: TakeLoanArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [metadataPubkey] = pda.deriveNftMetadataPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [borrowerTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.borrower,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [escrowTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.loan,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [freezeAuthorityPubkey] = pda.deriveFreezeAuthorityPDA(_program.programId);
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .takeLoan(
+      args.expectedAmount,
+      args.expectedDuration,
+      args.expectedInterest,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      borrower: args.borrower,
+      mint: args.mint,
+      metadata: metadataPubkey,
+      collection: args.collection,
+      loan: args.loan,
+      borrowerTokenAccount: borrowerTokenAccountPubkey,
+      escrowTokenAccount: escrowTokenAccountPubkey,
+      freezeAuthority: freezeAuthorityPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      associatedTokenProgram: new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+      programConfig: programConfigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Accepts a loan offer: the NFT moves into an escrow owned by the loan and the principal goes to the borrower
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` borrower: {@link PublicKey} The owner of the NFT used as collateral
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` loan: {@link Loan} The loan offer to accept
+ * 6. `[writable]` borrower_token_account: {@link TokenAccount} The borrower's token account holding the NFT
+ * 7. `[writable]` escrow_token_account: {@link TokenAccount} Escrow token account owned by the loan
+ * 8. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 9. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 10. `[]` token_program: {@link PublicKey} SPL Token program
+ * 11. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 12. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 13. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - expected_amount: {@link BN} Principal the borrower agreed to; the loan fails if the offer differs
+ * - expected_duration: {@link BN} Repayment period the borrower agreed to; the loan fails if the offer differs
+ * - expected_interest: {@link BN} Interest the borrower agreed to; the loan fails if the offer differs
+ */
+export const takeLoan = (
+	args: TakeLoanArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    takeLoanBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Accepts a loan offer: the NFT moves into an escrow owned by the loan and the principal goes to the borrower
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` borrower: {@link PublicKey} The owner of the NFT used as collateral
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` loan: {@link Loan} The loan offer to accept
+ * 6. `[writable]` borrower_token_account: {@link TokenAccount} The borrower's token account holding the NFT
+ * 7. `[writable]` escrow_token_account: {@link TokenAccount} Escrow token account owned by the loan
+ * 8. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 9. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 10. `[]` token_program: {@link PublicKey} SPL Token program
+ * 11. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 12. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 13. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Data:
+ * - expected_amount: {@link BN} Principal the borrower agreed to; the loan fails if the offer differs
+ * - expected_duration: {@link BN} Repayment period the borrower agreed to; the loan fails if the offer differs
+ * - expected_interest: {@link BN} Interest the borrower agreed to; the loan fails if the offer differs
+ */
+export const takeLoanSendAndConfirm = async (
+  args: Omit<TakeLoanArgs, "feePayer" | "borrower"> & {
+    signers: {
+      feePayer: web3.Signer,
+      borrower: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return takeLoanBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      borrower: args.signers.borrower.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.borrower])
+    .rpc();
+}
+
+export type RepayLoanArgs = {
+  feePayer: web3.PublicKey;
+  borrower: web3.PublicKey;
+  lender: web3.PublicKey;
+  mint: web3.PublicKey;
+  collection: web3.PublicKey;
+  loan: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Repays a loan with interest before its deadline and returns the NFT to the borrower
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` borrower: {@link PublicKey} The borrower of the loan
+ * 2. `[writable]` lender: {@link PublicKey} The lender, who receives the repayment
+ * 3. `[]` mint: {@link Mint} The mint account of the NFT
+ * 4. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 5. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 6. `[writable]` loan: {@link Loan} The loan to repay
+ * 7. `[writable]` escrow_token_account: {@link TokenAccount} Escrow token account owned by the loan
+ * 8. `[writable]` borrower_token_account: {@link TokenAccount} The borrower's token account receiving the NFT
+ * 9. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 10. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 11. `[]` token_program: {@link PublicKey} SPL Token program
+ * 12. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ */
+export const repayLoanBuilder = (
+	args: RepayLoanArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [metadataPubkey] = pda.deriveNftMetadataPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [escrowTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.loan,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [borrowerTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.borrower,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [freezeAuthorityPubkey] = pda.deriveFreezeAuthorityPDA(_program.programId);
+
+  return _program
+    .methods
+    .repayLoan(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      borrower: args.borrower,
+      lender: args.lender,
+      mint: args.mint,
+      metadata: metadataPubkey,
+      collection: args.collection,
+      loan: args.loan,
+      escrowTokenAccount: escrowTokenAccountPubkey,
+      borrowerTokenAccount: borrowerTokenAccountPubkey,
+      freezeAuthority: freezeAuthorityPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Repays a loan with interest before its deadline and returns the NFT to the borrower
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` borrower: {@link PublicKey} The borrower of the loan
+ * 2. `[writable]` lender: {@link PublicKey} The lender, who receives the repayment
+ * 3. `[]` mint: {@link Mint} The mint account of the NFT
+ * 4. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 5. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 6. `[writable]` loan: {@link Loan} The loan to repay
+ * 7. `[writable]` escrow_token_account: {@link TokenAccount} Escrow token account owned by the loan
+ * 8. `[writable]` borrower_token_account: {@link TokenAccount} The borrower's token account receiving the NFT
+ * 9. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 10. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 11. `[]` token_program: {@link PublicKey} SPL Token program
+ * 12. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ */
+export const repayLoan = (
+	args: RepayLoanArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    repayLoanBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Repays a loan with interest before its deadline and returns the NFT to the borrower
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` borrower: {@link PublicKey} The borrower of the loan
+ * 2. `[writable]` lender: {@link PublicKey} The lender, who receives the repayment
+ * 3. `[]` mint: {@link Mint} The mint account of the NFT
+ * 4. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 5. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 6. `[writable]` loan: {@link Loan} The loan to repay
+ * 7. `[writable]` escrow_token_account: {@link TokenAccount} Escrow token account owned by the loan
+ * 8. `[writable]` borrower_token_account: {@link TokenAccount} The borrower's token account receiving the NFT
+ * 9. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 10. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 11. `[]` token_program: {@link PublicKey} SPL Token program
+ * 12. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ */
+export const repayLoanSendAndConfirm = async (
+  args: Omit<RepayLoanArgs, "feePayer" | "borrower"> & {
+    signers: {
+      feePayer: web3.Signer,
+      borrower: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return repayLoanBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      borrower: args.signers.borrower.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.borrower])
+    .rpc();
+}
+
+export type ForecloseLoanArgs = {
+  feePayer: web3.PublicKey;
+  lender: web3.PublicKey;
+  mint: web3.PublicKey;
+  collection: web3.PublicKey;
+  loan: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Claims the collateral of a loan that was not repaid before its deadline
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` lender: {@link PublicKey} The lender of the loan
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` loan: {@link Loan} The defaulted loan
+ * 6. `[writable]` escrow_token_account: {@link TokenAccount} Escrow token account owned by the loan
+ * 7. `[writable]` lender_token_account: {@link TokenAccount} The lender's token account receiving the NFT, created if needed
+ * 8. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 9. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 10. `[]` token_program: {@link PublicKey} SPL Token program
+ * 11. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 12. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 13. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Remaining accounts:
+ * - `[writable]` creators: the collection creators, in the order stored on the collection; required for royalty-enforced collections
+ */
+export const forecloseLoanBuilder = (
+	args: ForecloseLoanArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [metadataPubkey] = pda.deriveNftMetadataPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [escrowTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.loan,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [lenderTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.lender,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [freezeAuthorityPubkey] = pda.deriveFreezeAuthorityPDA(_program.programId);
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .forecloseLoan(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      lender: args.lender,
+      mint: args.mint,
+      metadata: metadataPubkey,
+      collection: args.collection,
+      loan: args.loan,
+      escrowTokenAccount: escrowTokenAccountPubkey,
+      lenderTokenAccount: lenderTokenAccountPubkey,
+      freezeAuthority: freezeAuthorityPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      associatedTokenProgram: new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+      programConfig: programConfigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Claims the collateral of a loan that was not repaid before its deadline
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` lender: {@link PublicKey} The lender of the loan
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` loan: {@link Loan} The defaulted loan
+ * 6. `[writable]` escrow_token_account: {@link TokenAccount} Escrow token account owned by the loan
+ * 7. `[writable]` lender_token_account: {@link TokenAccount} The lender's token account receiving the NFT, created if needed
+ * 8. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 9. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 10. `[]` token_program: {@link PublicKey} SPL Token program
+ * 11. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 12. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 13. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Remaining accounts:
+ * - `[writable]` creators: the collection creators, in the order stored on the collection; required for royalty-enforced collections
+ */
+export const forecloseLoan = (
+	args: ForecloseLoanArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    forecloseLoanBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Claims the collateral of a loan that was not repaid before its deadline
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` lender: {@link PublicKey} The lender of the loan
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` loan: {@link Loan} The defaulted loan
+ * 6. `[writable]` escrow_token_account: {@link TokenAccount} Escrow token account owned by the loan
+ * 7. `[writable]` lender_token_account: {@link TokenAccount} The lender's token account receiving the NFT, created if needed
+ * 8. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 9. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 10. `[]` token_program: {@link PublicKey} SPL Token program
+ * 11. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 12. `[]` associated_token_program: {@link PublicKey} Associated Token program
+ * 13. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Remaining accounts:
+ * - `[writable]` creators: the collection creators, in the order stored on the collection; required for royalty-enforced collections
+ */
+export const forecloseLoanSendAndConfirm = async (
+  args: Omit<ForecloseLoanArgs, "feePayer" | "lender"> & {
+    signers: {
+      feePayer: web3.Signer,
+      lender: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return forecloseLoanBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      lender: args.signers.lender.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.lender])
+    .rpc();
+}
+
+export type EnableRoyaltyEnforcementArgs = {
+  feePayer: web3.PublicKey;
+  authority: web3.PublicKey;
+  seedAuthority: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Turns on royalty enforcement for a collection: its tokens are frozen from mint and only move through this program. Must be done before the first mint and cannot be undone
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to configure
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ */
+export const enableRoyaltyEnforcementBuilder = (
+	args: EnableRoyaltyEnforcementArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [collectionPubkey] = pda.deriveCollectionPDA({
+        authority: args.seedAuthority,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .enableRoyaltyEnforcement(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      collection: collectionPubkey,
+      authority: args.authority,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Turns on royalty enforcement for a collection: its tokens are frozen from mint and only move through this program. Must be done before the first mint and cannot be undone
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to configure
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ */
+export const enableRoyaltyEnforcement = (
+	args: EnableRoyaltyEnforcementArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    enableRoyaltyEnforcementBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Turns on royalty enforcement for a collection: its tokens are frozen from mint and only move through this program. Must be done before the first mint and cannot be undone
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to configure
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ */
+export const enableRoyaltyEnforcementSendAndConfirm = async (
+  args: Omit<EnableRoyaltyEnforcementArgs, "feePayer" | "authority"> & {
+    signers: {
+      feePayer: web3.Signer,
+      authority: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return enableRoyaltyEnforcementBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      authority: args.signers.authority.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.authority])
+    .rpc();
+}
+
+export type SetRuleSetArgs = {
+  feePayer: web3.PublicKey;
+  authority: web3.PublicKey;
+  seedAuthority: web3.PublicKey;
+  mode: IdlTypes<MetaplexNftProgram>["ruleSetMode"];
+  programs: web3.PublicKey[];
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Sets the programs allowed, or denied, to act as transfer authority or delegate for a collection's NFTs
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` collection: {@link NftCollection} The collection to configure
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[writable]` rule_set: {@link RuleSet} The collection's rule set, created if needed
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - mode: {@link RuleSetMode} Whether the listed programs are the only ones allowed, or the ones denied
+ * - programs: {@link PublicKey[]} The program IDs the rule set lists
+ */
+export const setRuleSetBuilder = (
+	args: SetRuleSetArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [collectionPubkey] = pda.deriveCollectionPDA({
+        authority: args.seedAuthority,
+    }, _program.programId);
+    const [ruleSetPubkey] = pda.deriveRuleSetPDA({
+        collection: collectionPubkey,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .setRuleSet(
+      args.mode,
+      args.programs,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      collection: collectionPubkey,
+      authority: args.authority,
+      ruleSet: ruleSetPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Sets the programs allowed, or denied, to act as transfer authority or delegate for a collection's NFTs
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` collection: {@link NftCollection} The collection to configure
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[writable]` rule_set: {@link RuleSet} The collection's rule set, created if needed
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - mode: {@link RuleSetMode} Whether the listed programs are the only ones allowed, or the ones denied
+ * - programs: {@link PublicKey[]} The program IDs the rule set lists
+ */
+export const setRuleSet = (
+	args: SetRuleSetArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    setRuleSetBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Sets the programs allowed, or denied, to act as transfer authority or delegate for a collection's NFTs
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` collection: {@link NftCollection} The collection to configure
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[writable]` rule_set: {@link RuleSet} The collection's rule set, created if needed
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - mode: {@link RuleSetMode} Whether the listed programs are the only ones allowed, or the ones denied
+ * - programs: {@link PublicKey[]} The program IDs the rule set lists
+ */
+export const setRuleSetSendAndConfirm = async (
+  args: Omit<SetRuleSetArgs, "feePayer" | "authority"> & {
+    signers: {
+      feePayer: web3.Signer,
+      authority: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return setRuleSetBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      authority: args.signers.authority.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.authority])
+    .rpc();
+}
+
+export type MakeCollectionSoulboundArgs = {
+  feePayer: web3.PublicKey;
+  authority: web3.PublicKey;
+  seedAuthority: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Makes a collection soulbound: its NFTs are frozen from mint and can never be transferred, only burned by their owner. Must be done before the first mint and cannot be undone
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to configure
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ */
+export const makeCollectionSoulboundBuilder = (
+	args: MakeCollectionSoulboundArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [collectionPubkey] = pda.deriveCollectionPDA({
+        authority: args.seedAuthority,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .makeCollectionSoulbound(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      collection: collectionPubkey,
+      authority: args.authority,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Makes a collection soulbound: its NFTs are frozen from mint and can never be transferred, only burned by their owner. Must be done before the first mint and cannot be undone
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to configure
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ */
+export const makeCollectionSoulbound = (
+	args: MakeCollectionSoulboundArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    makeCollectionSoulboundBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Makes a collection soulbound: its NFTs are frozen from mint and can never be transferred, only burned by their owner. Must be done before the first mint and cannot be undone
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to configure
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ */
+export const makeCollectionSoulboundSendAndConfirm = async (
+  args: Omit<MakeCollectionSoulboundArgs, "feePayer" | "authority"> & {
+    signers: {
+      feePayer: web3.Signer,
+      authority: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return makeCollectionSoulboundBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      authority: args.signers.authority.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.authority])
+    .rpc();
+}
+
+export type BurnNftArgs = {
+  feePayer: web3.PublicKey;
+  owner: web3.PublicKey;
+  mint: web3.PublicKey;
+  collection: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Burns an NFT held in its owner's wallet, closing its token and metadata accounts
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` owner: {@link PublicKey} The owner of the NFT; receives the closed accounts' rent
+ * 2. `[writable]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[writable]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` owner_token_account: {@link TokenAccount} The owner's token account holding the NFT
+ * 6. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 7. `[]` token_program: {@link PublicKey} SPL Token program
+ * 8. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 9. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ */
+export const burnNftBuilder = (
+	args: BurnNftArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [metadataPubkey] = pda.deriveNftMetadataPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [ownerTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.owner,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [freezeAuthorityPubkey] = pda.deriveFreezeAuthorityPDA(_program.programId);
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .burnNft(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      owner: args.owner,
+      mint: args.mint,
+      metadata: metadataPubkey,
+      collection: args.collection,
+      ownerTokenAccount: ownerTokenAccountPubkey,
+      freezeAuthority: freezeAuthorityPubkey,
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      programConfig: programConfigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Burns an NFT held in its owner's wallet, closing its token and metadata accounts
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` owner: {@link PublicKey} The owner of the NFT; receives the closed accounts' rent
+ * 2. `[writable]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[writable]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` owner_token_account: {@link TokenAccount} The owner's token account holding the NFT
+ * 6. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 7. `[]` token_program: {@link PublicKey} SPL Token program
+ * 8. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 9. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ */
+export const burnNft = (
+	args: BurnNftArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    burnNftBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Burns an NFT held in its owner's wallet, closing its token and metadata accounts
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` owner: {@link PublicKey} The owner of the NFT; receives the closed accounts' rent
+ * 2. `[writable]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[writable]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 5. `[writable]` owner_token_account: {@link TokenAccount} The owner's token account holding the NFT
+ * 6. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 7. `[]` token_program: {@link PublicKey} SPL Token program
+ * 8. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 9. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ */
+export const burnNftSendAndConfirm = async (
+  args: Omit<BurnNftArgs, "feePayer" | "owner"> & {
+    signers: {
+      feePayer: web3.Signer,
+      owner: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return burnNftBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      owner: args.signers.owner.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.owner])
+    .rpc();
+}
+
+export type ApproveDelegateArgs = {
+  feePayer: web3.PublicKey;
+  owner: web3.PublicKey;
+  mint: web3.PublicKey;
+  delegate: web3.PublicKey;
+  collection: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Approves a delegate that may sign transfer_nft in place of the owner until the NFT is transferred or the delegation revoked
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` owner: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[writable]` owner_token_account: {@link TokenAccount} The owner's token account holding the NFT
+ * 5. `[]` delegate: {@link PublicKey} The account to approve as transfer delegate; it, or the program owning it, must pass the collection's rule set
+ * 6. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 7. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 8. `[]` rule_set: {@link PublicKey} The rule set of the NFT's collection
+ * 9. `[]` instructions: {@link PublicKey} Instructions sysvar, to find the calling program
+ * 10. `[]` token_program: {@link PublicKey} SPL Token program
+ * 11. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 12. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ */
+export const approveDelegateBuilder = (
+	args: ApproveDelegateArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [metadataPubkey] = pda.deriveNftMetadataPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [ownerTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.owner,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [freezeAuthorityPubkey] = pda.deriveFreezeAuthorityPDA(_program.programId);
+    const [ruleSetPubkey] = pda.deriveRuleSetPDA({
+        collection: args.collection,
+    }, _program.programId);
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .approveDelegate(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      owner: args.owner,
+      mint: args.mint,
+      metadata: metadataPubkey,
+      ownerTokenAccount: ownerTokenAccountPubkey,
+      delegate: args.delegate,
+      collection: args.collection,
+      freezeAuthority: freezeAuthorityPubkey,
+      ruleSet: ruleSetPubkey,
+      instructions: new web3.PublicKey("Sysvar1nstructions1111111111111111111111111"),
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      programConfig: programConfigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Approves a delegate that may sign transfer_nft in place of the owner until the NFT is transferred or the delegation revoked
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` owner: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[writable]` owner_token_account: {@link TokenAccount} The owner's token account holding the NFT
+ * 5. `[]` delegate: {@link PublicKey} The account to approve as transfer delegate; it, or the program owning it, must pass the collection's rule set
+ * 6. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 7. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 8. `[]` rule_set: {@link PublicKey} The rule set of the NFT's collection
+ * 9. `[]` instructions: {@link PublicKey} Instructions sysvar, to find the calling program
+ * 10. `[]` token_program: {@link PublicKey} SPL Token program
+ * 11. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 12. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ */
+export const approveDelegate = (
+	args: ApproveDelegateArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    approveDelegateBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Approves a delegate that may sign transfer_nft in place of the owner until the NFT is transferred or the delegation revoked
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` owner: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[writable]` owner_token_account: {@link TokenAccount} The owner's token account holding the NFT
+ * 5. `[]` delegate: {@link PublicKey} The account to approve as transfer delegate; it, or the program owning it, must pass the collection's rule set
+ * 6. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 7. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 8. `[]` rule_set: {@link PublicKey} The rule set of the NFT's collection
+ * 9. `[]` instructions: {@link PublicKey} Instructions sysvar, to find the calling program
+ * 10. `[]` token_program: {@link PublicKey} SPL Token program
+ * 11. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ * 12. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ */
+export const approveDelegateSendAndConfirm = async (
+  args: Omit<ApproveDelegateArgs, "feePayer" | "owner"> & {
+    signers: {
+      feePayer: web3.Signer,
+      owner: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return approveDelegateBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      owner: args.signers.owner.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.owner])
+    .rpc();
+}
+
+export type RevokeDelegateArgs = {
+  feePayer: web3.PublicKey;
+  owner: web3.PublicKey;
+  mint: web3.PublicKey;
+  collection: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Revokes the NFT's transfer delegate
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` owner: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[writable]` owner_token_account: {@link TokenAccount} The owner's token account holding the NFT
+ * 5. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 6. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 7. `[]` token_program: {@link PublicKey} SPL Token program
+ * 8. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ */
+export const revokeDelegateBuilder = (
+	args: RevokeDelegateArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [metadataPubkey] = pda.deriveNftMetadataPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [ownerTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+        wallet: args.owner,
+        tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        mint: args.mint,
+    }, new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+    const [freezeAuthorityPubkey] = pda.deriveFreezeAuthorityPDA(_program.programId);
+
+  return _program
+    .methods
+    .revokeDelegate(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      owner: args.owner,
+      mint: args.mint,
+      metadata: metadataPubkey,
+      ownerTokenAccount: ownerTokenAccountPubkey,
+      collection: args.collection,
+      freezeAuthority: freezeAuthorityPubkey,
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Revokes the NFT's transfer delegate
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` owner: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[writable]` owner_token_account: {@link TokenAccount} The owner's token account holding the NFT
+ * 5. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 6. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 7. `[]` token_program: {@link PublicKey} SPL Token program
+ * 8. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ */
+export const revokeDelegate = (
+	args: RevokeDelegateArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    revokeDelegateBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Revokes the NFT's transfer delegate
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` owner: {@link PublicKey} The owner of the NFT
+ * 2. `[]` mint: {@link Mint} The mint account of the NFT
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 4. `[writable]` owner_token_account: {@link TokenAccount} The owner's token account holding the NFT
+ * 5. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 6. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 7. `[]` token_program: {@link PublicKey} SPL Token program
+ * 8. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ */
+export const revokeDelegateSendAndConfirm = async (
+  args: Omit<RevokeDelegateArgs, "feePayer" | "owner"> & {
+    signers: {
+      feePayer: web3.Signer,
+      owner: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return revokeDelegateBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      owner: args.signers.owner.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.owner])
+    .rpc();
+}
+
+export type FreezeNftArgs = {
+  feePayer: web3.PublicKey;
+  authority: web3.PublicKey;
+  mint: web3.PublicKey;
+  holder: web3.PublicKey;
+  holderTokenAccount: web3.PublicKey;
+  seedAuthority: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Freezes an NFT on behalf of its collection authority, e.g. while it is reported stolen or disputed; it cannot be transferred, listed or updated until thawed
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[]` mint: {@link Mint} The mint account of the NFT
+ * 4. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 5. `[]` holder: {@link PublicKey} The NFT's current holder, a wallet or one of the program's escrows
+ * 6. `[writable]` holder_token_account: {@link TokenAccount} The holder's token account holding the NFT
+ * 7. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 8. `[]` token_program: {@link PublicKey} SPL Token program
+ * 9. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ */
+export const freezeNftBuilder = (
+	args: FreezeNftArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [collectionPubkey] = pda.deriveCollectionPDA({
+        authority: args.seedAuthority,
+    }, _program.programId);
+    const [metadataPubkey] = pda.deriveNftMetadataPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [freezeAuthorityPubkey] = pda.deriveFreezeAuthorityPDA(_program.programId);
+
+  return _program
+    .methods
+    .freezeNft(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      collection: collectionPubkey,
+      authority: args.authority,
+      mint: args.mint,
+      metadata: metadataPubkey,
+      holder: args.holder,
+      holderTokenAccount: args.holderTokenAccount,
+      freezeAuthority: freezeAuthorityPubkey,
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Freezes an NFT on behalf of its collection authority, e.g. while it is reported stolen or disputed; it cannot be transferred, listed or updated until thawed
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[]` mint: {@link Mint} The mint account of the NFT
+ * 4. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 5. `[]` holder: {@link PublicKey} The NFT's current holder, a wallet or one of the program's escrows
+ * 6. `[writable]` holder_token_account: {@link TokenAccount} The holder's token account holding the NFT
+ * 7. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 8. `[]` token_program: {@link PublicKey} SPL Token program
+ * 9. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ */
+export const freezeNft = (
+	args: FreezeNftArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    freezeNftBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Freezes an NFT on behalf of its collection authority, e.g. while it is reported stolen or disputed; it cannot be transferred, listed or updated until thawed
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[]` mint: {@link Mint} The mint account of the NFT
+ * 4. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 5. `[]` holder: {@link PublicKey} The NFT's current holder, a wallet or one of the program's escrows
+ * 6. `[writable]` holder_token_account: {@link TokenAccount} The holder's token account holding the NFT
+ * 7. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 8. `[]` token_program: {@link PublicKey} SPL Token program
+ * 9. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ */
+export const freezeNftSendAndConfirm = async (
+  args: Omit<FreezeNftArgs, "feePayer" | "authority"> & {
+    signers: {
+      feePayer: web3.Signer,
+      authority: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return freezeNftBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      authority: args.signers.authority.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.authority])
+    .rpc();
+}
+
+export type ThawNftArgs = {
+  feePayer: web3.PublicKey;
+  authority: web3.PublicKey;
+  mint: web3.PublicKey;
+  holder: web3.PublicKey;
+  holderTokenAccount: web3.PublicKey;
+  seedAuthority: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Lifts a freeze placed by the collection authority
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[]` mint: {@link Mint} The mint account of the NFT
+ * 4. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 5. `[]` holder: {@link PublicKey} The NFT's current holder, a wallet or one of the program's escrows
+ * 6. `[writable]` holder_token_account: {@link TokenAccount} The holder's token account holding the NFT
+ * 7. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 8. `[]` token_program: {@link PublicKey} SPL Token program
+ * 9. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ */
+export const thawNftBuilder = (
+	args: ThawNftArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [collectionPubkey] = pda.deriveCollectionPDA({
+        authority: args.seedAuthority,
+    }, _program.programId);
+    const [metadataPubkey] = pda.deriveNftMetadataPDA({
+        mint: args.mint,
+    }, _program.programId);
+    const [freezeAuthorityPubkey] = pda.deriveFreezeAuthorityPDA(_program.programId);
+
+  return _program
+    .methods
+    .thawNft(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      collection: collectionPubkey,
+      authority: args.authority,
+      mint: args.mint,
+      metadata: metadataPubkey,
+      holder: args.holder,
+      holderTokenAccount: args.holderTokenAccount,
+      freezeAuthority: freezeAuthorityPubkey,
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Lifts a freeze placed by the collection authority
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[]` mint: {@link Mint} The mint account of the NFT
+ * 4. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 5. `[]` holder: {@link PublicKey} The NFT's current holder, a wallet or one of the program's escrows
+ * 6. `[writable]` holder_token_account: {@link TokenAccount} The holder's token account holding the NFT
+ * 7. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 8. `[]` token_program: {@link PublicKey} SPL Token program
+ * 9. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ */
+export const thawNft = (
+	args: ThawNftArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    thawNftBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Lifts a freeze placed by the collection authority
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` collection: {@link NftCollection} The collection the NFT belongs to
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[]` mint: {@link Mint} The mint account of the NFT
+ * 4. `[writable]` metadata: {@link NftMint} The metadata account of the NFT
+ * 5. `[]` holder: {@link PublicKey} The NFT's current holder, a wallet or one of the program's escrows
+ * 6. `[writable]` holder_token_account: {@link TokenAccount} The holder's token account holding the NFT
+ * 7. `[]` freeze_authority: {@link PublicKey} PDA set as freeze authority on the program's mints
+ * 8. `[]` token_program: {@link PublicKey} SPL Token program
+ * 9. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
+ */
+export const thawNftSendAndConfirm = async (
+  args: Omit<ThawNftArgs, "feePayer" | "authority"> & {
+    signers: {
+      feePayer: web3.Signer,
+      authority: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return thawNftBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      authority: args.signers.authority.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.authority])
+    .rpc();
+}
+
+export type InitializeProgramConfigArgs = {
+  feePayer: web3.PublicKey;
+  upgradeAuthority: web3.PublicKey;
+  programData: web3.PublicKey;
+  admin: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Creates the program-wide config and sets its admin; must be signed by the program's upgrade authority
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` program_config: {@link ProgramConfig} The program config account to initialize
+ * 2. `[writable]` treasury: {@link Treasury} The fee treasury to initialize
+ * 3. `[signer]` upgrade_authority: {@link PublicKey} The program's upgrade authority
+ * 4. `[]` program: {@link PublicKey} This program
+ * 5. `[]` program_data: {@link ProgramData} This program's program data account
+ * 6. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - admin: {@link PublicKey} The key allowed to pause the program and to hand over the admin role
+ */
+export const initializeProgramConfigBuilder = (
+	args: InitializeProgramConfigArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+    const [treasuryPubkey] = pda.deriveTreasuryPDA(_program.programId);
+
+  return _program
+    .methods
+    .initializeProgramConfig(
+      args.admin,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      programConfig: programConfigPubkey,
+      treasury: treasuryPubkey,
+      upgradeAuthority: args.upgradeAuthority,
+      program: new web3.PublicKey("4mAMsimURXatxBJNW6AydZBmHsmXrksPJDPafNAfHAeT"),
+      programData: args.programData,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Creates the program-wide config and sets its admin; must be signed by the program's upgrade authority
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` program_config: {@link ProgramConfig} The program config account to initialize
+ * 2. `[writable]` treasury: {@link Treasury} The fee treasury to initialize
+ * 3. `[signer]` upgrade_authority: {@link PublicKey} The program's upgrade authority
+ * 4. `[]` program: {@link PublicKey} This program
+ * 5. `[]` program_data: {@link ProgramData} This program's program data account
+ * 6. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - admin: {@link PublicKey} The key allowed to pause the program and to hand over the admin role
+ */
+export const initializeProgramConfig = (
+	args: InitializeProgramConfigArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    initializeProgramConfigBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Creates the program-wide config and sets its admin; must be signed by the program's upgrade authority
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` program_config: {@link ProgramConfig} The program config account to initialize
+ * 2. `[writable]` treasury: {@link Treasury} The fee treasury to initialize
+ * 3. `[signer]` upgrade_authority: {@link PublicKey} The program's upgrade authority
+ * 4. `[]` program: {@link PublicKey} This program
+ * 5. `[]` program_data: {@link ProgramData} This program's program data account
+ * 6. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - admin: {@link PublicKey} The key allowed to pause the program and to hand over the admin role
+ */
+export const initializeProgramConfigSendAndConfirm = async (
+  args: Omit<InitializeProgramConfigArgs, "feePayer" | "upgradeAuthority"> & {
+    signers: {
+      feePayer: web3.Signer,
+      upgradeAuthority: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return initializeProgramConfigBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      upgradeAuthority: args.signers.upgradeAuthority.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.upgradeAuthority])
+    .rpc();
+}
+
+export type SetProgramAdminArgs = {
+  feePayer: web3.PublicKey;
+  admin: web3.PublicKey;
+  newAdmin: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Hands the program admin role over to a new key
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` program_config: {@link ProgramConfig} The program config
+ * 2. `[signer]` admin: {@link PublicKey} The current program admin
+ *
+ * Data:
+ * - new_admin: {@link PublicKey} The new program admin
+ */
+export const setProgramAdminBuilder = (
+	args: SetProgramAdminArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .setProgramAdmin(
+      args.newAdmin,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      programConfig: programConfigPubkey,
+      admin: args.admin,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Hands the program admin role over to a new key
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` program_config: {@link ProgramConfig} The program config
+ * 2. `[signer]` admin: {@link PublicKey} The current program admin
+ *
+ * Data:
+ * - new_admin: {@link PublicKey} The new program admin
+ */
+export const setProgramAdmin = (
+	args: SetProgramAdminArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    setProgramAdminBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Hands the program admin role over to a new key
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` program_config: {@link ProgramConfig} The program config
+ * 2. `[signer]` admin: {@link PublicKey} The current program admin
+ *
+ * Data:
+ * - new_admin: {@link PublicKey} The new program admin
+ */
+export const setProgramAdminSendAndConfirm = async (
+  args: Omit<SetProgramAdminArgs, "feePayer" | "admin"> & {
+    signers: {
+      feePayer: web3.Signer,
+      admin: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return setProgramAdminBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      admin: args.signers.admin.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.admin])
+    .rpc();
+}
+
+export type SetProgramPausedArgs = {
+  feePayer: web3.PublicKey;
+  admin: web3.PublicKey;
+  paused: boolean;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Pauses or resumes minting, trading and other NFT activity across every collection
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` program_config: {@link ProgramConfig} The program config
+ * 2. `[signer]` admin: {@link PublicKey} The program admin
+ *
+ * Data:
+ * - paused: {@link boolean} Whether the program is paused
+ */
+export const setProgramPausedBuilder = (
+	args: SetProgramPausedArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .setProgramPaused(
+      args.paused,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      programConfig: programConfigPubkey,
+      admin: args.admin,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Pauses or resumes minting, trading and other NFT activity across every collection
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` program_config: {@link ProgramConfig} The program config
+ * 2. `[signer]` admin: {@link PublicKey} The program admin
+ *
+ * Data:
+ * - paused: {@link boolean} Whether the program is paused
+ */
+export const setProgramPaused = (
+	args: SetProgramPausedArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    setProgramPausedBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Pauses or resumes minting, trading and other NFT activity across every collection
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` program_config: {@link ProgramConfig} The program config
+ * 2. `[signer]` admin: {@link PublicKey} The program admin
+ *
+ * Data:
+ * - paused: {@link boolean} Whether the program is paused
+ */
+export const setProgramPausedSendAndConfirm = async (
+  args: Omit<SetProgramPausedArgs, "feePayer" | "admin"> & {
+    signers: {
+      feePayer: web3.Signer,
+      admin: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return setProgramPausedBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      admin: args.signers.admin.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.admin])
+    .rpc();
+}
+
+export type SetCollectionPausedArgs = {
+  feePayer: web3.PublicKey;
+  authority: web3.PublicKey;
+  seedAuthority: web3.PublicKey;
+  paused: boolean;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Pauses or resumes minting, trading and other NFT activity for a collection
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to pause or resume
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ *
+ * Data:
+ * - paused: {@link boolean} Whether the collection is paused
+ */
+export const setCollectionPausedBuilder = (
+	args: SetCollectionPausedArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [collectionPubkey] = pda.deriveCollectionPDA({
+        authority: args.seedAuthority,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .setCollectionPaused(
+      args.paused,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      collection: collectionPubkey,
+      authority: args.authority,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Pauses or resumes minting, trading and other NFT activity for a collection
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to pause or resume
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ *
+ * Data:
+ * - paused: {@link boolean} Whether the collection is paused
+ */
+export const setCollectionPaused = (
+	args: SetCollectionPausedArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    setCollectionPausedBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Pauses or resumes minting, trading and other NFT activity for a collection
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to pause or resume
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ *
+ * Data:
+ * - paused: {@link boolean} Whether the collection is paused
+ */
+export const setCollectionPausedSendAndConfirm = async (
+  args: Omit<SetCollectionPausedArgs, "feePayer" | "authority"> & {
+    signers: {
+      feePayer: web3.Signer,
+      authority: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return setCollectionPausedBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      authority: args.signers.authority.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.authority])
+    .rpc();
+}
+
+export type SetProtocolFeeArgs = {
+  feePayer: web3.PublicKey;
+  admin: web3.PublicKey;
+  mintFeeLamports: BN;
+  mintFeeBps: number;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Sets the protocol fee charged on every mint: a flat amount plus a share of the mint price
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` program_config: {@link ProgramConfig} The program config
+ * 2. `[signer]` admin: {@link PublicKey} The program admin
+ *
+ * Data:
+ * - mint_fee_lamports: {@link BN} Flat fee in lamports charged per minted NFT
+ * - mint_fee_bps: {@link number} Fee in basis points of the mint price
+ */
+export const setProtocolFeeBuilder = (
+	args: SetProtocolFeeArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .setProtocolFee(
+      args.mintFeeLamports,
+      args.mintFeeBps,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      programConfig: programConfigPubkey,
+      admin: args.admin,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Sets the protocol fee charged on every mint: a flat amount plus a share of the mint price
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` program_config: {@link ProgramConfig} The program config
+ * 2. `[signer]` admin: {@link PublicKey} The program admin
+ *
+ * Data:
+ * - mint_fee_lamports: {@link BN} Flat fee in lamports charged per minted NFT
+ * - mint_fee_bps: {@link number} Fee in basis points of the mint price
+ */
+export const setProtocolFee = (
+	args: SetProtocolFeeArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    setProtocolFeeBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Sets the protocol fee charged on every mint: a flat amount plus a share of the mint price
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` program_config: {@link ProgramConfig} The program config
+ * 2. `[signer]` admin: {@link PublicKey} The program admin
+ *
+ * Data:
+ * - mint_fee_lamports: {@link BN} Flat fee in lamports charged per minted NFT
+ * - mint_fee_bps: {@link number} Fee in basis points of the mint price
+ */
+export const setProtocolFeeSendAndConfirm = async (
+  args: Omit<SetProtocolFeeArgs, "feePayer" | "admin"> & {
+    signers: {
+      feePayer: web3.Signer,
+      admin: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return setProtocolFeeBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      admin: args.signers.admin.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.admin])
+    .rpc();
+}
+
+export type WithdrawFeesArgs = {
+  feePayer: web3.PublicKey;
+  admin: web3.PublicKey;
+  destination: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Sweeps the protocol fees collected in the treasury
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` program_config: {@link ProgramConfig} The program config
+ * 2. `[signer]` admin: {@link PublicKey} The program admin
+ * 3. `[writable]` treasury: {@link Treasury} The fee treasury
+ * 4. `[writable]` destination: {@link PublicKey} Receives the fees
+ */
+export const withdrawFeesBuilder = (
+	args: WithdrawFeesArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+    const [treasuryPubkey] = pda.deriveTreasuryPDA(_program.programId);
+
+  return _program
+    .methods
+    .withdrawFees(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      programConfig: programConfigPubkey,
+      admin: args.admin,
+      treasury: treasuryPubkey,
+      destination: args.destination,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Sweeps the protocol fees collected in the treasury
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` program_config: {@link ProgramConfig} The program config
+ * 2. `[signer]` admin: {@link PublicKey} The program admin
+ * 3. `[writable]` treasury: {@link Treasury} The fee treasury
+ * 4. `[writable]` destination: {@link PublicKey} Receives the fees
+ */
+export const withdrawFees = (
+	args: WithdrawFeesArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    withdrawFeesBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Sweeps the protocol fees collected in the treasury
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` program_config: {@link ProgramConfig} The program config
+ * 2. `[signer]` admin: {@link PublicKey} The program admin
+ * 3. `[writable]` treasury: {@link Treasury} The fee treasury
+ * 4. `[writable]` destination: {@link PublicKey} Receives the fees
+ */
+export const withdrawFeesSendAndConfirm = async (
+  args: Omit<WithdrawFeesArgs, "feePayer" | "admin"> & {
+    signers: {
+      feePayer: web3.Signer,
+      admin: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return withdrawFeesBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      admin: args.signers.admin.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.admin])
+    .rpc();
+}
+
+export type CreateMultisigArgs = {
+  feePayer: web3.PublicKey;
+  creator: web3.PublicKey;
+  nonce: BN;
+  signers: web3.PublicKey[];
+  threshold: number;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Creates an M-of-N multisig that can act as a collection authority by executing approved proposals
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` creator: {@link PublicKey} The creator of the multisig; only used to derive its address
+ * 2. `[writable]` multisig: {@link Multisig} The multisig account to initialize
+ * 3. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - nonce: {@link BN} Creator-chosen identifier distinguishing their multisigs
+ * - signers: {@link PublicKey[]} The member keys, at most 10
+ * - threshold: {@link number} Number of member approvals a proposal needs
+ */
+export const createMultisigBuilder = (
+	args: CreateMultisigArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [multisigPubkey] = pda.deriveMultisigPDA({
+        creator: args.creator,
+        nonce: args.nonce,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .createMultisig(
+      args.nonce,
+      args.signers,
+      args.threshold,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      creator: args.creator,
+      multisig: multisigPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Creates an M-of-N multisig that can act as a collection authority by executing approved proposals
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` creator: {@link PublicKey} The creator of the multisig; only used to derive its address
+ * 2. `[writable]` multisig: {@link Multisig} The multisig account to initialize
+ * 3. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - nonce: {@link BN} Creator-chosen identifier distinguishing their multisigs
+ * - signers: {@link PublicKey[]} The member keys, at most 10
+ * - threshold: {@link number} Number of member approvals a proposal needs
+ */
+export const createMultisig = (
+	args: CreateMultisigArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    createMultisigBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Creates an M-of-N multisig that can act as a collection authority by executing approved proposals
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` creator: {@link PublicKey} The creator of the multisig; only used to derive its address
+ * 2. `[writable]` multisig: {@link Multisig} The multisig account to initialize
+ * 3. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - nonce: {@link BN} Creator-chosen identifier distinguishing their multisigs
+ * - signers: {@link PublicKey[]} The member keys, at most 10
+ * - threshold: {@link number} Number of member approvals a proposal needs
+ */
+export const createMultisigSendAndConfirm = async (
+  args: Omit<CreateMultisigArgs, "feePayer" | "creator"> & {
+    signers: {
+      feePayer: web3.Signer,
+      creator: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return createMultisigBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      creator: args.signers.creator.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.creator])
+    .rpc();
+}
+
+export type SetMultisigSignersArgs = {
+  feePayer: web3.PublicKey;
+  multisig: web3.PublicKey;
+  signers: web3.PublicKey[];
+  threshold: number;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Replaces the members and threshold of a multisig; only callable by the multisig itself through an executed proposal
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` multisig: {@link Multisig} The multisig to update; must sign
+ *
+ * Data:
+ * - signers: {@link PublicKey[]} The new member keys, at most 10
+ * - threshold: {@link number} The new number of approvals a proposal needs
+ */
+export const setMultisigSignersBuilder = (
+	args: SetMultisigSignersArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+
+  return _program
+    .methods
+    .setMultisigSigners(
+      args.signers,
+      args.threshold,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: args.multisig,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Replaces the members and threshold of a multisig; only callable by the multisig itself through an executed proposal
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` multisig: {@link Multisig} The multisig to update; must sign
+ *
+ * Data:
+ * - signers: {@link PublicKey[]} The new member keys, at most 10
+ * - threshold: {@link number} The new number of approvals a proposal needs
+ */
+export const setMultisigSigners = (
+	args: SetMultisigSignersArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    setMultisigSignersBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Replaces the members and threshold of a multisig; only callable by the multisig itself through an executed proposal
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` multisig: {@link Multisig} The multisig to update; must sign
+ *
+ * Data:
+ * - signers: {@link PublicKey[]} The new member keys, at most 10
+ * - threshold: {@link number} The new number of approvals a proposal needs
+ */
+export const setMultisigSignersSendAndConfirm = async (
+  args: Omit<SetMultisigSignersArgs, "feePayer"> & {
+    signers: {
+      feePayer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return setMultisigSignersBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer])
+    .rpc();
+}
+
+export type CreateProposalArgs = {
+  feePayer: web3.PublicKey;
+  proposer: web3.PublicKey;
+  multisig: web3.PublicKey;
+  proposal: web3.PublicKey;
+  accounts: IdlTypes<MetaplexNftProgram>["proposalAccountMeta"][];
+  data: number[];
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Proposes an instruction of this program for the multisig to sign, such as a mint, metadata update or pause; the proposer's approval is recorded
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` proposer: {@link PublicKey} A member of the multisig
+ * 2. `[writable]` multisig: {@link Multisig} The multisig the proposal is for
+ * 3. `[writable]` proposal: {@link Proposal} The proposal account to initialize, at the multisig's next proposal index
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - accounts: {@link ProposalAccountMeta[]} Accounts of the proposed instruction; the multisig is marked as signer
+ * - data: {@link number[]} Data of the proposed instruction
+ */
+export const createProposalBuilder = (
+	args: CreateProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+
+  return _program
+    .methods
+    .createProposal(
+      args.accounts,
+      args.data,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      proposer: args.proposer,
+      multisig: args.multisig,
+      proposal: args.proposal,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Proposes an instruction of this program for the multisig to sign, such as a mint, metadata update or pause; the proposer's approval is recorded
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` proposer: {@link PublicKey} A member of the multisig
+ * 2. `[writable]` multisig: {@link Multisig} The multisig the proposal is for
+ * 3. `[writable]` proposal: {@link Proposal} The proposal account to initialize, at the multisig's next proposal index
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - accounts: {@link ProposalAccountMeta[]} Accounts of the proposed instruction; the multisig is marked as signer
+ * - data: {@link number[]} Data of the proposed instruction
+ */
+export const createProposal = (
+	args: CreateProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    createProposalBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Proposes an instruction of this program for the multisig to sign, such as a mint, metadata update or pause; the proposer's approval is recorded
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` proposer: {@link PublicKey} A member of the multisig
+ * 2. `[writable]` multisig: {@link Multisig} The multisig the proposal is for
+ * 3. `[writable]` proposal: {@link Proposal} The proposal account to initialize, at the multisig's next proposal index
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - accounts: {@link ProposalAccountMeta[]} Accounts of the proposed instruction; the multisig is marked as signer
+ * - data: {@link number[]} Data of the proposed instruction
+ */
+export const createProposalSendAndConfirm = async (
+  args: Omit<CreateProposalArgs, "feePayer" | "proposer"> & {
+    signers: {
+      feePayer: web3.Signer,
+      proposer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return createProposalBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      proposer: args.signers.proposer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.proposer])
+    .rpc();
+}
+
+export type ApproveProposalArgs = {
+  feePayer: web3.PublicKey;
+  signer: web3.PublicKey;
+  multisig: web3.PublicKey;
+  proposal: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Approves a pending proposal as a member of the multisig
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` signer: {@link PublicKey} A member of the multisig
+ * 2. `[]` multisig: {@link Multisig} The multisig the proposal is for
+ * 3. `[writable]` proposal: {@link Proposal} The proposal to approve
+ */
+export const approveProposalBuilder = (
+	args: ApproveProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+
+  return _program
+    .methods
+    .approveProposal(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      signer: args.signer,
+      multisig: args.multisig,
+      proposal: args.proposal,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Approves a pending proposal as a member of the multisig
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` signer: {@link PublicKey} A member of the multisig
+ * 2. `[]` multisig: {@link Multisig} The multisig the proposal is for
+ * 3. `[writable]` proposal: {@link Proposal} The proposal to approve
+ */
+export const approveProposal = (
+	args: ApproveProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    approveProposalBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Approves a pending proposal as a member of the multisig
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[signer]` signer: {@link PublicKey} A member of the multisig
+ * 2. `[]` multisig: {@link Multisig} The multisig the proposal is for
+ * 3. `[writable]` proposal: {@link Proposal} The proposal to approve
+ */
+export const approveProposalSendAndConfirm = async (
+  args: Omit<ApproveProposalArgs, "feePayer" | "signer"> & {
+    signers: {
+      feePayer: web3.Signer,
+      signer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return approveProposalBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      signer: args.signers.signer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.signer])
+    .rpc();
+}
+
+export type ExecuteProposalArgs = {
+  feePayer: web3.PublicKey;
+  multisig: web3.PublicKey;
+  proposal: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Executes a proposal once enough members approved it, signing the proposed instruction as the multisig
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link Multisig} The multisig the proposal is for
+ * 2. `[writable]` proposal: {@link Proposal} The proposal to execute
+ * 3. `[]` program: {@link PublicKey} This program, invoked with the proposed instruction
+ *
+ * Remaining accounts:
+ * - accounts: the proposed instruction's accounts, in the order stored on the proposal
+ */
+export const executeProposalBuilder = (
+	args: ExecuteProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+
+  return _program
+    .methods
+    .executeProposal(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: args.multisig,
+      proposal: args.proposal,
+      program: new web3.PublicKey("4mAMsimURXatxBJNW6AydZBmHsmXrksPJDPafNAfHAeT"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Executes a proposal once enough members approved it, signing the proposed instruction as the multisig
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link Multisig} The multisig the proposal is for
+ * 2. `[writable]` proposal: {@link Proposal} The proposal to execute
+ * 3. `[]` program: {@link PublicKey} This program, invoked with the proposed instruction
+ *
+ * Remaining accounts:
+ * - accounts: the proposed instruction's accounts, in the order stored on the proposal
+ */
+export const executeProposal = (
+	args: ExecuteProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    executeProposalBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Executes a proposal once enough members approved it, signing the proposed instruction as the multisig
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link Multisig} The multisig the proposal is for
+ * 2. `[writable]` proposal: {@link Proposal} The proposal to execute
+ * 3. `[]` program: {@link PublicKey} This program, invoked with the proposed instruction
+ *
+ * Remaining accounts:
+ * - accounts: the proposed instruction's accounts, in the order stored on the proposal
+ */
+export const executeProposalSendAndConfirm = async (
+  args: Omit<ExecuteProposalArgs, "feePayer"> & {
+    signers: {
+      feePayer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return executeProposalBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer])
+    .rpc();
+}
+
+export type LockMetadataArgs = {
+  feePayer: web3.PublicKey;
+  authority: web3.PublicKey;
+  metadata: web3.PublicKey | null;
+  seedAuthority: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Permanently locks the metadata of a single NFT, or of the whole collection when no NFT is given
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to lock, or that the NFT belongs to
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT to lock, omitted to lock the collection
+ */
+export const lockMetadataBuilder = (
+	args: LockMetadataArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [collectionPubkey] = pda.deriveCollectionPDA({
+        authority: args.seedAuthority,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .lockMetadata(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      collection: collectionPubkey,
+      authority: args.authority,
+      metadata: args.metadata,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Permanently locks the metadata of a single NFT, or of the whole collection when no NFT is given
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to lock, or that the NFT belongs to
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT to lock, omitted to lock the collection
+ */
+export const lockMetadata = (
+	args: LockMetadataArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    lockMetadataBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Permanently locks the metadata of a single NFT, or of the whole collection when no NFT is given
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to lock, or that the NFT belongs to
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[writable]` metadata: {@link NftMint} The metadata account of the NFT to lock, omitted to lock the collection
+ */
+export const lockMetadataSendAndConfirm = async (
+  args: Omit<LockMetadataArgs, "feePayer" | "authority"> & {
+    signers: {
+      feePayer: web3.Signer,
+      authority: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return lockMetadataBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      authority: args.signers.authority.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.authority])
+    .rpc();
+}
+
+export type SetUriRulesArgs = {
+  feePayer: web3.PublicKey;
+  authority: web3.PublicKey;
+  seedAuthority: web3.PublicKey;
+  maxUriLength: number;
+  allowedSchemes: string[];
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Sets the maximum length and allowed schemes of metadata URIs in a collection
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to configure
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ *
+ * Data:
+ * - max_uri_length: {@link number} Maximum URI length, at most the program-wide limit
+ * - allowed_schemes: {@link string[]} Lowercase URI schemes allowed, such as https, ipfs or ar
+ */
+export const setUriRulesBuilder = (
+	args: SetUriRulesArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [collectionPubkey] = pda.deriveCollectionPDA({
+        authority: args.seedAuthority,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .setUriRules(
+      args.maxUriLength,
+      args.allowedSchemes,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      collection: collectionPubkey,
+      authority: args.authority,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Sets the maximum length and allowed schemes of metadata URIs in a collection
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to configure
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ *
+ * Data:
+ * - max_uri_length: {@link number} Maximum URI length, at most the program-wide limit
+ * - allowed_schemes: {@link string[]} Lowercase URI schemes allowed, such as https, ipfs or ar
+ */
+export const setUriRules = (
+	args: SetUriRulesArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    setUriRulesBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Sets the maximum length and allowed schemes of metadata URIs in a collection
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to configure
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ *
+ * Data:
+ * - max_uri_length: {@link number} Maximum URI length, at most the program-wide limit
+ * - allowed_schemes: {@link string[]} Lowercase URI schemes allowed, such as https, ipfs or ar
+ */
+export const setUriRulesSendAndConfirm = async (
+  args: Omit<SetUriRulesArgs, "feePayer" | "authority"> & {
+    signers: {
+      feePayer: web3.Signer,
+      authority: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return setUriRulesBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      authority: args.signers.authority.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.authority])
+    .rpc();
+}
+
+export type RevealArgs = {
+  feePayer: web3.PublicKey;
+  authority: web3.PublicKey;
+  seedAuthority: web3.PublicKey;
+  entries: IdlTypes<MetaplexNftProgram>["revealEntry"][];
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Replaces the placeholder metadata of minted, unfrozen and mutable NFTs with their committed final name, uri and content hash
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` collection: {@link NftCollection} The collection holding the reveal commitment
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Remaining accounts:
+ * - `[writable]` metadata: one NFT metadata account per entry, in the same order
+ *
+ * Data:
+ * - entries: {@link RevealEntry[]} The revealed metadata and Merkle proof of each NFT; can be split across several transactions
+ */
+export const revealBuilder = (
+	args: RevealArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [collectionPubkey] = pda.deriveCollectionPDA({
+        authority: args.seedAuthority,
+    }, _program.programId);
+    const [programConfigPubkey] = pda.deriveProgramConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .reveal(
+      args.entries,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      collection: collectionPubkey,
+      authority: args.authority,
+      programConfig: programConfigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Replaces the placeholder metadata of minted, unfrozen and mutable NFTs with their committed final name, uri and content hash
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` collection: {@link NftCollection} The collection holding the reveal commitment
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Remaining accounts:
+ * - `[writable]` metadata: one NFT metadata account per entry, in the same order
+ *
+ * Data:
+ * - entries: {@link RevealEntry[]} The revealed metadata and Merkle proof of each NFT; can be split across several transactions
+ */
+export const reveal = (
+	args: RevealArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    revealBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Replaces the placeholder metadata of minted, unfrozen and mutable NFTs with their committed final name, uri and content hash
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` collection: {@link NftCollection} The collection holding the reveal commitment
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[]` program_config: {@link PublicKey} The program config, checked for a program-wide pause
+ *
+ * Remaining accounts:
+ * - `[writable]` metadata: one NFT metadata account per entry, in the same order
+ *
+ * Data:
+ * - entries: {@link RevealEntry[]} The revealed metadata and Merkle proof of each NFT; can be split across several transactions
+ */
+export const revealSendAndConfirm = async (
+  args: Omit<RevealArgs, "feePayer" | "authority"> & {
+    signers: {
+      feePayer: web3.Signer,
+      authority: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return revealBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      authority: args.signers.authority.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.authority])
+    .rpc();
+}
+
+export type SetBaseUriArgs = {
+  feePayer: web3.PublicKey;
+  authority: web3.PublicKey;
+  seedAuthority: web3.PublicKey;
+  baseUri: string;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Sets or changes the base URI that the collection's NFT URIs are resolved against, re-pointing every NFT at once; not while any NFT is locked
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to configure, resized to fit the base URI
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - base_uri: {@link string} Base URI, e.g. https://example.com/items/; empty to store full URIs, only possible before the first mint
+ */
+export const setBaseUriBuilder = (
+	args: SetBaseUriArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [collectionPubkey] = pda.deriveCollectionPDA({
+        authority: args.seedAuthority,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .setBaseUri(
+      args.baseUri,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      collection: collectionPubkey,
+      authority: args.authority,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Sets or changes the base URI that the collection's NFT URIs are resolved against, re-pointing every NFT at once; not while any NFT is locked
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to configure, resized to fit the base URI
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - base_uri: {@link string} Base URI, e.g. https://example.com/items/; empty to store full URIs, only possible before the first mint
+ */
+export const setBaseUri = (
+	args: SetBaseUriArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    setBaseUriBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Sets or changes the base URI that the collection's NFT URIs are resolved against, re-pointing every NFT at once; not while any NFT is locked
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to configure, resized to fit the base URI
+ * 2. `[signer]` authority: {@link PublicKey} The authority of the collection
+ * 3. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - base_uri: {@link string} Base URI, e.g. https://example.com/items/; empty to store full URIs, only possible before the first mint
+ */
+export const setBaseUriSendAndConfirm = async (
+  args: Omit<SetBaseUriArgs, "feePayer" | "authority"> & {
+    signers: {
+      feePayer: web3.Signer,
+      authority: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return setBaseUriBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      authority: args.signers.authority.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.authority])
+    .rpc();
+}
+
+export type WithdrawBidArgs = {
+  feePayer: web3.PublicKey;
+  bidder: web3.PublicKey;
+  bidRefund: web3.PublicKey;
+  payer: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Withdraws the lamports credited to an outbid auction bidder, closing the refund account
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` bidder: {@link PublicKey} The outbid bidder
+ * 2. `[writable]` bid_refund: {@link BidRefund} The bidder's refund account for the auction
+ * 3. `[writable]` payer: {@link PublicKey} Paid the refund account's rent, which closing it returns
+ */
+export const withdrawBidBuilder = (
+	args: WithdrawBidArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+
+  return _program
+    .methods
+    .withdrawBid(
+
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      bidder: args.bidder,
+      bidRefund: args.bidRefund,
+      payer: args.payer,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Withdraws the lamports credited to an outbid auction bidder, closing the refund account
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` bidder: {@link PublicKey} The outbid bidder
+ * 2. `[writable]` bid_refund: {@link BidRefund} The bidder's refund account for the auction
+ * 3. `[writable]` payer: {@link PublicKey} Paid the refund account's rent, which closing it returns
+ */
+export const withdrawBid = (
+	args: WithdrawBidArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    withdrawBidBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Withdraws the lamports credited to an outbid auction bidder, closing the refund account
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable, signer]` bidder: {@link PublicKey} The outbid bidder
+ * 2. `[writable]` bid_refund: {@link BidRefund} The bidder's refund account for the auction
+ * 3. `[writable]` payer: {@link PublicKey} Paid the refund account's rent, which closing it returns
+ */
+export const withdrawBidSendAndConfirm = async (
+  args: Omit<WithdrawBidArgs, "feePayer" | "bidder"> & {
+    signers: {
+      feePayer: web3.Signer,
+      bidder: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return withdrawBidBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      bidder: args.signers.bidder.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.bidder])
+    .rpc();
+}
+
+export type SetCollectionAuthorityArgs = {
+  feePayer: web3.PublicKey;
+  authority: web3.PublicKey;
+  seedAuthority: web3.PublicKey;
+  newAuthority: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Hands a collection over to a new authority, such as a multisig; the collection keeps its address
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to hand over
+ * 2. `[signer]` authority: {@link PublicKey} The current authority of the collection
+ *
+ * Data:
+ * - new_authority: {@link PublicKey} The new collection authority
+ */
+export const setCollectionAuthorityBuilder = (
+	args: SetCollectionAuthorityArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MetaplexNftProgram, never> => {
+    const [collectionPubkey] = pda.deriveCollectionPDA({
+        authority: args.seedAuthority,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .setCollectionAuthority(
+      args.newAuthority,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      collection: collectionPubkey,
+      authority: args.authority,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Hands a collection over to a new authority, such as a multisig; the collection keeps its address
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to hand over
+ * 2. `[signer]` authority: {@link PublicKey} The current authority of the collection
+ *
+ * Data:
+ * - new_authority: {@link PublicKey} The new collection authority
+ */
+export const setCollectionAuthority = (
+	args: SetCollectionAuthorityArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    setCollectionAuthorityBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Hands a collection over to a new authority, such as a multisig; the collection keeps its address
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` collection: {@link NftCollection} The collection to hand over
+ * 2. `[signer]` authority: {@link PublicKey} The current authority of the collection
+ *
+ * Data:
+ * - new_authority: {@link PublicKey} The new collection authority
+ */
+export const setCollectionAuthoritySendAndConfirm = async (
+  args: Omit<SetCollectionAuthorityArgs, "feePayer" | "authority"> & {
+    signers: {
+      feePayer: web3.Signer,
+      authority: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return setCollectionAuthorityBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      authority: args.signers.authority.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.authority])
+    .rpc();
+}
+
+// Getters
+
+export const getNftCollection = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MetaplexNftProgram>["nftCollection"]> => _program.account.nftCollection.fetch(publicKey, commitment);
+
+export const getNftMint = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MetaplexNftProgram>["nftMint"]> => _program.account.nftMint.fetch(publicKey, commitment);
+
+export const getListing = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MetaplexNftProgram>["listing"]> => _program.account.listing.fetch(publicKey, commitment);
+
+export const getBid = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MetaplexNftProgram>["bid"]> => _program.account.bid.fetch(publicKey, commitment);
+
+export const getAuction = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MetaplexNftProgram>["auction"]> => _program.account.auction.fetch(publicKey, commitment);
+
+export const getMintConfig = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MetaplexNftProgram>["mintConfig"]> => _program.account.mintConfig.fetch(publicKey, commitment);
+
+export const getMintReceipt = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MetaplexNftProgram>["mintReceipt"]> => _program.account.mintReceipt.fetch(publicKey, commitment);
+
+export const getRentalOffer = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MetaplexNftProgram>["rentalOffer"]> => _program.account.rentalOffer.fetch(publicKey, commitment);
+
+export const getStakePool = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MetaplexNftProgram>["stakePool"]> => _program.account.stakePool.fetch(publicKey, commitment);
+
+export const getStakeEntry = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MetaplexNftProgram>["stakeEntry"]> => _program.account.stakeEntry.fetch(publicKey, commitment);
+
+export const getVault = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MetaplexNftProgram>["vault"]> => _program.account.vault.fetch(publicKey, commitment);
+
+export const getLoan = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MetaplexNftProgram>["loan"]> => _program.account.loan.fetch(publicKey, commitment);
+
+export const getRuleSet = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MetaplexNftProgram>["ruleSet"]> => _program.account.ruleSet.fetch(publicKey, commitment);
+
+export const getProgramConfig = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MetaplexNftProgram>["programConfig"]> => _program.account.programConfig.fetch(publicKey, commitment);
+
+export const getTreasury = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MetaplexNftProgram>["treasury"]> => _program.account.treasury.fetch(publicKey, commitment);
+
+export const getMultisig = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MetaplexNftProgram>["multisig"]> => _program.account.multisig.fetch(publicKey, commitment);
+
+export const getProposal = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MetaplexNftProgram>["proposal"]> => _program.account.proposal.fetch(publicKey, commitment);
+
+export const getMetadataHistory = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MetaplexNftProgram>["metadataHistory"]> => _program.account.metadataHistory.fetch(publicKey, commitment);
+
+export type MetadataHistoryPage = {
+    entries: IdlTypes<MetaplexNftProgram>["metadataRevision"][];
+    /** Revision to pass as `before` for the next, older page, or null on the last page. */
+    nextBefore: number | null;
+};
+
+/**
+ * Pages through the past metadata revisions of an NFT, newest first.
+ * Only the most recent revisions are kept on chain; an NFT that was never
+ * updated has an empty history.
+ */
+export const getMetadataHistoryPage = async (
+    mint: web3.PublicKey,
+    options: { before?: number, limit?: number } = {},
+    commitment?: web3.Commitment
+): Promise<MetadataHistoryPage> => {
+    const [historyPubkey] = pda.deriveMetadataHistoryPDA({ mint }, _program.programId);
+    const history = await _program.account.metadataHistory.fetchNullable(historyPubkey, commitment);
+    const limit = options.limit ?? 10;
+    const older = (history?.entries ?? [])
+        .filter((entry) => options.before === undefined || entry.revision < options.before)
+        .sort((a, b) => b.revision - a.revision);
+    const entries = older.slice(0, limit);
+
+    return {
+        entries,
+        nextBefore: older.length > limit ? entries[entries.length - 1].revision : null,
+    };
+};
+
+/**
+ * Full metadata URI of an NFT. NFTs of collections with a base URI store
+ * only a suffix, or nothing to use `<index>.json`.
+ */
+export const resolveNftUri = (
+    nft: IdlAccounts<MetaplexNftProgram>["nftMint"],
+    collection: IdlAccounts<MetaplexNftProgram>["nftCollection"]
+): string => {
+    if (collection.baseUri === "") {
+        return nft.uri;
+    }
+    return collection.baseUri + (nft.uri === "" ? `${nft.itemNumber.toString()}.json` : nft.uri);
+};
+
+export const getItemIndex = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MetaplexNftProgram>["itemIndex"]> => _program.account.itemIndex.fetch(publicKey, commitment);
+
+/**
+ * Enumerates a collection's items in mint order through their item index
+ * PDAs, from item number `start` up to `limit` items or the collection's
+ * current count.
+ */
+export const getCollectionItems = async (
+    collection: web3.PublicKey,
+    options: { start?: number, limit?: number } = {},
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MetaplexNftProgram>["itemIndex"][]> => {
+    const { nftCount } = await getNftCollection(collection, commitment);
+    const start = options.start ?? 0;
+    const end = Math.min(nftCount.toNumber(), start + (options.limit ?? 100));
+    const addresses: web3.PublicKey[] = [];
+    for (let itemNumber = start; itemNumber < end; itemNumber++) {
+        const [itemIndexPubkey] = pda.deriveItemIndexPDA({
+            collection,
+            itemNumber: new BN(itemNumber),
+        }, _program.programId);
+        addresses.push(itemIndexPubkey);
+    }
+
+    const items: IdlAccounts<MetaplexNftProgram>["itemIndex"][] = [];
+    // getMultipleAccounts accepts at most 100 addresses per request.
+    for (let i = 0; i < addresses.length; i += 100) {
+        const chunk = await _program.account.itemIndex.fetchMultiple(addresses.slice(i, i + 100), commitment);
+        chunk.forEach((item) => item && items.push(item));
+    }
+    return items;
+};
+
+export const getBidRefund = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MetaplexNftProgram>["bidRefund"]> => _program.account.bidRefund.fetch(publicKey, commitment);
 export module CslSplTokenGetters {
     export const getMint = (
         publicKey: web3.PublicKey,
@@ -2,3 +2,48 @@ use anchor_lang::prelude::*;
 
 #[constant]
 pub const SEED: &str = "anchor";
+
+pub const MAX_NAME_LENGTH: usize = 32;
+
+pub const MAX_SYMBOL_LENGTH: usize = 10;
+
+pub const MAX_URI_LENGTH: usize = 200;
+
+/// Upper bound on entries per `mint_batch`, limited by transaction account and compute budgets.
+pub const MAX_BATCH_SIZE: usize = 5;
+
+/// Remaining accounts expected per `mint_batch` entry: mint, metadata, associated token account, wallet, item index.
+pub const MINT_BATCH_ACCOUNTS_PER_ENTRY: usize = 5;
+
+/// Maximum number of creators sharing a collection's royalties.
+pub const MAX_CREATORS: usize = 5;
+
+/// Length of a rental day, in seconds.
+pub const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Maximum number of program IDs in a collection's transfer rule set.
+pub const MAX_RULE_SET_PROGRAMS: usize = 10;
+
+/// Maximum number of signers of a multisig authority.
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+
+/// Maximum number of accounts of an instruction proposed to a multisig.
+pub const MAX_PROPOSAL_ACCOUNTS: usize = 24;
+
+/// Maximum data length of an instruction proposed to a multisig.
+pub const MAX_PROPOSAL_DATA_LENGTH: usize = 512;
+
+/// Maximum number of URI schemes a collection can allow.
+pub const MAX_URI_SCHEMES: usize = 4;
+
+/// Maximum length of an allowed URI scheme.
+pub const MAX_URI_SCHEME_LENGTH: usize = 10;
+
+/// URI schemes allowed by a newly initialized collection.
+pub const DEFAULT_URI_SCHEMES: [&str; 3] = ["https", "ipfs", "ar"];
+
+/// Number of past metadata revisions kept per NFT.
+pub const MAX_METADATA_HISTORY: usize = 8;
+
+/// Maximum length of the URI suffix stored by NFTs of a collection with a base URI.
+pub const MAX_URI_SUFFIX_LENGTH: usize = 32;
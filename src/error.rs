@@ -15,4 +15,166 @@ pub enum MetaplexNftProgramError {
 	InvalidMetadata,
 	#[msg("The specified NFT does not exist")]
 	NftNotFound,
+	#[msg("A batch must contain at least one entry")]
+	EmptyBatch,
+	#[msg("The batch contains more entries than allowed")]
+	BatchTooLarge,
+	#[msg("The remaining accounts do not match the batch entries")]
+	InvalidBatchAccounts,
+	#[msg("Arithmetic overflow")]
+	ArithmeticOverflow,
+	#[msg("Only the owner of the NFT can perform this action")]
+	NotNftOwner,
+	#[msg("The price must be greater than zero")]
+	InvalidPrice,
+	#[msg("Royalties must not exceed 10000 basis points and creator shares must add up to 100")]
+	InvalidRoyaltyConfig,
+	#[msg("The creator accounts do not match the collection creators")]
+	InvalidCreatorAccount,
+	#[msg("The listing was created in a different listing mode")]
+	ListingModeMismatch,
+	#[msg("The expiration must be in the future")]
+	InvalidExpiration,
+	#[msg("The bid has expired")]
+	BidExpired,
+	#[msg("The NFT does not match the bid")]
+	BidMintMismatch,
+	#[msg("The auction timing or increment is invalid")]
+	InvalidAuctionConfig,
+	#[msg("The auction has not started yet")]
+	AuctionNotStarted,
+	#[msg("The auction has already ended")]
+	AuctionEnded,
+	#[msg("The auction has not ended yet")]
+	AuctionNotEnded,
+	#[msg("The bid is below the reserve price or the minimum increment")]
+	BidTooLow,
+	#[msg("The refund account of the previous highest bidder is missing")]
+	InvalidPreviousBidder,
+	#[msg("The recipient does not match the auction outcome")]
+	InvalidAuctionRecipient,
+	#[msg("Invalid mint pricing configuration")]
+	InvalidMintConfig,
+	#[msg("The mint pricing cannot be changed once minting has started")]
+	MintConfigLocked,
+	#[msg("Minting has not started yet")]
+	MintNotStarted,
+	#[msg("All NFTs of this drop have been minted")]
+	MintSoldOut,
+	#[msg("A mint receipt is required when the collection has mint pricing")]
+	MissingMintReceipt,
+	#[msg("The mint price is still falling")]
+	MintNotConcluded,
+	#[msg("The NFT is rented out")]
+	RentalActive,
+	#[msg("The rental has not expired yet")]
+	RentalNotExpired,
+	#[msg("The NFT is not rented out")]
+	NoActiveRental,
+	#[msg("Invalid rental duration")]
+	InvalidRentalDuration,
+	#[msg("The NFT is staked")]
+	NftStaked,
+	#[msg("The reward mint must have the stake pool as mint authority")]
+	InvalidRewardMint,
+	#[msg("The reward rate must be greater than zero")]
+	InvalidRewardRate,
+	#[msg("The share supply must be greater than zero")]
+	InvalidShareSupply,
+	#[msg("The vault has already been bought out")]
+	VaultBoughtOut,
+	#[msg("The vault has not been bought out yet")]
+	VaultNotBoughtOut,
+	#[msg("The account holds no shares of this vault")]
+	NotShareHolder,
+	#[msg("Invalid loan terms")]
+	InvalidLoanTerms,
+	#[msg("The loan offer has already been taken")]
+	LoanAlreadyTaken,
+	#[msg("The loan offer has not been taken")]
+	LoanNotTaken,
+	#[msg("The loan is past its deadline")]
+	LoanExpired,
+	#[msg("The loan is not past its deadline yet")]
+	LoanNotExpired,
+	#[msg("Royalty enforcement can only be enabled before the first mint")]
+	RoyaltyEnforcementLocked,
+	#[msg("Zero-price transfers of royalty-enforced NFTs must be signed by the new owner and go directly from wallet to wallet")]
+	RoyaltyRequired,
+	#[msg("Too many programs in the rule set")]
+	TooManyRuleSetPrograms,
+	#[msg("The collection's rule set does not permit this program")]
+	ProgramNotPermitted,
+	#[msg("NFTs of this collection are soulbound and cannot be transferred")]
+	Soulbound,
+	#[msg("A collection can only become soulbound before its first mint")]
+	SoulboundLocked,
+	#[msg("The NFT's token account has an active delegate")]
+	TokenDelegated,
+	#[msg("The NFT has an approved transfer delegate; revoke it first")]
+	NftDelegated,
+	#[msg("The NFT has no approved transfer delegate")]
+	NoDelegate,
+	#[msg("Signer is neither the owner nor the approved delegate of the NFT")]
+	InvalidTransferAuthority,
+	#[msg("The NFT is frozen by its collection authority")]
+	NftFrozen,
+	#[msg("The NFT is not frozen")]
+	NftNotFrozen,
+	#[msg("The collection is paused")]
+	CollectionPaused,
+	#[msg("The program is paused")]
+	ProgramPaused,
+	#[msg("Protocol fee basis points cannot exceed 10000")]
+	InvalidProtocolFee,
+	#[msg("The treasury holds no fees to withdraw")]
+	NoFeesToWithdraw,
+	#[msg("Multisig signers must be distinct, at most 10, and reach the threshold")]
+	InvalidMultisig,
+	#[msg("Signer is not a member of the multisig")]
+	NotMultisigSigner,
+	#[msg("Signer already approved this proposal")]
+	AlreadyApproved,
+	#[msg("The proposal has already been executed")]
+	ProposalAlreadyExecuted,
+	#[msg("The proposal has not reached the multisig threshold")]
+	ProposalNotApproved,
+	#[msg("Accounts do not match the proposal")]
+	InvalidProposalAccounts,
+	#[msg("The proposed instruction is too large")]
+	ProposalTooLarge,
+	#[msg("The metadata has been locked and can no longer be updated")]
+	MetadataImmutable,
+	#[msg("The URI is longer than the collection allows")]
+	UriTooLong,
+	#[msg("The URI contains whitespace or control characters")]
+	UriInvalidCharacter,
+	#[msg("The URI has no scheme")]
+	UriMissingScheme,
+	#[msg("The URI scheme is not allowed by the collection")]
+	UriSchemeNotAllowed,
+	#[msg("Invalid URI rules")]
+	InvalidUriRules,
+	#[msg("The collection has no reveal commitment")]
+	NoRevealCommitment,
+	#[msg("The NFT has already been revealed")]
+	AlreadyRevealed,
+	#[msg("The NFT has not been revealed yet")]
+	NftNotRevealed,
+	#[msg("The revealed metadata does not match the collection's commitment")]
+	InvalidRevealProof,
+	#[msg("Remaining accounts must list one writable metadata account per reveal entry")]
+	InvalidRevealAccounts,
+	#[msg("A base URI cannot be added or removed once NFTs have been minted, nor changed while any NFT is locked")]
+	BaseUriLocked,
+	#[msg("The listing price is above the buyer's maximum")]
+	PriceAboveMaximum,
+	#[msg("The NFT is listed for sale; delist it first")]
+	NftListed,
+	#[msg("The bid amount is below the seller's minimum")]
+	BidBelowMinimum,
+	#[msg("The rental offer terms differ from what the renter expected")]
+	RentalTermsChanged,
+	#[msg("The loan offer terms differ from what the borrower expected")]
+	LoanTermsChanged,
 }
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ProgramAdminChanged {
+	pub old_admin: Pubkey,
+	pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct ProgramPauseChanged {
+	pub admin: Pubkey,
+	pub paused: bool,
+}
+
+#[event]
+pub struct ProtocolFeeChanged {
+	pub admin: Pubkey,
+	pub mint_fee_lamports: u64,
+	pub mint_fee_bps: u16,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+	pub admin: Pubkey,
+	pub destination: Pubkey,
+	pub amount: u64,
+}
+
+#[event]
+pub struct CollectionPauseChanged {
+	pub collection: Pubkey,
+	pub paused: bool,
+}
+
+#[event]
+pub struct CollectionAuthorityChanged {
+	pub collection: Pubkey,
+	pub old_authority: Pubkey,
+	pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct BaseUriChanged {
+	pub collection: Pubkey,
+	pub base_uri: String,
+}
@@ -0,0 +1,194 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::error::MetaplexNftProgramError;
+use crate::utils::{pay_royalties, transfer_lamports_from_program_account};
+
+
+
+
+	#[derive(Accounts)]
+	pub struct AcceptBid<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub seller: Signer<'info>,
+
+		#[account(
+			mut,
+			address = bid.bidder,
+		)]
+		/// CHECK: matched against the bid
+		pub bidder: UncheckedAccount<'info>,
+
+		pub mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"metadata",
+				mint.key().as_ref(),
+			],
+			bump,
+			constraint = metadata.owner == seller.key() @ MetaplexNftProgramError::NotNftOwner,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		#[account(
+			address = metadata.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			mut,
+			close = bidder,
+			constraint = bid.collection == collection.key() @ MetaplexNftProgramError::BidMintMismatch,
+			constraint = bid.mint.is_none_or(|bid_mint| bid_mint == mint.key()) @ MetaplexNftProgramError::BidMintMismatch,
+			seeds = [
+				b"bid",
+				bid.bidder.as_ref(),
+				bid.target().as_ref(),
+			],
+			bump = bid.bump,
+		)]
+		pub bid: Account<'info, Bid>,
+
+		#[account(
+			mut,
+			associated_token::mint = mint,
+			associated_token::authority = seller,
+			associated_token::token_program = token_program,
+		)]
+		pub seller_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			init_if_needed,
+			payer = seller,
+			associated_token::mint = mint,
+			associated_token::authority = bidder,
+			associated_token::token_program = token_program,
+		)]
+		pub bidder_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			seeds = [
+				b"freeze_authority",
+			],
+			bump,
+		)]
+		/// CHECK: PDA used only as the mints' freeze authority
+		pub freeze_authority: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+		pub associated_token_program: Program<'info, AssociatedToken>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+	}
+
+	impl<'info> AcceptBid<'info> {
+		pub fn cpi_csl_spl_token_transfer_checked(&self, amount: u64, decimals: u8) -> Result<()> {
+			anchor_spl::token::transfer_checked(
+				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::TransferChecked {
+						from: self.seller_token_account.to_account_info(),
+						mint: self.mint.to_account_info(),
+						to: self.bidder_token_account.to_account_info(),
+						authority: self.seller.to_account_info()
+					}
+				),
+				amount, 
+				decimals, 
+			)
+		}
+	}
+
+
+/// Accepts an offer: the NFT goes to the bidder and the escrowed lamports, minus royalties, go to the seller
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` seller: [AccountInfo] The current owner of the NFT
+/// 2. `[writable]` bidder: [AccountInfo] The bidder recorded on the bid
+/// 3. `[]` mint: [Mint] The mint account of the NFT
+/// 4. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 5. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 6. `[writable]` bid: [Bid] The bid to accept
+/// 7. `[writable]` seller_token_account: [TokenAccount] The seller's token account holding the NFT
+/// 8. `[writable]` bidder_token_account: [TokenAccount] The bidder's token account, created if needed
+/// 9. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 10. `[]` system_program: [AccountInfo] System program
+/// 11. `[]` token_program: [AccountInfo] SPL Token program
+/// 12. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 13. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 14. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Remaining accounts:
+/// - `[writable]` creators: the collection creators, in the order stored on the collection
+///
+/// Data:
+/// - min_amount: [u64] Lowest bid amount in lamports the seller accepts; guards against the bidder lowering the bid
+pub fn handler<'info>(
+	ctx: Context<'_, '_, 'info, 'info, AcceptBid<'info>>,
+	min_amount: u64,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	let accounts = &ctx.accounts;
+	let now = Clock::get()?.unix_timestamp;
+	require!(now <= accounts.bid.expires_at, MetaplexNftProgramError::BidExpired);
+	require!(accounts.bid.amount >= min_amount, MetaplexNftProgramError::BidBelowMinimum);
+	accounts.metadata.assert_transferable(now)?;
+	accounts.metadata.assert_not_delegated()?;
+
+	utils::thaw_if_frozen(
+		&accounts.seller_token_account,
+		&accounts.mint.to_account_info(),
+		&accounts.freeze_authority.to_account_info(),
+		ctx.bumps.freeze_authority,
+		&accounts.token_program.to_account_info(),
+	)?;
+	accounts.cpi_csl_spl_token_transfer_checked(
+		1,
+		accounts.mint.decimals,
+	)?;
+	utils::freeze_if_enforced(
+		&accounts.collection,
+		&accounts.bidder_token_account,
+		&accounts.mint.to_account_info(),
+		&accounts.freeze_authority.to_account_info(),
+		ctx.bumps.freeze_authority,
+		&accounts.token_program.to_account_info(),
+	)?;
+
+	let amount = accounts.bid.amount;
+	let escrow = accounts.bid.to_account_info();
+	let royalty = pay_royalties(&accounts.collection, amount, ctx.remaining_accounts, |creator, share| {
+		transfer_lamports_from_program_account(&escrow, creator, share)
+	})?;
+	transfer_lamports_from_program_account(&escrow, &accounts.seller.to_account_info(), amount - royalty)?;
+
+	ctx.accounts.metadata.owner = ctx.accounts.bidder.key();
+
+	Ok(())
+}
@@ -0,0 +1,165 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct ApproveDelegate<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		pub owner: Signer<'info>,
+
+		pub mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"metadata",
+				mint.key().as_ref(),
+			],
+			bump,
+			constraint = metadata.owner == owner.key() @ MetaplexNftProgramError::NotNftOwner,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		#[account(
+			mut,
+			associated_token::mint = mint,
+			associated_token::authority = owner,
+			associated_token::token_program = token_program,
+		)]
+		pub owner_token_account: Account<'info, TokenAccount>,
+
+		/// CHECK: any account may be approved, subject to the collection's rule set
+		pub delegate: UncheckedAccount<'info>,
+
+		#[account(
+			address = metadata.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			seeds = [
+				b"freeze_authority",
+			],
+			bump,
+		)]
+		/// CHECK: PDA used only as the mints' freeze authority
+		pub freeze_authority: UncheckedAccount<'info>,
+
+		#[account(
+			seeds = [
+				b"rule_set",
+				collection.key().as_ref(),
+			],
+			bump,
+		)]
+		/// CHECK: only read when the collection has a rule set
+		pub rule_set: UncheckedAccount<'info>,
+
+		#[account(
+			address = anchor_lang::solana_program::sysvar::instructions::ID,
+		)]
+		/// CHECK: the instructions sysvar
+		pub instructions: UncheckedAccount<'info>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+	}
+
+	impl<'info> ApproveDelegate<'info> {
+		pub fn cpi_csl_spl_token_approve(&self, amount: u64) -> Result<()> {
+			anchor_spl::token::approve(
+				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::Approve {
+						to: self.owner_token_account.to_account_info(),
+						delegate: self.delegate.to_account_info(),
+						authority: self.owner.to_account_info()
+					}
+				),
+				amount, 
+			)
+		}
+		pub fn cpi_csl_spl_token_freeze_account(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::freeze_account(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::FreezeAccount {
+						account: self.owner_token_account.to_account_info(),
+						mint: self.mint.to_account_info(),
+						authority: self.freeze_authority.to_account_info()
+					},
+					signer_seeds,
+				),
+			)
+		}
+	}
+
+
+/// Approves a delegate that may sign transfer_nft in place of the owner until the NFT is transferred or the delegation revoked
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[signer]` owner: [AccountInfo] The owner of the NFT
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[writable]` owner_token_account: [TokenAccount] The owner's token account holding the NFT
+/// 5. `[]` delegate: [AccountInfo] The account to approve as transfer delegate; it, or the program owning it, must pass the collection's rule set
+/// 6. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 7. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 8. `[]` rule_set: [AccountInfo] The rule set of the NFT's collection
+/// 9. `[]` instructions: [AccountInfo] Instructions sysvar, to find the calling program
+/// 10. `[]` token_program: [AccountInfo] SPL Token program
+/// 11. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 12. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+pub fn handler(
+	ctx: Context<ApproveDelegate>,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	ctx.accounts.metadata.assert_transferable(Clock::get()?.unix_timestamp)?;
+	// In-place listings and stakes hold the token account's delegate slot.
+	require!(ctx.accounts.owner_token_account.delegate.is_none(), MetaplexNftProgramError::TokenDelegated);
+	utils::assert_rule_set_permits(
+		&ctx.accounts.rule_set,
+		&ctx.accounts.instructions,
+		Some(&ctx.accounts.delegate),
+	)?;
+
+	// The delegate signs the token transfer itself, so it is also approved on
+	// the token account, which has to be thawed for that. The account is
+	// frozen again whatever the collection, so the delegate can only move the
+	// NFT through transfer_nft and never around the NftMint record.
+	utils::thaw_if_frozen(
+		&ctx.accounts.owner_token_account,
+		&ctx.accounts.mint.to_account_info(),
+		&ctx.accounts.freeze_authority.to_account_info(),
+		ctx.bumps.freeze_authority,
+		&ctx.accounts.token_program.to_account_info(),
+	)?;
+	ctx.accounts.cpi_csl_spl_token_approve(1)?;
+	ctx.accounts.cpi_csl_spl_token_freeze_account(&[&[
+		b"freeze_authority",
+		&[ctx.bumps.freeze_authority],
+	]])?;
+
+	ctx.accounts.metadata.delegate = ctx.accounts.delegate.key();
+
+	Ok(())
+}
@@ -0,0 +1,64 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct ApproveProposal<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		pub signer: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"multisig",
+				multisig.creator.as_ref(),
+				multisig.nonce.to_le_bytes().as_ref(),
+			],
+			bump = multisig.bump,
+		)]
+		pub multisig: Account<'info, Multisig>,
+
+		#[account(
+			mut,
+			has_one = multisig,
+			seeds = [
+				b"proposal",
+				multisig.key().as_ref(),
+				proposal.index.to_le_bytes().as_ref(),
+			],
+			bump = proposal.bump,
+		)]
+		pub proposal: Account<'info, Proposal>,
+	}
+
+/// Approves a pending proposal as a member of the multisig
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[signer]` signer: [AccountInfo] A member of the multisig
+/// 2. `[]` multisig: [Multisig] The multisig the proposal is for
+/// 3. `[writable]` proposal: [Proposal] The proposal to approve
+pub fn handler(
+	ctx: Context<ApproveProposal>,
+) -> Result<()> {
+	let multisig = &ctx.accounts.multisig;
+	let signer = ctx.accounts.signer.key();
+	multisig.assert_signer(&signer)?;
+
+	let proposal = &mut ctx.accounts.proposal;
+	require!(!proposal.executed, MetaplexNftProgramError::ProposalAlreadyExecuted);
+	require!(!proposal.approvals.contains(&signer), MetaplexNftProgramError::AlreadyApproved);
+
+	// Approvals of signers rotated out since the proposal was made no longer count.
+	proposal.approvals.retain(|approver| multisig.signers.contains(approver));
+	proposal.approvals.push(signer);
+
+	Ok(())
+}
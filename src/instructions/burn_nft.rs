@@ -0,0 +1,145 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct BurnNft<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub owner: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			close = owner,
+			seeds = [
+				b"metadata",
+				mint.key().as_ref(),
+			],
+			bump,
+			constraint = metadata.owner == owner.key() @ MetaplexNftProgramError::NotNftOwner,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		#[account(
+			mut,
+			address = metadata.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			mut,
+			associated_token::mint = mint,
+			associated_token::authority = owner,
+			associated_token::token_program = token_program,
+		)]
+		pub owner_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			seeds = [
+				b"freeze_authority",
+			],
+			bump,
+		)]
+		/// CHECK: PDA used only as the mints' freeze authority
+		pub freeze_authority: UncheckedAccount<'info>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+	}
+
+	impl<'info> BurnNft<'info> {
+		pub fn cpi_csl_spl_token_burn(&self, amount: u64) -> Result<()> {
+			anchor_spl::token::burn(
+				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::Burn {
+						mint: self.mint.to_account_info(),
+						from: self.owner_token_account.to_account_info(),
+						authority: self.owner.to_account_info()
+					}
+				),
+				amount, 
+			)
+		}
+		pub fn cpi_csl_spl_token_close_account(&self) -> Result<()> {
+			anchor_spl::token::close_account(
+				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::CloseAccount {
+						account: self.owner_token_account.to_account_info(),
+						destination: self.owner.to_account_info(),
+						authority: self.owner.to_account_info()
+					}
+				),
+			)
+		}
+	}
+
+
+/// Burns an NFT held in its owner's wallet, closing its token and metadata accounts
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` owner: [AccountInfo] The owner of the NFT; receives the closed accounts' rent
+/// 2. `[writable]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[writable]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[writable]` owner_token_account: [TokenAccount] The owner's token account holding the NFT
+/// 6. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 7. `[]` token_program: [AccountInfo] SPL Token program
+/// 8. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 9. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+pub fn handler(
+	ctx: Context<BurnNft>,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	let accounts = &ctx.accounts;
+	require!(!accounts.metadata.is_rented(Clock::get()?.unix_timestamp), MetaplexNftProgramError::RentalActive);
+	require!(!accounts.metadata.staked, MetaplexNftProgramError::NftStaked);
+	require!(!accounts.metadata.listed, MetaplexNftProgramError::NftListed);
+	require!(!accounts.metadata.frozen, MetaplexNftProgramError::NftFrozen);
+	// In-place listings leave their listing account as delegate.
+	require!(accounts.owner_token_account.delegate.is_none(), MetaplexNftProgramError::TokenDelegated);
+
+	utils::thaw_if_frozen(
+		&accounts.owner_token_account,
+		&accounts.mint.to_account_info(),
+		&accounts.freeze_authority.to_account_info(),
+		ctx.bumps.freeze_authority,
+		&accounts.token_program.to_account_info(),
+	)?;
+	accounts.cpi_csl_spl_token_burn(1)?;
+	accounts.cpi_csl_spl_token_close_account()?;
+
+	if !ctx.accounts.metadata.is_mutable {
+		let collection = &mut ctx.accounts.collection;
+		collection.locked_nft_count = collection.locked_nft_count.saturating_sub(1);
+	}
+
+	Ok(())
+}
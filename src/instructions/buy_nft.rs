@@ -0,0 +1,226 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::error::MetaplexNftProgramError;
+use crate::utils::{pay_royalties, transfer_lamports};
+
+
+
+
+	#[derive(Accounts)]
+	pub struct BuyNft<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub buyer: Signer<'info>,
+
+		#[account(
+			mut,
+			address = listing.seller,
+		)]
+		/// CHECK: matched against the listing
+		pub seller: UncheckedAccount<'info>,
+
+		pub mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"metadata",
+				mint.key().as_ref(),
+			],
+			bump,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		#[account(
+			address = metadata.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			mut,
+			close = seller,
+			has_one = mint,
+			seeds = [
+				b"listing",
+				mint.key().as_ref(),
+			],
+			bump = listing.bump,
+		)]
+		pub listing: Account<'info, Listing>,
+
+		#[account(
+			mut,
+			address = listing.token_account,
+		)]
+		pub listing_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			init_if_needed,
+			payer = buyer,
+			associated_token::mint = mint,
+			associated_token::authority = buyer,
+			associated_token::token_program = token_program,
+		)]
+		pub buyer_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			seeds = [
+				b"freeze_authority",
+			],
+			bump,
+		)]
+		/// CHECK: PDA used only as the mints' freeze authority
+		pub freeze_authority: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+		pub associated_token_program: Program<'info, AssociatedToken>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+	}
+
+	impl<'info> BuyNft<'info> {
+		pub fn cpi_csl_spl_token_transfer_checked(&self, amount: u64, decimals: u8, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::transfer_checked(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::TransferChecked {
+						from: self.listing_token_account.to_account_info(),
+						mint: self.mint.to_account_info(),
+						to: self.buyer_token_account.to_account_info(),
+						authority: self.listing.to_account_info()
+					},
+					signer_seeds,
+				),
+				amount, 
+				decimals, 
+			)
+		}
+		pub fn cpi_csl_spl_token_thaw_account(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::thaw_account(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::ThawAccount {
+						account: self.listing_token_account.to_account_info(),
+						mint: self.mint.to_account_info(),
+						authority: self.freeze_authority.to_account_info()
+					},
+					signer_seeds,
+				),
+			)
+		}
+		pub fn cpi_csl_spl_token_close_account(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::close_account(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::CloseAccount {
+						account: self.listing_token_account.to_account_info(),
+						destination: self.seller.to_account_info(),
+						authority: self.listing.to_account_info()
+					},
+					signer_seeds,
+				),
+			)
+		}
+	}
+
+
+/// Buys a listed NFT, paying the seller and the collection royalties
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` buyer: [AccountInfo] The buyer of the NFT
+/// 2. `[writable]` seller: [AccountInfo] The seller recorded on the listing
+/// 3. `[]` mint: [Mint] The mint account of the NFT
+/// 4. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 5. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 6. `[writable]` listing: [Listing] The listing to fill
+/// 7. `[writable]` listing_token_account: [TokenAccount] The token account recorded on the listing: the escrow, or the seller's account for in-place listings
+/// 8. `[writable]` buyer_token_account: [TokenAccount] The buyer's token account, created if needed
+/// 9. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 10. `[]` system_program: [AccountInfo] System program
+/// 11. `[]` token_program: [AccountInfo] SPL Token program
+/// 12. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 13. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 14. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Remaining accounts:
+/// - `[writable]` creators: the collection creators, in the order stored on the collection
+///
+/// Data:
+/// - max_price: [u64] Highest price in lamports the buyer accepts; guards against the seller raising the price
+pub fn handler<'info>(
+	ctx: Context<'_, '_, 'info, 'info, BuyNft<'info>>,
+	max_price: u64,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	// The listing being filled is the one holding the NFT.
+	ctx.accounts.metadata.listed = false;
+	let accounts = &ctx.accounts;
+	accounts.metadata.assert_transferable(Clock::get()?.unix_timestamp)?;
+
+	let price = accounts.listing.price;
+	require!(price <= max_price, MetaplexNftProgramError::PriceAboveMaximum);
+	let buyer = accounts.buyer.to_account_info();
+	let system_program = accounts.system_program.to_account_info();
+
+	let royalty = pay_royalties(&accounts.collection, price, ctx.remaining_accounts, |creator, amount| {
+		transfer_lamports(&buyer, creator, &system_program, amount)
+	})?;
+	transfer_lamports(&buyer, &accounts.seller.to_account_info(), &system_program, price - royalty)?;
+
+	let mint_key = accounts.mint.key();
+	let signer_seeds: &[&[&[u8]]] = &[&[
+		b"listing",
+		mint_key.as_ref(),
+		&[accounts.listing.bump],
+	]];
+	if accounts.listing.escrowless {
+		accounts.cpi_csl_spl_token_thaw_account(&[&[
+			b"freeze_authority",
+			&[ctx.bumps.freeze_authority],
+		]])?;
+	}
+	// For in-place listings the listing is the delegate of the seller's token
+	// account; moving the whole delegated amount also clears the delegate.
+	accounts.cpi_csl_spl_token_transfer_checked(
+		1,
+		accounts.mint.decimals,
+		signer_seeds,
+	)?;
+	if !accounts.listing.escrowless {
+		accounts.cpi_csl_spl_token_close_account(signer_seeds)?;
+	}
+	utils::freeze_if_enforced(
+		&accounts.collection,
+		&accounts.buyer_token_account,
+		&accounts.mint.to_account_info(),
+		&accounts.freeze_authority.to_account_info(),
+		ctx.bumps.freeze_authority,
+		&accounts.token_program.to_account_info(),
+	)?;
+
+	ctx.accounts.metadata.owner = ctx.accounts.buyer.key();
+
+	Ok(())
+}
@@ -0,0 +1,231 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::error::MetaplexNftProgramError;
+use crate::utils::{pay_royalties, transfer_lamports};
+
+
+
+
+	#[derive(Accounts)]
+	pub struct BuyoutVault<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub buyer: Signer<'info>,
+
+		pub mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"metadata",
+				mint.key().as_ref(),
+			],
+			bump,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		#[account(
+			address = metadata.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			mut,
+			has_one = mint,
+			has_one = share_mint,
+			seeds = [
+				b"vault",
+				mint.key().as_ref(),
+			],
+			bump = vault.bump,
+		)]
+		pub vault: Account<'info, Vault>,
+
+		#[account(
+			mut,
+		)]
+		pub share_mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			associated_token::mint = share_mint,
+			associated_token::authority = buyer,
+			associated_token::token_program = token_program,
+		)]
+		pub buyer_share_account: Account<'info, TokenAccount>,
+
+		#[account(
+			mut,
+			associated_token::mint = mint,
+			associated_token::authority = vault,
+			associated_token::token_program = token_program,
+		)]
+		pub vault_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			init_if_needed,
+			payer = fee_payer,
+			associated_token::mint = mint,
+			associated_token::authority = buyer,
+			associated_token::token_program = token_program,
+		)]
+		pub buyer_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			seeds = [
+				b"freeze_authority",
+			],
+			bump,
+		)]
+		/// CHECK: PDA used only as the mints' freeze authority
+		pub freeze_authority: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+		pub associated_token_program: Program<'info, AssociatedToken>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+	}
+
+	impl<'info> BuyoutVault<'info> {
+		pub fn cpi_csl_spl_token_burn(&self, amount: u64) -> Result<()> {
+			anchor_spl::token::burn(
+				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::Burn {
+						mint: self.share_mint.to_account_info(),
+						from: self.buyer_share_account.to_account_info(),
+						authority: self.buyer.to_account_info()
+					}
+				),
+				amount, 
+			)
+		}
+		pub fn cpi_csl_spl_token_transfer_checked(&self, amount: u64, decimals: u8, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::transfer_checked(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::TransferChecked {
+						from: self.vault_token_account.to_account_info(),
+						mint: self.mint.to_account_info(),
+						to: self.buyer_token_account.to_account_info(),
+						authority: self.vault.to_account_info()
+					},
+					signer_seeds,
+				),
+				amount, 
+				decimals, 
+			)
+		}
+	}
+
+
+/// Buys the NFT out of a vault: a share holder burns their shares and pays the reserve price for the remaining shares into the vault
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` buyer: [AccountInfo] A share holder buying the NFT
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[writable]` vault: [Vault] The vault holding the NFT
+/// 6. `[writable]` share_mint: [Mint] The vault's share mint
+/// 7. `[writable]` buyer_share_account: [TokenAccount] The buyer's share token account
+/// 8. `[writable]` vault_token_account: [TokenAccount] The vault's token account holding the NFT
+/// 9. `[writable]` buyer_token_account: [TokenAccount] The buyer's token account receiving the NFT, created if needed
+/// 10. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 11. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 12. `[]` token_program: [AccountInfo] SPL Token program
+/// 13. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 14. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 15. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Remaining accounts:
+/// - `[writable]` creators: the collection creators, in the order stored on the collection; required for royalty-enforced collections
+pub fn handler<'info>(
+	ctx: Context<'_, '_, 'info, 'info, BuyoutVault<'info>>,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	let vault = &ctx.accounts.vault;
+	require!(!vault.bought_out, MetaplexNftProgramError::VaultBoughtOut);
+
+	let held = ctx.accounts.buyer_share_account.amount;
+	require!(held > 0, MetaplexNftProgramError::NotShareHolder);
+
+	// The buyer's own shares count towards the reserve; the rest is paid in,
+	// rounded up so the other holders never receive less than their part.
+	let outstanding_shares = vault.total_shares - held;
+	let proceeds = (vault.reserve_price as u128 * outstanding_shares as u128)
+		.div_ceil(vault.total_shares as u128);
+	let proceeds = u64::try_from(proceeds).map_err(|_| MetaplexNftProgramError::ArithmeticOverflow)?;
+
+	let buyer = ctx.accounts.buyer.to_account_info();
+	let system_program = ctx.accounts.system_program.to_account_info();
+	// On royalty-enforced collections the buyout is a sale of the outstanding
+	// shares. The creators' royalty on it is charged to the buyer on top of
+	// the proceeds, so the other holders still receive their full part.
+	if ctx.accounts.collection.enforce_royalties {
+		pay_royalties(&ctx.accounts.collection, proceeds, ctx.remaining_accounts, |creator, amount| {
+			transfer_lamports(&buyer, creator, &system_program, amount)
+		})?;
+	}
+	if proceeds > 0 {
+		transfer_lamports(
+			&buyer,
+			&ctx.accounts.vault.to_account_info(),
+			&system_program,
+			proceeds,
+		)?;
+	}
+	ctx.accounts.cpi_csl_spl_token_burn(held)?;
+
+	let mint_key = ctx.accounts.mint.key();
+	ctx.accounts.cpi_csl_spl_token_transfer_checked(
+		1,
+		ctx.accounts.mint.decimals,
+		&[&[
+			b"vault",
+			mint_key.as_ref(),
+			&[ctx.accounts.vault.bump],
+		]],
+	)?;
+	utils::freeze_if_enforced(
+		&ctx.accounts.collection,
+		&ctx.accounts.buyer_token_account,
+		&ctx.accounts.mint.to_account_info(),
+		&ctx.accounts.freeze_authority.to_account_info(),
+		ctx.bumps.freeze_authority,
+		&ctx.accounts.token_program.to_account_info(),
+	)?;
+
+	let vault = &mut ctx.accounts.vault;
+	vault.bought_out = true;
+	vault.buyer = ctx.accounts.buyer.key();
+	vault.outstanding_shares = outstanding_shares;
+	vault.proceeds = proceeds;
+
+	ctx.accounts.metadata.owner = ctx.accounts.buyer.key();
+
+	Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct CancelBid<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub bidder: Signer<'info>,
+
+		#[account(
+			mut,
+			close = bidder,
+			has_one = bidder,
+			seeds = [
+				b"bid",
+				bidder.key().as_ref(),
+				bid.target().as_ref(),
+			],
+			bump = bid.bump,
+		)]
+		pub bid: Account<'info, Bid>,
+	}
+
+/// Cancels an offer and refunds the escrowed lamports to the bidder
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` bidder: [AccountInfo] The account that made the offer
+/// 2. `[writable]` bid: [Bid] The bid to cancel
+pub fn handler(
+	_ctx: Context<CancelBid>,
+) -> Result<()> {
+	// Closing the bid returns the escrowed amount together with its rent.
+	Ok(())
+}
@@ -0,0 +1,47 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct CancelLoanOffer<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub lender: Signer<'info>,
+
+		#[account(
+			mut,
+			close = lender,
+			has_one = lender,
+			constraint = !loan.is_taken() @ MetaplexNftProgramError::LoanAlreadyTaken,
+			seeds = [
+				b"loan",
+				lender.key().as_ref(),
+				loan.offer_id.to_le_bytes().as_ref(),
+			],
+			bump = loan.bump,
+		)]
+		pub loan: Account<'info, Loan>,
+	}
+
+/// Withdraws a loan offer that has not been taken, refunding the principal to the lender
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` lender: [AccountInfo] The lender who made the offer
+/// 2. `[writable]` loan: [Loan] The loan offer to cancel
+pub fn handler(
+	_ctx: Context<CancelLoanOffer>,
+) -> Result<()> {
+	// Closing the offer returns the escrowed principal together with its rent.
+	Ok(())
+}
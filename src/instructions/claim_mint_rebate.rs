@@ -0,0 +1,71 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+use crate::utils::transfer_lamports_from_program_account;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct ClaimMintRebate<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			address = mint_receipt.minter,
+		)]
+		/// CHECK: matched against the mint receipt
+		pub minter: UncheckedAccount<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"mint_config",
+				mint_config.collection.as_ref(),
+			],
+			bump = mint_config.bump,
+		)]
+		pub mint_config: Account<'info, MintConfig>,
+
+		#[account(
+			mut,
+			close = minter,
+			has_one = mint_config,
+			seeds = [
+				b"mint_receipt",
+				mint_receipt.mint.as_ref(),
+			],
+			bump,
+		)]
+		pub mint_receipt: Account<'info, MintReceipt>,
+	}
+
+/// Refunds a minter the difference between the price they paid and the final clearing price, closing their mint receipt
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Anyone; the rebate always goes to the minter
+/// 1. `[writable]` minter: [AccountInfo] The account that paid for the mint
+/// 2. `[writable]` mint_config: [MintConfig] The collection's mint pricing
+/// 3. `[writable]` mint_receipt: [MintReceipt] The receipt of the mint to refund
+pub fn handler(
+	ctx: Context<ClaimMintRebate>,
+) -> Result<()> {
+	let clearing_price = ctx.accounts.mint_config
+		.clearing_price(Clock::get()?.unix_timestamp)
+		.ok_or(MetaplexNftProgramError::MintNotConcluded)?;
+
+	let rebate = ctx.accounts.mint_receipt.price.saturating_sub(clearing_price);
+	if rebate > 0 {
+		transfer_lamports_from_program_account(
+			&ctx.accounts.mint_config.to_account_info(),
+			&ctx.accounts.minter.to_account_info(),
+			rebate,
+		)?;
+	}
+
+	Ok(())
+}
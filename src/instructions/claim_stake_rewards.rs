@@ -0,0 +1,132 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+	#[derive(Accounts)]
+	pub struct ClaimStakeRewards<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		pub owner: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"stake_pool",
+				stake_pool.collection.as_ref(),
+			],
+			bump = stake_pool.bump,
+		)]
+		pub stake_pool: Account<'info, StakePool>,
+
+		#[account(
+			address = stake_pool.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			mut,
+			has_one = owner,
+			has_one = stake_pool,
+			seeds = [
+				b"stake_entry",
+				stake_entry.mint.as_ref(),
+			],
+			bump = stake_entry.bump,
+		)]
+		pub stake_entry: Account<'info, StakeEntry>,
+
+		#[account(
+			mut,
+			address = stake_pool.reward_mint,
+		)]
+		pub reward_mint: Account<'info, Mint>,
+
+		#[account(
+			init_if_needed,
+			payer = fee_payer,
+			associated_token::mint = reward_mint,
+			associated_token::authority = owner,
+			associated_token::token_program = token_program,
+		)]
+		pub owner_reward_account: Account<'info, TokenAccount>,
+
+		pub system_program: Program<'info, System>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+		pub associated_token_program: Program<'info, AssociatedToken>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+	}
+
+	impl<'info> ClaimStakeRewards<'info> {
+		pub fn cpi_csl_spl_token_mint_to(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::mint_to(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::MintTo {
+						mint: self.reward_mint.to_account_info(),
+						to: self.owner_reward_account.to_account_info(),
+						authority: self.stake_pool.to_account_info()
+					},
+					signer_seeds,
+				),
+				amount, 
+			)
+		}
+	}
+
+
+/// Mints the rewards a staked NFT has accrued since its last claim
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[signer]` owner: [AccountInfo] The owner of the staked NFT
+/// 2. `[]` stake_pool: [StakePool] The stake pool of the NFT's collection
+/// 3. `[]` collection: [NftCollection] The collection of the stake pool
+/// 4. `[writable]` stake_entry: [StakeEntry] The stake entry of the NFT
+/// 5. `[writable]` reward_mint: [Mint] The pool's reward token mint
+/// 6. `[writable]` owner_reward_account: [TokenAccount] The owner's reward token account, created if needed
+/// 7. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 8. `[]` token_program: [AccountInfo] SPL Token program
+/// 9. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 10. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 11. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+pub fn handler(
+	ctx: Context<ClaimStakeRewards>,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	let now = Clock::get()?.unix_timestamp;
+	let stake_pool = &ctx.accounts.stake_pool;
+	let rewards = ctx.accounts.stake_entry.pending_rewards(stake_pool.reward_rate, now)?;
+
+	if rewards > 0 {
+		ctx.accounts.cpi_csl_spl_token_mint_to(rewards, &[&[
+			b"stake_pool",
+			stake_pool.collection.as_ref(),
+			&[stake_pool.bump],
+		]])?;
+	}
+
+	ctx.accounts.stake_entry.last_claimed_at = now;
+
+	Ok(())
+}
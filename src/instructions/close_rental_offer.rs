@@ -0,0 +1,44 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct CloseRentalOffer<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub owner: Signer<'info>,
+
+		#[account(
+			mut,
+			close = owner,
+			has_one = owner,
+			seeds = [
+				b"rental",
+				rental_offer.mint.as_ref(),
+				rental_offer.owner.as_ref(),
+			],
+			bump = rental_offer.bump,
+		)]
+		pub rental_offer: Account<'info, RentalOffer>,
+	}
+
+/// Withdraws a rental offer so the NFT can no longer be rented
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` owner: [AccountInfo] The owner who created the offer
+/// 2. `[writable]` rental_offer: [RentalOffer] The rental offer to close
+pub fn handler(
+	_ctx: Context<CloseRentalOffer>,
+) -> Result<()> {
+	// Closing the offer stops new rentals; a running rental lasts until it expires.
+	Ok(())
+}
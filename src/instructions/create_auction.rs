@@ -0,0 +1,198 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		reserve_price: u64,
+		start_time: i64,
+		end_time: i64,
+		min_increment: u64,
+		extension_window: i64,
+	)]
+	pub struct CreateAuction<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub seller: Signer<'info>,
+
+		pub mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"metadata",
+				mint.key().as_ref(),
+			],
+			bump,
+			constraint = metadata.owner == seller.key() @ MetaplexNftProgramError::NotNftOwner,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		#[account(
+			address = metadata.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			init,
+			space=185,
+			payer=seller,
+			seeds = [
+				b"auction",
+				mint.key().as_ref(),
+			],
+			bump,
+		)]
+		pub auction: Account<'info, Auction>,
+
+		#[account(
+			mut,
+			associated_token::mint = mint,
+			associated_token::authority = seller,
+			associated_token::token_program = token_program,
+		)]
+		pub seller_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			init,
+			payer = seller,
+			associated_token::mint = mint,
+			associated_token::authority = auction,
+			associated_token::token_program = token_program,
+		)]
+		pub escrow_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			seeds = [
+				b"freeze_authority",
+			],
+			bump,
+		)]
+		/// CHECK: PDA used only as the mints' freeze authority
+		pub freeze_authority: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+		pub associated_token_program: Program<'info, AssociatedToken>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+	}
+
+	impl<'info> CreateAuction<'info> {
+		pub fn cpi_csl_spl_token_transfer_checked(&self, amount: u64, decimals: u8) -> Result<()> {
+			anchor_spl::token::transfer_checked(
+				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::TransferChecked {
+						from: self.seller_token_account.to_account_info(),
+						mint: self.mint.to_account_info(),
+						to: self.escrow_token_account.to_account_info(),
+						authority: self.seller.to_account_info()
+					}
+				),
+				amount, 
+				decimals, 
+			)
+		}
+	}
+
+
+/// Puts an NFT up for an English auction, moving it into an escrow owned by the auction
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` seller: [AccountInfo] The owner of the NFT
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[writable]` auction: [Auction] The auction account to initialize
+/// 6. `[writable]` seller_token_account: [TokenAccount] The seller's token account holding the NFT
+/// 7. `[writable]` escrow_token_account: [TokenAccount] Escrow token account owned by the auction
+/// 8. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 9. `[]` system_program: [AccountInfo] System program
+/// 10. `[]` token_program: [AccountInfo] SPL Token program
+/// 11. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 12. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 13. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Data:
+/// - reserve_price: [u64] Lowest accepted bid in lamports
+/// - start_time: [i64] Unix timestamp at which bidding opens
+/// - end_time: [i64] Unix timestamp at which bidding closes
+/// - min_increment: [u64] Minimum amount in lamports by which a bid must beat the current highest bid
+/// - extension_window: [i64] Seconds before the end in which a new bid extends the auction to this many seconds from the bid
+pub fn handler(
+	ctx: Context<CreateAuction>,
+	reserve_price: u64,
+	start_time: i64,
+	end_time: i64,
+	min_increment: u64,
+	extension_window: i64,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	require!(reserve_price > 0, MetaplexNftProgramError::InvalidPrice);
+	let now = Clock::get()?.unix_timestamp;
+	ctx.accounts.metadata.assert_transferable(now)?;
+	ctx.accounts.metadata.assert_not_delegated()?;
+	require!(
+		start_time < end_time
+			&& end_time > now
+			&& min_increment > 0
+			&& extension_window >= 0,
+		MetaplexNftProgramError::InvalidAuctionConfig
+	);
+
+	utils::thaw_if_frozen(
+		&ctx.accounts.seller_token_account,
+		&ctx.accounts.mint.to_account_info(),
+		&ctx.accounts.freeze_authority.to_account_info(),
+		ctx.bumps.freeze_authority,
+		&ctx.accounts.token_program.to_account_info(),
+	)?;
+	ctx.accounts.cpi_csl_spl_token_transfer_checked(
+		1,
+		ctx.accounts.mint.decimals,
+	)?;
+
+	let auction = &mut ctx.accounts.auction;
+	auction.seller = ctx.accounts.seller.key();
+	auction.mint = ctx.accounts.mint.key();
+	auction.collection = ctx.accounts.metadata.collection;
+	auction.reserve_price = reserve_price;
+	auction.start_time = start_time;
+	auction.end_time = end_time;
+	auction.min_increment = min_increment;
+	auction.extension_window = extension_window;
+	auction.highest_bidder = Pubkey::default();
+	auction.highest_bid = 0;
+	auction.bump = ctx.bumps.auction;
+
+	ctx.accounts.metadata.owner = auction.key();
+
+	Ok(())
+}
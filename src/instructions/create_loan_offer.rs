@@ -0,0 +1,98 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+use crate::utils::transfer_lamports;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		offer_id: u64,
+		amount: u64,
+		duration: i64,
+		interest: u64,
+	)]
+	pub struct CreateLoanOffer<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub lender: Signer<'info>,
+
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			init,
+			// 8-byte discriminator and 169 bytes of Loan fields
+			space=177,
+			payer=lender,
+			seeds = [
+				b"loan",
+				lender.key().as_ref(),
+				offer_id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub loan: Account<'info, Loan>,
+
+		pub system_program: Program<'info, System>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+	}
+
+/// Offers a loan against any NFT of a collection, escrowing the principal in the loan account
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` lender: [AccountInfo] The account lending the SOL
+/// 2. `[]` collection: [NftCollection] The collection accepted as collateral
+/// 3. `[writable]` loan: [Loan] The loan account to initialize
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 5. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Data:
+/// - offer_id: [u64] Lender-chosen identifier distinguishing their offers
+/// - amount: [u64] Principal in lamports
+/// - duration: [i64] Seconds the borrower has to repay once the loan is taken
+/// - interest: [u64] Interest in lamports owed on top of the principal
+pub fn handler(
+	ctx: Context<CreateLoanOffer>,
+	offer_id: u64,
+	amount: u64,
+	duration: i64,
+	interest: u64,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	require!(amount > 0 && duration > 0, MetaplexNftProgramError::InvalidLoanTerms);
+
+	transfer_lamports(
+		&ctx.accounts.lender.to_account_info(),
+		&ctx.accounts.loan.to_account_info(),
+		&ctx.accounts.system_program.to_account_info(),
+		amount,
+	)?;
+
+	let loan = &mut ctx.accounts.loan;
+	loan.lender = ctx.accounts.lender.key();
+	loan.collection = ctx.accounts.collection.key();
+	loan.offer_id = offer_id;
+	loan.amount = amount;
+	loan.duration = duration;
+	loan.interest = interest;
+	loan.bump = ctx.bumps.loan;
+
+	Ok(())
+}
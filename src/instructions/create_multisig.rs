@@ -0,0 +1,66 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		nonce: u64,
+		signers: Vec<Pubkey>,
+		threshold: u8,
+	)]
+	pub struct CreateMultisig<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		pub creator: Signer<'info>,
+
+		#[account(
+			init,
+			space=382,
+			payer=fee_payer,
+			seeds = [
+				b"multisig",
+				creator.key().as_ref(),
+				nonce.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub multisig: Account<'info, Multisig>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Creates an M-of-N multisig that can act as a collection authority by executing approved proposals
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[signer]` creator: [AccountInfo] The creator of the multisig; only used to derive its address
+/// 2. `[writable]` multisig: [Multisig] The multisig account to initialize
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - nonce: [u64] Creator-chosen identifier distinguishing their multisigs
+/// - signers: [Vec<Pubkey>] The member keys, at most 10
+/// - threshold: [u8] Number of member approvals a proposal needs
+pub fn handler(
+	ctx: Context<CreateMultisig>,
+	nonce: u64,
+	signers: Vec<Pubkey>,
+	threshold: u8,
+) -> Result<()> {
+	Multisig::validate_signers(&signers, threshold)?;
+
+	let multisig = &mut ctx.accounts.multisig;
+	multisig.creator = ctx.accounts.creator.key();
+	multisig.nonce = nonce;
+	multisig.signers = signers;
+	multisig.threshold = threshold;
+	multisig.proposal_count = 0;
+	multisig.bump = ctx.bumps.multisig;
+
+	Ok(())
+}
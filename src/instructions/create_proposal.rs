@@ -0,0 +1,88 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		accounts: Vec<ProposalAccountMeta>,
+		data: Vec<u8>,
+	)]
+	pub struct CreateProposal<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		pub proposer: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"multisig",
+				multisig.creator.as_ref(),
+				multisig.nonce.to_le_bytes().as_ref(),
+			],
+			bump = multisig.bump,
+		)]
+		pub multisig: Account<'info, Multisig>,
+
+		#[account(
+			init,
+			space=1742,
+			payer=fee_payer,
+			seeds = [
+				b"proposal",
+				multisig.key().as_ref(),
+				multisig.proposal_count.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub proposal: Account<'info, Proposal>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Proposes an instruction of this program for the multisig to sign, such as a mint, metadata update or pause; the proposer's approval is recorded
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[signer]` proposer: [AccountInfo] A member of the multisig
+/// 2. `[writable]` multisig: [Multisig] The multisig the proposal is for
+/// 3. `[writable]` proposal: [Proposal] The proposal account to initialize, at the multisig's next proposal index
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - accounts: [Vec<ProposalAccountMeta>] Accounts of the proposed instruction; the multisig is marked as signer
+/// - data: [Vec<u8>] Data of the proposed instruction
+pub fn handler(
+	ctx: Context<CreateProposal>,
+	accounts: Vec<ProposalAccountMeta>,
+	data: Vec<u8>,
+) -> Result<()> {
+	ctx.accounts.multisig.assert_signer(&ctx.accounts.proposer.key())?;
+	require!(
+		accounts.len() <= MAX_PROPOSAL_ACCOUNTS && data.len() <= MAX_PROPOSAL_DATA_LENGTH,
+		MetaplexNftProgramError::ProposalTooLarge
+	);
+
+	let multisig = &mut ctx.accounts.multisig;
+	let proposal = &mut ctx.accounts.proposal;
+	proposal.multisig = multisig.key();
+	proposal.index = multisig.proposal_count;
+	proposal.proposer = ctx.accounts.proposer.key();
+	proposal.accounts = accounts;
+	proposal.data = data;
+	proposal.approvals = vec![ctx.accounts.proposer.key()];
+	proposal.executed = false;
+	proposal.bump = ctx.bumps.proposal;
+
+	multisig.proposal_count = multisig.proposal_count
+		.checked_add(1)
+		.ok_or(MetaplexNftProgramError::ArithmeticOverflow)?;
+
+	Ok(())
+}
@@ -0,0 +1,98 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::Mint;
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		price_per_day: u64,
+		max_days: u32,
+	)]
+	pub struct CreateRentalOffer<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		pub owner: Signer<'info>,
+
+		pub mint: Account<'info, Mint>,
+
+		#[account(
+			seeds = [
+				b"metadata",
+				mint.key().as_ref(),
+			],
+			bump,
+			constraint = metadata.owner == owner.key() @ MetaplexNftProgramError::NotNftOwner,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		#[account(
+			address = metadata.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			init,
+			space=85,
+			payer=fee_payer,
+			seeds = [
+				b"rental",
+				mint.key().as_ref(),
+				owner.key().as_ref(),
+			],
+			bump,
+		)]
+		pub rental_offer: Account<'info, RentalOffer>,
+
+		pub system_program: Program<'info, System>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+	}
+
+/// Offers the usage rights of an NFT for rent at a daily price, without giving up ownership
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[signer]` owner: [AccountInfo] The owner of the NFT
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[writable]` rental_offer: [RentalOffer] The rental offer to initialize
+/// 6. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 7. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Data:
+/// - price_per_day: [u64] Rent in lamports per day
+/// - max_days: [u32] Longest rental the owner accepts, in days
+pub fn handler(
+	ctx: Context<CreateRentalOffer>,
+	price_per_day: u64,
+	max_days: u32,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	require!(price_per_day > 0, MetaplexNftProgramError::InvalidPrice);
+	require!(max_days > 0, MetaplexNftProgramError::InvalidRentalDuration);
+
+	let rental_offer = &mut ctx.accounts.rental_offer;
+	rental_offer.owner = ctx.accounts.owner.key();
+	rental_offer.mint = ctx.accounts.mint.key();
+	rental_offer.price_per_day = price_per_day;
+	rental_offer.max_days = max_days;
+	rental_offer.bump = ctx.bumps.rental_offer;
+
+	Ok(())
+}
@@ -0,0 +1,79 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::Mint;
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		reward_rate: u64,
+	)]
+	pub struct CreateStakePool<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			has_one = authority @ MetaplexNftProgramError::InvalidAuthority,
+			seeds = [
+				b"collection",
+				collection.seed_authority.as_ref(),
+			],
+			bump,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		pub authority: Signer<'info>,
+
+		#[account(
+			init,
+			space=81,
+			payer=fee_payer,
+			seeds = [
+				b"stake_pool",
+				collection.key().as_ref(),
+			],
+			bump,
+		)]
+		pub stake_pool: Account<'info, StakePool>,
+
+		#[account(
+			constraint = reward_mint.mint_authority == COption::Some(stake_pool.key()) @ MetaplexNftProgramError::InvalidRewardMint,
+		)]
+		pub reward_mint: Account<'info, Mint>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Creates the staking pool of a collection; the reward mint must already have the pool PDA as mint authority
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` collection: [NftCollection] The collection whose NFTs can be staked
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+/// 3. `[writable]` stake_pool: [StakePool] The stake pool to initialize
+/// 4. `[]` reward_mint: [Mint] The pool's reward token mint
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - reward_rate: [u64] Reward tokens, in base units, earned per staked NFT per second
+pub fn handler(
+	ctx: Context<CreateStakePool>,
+	reward_rate: u64,
+) -> Result<()> {
+	require!(reward_rate > 0, MetaplexNftProgramError::InvalidRewardRate);
+
+	let stake_pool = &mut ctx.accounts.stake_pool;
+	stake_pool.collection = ctx.accounts.collection.key();
+	stake_pool.reward_mint = ctx.accounts.reward_mint.key();
+	stake_pool.reward_rate = reward_rate;
+	stake_pool.bump = ctx.bumps.stake_pool;
+
+	Ok(())
+}
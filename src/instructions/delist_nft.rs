@@ -0,0 +1,155 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct DelistNft<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub seller: Signer<'info>,
+
+		pub mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"metadata",
+				mint.key().as_ref(),
+			],
+			bump,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		#[account(
+			address = metadata.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			mut,
+			close = seller,
+			has_one = seller,
+			has_one = mint,
+			constraint = !listing.escrowless @ MetaplexNftProgramError::ListingModeMismatch,
+			seeds = [
+				b"listing",
+				mint.key().as_ref(),
+			],
+			bump = listing.bump,
+		)]
+		pub listing: Account<'info, Listing>,
+
+		#[account(
+			mut,
+			associated_token::mint = mint,
+			associated_token::authority = seller,
+			associated_token::token_program = token_program,
+		)]
+		pub seller_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			mut,
+			address = listing.token_account,
+		)]
+		pub escrow_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			seeds = [
+				b"freeze_authority",
+			],
+			bump,
+		)]
+		/// CHECK: PDA used only as the mints' freeze authority
+		pub freeze_authority: UncheckedAccount<'info>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+	}
+
+	impl<'info> DelistNft<'info> {
+		pub fn cpi_csl_spl_token_transfer_checked(&self, amount: u64, decimals: u8, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::transfer_checked(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::TransferChecked {
+						from: self.escrow_token_account.to_account_info(),
+						mint: self.mint.to_account_info(),
+						to: self.seller_token_account.to_account_info(),
+						authority: self.listing.to_account_info()
+					},
+					signer_seeds,
+				),
+				amount, 
+				decimals, 
+			)
+		}
+		pub fn cpi_csl_spl_token_close_account(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::close_account(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::CloseAccount {
+						account: self.escrow_token_account.to_account_info(),
+						destination: self.seller.to_account_info(),
+						authority: self.listing.to_account_info()
+					},
+					signer_seeds,
+				),
+			)
+		}
+	}
+
+
+/// Cancels a listing and returns the escrowed NFT to the seller
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` seller: [AccountInfo] The seller that created the listing
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[writable]` listing: [Listing] The listing to close
+/// 6. `[writable]` seller_token_account: [TokenAccount] The seller's token account
+/// 7. `[writable]` escrow_token_account: [TokenAccount] Escrow token account owned by the listing
+/// 8. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 9. `[]` token_program: [AccountInfo] SPL Token program
+/// 10. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+pub fn handler(
+	ctx: Context<DelistNft>,
+) -> Result<()> {
+	let mint_key = ctx.accounts.mint.key();
+	let signer_seeds: &[&[&[u8]]] = &[&[
+		b"listing",
+		mint_key.as_ref(),
+		&[ctx.accounts.listing.bump],
+	]];
+
+	ctx.accounts.cpi_csl_spl_token_transfer_checked(
+		1,
+		ctx.accounts.mint.decimals,
+		signer_seeds,
+	)?;
+	ctx.accounts.cpi_csl_spl_token_close_account(signer_seeds)?;
+	utils::freeze_if_enforced(
+		&ctx.accounts.collection,
+		&ctx.accounts.seller_token_account,
+		&ctx.accounts.mint.to_account_info(),
+		&ctx.accounts.freeze_authority.to_account_info(),
+		ctx.bumps.freeze_authority,
+		&ctx.accounts.token_program.to_account_info(),
+	)?;
+
+	ctx.accounts.metadata.owner = ctx.accounts.seller.key();
+
+	Ok(())
+}
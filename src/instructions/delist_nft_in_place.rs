@@ -0,0 +1,146 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct DelistNftInPlace<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub seller: Signer<'info>,
+
+		pub mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"metadata",
+				mint.key().as_ref(),
+			],
+			bump,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		#[account(
+			mut,
+			close = seller,
+			has_one = seller,
+			has_one = mint,
+			constraint = listing.escrowless @ MetaplexNftProgramError::ListingModeMismatch,
+			seeds = [
+				b"listing",
+				mint.key().as_ref(),
+			],
+			bump = listing.bump,
+		)]
+		pub listing: Account<'info, Listing>,
+
+		#[account(
+			address = listing.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			mut,
+			address = listing.token_account,
+		)]
+		pub seller_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			seeds = [
+				b"freeze_authority",
+			],
+			bump,
+		)]
+		/// CHECK: PDA used only as the mints' freeze authority
+		pub freeze_authority: UncheckedAccount<'info>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+	}
+
+	impl<'info> DelistNftInPlace<'info> {
+		pub fn cpi_csl_spl_token_thaw_account(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::thaw_account(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::ThawAccount {
+						account: self.seller_token_account.to_account_info(),
+						mint: self.mint.to_account_info(),
+						authority: self.freeze_authority.to_account_info()
+					},
+					signer_seeds,
+				),
+			)
+		}
+		pub fn cpi_csl_spl_token_freeze_account(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::freeze_account(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::FreezeAccount {
+						account: self.seller_token_account.to_account_info(),
+						mint: self.mint.to_account_info(),
+						authority: self.freeze_authority.to_account_info()
+					},
+					signer_seeds,
+				),
+			)
+		}
+		pub fn cpi_csl_spl_token_revoke(&self) -> Result<()> {
+			anchor_spl::token::revoke(
+				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::Revoke {
+						source: self.seller_token_account.to_account_info(),
+						authority: self.seller.to_account_info()
+					}
+				),
+			)
+		}
+	}
+
+
+/// Cancels an in-place listing, thawing the seller's token account and revoking the listing delegate
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` seller: [AccountInfo] The seller that created the listing
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT, no longer marked as listed
+/// 4. `[writable]` listing: [Listing] The listing to close
+/// 5. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 6. `[writable]` seller_token_account: [TokenAccount] The seller's token account
+/// 7. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 8. `[]` token_program: [AccountInfo] SPL Token program
+/// 9. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+pub fn handler(
+	ctx: Context<DelistNftInPlace>,
+) -> Result<()> {
+	// The token program only revokes on thawed accounts; tokens of
+	// royalty-enforced collections and NFTs frozen by the collection
+	// authority go back to being frozen.
+	ctx.accounts.cpi_csl_spl_token_thaw_account(&[&[
+		b"freeze_authority",
+		&[ctx.bumps.freeze_authority],
+	]])?;
+	ctx.accounts.cpi_csl_spl_token_revoke()?;
+	if ctx.accounts.collection.enforce_royalties || ctx.accounts.metadata.frozen {
+		ctx.accounts.cpi_csl_spl_token_freeze_account(&[&[
+			b"freeze_authority",
+			&[ctx.bumps.freeze_authority],
+		]])?;
+	}
+
+	ctx.accounts.metadata.listed = false;
+
+	Ok(())
+}
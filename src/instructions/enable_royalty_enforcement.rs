@@ -0,0 +1,47 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct EnableRoyaltyEnforcement<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority @ MetaplexNftProgramError::InvalidAuthority,
+			seeds = [
+				b"collection",
+				collection.seed_authority.as_ref(),
+			],
+			bump,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		pub authority: Signer<'info>,
+	}
+
+/// Turns on royalty enforcement for a collection: its tokens are frozen from mint and only move through this program. Must be done before the first mint and cannot be undone
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` collection: [NftCollection] The collection to configure
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+pub fn handler(
+	ctx: Context<EnableRoyaltyEnforcement>,
+) -> Result<()> {
+	// Every token has to be frozen from its mint on, so the mode cannot be
+	// switched on once tokens are in circulation, nor switched off again.
+	let collection = &mut ctx.accounts.collection;
+	require!(collection.nft_count == 0, MetaplexNftProgramError::RoyaltyEnforcementLocked);
+
+	collection.enforce_royalties = true;
+
+	Ok(())
+}
@@ -0,0 +1,46 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct EndRental<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"metadata",
+				metadata.mint.as_ref(),
+			],
+			bump,
+		)]
+		pub metadata: Account<'info, NftMint>,
+	}
+
+/// Clears the user of an NFT once its rental has expired; callable by anyone
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` metadata: [NftMint] The metadata account of the rented NFT
+pub fn handler(
+	ctx: Context<EndRental>,
+) -> Result<()> {
+	let metadata = &mut ctx.accounts.metadata;
+	require!(metadata.user != Pubkey::default(), MetaplexNftProgramError::NoActiveRental);
+	require!(
+		Clock::get()?.unix_timestamp >= metadata.expires_at,
+		MetaplexNftProgramError::RentalNotExpired
+	);
+
+	metadata.user = Pubkey::default();
+	metadata.expires_at = 0;
+
+	Ok(())
+}
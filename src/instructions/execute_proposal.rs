@@ -0,0 +1,106 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_lang::solana_program::{
+	instruction::{AccountMeta, Instruction},
+	program::invoke_signed,
+};
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct ExecuteProposal<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		// Not writable here: the proposed instruction may update the multisig
+		// itself, which must not be overwritten on exit.
+		#[account(
+			seeds = [
+				b"multisig",
+				multisig.creator.as_ref(),
+				multisig.nonce.to_le_bytes().as_ref(),
+			],
+			bump = multisig.bump,
+		)]
+		pub multisig: Account<'info, Multisig>,
+
+		#[account(
+			mut,
+			has_one = multisig,
+			seeds = [
+				b"proposal",
+				multisig.key().as_ref(),
+				proposal.index.to_le_bytes().as_ref(),
+			],
+			bump = proposal.bump,
+		)]
+		pub proposal: Account<'info, Proposal>,
+
+		pub program: Program<'info, crate::program::MetaplexNftProgram>,
+	}
+
+/// Executes a proposal once enough members approved it, signing the proposed instruction as the multisig
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [Multisig] The multisig the proposal is for
+/// 2. `[writable]` proposal: [Proposal] The proposal to execute
+/// 3. `[]` program: [AccountInfo] This program, invoked with the proposed instruction
+///
+/// Remaining accounts:
+/// - accounts: the proposed instruction's accounts, in the order stored on the proposal
+pub fn handler<'info>(
+	ctx: Context<'_, '_, 'info, 'info, ExecuteProposal<'info>>,
+) -> Result<()> {
+	let multisig = &ctx.accounts.multisig;
+	let proposal = &mut ctx.accounts.proposal;
+	require!(!proposal.executed, MetaplexNftProgramError::ProposalAlreadyExecuted);
+	let approvals = proposal.approvals.iter()
+		.filter(|approver| multisig.signers.contains(approver))
+		.count();
+	require!(approvals >= multisig.threshold as usize, MetaplexNftProgramError::ProposalNotApproved);
+
+	require!(
+		ctx.remaining_accounts.len() == proposal.accounts.len(),
+		MetaplexNftProgramError::InvalidProposalAccounts
+	);
+	for (meta, account) in proposal.accounts.iter().zip(ctx.remaining_accounts) {
+		require_keys_eq!(account.key(), meta.pubkey, MetaplexNftProgramError::InvalidProposalAccounts);
+	}
+
+	proposal.executed = true;
+	proposal.exit(&crate::ID)?;
+
+	let instruction = Instruction {
+		program_id: crate::ID,
+		accounts: proposal.accounts.iter()
+			.map(|meta| AccountMeta {
+				pubkey: meta.pubkey,
+				is_signer: meta.is_signer,
+				is_writable: meta.is_writable,
+			})
+			.collect(),
+		data: proposal.data.clone(),
+	};
+	let mut account_infos = ctx.remaining_accounts.to_vec();
+	account_infos.push(ctx.accounts.program.to_account_info());
+
+	invoke_signed(
+		&instruction,
+		&account_infos,
+		&[&[
+			b"multisig",
+			multisig.creator.as_ref(),
+			multisig.nonce.to_le_bytes().as_ref(),
+			&[multisig.bump],
+		]],
+	)?;
+
+	Ok(())
+}
@@ -0,0 +1,199 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::error::MetaplexNftProgramError;
+use crate::utils::{pay_royalties, transfer_lamports};
+
+
+
+
+	#[derive(Accounts)]
+	pub struct ForecloseLoan<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub lender: Signer<'info>,
+
+		pub mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"metadata",
+				mint.key().as_ref(),
+			],
+			bump,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		#[account(
+			address = metadata.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			mut,
+			close = lender,
+			has_one = lender,
+			has_one = mint,
+			constraint = loan.is_taken() @ MetaplexNftProgramError::LoanNotTaken,
+			seeds = [
+				b"loan",
+				lender.key().as_ref(),
+				loan.offer_id.to_le_bytes().as_ref(),
+			],
+			bump = loan.bump,
+		)]
+		pub loan: Account<'info, Loan>,
+
+		#[account(
+			mut,
+			associated_token::mint = mint,
+			associated_token::authority = loan,
+			associated_token::token_program = token_program,
+		)]
+		pub escrow_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			init_if_needed,
+			payer = lender,
+			associated_token::mint = mint,
+			associated_token::authority = lender,
+			associated_token::token_program = token_program,
+		)]
+		pub lender_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			seeds = [
+				b"freeze_authority",
+			],
+			bump,
+		)]
+		/// CHECK: PDA used only as the mints' freeze authority
+		pub freeze_authority: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+		pub associated_token_program: Program<'info, AssociatedToken>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+	}
+
+	impl<'info> ForecloseLoan<'info> {
+		pub fn cpi_csl_spl_token_transfer_checked(&self, amount: u64, decimals: u8, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::transfer_checked(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::TransferChecked {
+						from: self.escrow_token_account.to_account_info(),
+						mint: self.mint.to_account_info(),
+						to: self.lender_token_account.to_account_info(),
+						authority: self.loan.to_account_info()
+					},
+					signer_seeds,
+				),
+				amount, 
+				decimals, 
+			)
+		}
+		pub fn cpi_csl_spl_token_close_account(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::close_account(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::CloseAccount {
+						account: self.escrow_token_account.to_account_info(),
+						destination: self.lender.to_account_info(),
+						authority: self.loan.to_account_info()
+					},
+					signer_seeds,
+				),
+			)
+		}
+	}
+
+
+/// Claims the collateral of a loan that was not repaid before its deadline
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` lender: [AccountInfo] The lender of the loan
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[writable]` loan: [Loan] The defaulted loan
+/// 6. `[writable]` escrow_token_account: [TokenAccount] Escrow token account owned by the loan
+/// 7. `[writable]` lender_token_account: [TokenAccount] The lender's token account receiving the NFT, created if needed
+/// 8. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 9. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 10. `[]` token_program: [AccountInfo] SPL Token program
+/// 11. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 12. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 13. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Remaining accounts:
+/// - `[writable]` creators: the collection creators, in the order stored on the collection; required for royalty-enforced collections
+pub fn handler<'info>(
+	ctx: Context<'_, '_, 'info, 'info, ForecloseLoan<'info>>,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	let loan = &ctx.accounts.loan;
+	require!(
+		Clock::get()?.unix_timestamp > loan.deadline(),
+		MetaplexNftProgramError::LoanNotExpired
+	);
+
+	// Foreclosure sells the NFT to the lender for the unpaid principal, so
+	// royalty-enforced collections charge the royalty on it to the lender.
+	if ctx.accounts.collection.enforce_royalties {
+		let lender = ctx.accounts.lender.to_account_info();
+		let system_program = ctx.accounts.system_program.to_account_info();
+		pay_royalties(&ctx.accounts.collection, loan.amount, ctx.remaining_accounts, |creator, amount| {
+			transfer_lamports(&lender, creator, &system_program, amount)
+		})?;
+	}
+
+	let offer_id = loan.offer_id.to_le_bytes();
+	let signer_seeds: &[&[&[u8]]] = &[&[
+		b"loan",
+		loan.lender.as_ref(),
+		offer_id.as_ref(),
+		&[loan.bump],
+	]];
+	ctx.accounts.cpi_csl_spl_token_transfer_checked(
+		1,
+		ctx.accounts.mint.decimals,
+		signer_seeds,
+	)?;
+	ctx.accounts.cpi_csl_spl_token_close_account(signer_seeds)?;
+	utils::freeze_if_enforced(
+		&ctx.accounts.collection,
+		&ctx.accounts.lender_token_account,
+		&ctx.accounts.mint.to_account_info(),
+		&ctx.accounts.freeze_authority.to_account_info(),
+		ctx.bumps.freeze_authority,
+		&ctx.accounts.token_program.to_account_info(),
+	)?;
+
+	ctx.accounts.metadata.owner = ctx.accounts.lender.key();
+
+	Ok(())
+}
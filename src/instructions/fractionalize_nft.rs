@@ -0,0 +1,235 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{spl_token::instruction::AuthorityType, Mint, Token, TokenAccount},
+};
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		total_shares: u64,
+		reserve_price: u64,
+	)]
+	pub struct FractionalizeNft<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		pub owner: Signer<'info>,
+
+		pub mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"metadata",
+				mint.key().as_ref(),
+			],
+			bump,
+			constraint = metadata.owner == owner.key() @ MetaplexNftProgramError::NotNftOwner,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		#[account(
+			address = metadata.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			init,
+			space=170,
+			payer=fee_payer,
+			seeds = [
+				b"vault",
+				mint.key().as_ref(),
+			],
+			bump,
+		)]
+		pub vault: Account<'info, Vault>,
+
+		#[account(
+			init,
+			payer = fee_payer,
+			mint::decimals = 0,
+			mint::authority = vault,
+			seeds = [
+				b"shares",
+				vault.key().as_ref(),
+			],
+			bump,
+		)]
+		pub share_mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			associated_token::mint = mint,
+			associated_token::authority = owner,
+			associated_token::token_program = token_program,
+		)]
+		pub owner_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			init,
+			payer = fee_payer,
+			associated_token::mint = mint,
+			associated_token::authority = vault,
+			associated_token::token_program = token_program,
+		)]
+		pub vault_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			init_if_needed,
+			payer = fee_payer,
+			associated_token::mint = share_mint,
+			associated_token::authority = owner,
+			associated_token::token_program = token_program,
+		)]
+		pub owner_share_account: Account<'info, TokenAccount>,
+
+		#[account(
+			seeds = [
+				b"freeze_authority",
+			],
+			bump,
+		)]
+		/// CHECK: PDA used only as the mints' freeze authority
+		pub freeze_authority: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+		pub associated_token_program: Program<'info, AssociatedToken>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+	}
+
+	impl<'info> FractionalizeNft<'info> {
+		pub fn cpi_csl_spl_token_transfer_checked(&self, amount: u64, decimals: u8) -> Result<()> {
+			anchor_spl::token::transfer_checked(
+				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::TransferChecked {
+						from: self.owner_token_account.to_account_info(),
+						mint: self.mint.to_account_info(),
+						to: self.vault_token_account.to_account_info(),
+						authority: self.owner.to_account_info()
+					}
+				),
+				amount, 
+				decimals, 
+			)
+		}
+		pub fn cpi_csl_spl_token_mint_to(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::mint_to(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::MintTo {
+						mint: self.share_mint.to_account_info(),
+						to: self.owner_share_account.to_account_info(),
+						authority: self.vault.to_account_info()
+					},
+					signer_seeds,
+				),
+				amount, 
+			)
+		}
+		pub fn cpi_csl_spl_token_set_authority(&self, authority_type: AuthorityType, new_authority: Option<Pubkey>, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::set_authority(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::SetAuthority {
+						current_authority: self.vault.to_account_info(),
+						account_or_mint: self.share_mint.to_account_info()
+					},
+					signer_seeds,
+				),
+				authority_type, 
+				new_authority, 
+			)
+		}
+	}
+
+
+/// Locks an NFT in a vault and mints a fixed supply of fungible shares to the depositor
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[signer]` owner: [AccountInfo] The owner of the NFT
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[writable]` vault: [Vault] The vault to initialize
+/// 6. `[writable]` share_mint: [Mint] The share mint to initialize
+/// 7. `[writable]` owner_token_account: [TokenAccount] The owner's token account holding the NFT
+/// 8. `[writable]` vault_token_account: [TokenAccount] The vault's token account receiving the NFT
+/// 9. `[writable]` owner_share_account: [TokenAccount] The owner's share token account, created if needed
+/// 10. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 11. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 12. `[]` token_program: [AccountInfo] SPL Token program
+/// 13. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 14. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 15. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Data:
+/// - total_shares: [u64] Number of shares minted to the depositor; must be greater than zero
+/// - reserve_price: [u64] Price in lamports of the whole NFT in a buyout
+pub fn handler(
+	ctx: Context<FractionalizeNft>,
+	total_shares: u64,
+	reserve_price: u64,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	require!(total_shares > 0, MetaplexNftProgramError::InvalidShareSupply);
+	require!(reserve_price > 0, MetaplexNftProgramError::InvalidPrice);
+	ctx.accounts.metadata.assert_transferable(Clock::get()?.unix_timestamp)?;
+	ctx.accounts.metadata.assert_not_delegated()?;
+
+	utils::thaw_if_frozen(
+		&ctx.accounts.owner_token_account,
+		&ctx.accounts.mint.to_account_info(),
+		&ctx.accounts.freeze_authority.to_account_info(),
+		ctx.bumps.freeze_authority,
+		&ctx.accounts.token_program.to_account_info(),
+	)?;
+	ctx.accounts.cpi_csl_spl_token_transfer_checked(
+		1,
+		ctx.accounts.mint.decimals,
+	)?;
+
+	let mint_key = ctx.accounts.mint.key();
+	let signer_seeds: &[&[&[u8]]] = &[&[
+		b"vault",
+		mint_key.as_ref(),
+		&[ctx.bumps.vault],
+	]];
+	ctx.accounts.cpi_csl_spl_token_mint_to(total_shares, signer_seeds)?;
+	// Dropping the mint authority makes the share supply fixed for good.
+	ctx.accounts.cpi_csl_spl_token_set_authority(AuthorityType::MintTokens, None, signer_seeds)?;
+
+	let vault = &mut ctx.accounts.vault;
+	vault.curator = ctx.accounts.owner.key();
+	vault.mint = mint_key;
+	vault.share_mint = ctx.accounts.share_mint.key();
+	vault.total_shares = total_shares;
+	vault.reserve_price = reserve_price;
+	vault.bought_out = false;
+	vault.bump = ctx.bumps.vault;
+
+	ctx.accounts.metadata.owner = vault.key();
+
+	Ok(())
+}
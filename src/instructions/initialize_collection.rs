@@ -0,0 +1,85 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		symbol: String,
+		uri: String,
+		content_hash: Option<[u8; 32]>,
+		reveal_root: Option<[u8; 32]>,
+	)]
+	pub struct InitializeCollection<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			init,
+			space=NftCollection::SPACE,
+			payer=fee_payer,
+			seeds = [
+				b"collection",
+				authority.key().as_ref(),
+			],
+			bump,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		pub authority: Signer<'info>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Creates a new NFT collection
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` collection: [NftCollection] The collection account to initialize
+/// 2. `[signer]` authority: [AccountInfo] The authority that can mint NFTs to this collection
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - name: [String] Name of the collection
+/// - symbol: [String] Symbol of the collection
+/// - uri: [String] URI to the collection metadata
+/// - content_hash: [Option<[u8; 32]>] SHA-256 hash of the collection metadata JSON, if committed
+/// - reveal_root: [Option<[u8; 32]>] Merkle root of the final NFT metadata for a placeholder launch, revealed later with `reveal`
+pub fn handler(
+	ctx: Context<InitializeCollection>,
+	name: String,
+	symbol: String,
+	uri: String,
+	content_hash: Option<[u8; 32]>,
+	reveal_root: Option<[u8; 32]>,
+) -> Result<()> {
+	require!(
+		name.len() <= MAX_NAME_LENGTH && symbol.len() <= MAX_SYMBOL_LENGTH,
+		MetaplexNftProgramError::InvalidMetadata
+	);
+
+	let collection = &mut ctx.accounts.collection;
+	collection.max_uri_length = MAX_URI_LENGTH as u16;
+	collection.allowed_uri_schemes = DEFAULT_URI_SCHEMES.iter().map(|scheme| scheme.to_string()).collect();
+	collection.validate_uri(&uri)?;
+
+	collection.authority = ctx.accounts.authority.key();
+	collection.seed_authority = ctx.accounts.authority.key();
+	collection.name = name;
+	collection.symbol = symbol;
+	collection.uri = uri;
+	collection.content_hash = content_hash;
+	collection.reveal_root = reveal_root;
+	collection.base_uri = String::new();
+	collection.verified = false;
+	collection.nft_count = 0;
+	collection.locked_nft_count = 0;
+	collection.is_mutable = true;
+
+	Ok(())
+}
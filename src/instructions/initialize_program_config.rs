@@ -0,0 +1,90 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+use crate::events::ProgramAdminChanged;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		admin: Pubkey,
+	)]
+	pub struct InitializeProgramConfig<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			init,
+			space=84,
+			payer=fee_payer,
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		pub program_config: Account<'info, ProgramConfig>,
+
+		#[account(
+			init,
+			space=9,
+			payer=fee_payer,
+			seeds = [
+				b"treasury",
+			],
+			bump,
+		)]
+		pub treasury: Account<'info, Treasury>,
+
+		pub upgrade_authority: Signer<'info>,
+
+		#[account(
+			constraint = program.programdata_address()? == Some(program_data.key()) @ MetaplexNftProgramError::InvalidAuthority,
+		)]
+		pub program: Program<'info, crate::program::MetaplexNftProgram>,
+
+		#[account(
+			constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key()) @ MetaplexNftProgramError::InvalidAuthority,
+		)]
+		pub program_data: Account<'info, ProgramData>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Creates the program-wide config and sets its admin; must be signed by the program's upgrade authority
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` program_config: [ProgramConfig] The program config account to initialize
+/// 2. `[writable]` treasury: [Treasury] The fee treasury to initialize
+/// 3. `[signer]` upgrade_authority: [AccountInfo] The program's upgrade authority
+/// 4. `[]` program: [AccountInfo] This program
+/// 5. `[]` program_data: [ProgramData] This program's program data account
+/// 6. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - admin: [Pubkey] The key allowed to pause the program and to hand over the admin role
+pub fn handler(
+	ctx: Context<InitializeProgramConfig>,
+	admin: Pubkey,
+) -> Result<()> {
+	let program_config = &mut ctx.accounts.program_config;
+	program_config.admin = admin;
+	program_config.paused = false;
+	program_config.bump = ctx.bumps.program_config;
+	program_config.mint_fee_lamports = 0;
+	program_config.mint_fee_bps = 0;
+	program_config.treasury = ctx.accounts.treasury.key();
+
+	ctx.accounts.treasury.bump = ctx.bumps.treasury;
+
+	emit!(ProgramAdminChanged {
+		old_admin: Pubkey::default(),
+		new_admin: admin,
+	});
+
+	Ok(())
+}
@@ -0,0 +1,176 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		price: u64,
+	)]
+	pub struct ListNft<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub seller: Signer<'info>,
+
+		pub mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"metadata",
+				mint.key().as_ref(),
+			],
+			bump,
+			constraint = metadata.owner == seller.key() @ MetaplexNftProgramError::NotNftOwner,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		#[account(
+			address = metadata.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			init,
+			space=146,
+			payer=seller,
+			seeds = [
+				b"listing",
+				mint.key().as_ref(),
+			],
+			bump,
+		)]
+		pub listing: Account<'info, Listing>,
+
+		#[account(
+			mut,
+			associated_token::mint = mint,
+			associated_token::authority = seller,
+			associated_token::token_program = token_program,
+		)]
+		pub seller_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			init,
+			payer = seller,
+			associated_token::mint = mint,
+			associated_token::authority = listing,
+			associated_token::token_program = token_program,
+		)]
+		pub escrow_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			seeds = [
+				b"freeze_authority",
+			],
+			bump,
+		)]
+		/// CHECK: PDA used only as the mints' freeze authority
+		pub freeze_authority: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+		pub associated_token_program: Program<'info, AssociatedToken>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+	}
+
+	impl<'info> ListNft<'info> {
+		pub fn cpi_csl_spl_token_transfer_checked(&self, amount: u64, decimals: u8) -> Result<()> {
+			anchor_spl::token::transfer_checked(
+				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::TransferChecked {
+						from: self.seller_token_account.to_account_info(),
+						mint: self.mint.to_account_info(),
+						to: self.escrow_token_account.to_account_info(),
+						authority: self.seller.to_account_info()
+					}
+				),
+				amount, 
+				decimals, 
+			)
+		}
+	}
+
+
+/// Lists an NFT for sale at a fixed price, moving it into an escrow owned by the listing
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` seller: [AccountInfo] The owner of the NFT
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[writable]` listing: [Listing] The listing account to initialize
+/// 6. `[writable]` seller_token_account: [TokenAccount] The seller's token account holding the NFT
+/// 7. `[writable]` escrow_token_account: [TokenAccount] Escrow token account owned by the listing
+/// 8. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 9. `[]` system_program: [AccountInfo] System program
+/// 10. `[]` token_program: [AccountInfo] SPL Token program
+/// 11. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 12. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 13. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Data:
+/// - price: [u64] Sale price in lamports
+pub fn handler(
+	ctx: Context<ListNft>,
+	price: u64,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	require!(price > 0, MetaplexNftProgramError::InvalidPrice);
+	ctx.accounts.metadata.assert_transferable(Clock::get()?.unix_timestamp)?;
+	ctx.accounts.metadata.assert_not_delegated()?;
+
+	utils::thaw_if_frozen(
+		&ctx.accounts.seller_token_account,
+		&ctx.accounts.mint.to_account_info(),
+		&ctx.accounts.freeze_authority.to_account_info(),
+		ctx.bumps.freeze_authority,
+		&ctx.accounts.token_program.to_account_info(),
+	)?;
+	ctx.accounts.cpi_csl_spl_token_transfer_checked(
+		1,
+		ctx.accounts.mint.decimals,
+	)?;
+
+	let listing = &mut ctx.accounts.listing;
+	listing.seller = ctx.accounts.seller.key();
+	listing.mint = ctx.accounts.mint.key();
+	listing.collection = ctx.accounts.metadata.collection;
+	listing.price = price;
+	listing.token_account = ctx.accounts.escrow_token_account.key();
+	listing.escrowless = false;
+	listing.bump = ctx.bumps.listing;
+
+	// The escrow now holds the token, so the listing PDA is its owner until
+	// the NFT is bought or delisted.
+	ctx.accounts.metadata.owner = listing.key();
+
+	Ok(())
+}
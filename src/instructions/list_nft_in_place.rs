@@ -0,0 +1,194 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		price: u64,
+	)]
+	pub struct ListNftInPlace<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub seller: Signer<'info>,
+
+		pub mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"metadata",
+				mint.key().as_ref(),
+			],
+			bump,
+			constraint = metadata.owner == seller.key() @ MetaplexNftProgramError::NotNftOwner,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		#[account(
+			address = metadata.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			init,
+			space=146,
+			payer=seller,
+			seeds = [
+				b"listing",
+				mint.key().as_ref(),
+			],
+			bump,
+		)]
+		pub listing: Account<'info, Listing>,
+
+		#[account(
+			mut,
+			associated_token::mint = mint,
+			associated_token::authority = seller,
+			associated_token::token_program = token_program,
+		)]
+		pub seller_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			seeds = [
+				b"freeze_authority",
+			],
+			bump,
+		)]
+		/// CHECK: PDA used only as the mints' freeze authority
+		pub freeze_authority: UncheckedAccount<'info>,
+
+		#[account(
+			seeds = [
+				b"rule_set",
+				metadata.collection.as_ref(),
+			],
+			bump,
+		)]
+		/// CHECK: only read when the collection has a rule set
+		pub rule_set: UncheckedAccount<'info>,
+
+		#[account(
+			address = anchor_lang::solana_program::sysvar::instructions::ID,
+		)]
+		/// CHECK: the instructions sysvar
+		pub instructions: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+	}
+
+	impl<'info> ListNftInPlace<'info> {
+		pub fn cpi_csl_spl_token_approve(&self, amount: u64) -> Result<()> {
+			anchor_spl::token::approve(
+				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::Approve {
+						to: self.seller_token_account.to_account_info(),
+						delegate: self.listing.to_account_info(),
+						authority: self.seller.to_account_info()
+					}
+				),
+				amount, 
+			)
+		}
+		pub fn cpi_csl_spl_token_freeze_account(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::freeze_account(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::FreezeAccount {
+						account: self.seller_token_account.to_account_info(),
+						mint: self.mint.to_account_info(),
+						authority: self.freeze_authority.to_account_info()
+					},
+					signer_seeds,
+				),
+			)
+		}
+	}
+
+
+/// Lists an NFT for sale without escrow: the listing becomes delegate of the seller's token account, which is frozen until the NFT is sold or delisted
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` seller: [AccountInfo] The owner of the NFT
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT, marked as listed
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[writable]` listing: [Listing] The listing account to initialize
+/// 6. `[writable]` seller_token_account: [TokenAccount] The seller's token account holding the NFT
+/// 7. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 8. `[]` rule_set: [AccountInfo] The rule set of the NFT's collection
+/// 9. `[]` instructions: [AccountInfo] Instructions sysvar, to find the calling program
+/// 10. `[]` system_program: [AccountInfo] System program
+/// 11. `[]` token_program: [AccountInfo] SPL Token program
+/// 12. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 13. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Data:
+/// - price: [u64] Sale price in lamports
+pub fn handler(
+	ctx: Context<ListNftInPlace>,
+	price: u64,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	require!(price > 0, MetaplexNftProgramError::InvalidPrice);
+	ctx.accounts.metadata.assert_transferable(Clock::get()?.unix_timestamp)?;
+	ctx.accounts.metadata.assert_not_delegated()?;
+	utils::assert_rule_set_permits(
+		&ctx.accounts.rule_set,
+		&ctx.accounts.instructions,
+		None,
+	)?;
+
+	// Tokens of royalty-enforced collections are frozen at rest, and the
+	// token program does not approve delegates on frozen accounts.
+	utils::thaw_if_frozen(
+		&ctx.accounts.seller_token_account,
+		&ctx.accounts.mint.to_account_info(),
+		&ctx.accounts.freeze_authority.to_account_info(),
+		ctx.bumps.freeze_authority,
+		&ctx.accounts.token_program.to_account_info(),
+	)?;
+	ctx.accounts.cpi_csl_spl_token_approve(1)?;
+	ctx.accounts.cpi_csl_spl_token_freeze_account(&[&[
+		b"freeze_authority",
+		&[ctx.bumps.freeze_authority],
+	]])?;
+
+	let listing = &mut ctx.accounts.listing;
+	listing.seller = ctx.accounts.seller.key();
+	listing.mint = ctx.accounts.mint.key();
+	listing.collection = ctx.accounts.metadata.collection;
+	listing.price = price;
+	listing.token_account = ctx.accounts.seller_token_account.key();
+	listing.escrowless = true;
+	listing.bump = ctx.bumps.listing;
+
+	ctx.accounts.metadata.listed = true;
+
+	Ok(())
+}
@@ -0,0 +1,64 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct LockMetadata<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority @ MetaplexNftProgramError::InvalidAuthority,
+			seeds = [
+				b"collection",
+				collection.seed_authority.as_ref(),
+			],
+			bump,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			constraint = metadata.collection == collection.key() @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub metadata: Option<Account<'info, NftMint>>,
+	}
+
+/// Permanently locks the metadata of a single NFT, or of the whole collection when no NFT is given
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` collection: [NftCollection] The collection to lock, or that the NFT belongs to
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT to lock, omitted to lock the collection
+pub fn handler(
+	ctx: Context<LockMetadata>,
+) -> Result<()> {
+	match ctx.accounts.metadata.as_mut() {
+		Some(metadata) => {
+			require!(metadata.is_mutable, MetaplexNftProgramError::MetadataImmutable);
+			metadata.is_mutable = false;
+			// Locked NFTs pin the base URI their URIs resolve against.
+			let collection = &mut ctx.accounts.collection;
+			collection.locked_nft_count = collection.locked_nft_count
+				.checked_add(1)
+				.ok_or(MetaplexNftProgramError::ArithmeticOverflow)?;
+		}
+		None => {
+			let collection = &mut ctx.accounts.collection;
+			require!(collection.is_mutable, MetaplexNftProgramError::MetadataImmutable);
+			collection.is_mutable = false;
+		}
+	}
+
+	Ok(())
+}
@@ -0,0 +1,46 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct MakeCollectionSoulbound<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority @ MetaplexNftProgramError::InvalidAuthority,
+			seeds = [
+				b"collection",
+				collection.seed_authority.as_ref(),
+			],
+			bump,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		pub authority: Signer<'info>,
+	}
+
+/// Makes a collection soulbound: its NFTs are frozen from mint and can never be transferred, only burned by their owner. Must be done before the first mint and cannot be undone
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` collection: [NftCollection] The collection to configure
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+pub fn handler(
+	ctx: Context<MakeCollectionSoulbound>,
+) -> Result<()> {
+	// Each NFT records the flag at mint, so it cannot change once tokens exist.
+	let collection = &mut ctx.accounts.collection;
+	require!(collection.nft_count == 0, MetaplexNftProgramError::SoulboundLocked);
+
+	collection.soulbound = true;
+
+	Ok(())
+}
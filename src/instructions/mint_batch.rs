@@ -0,0 +1,324 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{spl_token::instruction::AuthorityType, Mint, Token},
+};
+
+use crate::error::MetaplexNftProgramError;
+
+
+	#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+	pub struct MintBatchEntry {
+		pub name: String,
+		pub uri: String,
+		pub recipient: Pubkey,
+		pub content_hash: Option<[u8; 32]>,
+	}
+
+	#[derive(Accounts)]
+	#[instruction(
+		entries: Vec<MintBatchEntry>,
+	)]
+	pub struct MintBatch<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority @ MetaplexNftProgramError::InvalidAuthority,
+			seeds = [
+				b"collection",
+				collection.seed_authority.as_ref(),
+			],
+			bump,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		pub authority: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"freeze_authority",
+			],
+			bump,
+		)]
+		/// CHECK: PDA used only as the mints' freeze authority
+		pub freeze_authority: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+		pub csl_spl_assoc_token_v0_0_0: Program<'info, AssociatedToken>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"treasury",
+			],
+			bump,
+		)]
+		/// CHECK: only credited once the program config exists
+		pub treasury: UncheckedAccount<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"mint_config",
+				collection.key().as_ref(),
+			],
+			bump,
+		)]
+		/// CHECK: only read when the collection has configured mint pricing
+		pub mint_config: UncheckedAccount<'info>,
+	}
+
+	impl<'info> MintBatch<'info> {
+		pub fn cpi_system_create_account(&self, to: &AccountInfo<'info>, space: usize, owner: &Pubkey, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_lang::system_program::create_account(
+				CpiContext::new_with_signer(self.system_program.to_account_info(),
+					anchor_lang::system_program::CreateAccount {
+						from: self.fee_payer.to_account_info(),
+						to: to.clone()
+					},
+					signer_seeds,
+				),
+				Rent::get()?.minimum_balance(space),
+				space as u64,
+				owner,
+			)
+		}
+		pub fn cpi_csl_spl_token_initialize_mint2(&self, mint: &AccountInfo<'info>, decimals: u8, mint_authority: Pubkey, freeze_authority: Option<Pubkey>) -> Result<()> {
+			anchor_spl::token::initialize_mint2(
+				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(),
+					anchor_spl::token::InitializeMint2 {
+						mint: mint.clone()
+					}
+				),
+				decimals,
+				&mint_authority,
+				freeze_authority.as_ref(),
+			)
+		}
+		pub fn cpi_csl_spl_assoc_token_create(&self, assoc_token_account: &AccountInfo<'info>, wallet: &AccountInfo<'info>, mint: &AccountInfo<'info>) -> Result<()> {
+			anchor_spl::associated_token::create(
+				CpiContext::new(self.csl_spl_assoc_token_v0_0_0.to_account_info(),
+					anchor_spl::associated_token::Create {
+						payer: self.fee_payer.to_account_info(),
+						associated_token: assoc_token_account.clone(),
+						authority: wallet.clone(),
+						mint: mint.clone(),
+						system_program: self.system_program.to_account_info(),
+						token_program: self.token_program.to_account_info()
+					}
+				),
+			)
+		}
+		pub fn cpi_csl_spl_token_freeze_account(&self, account: &AccountInfo<'info>, mint: &AccountInfo<'info>, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::freeze_account(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(),
+					anchor_spl::token::FreezeAccount {
+						account: account.clone(),
+						mint: mint.clone(),
+						authority: self.freeze_authority.to_account_info()
+					},
+					signer_seeds,
+				),
+			)
+		}
+		pub fn cpi_csl_spl_token_mint_to(&self, mint: &AccountInfo<'info>, to: &AccountInfo<'info>, amount: u64) -> Result<()> {
+			anchor_spl::token::mint_to(
+				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(),
+					anchor_spl::token::MintTo {
+						mint: mint.clone(),
+						to: to.clone(),
+						authority: self.authority.to_account_info()
+					}
+				),
+				amount,
+			)
+		}
+		pub fn cpi_csl_spl_token_set_authority(&self, mint: &AccountInfo<'info>, authority_type: AuthorityType, new_authority: Option<Pubkey>) -> Result<()> {
+			anchor_spl::token::set_authority(
+				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(),
+					anchor_spl::token::SetAuthority {
+						current_authority: self.authority.to_account_info(),
+						account_or_mint: mint.clone()
+					}
+				),
+				authority_type,
+				new_authority,
+			)
+		}
+
+		/// Creates the mint, metadata, item index and associated token account of a single
+		/// batch entry and mints its token to the recipient.
+		fn mint_entry(&self, entry: &MintBatchEntry, accounts: &[AccountInfo<'info>], item_number: u64, freeze_authority_bump: u8) -> Result<()> {
+			let [mint, metadata, assoc_token_account, wallet, item_index] = accounts else {
+				return err!(MetaplexNftProgramError::InvalidBatchAccounts);
+			};
+
+			require!(entry.name.len() <= MAX_NAME_LENGTH, MetaplexNftProgramError::InvalidMetadata);
+			self.collection.validate_nft_uri(&entry.uri, item_number)?;
+			require!(mint.is_signer && mint.is_writable, MetaplexNftProgramError::InvalidBatchAccounts);
+			require!(
+				metadata.is_writable && assoc_token_account.is_writable && item_index.is_writable,
+				MetaplexNftProgramError::InvalidBatchAccounts
+			);
+			require_keys_eq!(wallet.key(), entry.recipient, MetaplexNftProgramError::InvalidBatchAccounts);
+
+			let (metadata_key, metadata_bump) = Pubkey::find_program_address(
+				&[b"metadata", mint.key.as_ref()],
+				&crate::ID,
+			);
+			require_keys_eq!(metadata.key(), metadata_key, MetaplexNftProgramError::InvalidBatchAccounts);
+
+			let collection_key = self.collection.key();
+			let item_number_bytes = item_number.to_le_bytes();
+			let (item_index_key, item_index_bump) = Pubkey::find_program_address(
+				&[b"item", collection_key.as_ref(), &item_number_bytes],
+				&crate::ID,
+			);
+			require_keys_eq!(item_index.key(), item_index_key, MetaplexNftProgramError::InvalidBatchAccounts);
+
+			self.cpi_system_create_account(mint, Mint::LEN, &self.token_program.key(), &[])?;
+			self.cpi_csl_spl_token_initialize_mint2(mint, 0, self.authority.key(), Some(self.freeze_authority.key()))?;
+
+			utils::create_pda_account(
+				&self.fee_payer.to_account_info(),
+				metadata,
+				&self.system_program.to_account_info(),
+				NftMint::space(&self.collection),
+				&[&[b"metadata", mint.key.as_ref(), &[metadata_bump]]],
+			)?;
+			let nft = NftMint {
+				mint: mint.key(),
+				owner: entry.recipient,
+				name: entry.name.clone(),
+				symbol: self.collection.nft_symbol(),
+				uri: entry.uri.clone(),
+				collection: self.collection.key(),
+				user: Pubkey::default(),
+				expires_at: 0,
+				staked: false,
+				soulbound: self.collection.soulbound,
+				delegate: Pubkey::default(),
+				frozen: false,
+				is_mutable: true,
+				content_hash: entry.content_hash,
+				revision: 0,
+				item_number,
+				revealed: self.collection.reveal_root.is_none(),
+				listed: false,
+			};
+			nft.try_serialize(&mut &mut metadata.try_borrow_mut_data()?[..])?;
+
+			utils::create_pda_account(
+				&self.fee_payer.to_account_info(),
+				item_index,
+				&self.system_program.to_account_info(),
+				81,
+				&[&[b"item", collection_key.as_ref(), &item_number_bytes, &[item_index_bump]]],
+			)?;
+			let index = ItemIndex {
+				collection: collection_key,
+				item_number,
+				mint: mint.key(),
+				bump: item_index_bump,
+			};
+			index.try_serialize(&mut &mut item_index.try_borrow_mut_data()?[..])?;
+
+			self.cpi_csl_spl_assoc_token_create(assoc_token_account, wallet, mint)?;
+			self.cpi_csl_spl_token_mint_to(mint, assoc_token_account, 1)?;
+			// No further tokens can ever be minted for the NFT.
+			self.cpi_csl_spl_token_set_authority(mint, AuthorityType::MintTokens, None)?;
+			if self.collection.keeps_tokens_frozen() {
+				self.cpi_csl_spl_token_freeze_account(
+					assoc_token_account,
+					mint,
+					&[&[b"freeze_authority", &[freeze_authority_bump]]],
+				)?;
+			}
+			Ok(())
+		}
+	}
+
+
+/// Mints several NFTs to a collection in a single instruction
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` collection: [NftCollection] The collection to mint the NFTs to
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+/// 3. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 5. `[]` token_program: [AccountInfo] SPL Token program
+/// 6. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 7. `[]` csl_spl_assoc_token_v0_0_0: [AccountInfo] Auto-generated, CslSplAssocTokenProgram v0.0.0
+/// 8. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+/// 9. `[writable]` treasury: [AccountInfo] The fee treasury, credited with the protocol fee once the program config exists
+/// 10. `[writable]` mint_config: [MintConfig] The collection's mint pricing, whose supply cap the batch counts against; may be uninitialized for free mints
+///
+/// Data:
+/// - entries: [Vec<MintBatchEntry>] One entry per NFT; each consumes mint, metadata, assoc_token_account, wallet and item_index from the remaining accounts, in that order
+pub fn handler<'info>(
+	ctx: Context<'_, '_, 'info, 'info, MintBatch<'info>>,
+	entries: Vec<MintBatchEntry>,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	require!(!entries.is_empty(), MetaplexNftProgramError::EmptyBatch);
+	require!(entries.len() <= MAX_BATCH_SIZE, MetaplexNftProgramError::BatchTooLarge);
+	require!(
+		ctx.remaining_accounts.len() == entries.len() * MINT_BATCH_ACCOUNTS_PER_ENTRY,
+		MetaplexNftProgramError::InvalidBatchAccounts
+	);
+
+	if let Some(mut mint_config) = MintConfig::load(&ctx.accounts.mint_config)? {
+		mint_config.record_mints(entries.len() as u64)?;
+		mint_config.store(&ctx.accounts.mint_config)?;
+	}
+
+	if let Some(program_config) = ProgramConfig::load(&ctx.accounts.program_config)? {
+		let fee = program_config.mint_fee(0)?
+			.checked_mul(entries.len() as u64)
+			.ok_or(MetaplexNftProgramError::ArithmeticOverflow)?;
+		if fee > 0 {
+			utils::transfer_lamports(
+				&ctx.accounts.fee_payer.to_account_info(),
+				&ctx.accounts.treasury.to_account_info(),
+				&ctx.accounts.system_program.to_account_info(),
+				fee,
+			)?;
+		}
+	}
+
+	let groups = ctx.remaining_accounts.chunks(MINT_BATCH_ACCOUNTS_PER_ENTRY);
+	for (index, (entry, accounts)) in entries.iter().zip(groups).enumerate() {
+		let item_number = ctx.accounts.collection.nft_count + index as u64;
+		ctx.accounts.mint_entry(entry, accounts, item_number, ctx.bumps.freeze_authority).inspect_err(|_| {
+			msg!("mint_batch: entry {} ({}) failed", index, entry.name);
+		})?;
+	}
+
+	let collection = &mut ctx.accounts.collection;
+	collection.nft_count = collection.nft_count
+		.checked_add(entries.len() as u64)
+		.ok_or(MetaplexNftProgramError::ArithmeticOverflow)?;
+
+	Ok(())
+}
@@ -0,0 +1,322 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{spl_token::instruction::AuthorityType, Mint, Token, TokenAccount},
+};
+
+use crate::error::MetaplexNftProgramError;
+use crate::utils::transfer_lamports;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		symbol: String,
+		uri: String,
+		content_hash: Option<[u8; 32]>,
+	)]
+	pub struct MintNft<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority @ MetaplexNftProgramError::InvalidAuthority,
+			seeds = [
+				b"collection",
+				collection.seed_authority.as_ref(),
+			],
+			bump,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			init,
+			payer = fee_payer,
+			mint::decimals = 0,
+			mint::authority = owner,
+			mint::freeze_authority = freeze_authority,
+		)]
+		pub mint: Account<'info, Mint>,
+
+		#[account(
+			init,
+			space=NftMint::space(&collection),
+			payer=fee_payer,
+			seeds = [
+				b"metadata",
+				mint.key().as_ref(),
+			],
+			bump,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		pub authority: Signer<'info>,
+
+		pub owner: Signer<'info>,
+
+		pub system_program: Program<'info, System>,
+
+		#[account(
+			mut,
+			owner=Pubkey::from_str("11111111111111111111111111111111").unwrap(),
+		)]
+		pub funding: Signer<'info>,
+
+		#[account(
+			init,
+			payer = funding,
+			associated_token::mint = mint,
+			associated_token::authority = wallet,
+			associated_token::token_program = token_program,
+		)]
+		pub assoc_token_account: Account<'info, TokenAccount>,
+
+		/// CHECK: implement manual checks if needed
+		pub wallet: UncheckedAccount<'info>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+		pub associated_token_program: Program<'info, AssociatedToken>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"mint_config",
+				collection.key().as_ref(),
+			],
+			bump,
+		)]
+		/// CHECK: only read when the collection has configured mint pricing
+		pub mint_config: UncheckedAccount<'info>,
+
+		#[account(
+			init,
+			space=112,
+			payer=funding,
+			seeds = [
+				b"mint_receipt",
+				mint.key().as_ref(),
+			],
+			bump,
+		)]
+		pub mint_receipt: Option<Account<'info, MintReceipt>>,
+
+		#[account(
+			seeds = [
+				b"freeze_authority",
+			],
+			bump,
+		)]
+		/// CHECK: PDA used only as the mints' freeze authority
+		pub freeze_authority: UncheckedAccount<'info>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"treasury",
+			],
+			bump,
+		)]
+		/// CHECK: only credited once the program config exists
+		pub treasury: UncheckedAccount<'info>,
+
+		#[account(
+			init,
+			space=81,
+			payer=fee_payer,
+			seeds = [
+				b"item",
+				collection.key().as_ref(),
+				collection.nft_count.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub item_index: Account<'info, ItemIndex>,
+	}
+
+	impl<'info> MintNft<'info> {
+		pub fn cpi_csl_spl_token_freeze_account(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::freeze_account(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::FreezeAccount {
+						account: self.assoc_token_account.to_account_info(),
+						mint: self.mint.to_account_info(),
+						authority: self.freeze_authority.to_account_info()
+					},
+					signer_seeds,
+				),
+			)
+		}
+		pub fn cpi_csl_spl_token_mint_to(&self, amount: u64) -> Result<()> {
+			anchor_spl::token::mint_to(
+				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::MintTo {
+						mint: self.mint.to_account_info(),
+						to: self.assoc_token_account.to_account_info(),
+						authority: self.owner.to_account_info()
+					}
+				),
+				amount, 
+			)
+		}
+		pub fn cpi_csl_spl_token_set_authority(&self, authority_type: AuthorityType, new_authority: Option<Pubkey>) -> Result<()> {
+			anchor_spl::token::set_authority(
+				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::SetAuthority {
+						current_authority: self.owner.to_account_info(),
+						account_or_mint: self.mint.to_account_info()
+					}
+				),
+				authority_type, 
+				new_authority, 
+			)
+		}
+	}
+
+
+/// Mints a new NFT to a collection
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` collection: [NftCollection] The collection to mint the NFT to
+/// 2. `[writable, signer]` mint: [Mint] The mint account for the new NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account for the new NFT
+/// 4. `[signer]` authority: [AccountInfo] The authority of the collection
+/// 5. `[signer]` owner: [AccountInfo] The owner of the new NFT
+/// 6. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 7. `[writable, signer]` funding: [AccountInfo] Funding account (must be a system account)
+/// 8. `[writable]` assoc_token_account: [AccountInfo] Associated token account address to be created
+/// 9. `[]` wallet: [AccountInfo] Wallet address for the new associated token account
+/// 10. `[]` token_program: [AccountInfo] SPL Token program
+/// 11. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 12. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 13. `[writable]` mint_config: [MintConfig] The collection's mint pricing; may be uninitialized for free mints
+/// 14. `[writable]` mint_receipt: [MintReceipt] (optional) Records the price paid; required when the collection has mint pricing
+/// 15. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 16. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+/// 17. `[writable]` treasury: [AccountInfo] The fee treasury, credited with the protocol fee once the program config exists
+/// 18. `[writable]` item_index: [ItemIndex] The collection's item index entry for the new NFT, at the collection's current nft_count
+///
+/// Data:
+/// - name: [String] Name of the NFT
+/// - symbol: [String] Symbol of the NFT
+/// - uri: [String] URI to the NFT metadata, or its suffix when the collection has a base URI
+/// - content_hash: [Option<[u8; 32]>] SHA-256 hash of the NFT metadata JSON, if committed
+pub fn handler(
+	ctx: Context<MintNft>,
+	name: String,
+	symbol: String,
+	uri: String,
+	content_hash: Option<[u8; 32]>,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	require!(
+		name.len() <= MAX_NAME_LENGTH && symbol.len() <= MAX_SYMBOL_LENGTH,
+		MetaplexNftProgramError::InvalidMetadata
+	);
+	require!(
+		ctx.accounts.collection.base_uri.is_empty() || symbol.is_empty() || symbol == ctx.accounts.collection.symbol,
+		MetaplexNftProgramError::InvalidMetadata
+	);
+	ctx.accounts.collection.validate_nft_uri(&uri, ctx.accounts.collection.nft_count)?;
+
+	let mut price = 0;
+	if let Some(mut mint_config) = MintConfig::load(&ctx.accounts.mint_config)? {
+		mint_config.record_paid_mint()?;
+		price = mint_config.price_at(Clock::get()?.unix_timestamp)?;
+
+		transfer_lamports(
+			&ctx.accounts.funding.to_account_info(),
+			&ctx.accounts.mint_config.to_account_info(),
+			&ctx.accounts.system_program.to_account_info(),
+			price,
+		)?;
+
+		let receipt = ctx.accounts.mint_receipt.as_mut()
+			.ok_or(MetaplexNftProgramError::MissingMintReceipt)?;
+		receipt.minter = ctx.accounts.funding.key();
+		receipt.mint_config = ctx.accounts.mint_config.key();
+		receipt.mint = ctx.accounts.mint.key();
+		receipt.price = price;
+
+		mint_config.last_price = price;
+		mint_config.store(&ctx.accounts.mint_config)?;
+	}
+
+	if let Some(program_config) = ProgramConfig::load(&ctx.accounts.program_config)? {
+		let fee = program_config.mint_fee(price)?;
+		if fee > 0 {
+			transfer_lamports(
+				&ctx.accounts.funding.to_account_info(),
+				&ctx.accounts.treasury.to_account_info(),
+				&ctx.accounts.system_program.to_account_info(),
+				fee,
+			)?;
+		}
+	}
+
+	ctx.accounts.cpi_csl_spl_token_mint_to(
+		1,
+	)?;
+	// No further tokens can ever be minted for the NFT.
+	ctx.accounts.cpi_csl_spl_token_set_authority(
+		AuthorityType::MintTokens,
+		None,
+	)?;
+	// Tokens of royalty-enforced collections only move through this program,
+	// and those of soulbound collections never move at all.
+	if ctx.accounts.collection.keeps_tokens_frozen() {
+		ctx.accounts.cpi_csl_spl_token_freeze_account(&[&[
+			b"freeze_authority",
+			&[ctx.bumps.freeze_authority],
+		]])?;
+	}
+
+	let metadata = &mut ctx.accounts.metadata;
+	metadata.mint = ctx.accounts.mint.key();
+	metadata.owner = ctx.accounts.wallet.key();
+	metadata.name = name;
+	metadata.symbol = if ctx.accounts.collection.base_uri.is_empty() {
+		symbol
+	} else {
+		String::new()
+	};
+	metadata.uri = uri;
+	metadata.content_hash = content_hash;
+	metadata.collection = ctx.accounts.collection.key();
+	metadata.soulbound = ctx.accounts.collection.soulbound;
+	metadata.is_mutable = true;
+	metadata.item_number = ctx.accounts.collection.nft_count;
+	metadata.revealed = ctx.accounts.collection.reveal_root.is_none();
+
+	let item_index = &mut ctx.accounts.item_index;
+	item_index.collection = ctx.accounts.collection.key();
+	item_index.item_number = ctx.accounts.collection.nft_count;
+	item_index.mint = ctx.accounts.mint.key();
+	item_index.bump = ctx.bumps.item_index;
+
+	let collection = &mut ctx.accounts.collection;
+	collection.nft_count = collection.nft_count
+		.checked_add(1)
+		.ok_or(MetaplexNftProgramError::ArithmeticOverflow)?;
+
+	Ok(())
+}
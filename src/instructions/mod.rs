@@ -0,0 +1,127 @@
+// Every instruction module exports its own `handler`; lib.rs calls each one
+// through its module path, so the glob re-exports never resolve to them.
+#![allow(ambiguous_glob_reexports)]
+
+pub mod initialize_collection;
+pub mod mint_nft;
+pub mod update_nft_metadata;
+pub mod transfer_nft;
+pub mod mint_batch;
+pub mod set_collection_royalties;
+pub mod list_nft;
+pub mod delist_nft;
+pub mod buy_nft;
+pub mod list_nft_in_place;
+pub mod delist_nft_in_place;
+pub mod place_bid;
+pub mod cancel_bid;
+pub mod accept_bid;
+pub mod create_auction;
+pub mod place_auction_bid;
+pub mod settle_auction;
+pub mod set_mint_config;
+pub mod claim_mint_rebate;
+pub mod withdraw_mint_proceeds;
+pub mod create_rental_offer;
+pub mod rent_nft;
+pub mod end_rental;
+pub mod close_rental_offer;
+pub mod create_stake_pool;
+pub mod stake_nft;
+pub mod claim_stake_rewards;
+pub mod unstake_nft;
+pub mod fractionalize_nft;
+pub mod buyout_vault;
+pub mod redeem_shares;
+pub mod create_loan_offer;
+pub mod cancel_loan_offer;
+pub mod take_loan;
+pub mod repay_loan;
+pub mod foreclose_loan;
+pub mod enable_royalty_enforcement;
+pub mod set_rule_set;
+pub mod make_collection_soulbound;
+pub mod burn_nft;
+pub mod approve_delegate;
+pub mod revoke_delegate;
+pub mod freeze_nft;
+pub mod thaw_nft;
+pub mod initialize_program_config;
+pub mod set_program_admin;
+pub mod set_program_paused;
+pub mod set_collection_paused;
+pub mod set_protocol_fee;
+pub mod withdraw_fees;
+pub mod create_multisig;
+pub mod set_multisig_signers;
+pub mod create_proposal;
+pub mod approve_proposal;
+pub mod execute_proposal;
+pub mod lock_metadata;
+pub mod set_uri_rules;
+pub mod reveal;
+pub mod set_base_uri;
+pub mod withdraw_bid;
+pub mod set_collection_authority;
+
+pub use initialize_collection::*;
+pub use mint_nft::*;
+pub use update_nft_metadata::*;
+pub use transfer_nft::*;
+pub use mint_batch::*;
+pub use set_collection_royalties::*;
+pub use list_nft::*;
+pub use delist_nft::*;
+pub use buy_nft::*;
+pub use list_nft_in_place::*;
+pub use delist_nft_in_place::*;
+pub use place_bid::*;
+pub use cancel_bid::*;
+pub use accept_bid::*;
+pub use create_auction::*;
+pub use place_auction_bid::*;
+pub use settle_auction::*;
+pub use set_mint_config::*;
+pub use claim_mint_rebate::*;
+pub use withdraw_mint_proceeds::*;
+pub use create_rental_offer::*;
+pub use rent_nft::*;
+pub use end_rental::*;
+pub use close_rental_offer::*;
+pub use create_stake_pool::*;
+pub use stake_nft::*;
+pub use claim_stake_rewards::*;
+pub use unstake_nft::*;
+pub use fractionalize_nft::*;
+pub use buyout_vault::*;
+pub use redeem_shares::*;
+pub use create_loan_offer::*;
+pub use cancel_loan_offer::*;
+pub use take_loan::*;
+pub use repay_loan::*;
+pub use foreclose_loan::*;
+pub use enable_royalty_enforcement::*;
+pub use set_rule_set::*;
+pub use make_collection_soulbound::*;
+pub use burn_nft::*;
+pub use approve_delegate::*;
+pub use revoke_delegate::*;
+pub use freeze_nft::*;
+pub use thaw_nft::*;
+pub use initialize_program_config::*;
+pub use set_program_admin::*;
+pub use set_program_paused::*;
+pub use set_collection_paused::*;
+pub use set_protocol_fee::*;
+pub use withdraw_fees::*;
+pub use create_multisig::*;
+pub use set_multisig_signers::*;
+pub use create_proposal::*;
+pub use approve_proposal::*;
+pub use execute_proposal::*;
+pub use lock_metadata::*;
+pub use set_uri_rules::*;
+pub use reveal::*;
+pub use set_base_uri::*;
+pub use withdraw_bid::*;
+pub use set_collection_authority::*;
\ No newline at end of file
@@ -0,0 +1,138 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+use crate::utils::{transfer_lamports, transfer_lamports_from_program_account};
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		amount: u64,
+	)]
+	pub struct PlaceAuctionBid<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub bidder: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"auction",
+				auction.mint.as_ref(),
+			],
+			bump = auction.bump,
+		)]
+		pub auction: Account<'info, Auction>,
+
+		#[account(
+			address = auction.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			init_if_needed,
+			space=113,
+			payer=bidder,
+			seeds = [
+				b"bid_refund",
+				auction.key().as_ref(),
+				auction.highest_bidder.as_ref(),
+			],
+			bump,
+		)]
+		pub previous_bid_refund: Option<Account<'info, BidRefund>>,
+
+		pub system_program: Program<'info, System>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+	}
+
+/// Bids on an auction, escrowing the bid and crediting the outbid amount to the previous highest bidder's refund account
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` bidder: [AccountInfo] The account placing the bid
+/// 2. `[writable]` auction: [Auction] The auction to bid on
+/// 3. `[]` collection: [NftCollection] The collection of the auctioned NFT
+/// 4. `[writable]` previous_bid_refund: [BidRefund] Refund account of the current highest bidder, credited with the outbid amount and created if needed; required once the auction has a bid
+/// 5. `[]` system_program: [AccountInfo] System program
+/// 6. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Data:
+/// - amount: [u64] Bid in lamports
+pub fn handler(
+	ctx: Context<PlaceAuctionBid>,
+	amount: u64,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	let now = Clock::get()?.unix_timestamp;
+	let auction = &ctx.accounts.auction;
+	require!(now >= auction.start_time, MetaplexNftProgramError::AuctionNotStarted);
+	require!(now < auction.end_time, MetaplexNftProgramError::AuctionEnded);
+
+	let minimum = if auction.highest_bid == 0 {
+		auction.reserve_price
+	} else {
+		auction.highest_bid
+			.checked_add(auction.min_increment)
+			.ok_or(MetaplexNftProgramError::ArithmeticOverflow)?
+	};
+	require!(amount >= minimum, MetaplexNftProgramError::BidTooLow);
+
+	transfer_lamports(
+		&ctx.accounts.bidder.to_account_info(),
+		&auction.to_account_info(),
+		&ctx.accounts.system_program.to_account_info(),
+		amount,
+	)?;
+
+	// The outbid amount is credited to the previous bidder's refund account
+	// rather than sent to them, so a bidder that cannot receive lamports
+	// cannot block new bids.
+	if auction.highest_bid > 0 {
+		let (Some(refund), Some(bump)) = (ctx.accounts.previous_bid_refund.as_mut(), ctx.bumps.previous_bid_refund) else {
+			return err!(MetaplexNftProgramError::InvalidPreviousBidder);
+		};
+		transfer_lamports_from_program_account(
+			&auction.to_account_info(),
+			&refund.to_account_info(),
+			auction.highest_bid,
+		)?;
+		if refund.payer == Pubkey::default() {
+			refund.payer = ctx.accounts.bidder.key();
+		}
+		refund.auction = auction.key();
+		refund.bidder = auction.highest_bidder;
+		refund.amount = refund.amount
+			.checked_add(auction.highest_bid)
+			.ok_or(MetaplexNftProgramError::ArithmeticOverflow)?;
+		refund.bump = bump;
+	}
+
+	let auction = &mut ctx.accounts.auction;
+	auction.highest_bidder = ctx.accounts.bidder.key();
+	auction.highest_bid = amount;
+
+	// Anti-sniping: a bid inside the extension window pushes the end back so
+	// that other bidders always get a full window to respond.
+	if auction.end_time - now < auction.extension_window {
+		auction.end_time = now + auction.extension_window;
+	}
+
+	Ok(())
+}
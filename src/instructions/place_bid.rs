@@ -0,0 +1,97 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+use crate::utils::transfer_lamports;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		mint: Option<Pubkey>,
+		amount: u64,
+		expires_at: i64,
+	)]
+	pub struct PlaceBid<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub bidder: Signer<'info>,
+
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			init,
+			space=122,
+			payer=bidder,
+			seeds = [
+				b"bid",
+				bidder.key().as_ref(),
+				mint.unwrap_or(collection.key()).as_ref(),
+			],
+			bump,
+		)]
+		pub bid: Account<'info, Bid>,
+
+		pub system_program: Program<'info, System>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+	}
+
+/// Places an offer on a specific NFT, or on any NFT of a collection, escrowing the offered lamports in the bid account
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` bidder: [AccountInfo] The account making the offer
+/// 2. `[]` collection: [NftCollection] The collection the offer targets
+/// 3. `[writable]` bid: [Bid] The bid account to initialize
+/// 4. `[]` system_program: [AccountInfo] System program
+/// 5. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Data:
+/// - mint: [Option<Pubkey>] The NFT the offer is for, or none for any NFT of the collection
+/// - amount: [u64] Offered amount in lamports
+/// - expires_at: [i64] Unix timestamp after which the offer can no longer be accepted
+pub fn handler(
+	ctx: Context<PlaceBid>,
+	mint: Option<Pubkey>,
+	amount: u64,
+	expires_at: i64,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	require!(amount > 0, MetaplexNftProgramError::InvalidPrice);
+	require!(
+		expires_at > Clock::get()?.unix_timestamp,
+		MetaplexNftProgramError::InvalidExpiration
+	);
+
+	transfer_lamports(
+		&ctx.accounts.bidder.to_account_info(),
+		&ctx.accounts.bid.to_account_info(),
+		&ctx.accounts.system_program.to_account_info(),
+		amount,
+	)?;
+
+	let bid = &mut ctx.accounts.bid;
+	bid.bidder = ctx.accounts.bidder.key();
+	bid.collection = ctx.accounts.collection.key();
+	bid.mint = mint;
+	bid.amount = amount;
+	bid.expires_at = expires_at;
+	bid.bump = ctx.bumps.bid;
+
+	Ok(())
+}
@@ -0,0 +1,104 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::error::MetaplexNftProgramError;
+use crate::utils::transfer_lamports_from_program_account;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct RedeemShares<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub holder: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = share_mint,
+			seeds = [
+				b"vault",
+				vault.mint.as_ref(),
+			],
+			bump = vault.bump,
+		)]
+		pub vault: Account<'info, Vault>,
+
+		#[account(
+			mut,
+		)]
+		pub share_mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			associated_token::mint = share_mint,
+			associated_token::authority = holder,
+			associated_token::token_program = token_program,
+		)]
+		pub holder_share_account: Account<'info, TokenAccount>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+	}
+
+	impl<'info> RedeemShares<'info> {
+		pub fn cpi_csl_spl_token_burn(&self, amount: u64) -> Result<()> {
+			anchor_spl::token::burn(
+				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::Burn {
+						mint: self.share_mint.to_account_info(),
+						from: self.holder_share_account.to_account_info(),
+						authority: self.holder.to_account_info()
+					}
+				),
+				amount, 
+			)
+		}
+	}
+
+
+/// Burns a holder's shares of a bought-out vault and pays out their part of the buyout proceeds
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` holder: [AccountInfo] The share holder
+/// 2. `[writable]` vault: [Vault] The bought-out vault
+/// 3. `[writable]` share_mint: [Mint] The vault's share mint
+/// 4. `[writable]` holder_share_account: [TokenAccount] The holder's share token account
+/// 5. `[]` token_program: [AccountInfo] SPL Token program
+/// 6. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+pub fn handler(
+	ctx: Context<RedeemShares>,
+) -> Result<()> {
+	let vault = &ctx.accounts.vault;
+	require!(vault.bought_out, MetaplexNftProgramError::VaultNotBoughtOut);
+
+	let shares = ctx.accounts.holder_share_account.amount;
+	require!(shares > 0, MetaplexNftProgramError::NotShareHolder);
+
+	// Paying out of the remaining pool keeps the last holder from being short
+	// changed by rounding.
+	let amount = (vault.proceeds as u128 * shares as u128 / vault.outstanding_shares as u128) as u64;
+
+	ctx.accounts.cpi_csl_spl_token_burn(shares)?;
+	transfer_lamports_from_program_account(
+		&ctx.accounts.vault.to_account_info(),
+		&ctx.accounts.holder.to_account_info(),
+		amount,
+	)?;
+
+	let vault = &mut ctx.accounts.vault;
+	vault.proceeds -= amount;
+	vault.outstanding_shares -= shares;
+
+	Ok(())
+}
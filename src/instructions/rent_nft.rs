@@ -0,0 +1,121 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+use crate::utils::transfer_lamports;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		days: u32,
+	)]
+	pub struct RentNft<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub renter: Signer<'info>,
+
+		#[account(
+			mut,
+			address = rental_offer.owner,
+		)]
+		/// CHECK: matched against the rental offer
+		pub owner: UncheckedAccount<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"metadata",
+				rental_offer.mint.as_ref(),
+			],
+			bump,
+			constraint = metadata.owner == rental_offer.owner @ MetaplexNftProgramError::NotNftOwner,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		#[account(
+			address = metadata.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			seeds = [
+				b"rental",
+				rental_offer.mint.as_ref(),
+				rental_offer.owner.as_ref(),
+			],
+			bump = rental_offer.bump,
+		)]
+		pub rental_offer: Account<'info, RentalOffer>,
+
+		pub system_program: Program<'info, System>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+	}
+
+/// Rents an NFT, paying the owner and becoming its user until the rental expires
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` renter: [AccountInfo] The account renting the NFT
+/// 2. `[writable]` owner: [AccountInfo] The owner of the NFT, who receives the rent
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[]` rental_offer: [RentalOffer] The rental offer to take
+/// 6. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 7. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Data:
+/// - days: [u32] Rental duration in days
+/// - expected_price_per_day: [u64] Daily price the renter agreed to; the rental fails if the offer differs
+/// - expected_max_days: [u32] Maximum duration the renter saw on the offer; the rental fails if the offer differs
+pub fn handler(
+	ctx: Context<RentNft>,
+	days: u32,
+	expected_price_per_day: u64,
+	expected_max_days: u32,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	let now = Clock::get()?.unix_timestamp;
+	let rental_offer = &ctx.accounts.rental_offer;
+	require!(
+		rental_offer.price_per_day == expected_price_per_day
+			&& rental_offer.max_days == expected_max_days,
+		MetaplexNftProgramError::RentalTermsChanged
+	);
+	require!(
+		days > 0 && days <= rental_offer.max_days,
+		MetaplexNftProgramError::InvalidRentalDuration
+	);
+	require!(!ctx.accounts.metadata.is_rented(now), MetaplexNftProgramError::RentalActive);
+
+	let price = rental_offer.price_per_day
+		.checked_mul(days as u64)
+		.ok_or(MetaplexNftProgramError::ArithmeticOverflow)?;
+	transfer_lamports(
+		&ctx.accounts.renter.to_account_info(),
+		&ctx.accounts.owner.to_account_info(),
+		&ctx.accounts.system_program.to_account_info(),
+		price,
+	)?;
+
+	let metadata = &mut ctx.accounts.metadata;
+	metadata.user = ctx.accounts.renter.key();
+	metadata.expires_at = now + days as i64 * SECONDS_PER_DAY;
+
+	Ok(())
+}
@@ -0,0 +1,185 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::error::MetaplexNftProgramError;
+use crate::utils::transfer_lamports;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct RepayLoan<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub borrower: Signer<'info>,
+
+		#[account(
+			mut,
+			address = loan.lender,
+		)]
+		/// CHECK: matched against the loan
+		pub lender: UncheckedAccount<'info>,
+
+		pub mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"metadata",
+				mint.key().as_ref(),
+			],
+			bump,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		#[account(
+			address = metadata.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			mut,
+			close = lender,
+			has_one = borrower,
+			has_one = mint,
+			seeds = [
+				b"loan",
+				loan.lender.as_ref(),
+				loan.offer_id.to_le_bytes().as_ref(),
+			],
+			bump = loan.bump,
+		)]
+		pub loan: Account<'info, Loan>,
+
+		#[account(
+			mut,
+			associated_token::mint = mint,
+			associated_token::authority = loan,
+			associated_token::token_program = token_program,
+		)]
+		pub escrow_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			mut,
+			associated_token::mint = mint,
+			associated_token::authority = borrower,
+			associated_token::token_program = token_program,
+		)]
+		pub borrower_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			seeds = [
+				b"freeze_authority",
+			],
+			bump,
+		)]
+		/// CHECK: PDA used only as the mints' freeze authority
+		pub freeze_authority: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+	}
+
+	impl<'info> RepayLoan<'info> {
+		pub fn cpi_csl_spl_token_transfer_checked(&self, amount: u64, decimals: u8, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::transfer_checked(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::TransferChecked {
+						from: self.escrow_token_account.to_account_info(),
+						mint: self.mint.to_account_info(),
+						to: self.borrower_token_account.to_account_info(),
+						authority: self.loan.to_account_info()
+					},
+					signer_seeds,
+				),
+				amount, 
+				decimals, 
+			)
+		}
+		pub fn cpi_csl_spl_token_close_account(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::close_account(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::CloseAccount {
+						account: self.escrow_token_account.to_account_info(),
+						destination: self.borrower.to_account_info(),
+						authority: self.loan.to_account_info()
+					},
+					signer_seeds,
+				),
+			)
+		}
+	}
+
+
+/// Repays a loan with interest before its deadline and returns the NFT to the borrower
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` borrower: [AccountInfo] The borrower of the loan
+/// 2. `[writable]` lender: [AccountInfo] The lender, who receives the repayment
+/// 3. `[]` mint: [Mint] The mint account of the NFT
+/// 4. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 5. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 6. `[writable]` loan: [Loan] The loan to repay
+/// 7. `[writable]` escrow_token_account: [TokenAccount] Escrow token account owned by the loan
+/// 8. `[writable]` borrower_token_account: [TokenAccount] The borrower's token account receiving the NFT
+/// 9. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 10. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 11. `[]` token_program: [AccountInfo] SPL Token program
+/// 12. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+pub fn handler(
+	ctx: Context<RepayLoan>,
+) -> Result<()> {
+	let loan = &ctx.accounts.loan;
+	require!(
+		Clock::get()?.unix_timestamp <= loan.deadline(),
+		MetaplexNftProgramError::LoanExpired
+	);
+
+	let repayment = loan.amount
+		.checked_add(loan.interest)
+		.ok_or(MetaplexNftProgramError::ArithmeticOverflow)?;
+	transfer_lamports(
+		&ctx.accounts.borrower.to_account_info(),
+		&ctx.accounts.lender.to_account_info(),
+		&ctx.accounts.system_program.to_account_info(),
+		repayment,
+	)?;
+
+	let offer_id = loan.offer_id.to_le_bytes();
+	let signer_seeds: &[&[&[u8]]] = &[&[
+		b"loan",
+		loan.lender.as_ref(),
+		offer_id.as_ref(),
+		&[loan.bump],
+	]];
+	ctx.accounts.cpi_csl_spl_token_transfer_checked(
+		1,
+		ctx.accounts.mint.decimals,
+		signer_seeds,
+	)?;
+	ctx.accounts.cpi_csl_spl_token_close_account(signer_seeds)?;
+	utils::freeze_if_enforced(
+		&ctx.accounts.collection,
+		&ctx.accounts.borrower_token_account,
+		&ctx.accounts.mint.to_account_info(),
+		&ctx.accounts.freeze_authority.to_account_info(),
+		ctx.bumps.freeze_authority,
+		&ctx.accounts.token_program.to_account_info(),
+	)?;
+
+	ctx.accounts.metadata.owner = ctx.accounts.borrower.key();
+
+	Ok(())
+}
@@ -0,0 +1,97 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+
+
+	#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+	pub struct RevealEntry {
+		pub name: String,
+		pub uri: String,
+		/// SHA-256 hash of the NFT metadata JSON, if committed.
+		pub content_hash: Option<[u8; 32]>,
+		/// Sibling hashes from the NFT's leaf up to the collection's reveal root.
+		pub proof: Vec<[u8; 32]>,
+	}
+
+	#[derive(Accounts)]
+	#[instruction(
+		entries: Vec<RevealEntry>,
+	)]
+	pub struct Reveal<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			has_one = authority @ MetaplexNftProgramError::InvalidAuthority,
+			seeds = [
+				b"collection",
+				collection.seed_authority.as_ref(),
+			],
+			bump,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		pub authority: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+	}
+
+/// Replaces the placeholder metadata of minted, unfrozen and mutable NFTs with their committed final name, uri and content hash
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` collection: [NftCollection] The collection holding the reveal commitment
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+/// 3. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Remaining accounts:
+/// - `[writable]` metadata: one NFT metadata account per entry, in the same order
+///
+/// Data:
+/// - entries: [Vec<RevealEntry>] The revealed metadata and Merkle proof of each NFT; can be split across several transactions
+pub fn handler<'info>(
+	ctx: Context<'_, '_, 'info, 'info, Reveal<'info>>,
+	entries: Vec<RevealEntry>,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	require!(!entries.is_empty(), MetaplexNftProgramError::EmptyBatch);
+	require!(
+		ctx.remaining_accounts.len() == entries.len(),
+		MetaplexNftProgramError::InvalidRevealAccounts
+	);
+
+	let collection = &ctx.accounts.collection;
+	for (entry, info) in entries.iter().zip(ctx.remaining_accounts.iter()) {
+		require!(info.is_writable, MetaplexNftProgramError::InvalidRevealAccounts);
+		let mut metadata = Account::<NftMint>::try_from(info)?;
+		require_keys_eq!(metadata.collection, collection.key(), MetaplexNftProgramError::CollectionNotFound);
+		require!(!metadata.revealed, MetaplexNftProgramError::AlreadyRevealed);
+		require!(!metadata.frozen, MetaplexNftProgramError::NftFrozen);
+		require!(
+			collection.is_mutable && metadata.is_mutable,
+			MetaplexNftProgramError::MetadataImmutable
+		);
+
+		require!(entry.name.len() <= MAX_NAME_LENGTH, MetaplexNftProgramError::InvalidMetadata);
+		collection.validate_nft_uri(&entry.uri, metadata.item_number)?;
+		collection.verify_reveal(metadata.item_number, &entry.name, &entry.uri, entry.content_hash, &entry.proof)?;
+
+		metadata.name = entry.name.clone();
+		metadata.uri = entry.uri.clone();
+		metadata.content_hash = entry.content_hash;
+		metadata.revealed = true;
+		metadata.exit(&crate::ID)?;
+	}
+
+	Ok(())
+}
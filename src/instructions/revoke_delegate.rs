@@ -0,0 +1,123 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct RevokeDelegate<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		pub owner: Signer<'info>,
+
+		pub mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"metadata",
+				mint.key().as_ref(),
+			],
+			bump,
+			constraint = metadata.owner == owner.key() @ MetaplexNftProgramError::NotNftOwner,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		#[account(
+			mut,
+			associated_token::mint = mint,
+			associated_token::authority = owner,
+			associated_token::token_program = token_program,
+		)]
+		pub owner_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			address = metadata.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			seeds = [
+				b"freeze_authority",
+			],
+			bump,
+		)]
+		/// CHECK: PDA used only as the mints' freeze authority
+		pub freeze_authority: UncheckedAccount<'info>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+	}
+
+	impl<'info> RevokeDelegate<'info> {
+		pub fn cpi_csl_spl_token_revoke(&self) -> Result<()> {
+			anchor_spl::token::revoke(
+				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::Revoke {
+						source: self.owner_token_account.to_account_info(),
+						authority: self.owner.to_account_info()
+					}
+				),
+			)
+		}
+		pub fn cpi_csl_spl_token_freeze_account(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::freeze_account(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::FreezeAccount {
+						account: self.owner_token_account.to_account_info(),
+						mint: self.mint.to_account_info(),
+						authority: self.freeze_authority.to_account_info()
+					},
+					signer_seeds,
+				),
+			)
+		}
+	}
+
+
+/// Revokes the NFT's transfer delegate
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[signer]` owner: [AccountInfo] The owner of the NFT
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[writable]` owner_token_account: [TokenAccount] The owner's token account holding the NFT
+/// 5. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 6. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 7. `[]` token_program: [AccountInfo] SPL Token program
+/// 8. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+pub fn handler(
+	ctx: Context<RevokeDelegate>,
+) -> Result<()> {
+	require!(ctx.accounts.metadata.has_delegate(), MetaplexNftProgramError::NoDelegate);
+
+	utils::thaw_if_frozen(
+		&ctx.accounts.owner_token_account,
+		&ctx.accounts.mint.to_account_info(),
+		&ctx.accounts.freeze_authority.to_account_info(),
+		ctx.bumps.freeze_authority,
+		&ctx.accounts.token_program.to_account_info(),
+	)?;
+	ctx.accounts.cpi_csl_spl_token_revoke()?;
+	// Tokens of royalty-enforced collections and NFTs frozen by the
+	// collection authority go back to being frozen.
+	if ctx.accounts.collection.enforce_royalties || ctx.accounts.metadata.frozen {
+		ctx.accounts.cpi_csl_spl_token_freeze_account(&[&[
+			b"freeze_authority",
+			&[ctx.bumps.freeze_authority],
+		]])?;
+	}
+
+	ctx.accounts.metadata.delegate = Pubkey::default();
+
+	Ok(())
+}
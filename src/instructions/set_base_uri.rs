@@ -0,0 +1,77 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+use crate::events::BaseUriChanged;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		base_uri: String,
+	)]
+	pub struct SetBaseUri<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority @ MetaplexNftProgramError::InvalidAuthority,
+			seeds = [
+				b"collection",
+				collection.seed_authority.as_ref(),
+			],
+			bump,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		pub authority: Signer<'info>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Sets or changes the base URI that the collection's NFT URIs are resolved against, re-pointing every NFT at once; not while any NFT is locked
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` collection: [NftCollection] The collection to configure, resized to fit the base URI
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - base_uri: [String] Base URI, e.g. https://example.com/items/; empty to store full URIs, only possible before the first mint
+pub fn handler(
+	ctx: Context<SetBaseUri>,
+	base_uri: String,
+) -> Result<()> {
+	let collection = &ctx.accounts.collection;
+	require!(collection.is_mutable, MetaplexNftProgramError::MetadataImmutable);
+	require!(
+		collection.nft_count == 0 || collection.base_uri.is_empty() == base_uri.is_empty(),
+		MetaplexNftProgramError::BaseUriLocked
+	);
+	require!(collection.locked_nft_count == 0, MetaplexNftProgramError::BaseUriLocked);
+	if !base_uri.is_empty() {
+		collection.validate_uri(&base_uri)?;
+	}
+
+	utils::resize_program_account(
+		&collection.to_account_info(),
+		&ctx.accounts.fee_payer.to_account_info(),
+		&ctx.accounts.system_program.to_account_info(),
+		NftCollection::SPACE + base_uri.len(),
+	)?;
+
+	let collection = &mut ctx.accounts.collection;
+	collection.base_uri = base_uri;
+
+	emit!(BaseUriChanged {
+		collection: collection.key(),
+		base_uri: collection.base_uri.clone(),
+	});
+
+	Ok(())
+}
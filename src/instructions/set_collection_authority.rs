@@ -0,0 +1,60 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+use crate::events::CollectionAuthorityChanged;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		new_authority: Pubkey,
+	)]
+	pub struct SetCollectionAuthority<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority @ MetaplexNftProgramError::InvalidAuthority,
+			seeds = [
+				b"collection",
+				collection.seed_authority.as_ref(),
+			],
+			bump,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		pub authority: Signer<'info>,
+	}
+
+/// Hands a collection over to a new authority, such as a multisig; the collection keeps its address
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` collection: [NftCollection] The collection to hand over
+/// 2. `[signer]` authority: [AccountInfo] The current authority of the collection
+///
+/// Data:
+/// - new_authority: [Pubkey] The new collection authority
+pub fn handler(
+	ctx: Context<SetCollectionAuthority>,
+	new_authority: Pubkey,
+) -> Result<()> {
+	let collection = &mut ctx.accounts.collection;
+	let old_authority = collection.authority;
+	// The address stays derived from `seed_authority`, so clients keep finding
+	// the collection under the key that created it.
+	collection.authority = new_authority;
+
+	emit!(CollectionAuthorityChanged {
+		collection: collection.key(),
+		old_authority,
+		new_authority,
+	});
+
+	Ok(())
+}
@@ -0,0 +1,55 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+use crate::events::CollectionPauseChanged;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		paused: bool,
+	)]
+	pub struct SetCollectionPaused<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority @ MetaplexNftProgramError::InvalidAuthority,
+			seeds = [
+				b"collection",
+				collection.seed_authority.as_ref(),
+			],
+			bump,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		pub authority: Signer<'info>,
+	}
+
+/// Pauses or resumes minting, trading and other NFT activity for a collection
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` collection: [NftCollection] The collection to pause or resume
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+///
+/// Data:
+/// - paused: [bool] Whether the collection is paused
+pub fn handler(
+	ctx: Context<SetCollectionPaused>,
+	paused: bool,
+) -> Result<()> {
+	ctx.accounts.collection.paused = paused;
+
+	emit!(CollectionPauseChanged {
+		collection: ctx.accounts.collection.key(),
+		paused,
+	});
+
+	Ok(())
+}
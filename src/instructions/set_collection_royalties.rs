@@ -0,0 +1,61 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		seller_fee_basis_points: u16,
+		creators: Vec<CollectionCreator>,
+	)]
+	pub struct SetCollectionRoyalties<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority @ MetaplexNftProgramError::InvalidAuthority,
+			seeds = [
+				b"collection",
+				collection.seed_authority.as_ref(),
+			],
+			bump,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		pub authority: Signer<'info>,
+	}
+
+/// Sets the royalty paid to the collection creators on marketplace sales
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` collection: [NftCollection] The collection to configure
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+///
+/// Data:
+/// - seller_fee_basis_points: [u16] Royalty in basis points of the sale price
+/// - creators: [Vec<CollectionCreator>] Creators sharing the royalty; shares must add up to 100
+pub fn handler(
+	ctx: Context<SetCollectionRoyalties>,
+	seller_fee_basis_points: u16,
+	creators: Vec<CollectionCreator>,
+) -> Result<()> {
+	require!(seller_fee_basis_points <= 10_000, MetaplexNftProgramError::InvalidRoyaltyConfig);
+	require!(creators.len() <= MAX_CREATORS, MetaplexNftProgramError::InvalidRoyaltyConfig);
+	if seller_fee_basis_points > 0 || !creators.is_empty() {
+		let total_share: u32 = creators.iter().map(|creator| creator.share as u32).sum();
+		require!(total_share == 100, MetaplexNftProgramError::InvalidRoyaltyConfig);
+	}
+
+	let collection = &mut ctx.accounts.collection;
+	collection.seller_fee_basis_points = seller_fee_basis_points;
+	collection.creators = creators;
+
+	Ok(())
+}
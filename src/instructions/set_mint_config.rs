@@ -0,0 +1,94 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		start_price: u64,
+		end_price: u64,
+		start_time: i64,
+		decay_interval: i64,
+		price_step: u64,
+		max_supply: u64,
+	)]
+	pub struct SetMintConfig<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			has_one = authority @ MetaplexNftProgramError::InvalidAuthority,
+			seeds = [
+				b"collection",
+				collection.seed_authority.as_ref(),
+			],
+			bump,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			init_if_needed,
+			space=121,
+			payer=fee_payer,
+			seeds = [
+				b"mint_config",
+				collection.key().as_ref(),
+			],
+			bump,
+		)]
+		pub mint_config: Account<'info, MintConfig>,
+
+		pub authority: Signer<'info>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Configures a falling mint price for the collection; can only be changed before the first mint
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` collection: [NftCollection] The collection to configure
+/// 2. `[writable]` mint_config: [MintConfig] The mint pricing account, created if needed
+/// 3. `[signer]` authority: [AccountInfo] The authority of the collection
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - start_price: [u64] Mint price in lamports when minting opens
+/// - end_price: [u64] Floor the price never falls below
+/// - start_time: [i64] Unix timestamp at which minting opens
+/// - decay_interval: [i64] Seconds between two price decreases
+/// - price_step: [u64] Lamports the price falls by every decay interval
+/// - max_supply: [u64] Number of NFTs sold through this drop
+pub fn handler(
+	ctx: Context<SetMintConfig>,
+	start_price: u64,
+	end_price: u64,
+	start_time: i64,
+	decay_interval: i64,
+	price_step: u64,
+	max_supply: u64,
+) -> Result<()> {
+	require!(start_price >= end_price, MetaplexNftProgramError::InvalidMintConfig);
+	require!(decay_interval > 0, MetaplexNftProgramError::InvalidMintConfig);
+	require!(max_supply > 0, MetaplexNftProgramError::InvalidMintConfig);
+
+	let mint_config = &mut ctx.accounts.mint_config;
+	require!(mint_config.minted == 0, MetaplexNftProgramError::MintConfigLocked);
+
+	mint_config.collection = ctx.accounts.collection.key();
+	mint_config.start_price = start_price;
+	mint_config.end_price = end_price;
+	mint_config.start_time = start_time;
+	mint_config.decay_interval = decay_interval;
+	mint_config.price_step = price_step;
+	mint_config.max_supply = max_supply;
+	mint_config.last_price = start_price;
+	mint_config.bump = ctx.bumps.mint_config;
+
+	Ok(())
+}
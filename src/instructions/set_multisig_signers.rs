@@ -0,0 +1,55 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		signers: Vec<Pubkey>,
+		threshold: u8,
+	)]
+	pub struct SetMultisigSigners<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			// Only the multisig itself, through an executed proposal, signs.
+			signer @ MetaplexNftProgramError::InvalidAuthority,
+			seeds = [
+				b"multisig",
+				multisig.creator.as_ref(),
+				multisig.nonce.to_le_bytes().as_ref(),
+			],
+			bump = multisig.bump,
+		)]
+		pub multisig: Account<'info, Multisig>,
+	}
+
+/// Replaces the members and threshold of a multisig; only callable by the multisig itself through an executed proposal
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` multisig: [Multisig] The multisig to update; must sign
+///
+/// Data:
+/// - signers: [Vec<Pubkey>] The new member keys, at most 10
+/// - threshold: [u8] The new number of approvals a proposal needs
+pub fn handler(
+	ctx: Context<SetMultisigSigners>,
+	signers: Vec<Pubkey>,
+	threshold: u8,
+) -> Result<()> {
+	Multisig::validate_signers(&signers, threshold)?;
+
+	let multisig = &mut ctx.accounts.multisig;
+	multisig.signers = signers;
+	multisig.threshold = threshold;
+
+	Ok(())
+}
@@ -0,0 +1,56 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+use crate::events::ProgramAdminChanged;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		new_admin: Pubkey,
+	)]
+	pub struct SetProgramAdmin<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = admin @ MetaplexNftProgramError::InvalidAuthority,
+			seeds = [
+				b"program_config",
+			],
+			bump = program_config.bump,
+		)]
+		pub program_config: Account<'info, ProgramConfig>,
+
+		pub admin: Signer<'info>,
+	}
+
+/// Hands the program admin role over to a new key
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` program_config: [ProgramConfig] The program config
+/// 2. `[signer]` admin: [AccountInfo] The current program admin
+///
+/// Data:
+/// - new_admin: [Pubkey] The new program admin
+pub fn handler(
+	ctx: Context<SetProgramAdmin>,
+	new_admin: Pubkey,
+) -> Result<()> {
+	let program_config = &mut ctx.accounts.program_config;
+	let old_admin = program_config.admin;
+	program_config.admin = new_admin;
+
+	emit!(ProgramAdminChanged {
+		old_admin,
+		new_admin,
+	});
+
+	Ok(())
+}
@@ -0,0 +1,54 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+use crate::events::ProgramPauseChanged;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		paused: bool,
+	)]
+	pub struct SetProgramPaused<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = admin @ MetaplexNftProgramError::InvalidAuthority,
+			seeds = [
+				b"program_config",
+			],
+			bump = program_config.bump,
+		)]
+		pub program_config: Account<'info, ProgramConfig>,
+
+		pub admin: Signer<'info>,
+	}
+
+/// Pauses or resumes minting, trading and other NFT activity across every collection
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` program_config: [ProgramConfig] The program config
+/// 2. `[signer]` admin: [AccountInfo] The program admin
+///
+/// Data:
+/// - paused: [bool] Whether the program is paused
+pub fn handler(
+	ctx: Context<SetProgramPaused>,
+	paused: bool,
+) -> Result<()> {
+	ctx.accounts.program_config.paused = paused;
+
+	emit!(ProgramPauseChanged {
+		admin: ctx.accounts.admin.key(),
+		paused,
+	});
+
+	Ok(())
+}
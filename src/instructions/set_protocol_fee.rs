@@ -0,0 +1,62 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+use crate::events::ProtocolFeeChanged;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		mint_fee_lamports: u64,
+		mint_fee_bps: u16,
+	)]
+	pub struct SetProtocolFee<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = admin @ MetaplexNftProgramError::InvalidAuthority,
+			seeds = [
+				b"program_config",
+			],
+			bump = program_config.bump,
+		)]
+		pub program_config: Account<'info, ProgramConfig>,
+
+		pub admin: Signer<'info>,
+	}
+
+/// Sets the protocol fee charged on every mint: a flat amount plus a share of the mint price
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` program_config: [ProgramConfig] The program config
+/// 2. `[signer]` admin: [AccountInfo] The program admin
+///
+/// Data:
+/// - mint_fee_lamports: [u64] Flat fee in lamports charged per minted NFT
+/// - mint_fee_bps: [u16] Fee in basis points of the mint price
+pub fn handler(
+	ctx: Context<SetProtocolFee>,
+	mint_fee_lamports: u64,
+	mint_fee_bps: u16,
+) -> Result<()> {
+	require!(mint_fee_bps <= 10_000, MetaplexNftProgramError::InvalidProtocolFee);
+
+	let program_config = &mut ctx.accounts.program_config;
+	program_config.mint_fee_lamports = mint_fee_lamports;
+	program_config.mint_fee_bps = mint_fee_bps;
+
+	emit!(ProtocolFeeChanged {
+		admin: ctx.accounts.admin.key(),
+		mint_fee_lamports,
+		mint_fee_bps,
+	});
+
+	Ok(())
+}
@@ -0,0 +1,73 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		mode: RuleSetMode,
+		programs: Vec<Pubkey>,
+	)]
+	pub struct SetRuleSet<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			has_one = authority @ MetaplexNftProgramError::InvalidAuthority,
+			seeds = [
+				b"collection",
+				collection.seed_authority.as_ref(),
+			],
+			bump,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		pub authority: Signer<'info>,
+
+		#[account(
+			init_if_needed,
+			space=366,
+			payer=fee_payer,
+			seeds = [
+				b"rule_set",
+				collection.key().as_ref(),
+			],
+			bump,
+		)]
+		pub rule_set: Account<'info, RuleSet>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Sets the programs allowed, or denied, to act as transfer authority or delegate for a collection's NFTs
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` collection: [NftCollection] The collection to configure
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+/// 3. `[writable]` rule_set: [RuleSet] The collection's rule set, created if needed
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - mode: [RuleSetMode] Whether the listed programs are the only ones allowed, or the ones denied
+/// - programs: [Vec<Pubkey>] The program IDs the rule set lists
+pub fn handler(
+	ctx: Context<SetRuleSet>,
+	mode: RuleSetMode,
+	programs: Vec<Pubkey>,
+) -> Result<()> {
+	require!(programs.len() <= MAX_RULE_SET_PROGRAMS, MetaplexNftProgramError::TooManyRuleSetPrograms);
+
+	let rule_set = &mut ctx.accounts.rule_set;
+	rule_set.collection = ctx.accounts.collection.key();
+	rule_set.mode = mode;
+	rule_set.programs = programs;
+	rule_set.bump = ctx.bumps.rule_set;
+
+	Ok(())
+}
@@ -0,0 +1,72 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		max_uri_length: u16,
+		allowed_schemes: Vec<String>,
+	)]
+	pub struct SetUriRules<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority @ MetaplexNftProgramError::InvalidAuthority,
+			seeds = [
+				b"collection",
+				collection.seed_authority.as_ref(),
+			],
+			bump,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		pub authority: Signer<'info>,
+	}
+
+/// Sets the maximum length and allowed schemes of metadata URIs in a collection
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` collection: [NftCollection] The collection to configure
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+///
+/// Data:
+/// - max_uri_length: [u16] Maximum URI length, at most the program-wide limit
+/// - allowed_schemes: [Vec<String>] Lowercase URI schemes allowed, such as https, ipfs or ar
+pub fn handler(
+	ctx: Context<SetUriRules>,
+	max_uri_length: u16,
+	allowed_schemes: Vec<String>,
+) -> Result<()> {
+	require!(
+		max_uri_length > 0 && max_uri_length as usize <= MAX_URI_LENGTH,
+		MetaplexNftProgramError::InvalidUriRules
+	);
+	require!(
+		!allowed_schemes.is_empty() && allowed_schemes.len() <= MAX_URI_SCHEMES,
+		MetaplexNftProgramError::InvalidUriRules
+	);
+	for scheme in allowed_schemes.iter() {
+		// RFC 3986: a letter followed by letters, digits, "+", "-" or ".".
+		require!(
+			scheme.len() <= MAX_URI_SCHEME_LENGTH
+				&& scheme.starts_with(|c: char| c.is_ascii_lowercase())
+				&& scheme.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "+-.".contains(c)),
+			MetaplexNftProgramError::InvalidUriRules
+		);
+	}
+
+	let collection = &mut ctx.accounts.collection;
+	collection.max_uri_length = max_uri_length;
+	collection.allowed_uri_schemes = allowed_schemes;
+
+	Ok(())
+}
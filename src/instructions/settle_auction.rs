@@ -0,0 +1,203 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::error::MetaplexNftProgramError;
+use crate::utils::{pay_royalties, transfer_lamports_from_program_account};
+
+
+
+
+	#[derive(Accounts)]
+	pub struct SettleAuction<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			address = auction.seller,
+		)]
+		/// CHECK: matched against the auction
+		pub seller: UncheckedAccount<'info>,
+
+		#[account(
+			address = auction.recipient() @ MetaplexNftProgramError::InvalidAuctionRecipient,
+		)]
+		/// CHECK: matched against the auction outcome
+		pub recipient: UncheckedAccount<'info>,
+
+		pub mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"metadata",
+				mint.key().as_ref(),
+			],
+			bump,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		#[account(
+			address = auction.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			mut,
+			close = seller,
+			has_one = mint,
+			seeds = [
+				b"auction",
+				mint.key().as_ref(),
+			],
+			bump = auction.bump,
+		)]
+		pub auction: Account<'info, Auction>,
+
+		#[account(
+			mut,
+			associated_token::mint = mint,
+			associated_token::authority = auction,
+			associated_token::token_program = token_program,
+		)]
+		pub escrow_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			init_if_needed,
+			payer = fee_payer,
+			associated_token::mint = mint,
+			associated_token::authority = recipient,
+			associated_token::token_program = token_program,
+		)]
+		pub recipient_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			seeds = [
+				b"freeze_authority",
+			],
+			bump,
+		)]
+		/// CHECK: PDA used only as the mints' freeze authority
+		pub freeze_authority: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+		pub associated_token_program: Program<'info, AssociatedToken>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+	}
+
+	impl<'info> SettleAuction<'info> {
+		pub fn cpi_csl_spl_token_transfer_checked(&self, amount: u64, decimals: u8, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::transfer_checked(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::TransferChecked {
+						from: self.escrow_token_account.to_account_info(),
+						mint: self.mint.to_account_info(),
+						to: self.recipient_token_account.to_account_info(),
+						authority: self.auction.to_account_info()
+					},
+					signer_seeds,
+				),
+				amount, 
+				decimals, 
+			)
+		}
+		pub fn cpi_csl_spl_token_close_account(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::close_account(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::CloseAccount {
+						account: self.escrow_token_account.to_account_info(),
+						destination: self.seller.to_account_info(),
+						authority: self.auction.to_account_info()
+					},
+					signer_seeds,
+				),
+			)
+		}
+	}
+
+
+/// Settles an ended auction: the NFT goes to the winner, or back to the seller without bids, and the seller and creators are paid
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Anyone; pays for the recipient's token account if needed
+/// 1. `[writable]` seller: [AccountInfo] The seller recorded on the auction
+/// 2. `[]` recipient: [AccountInfo] The highest bidder, or the seller if there were no bids
+/// 3. `[]` mint: [Mint] The mint account of the NFT
+/// 4. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 5. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 6. `[writable]` auction: [Auction] The auction to settle
+/// 7. `[writable]` escrow_token_account: [TokenAccount] Escrow token account owned by the auction
+/// 8. `[writable]` recipient_token_account: [TokenAccount] The recipient's token account, created if needed
+/// 9. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 10. `[]` system_program: [AccountInfo] System program
+/// 11. `[]` token_program: [AccountInfo] SPL Token program
+/// 12. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 13. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 14. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Remaining accounts:
+/// - `[writable]` creators: the collection creators, in the order stored on the collection
+pub fn handler<'info>(
+	ctx: Context<'_, '_, 'info, 'info, SettleAuction<'info>>,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	let accounts = &ctx.accounts;
+	require!(
+		Clock::get()?.unix_timestamp >= accounts.auction.end_time,
+		MetaplexNftProgramError::AuctionNotEnded
+	);
+
+	let mint_key = accounts.mint.key();
+	let signer_seeds: &[&[&[u8]]] = &[&[
+		b"auction",
+		mint_key.as_ref(),
+		&[accounts.auction.bump],
+	]];
+	accounts.cpi_csl_spl_token_transfer_checked(
+		1,
+		accounts.mint.decimals,
+		signer_seeds,
+	)?;
+	accounts.cpi_csl_spl_token_close_account(signer_seeds)?;
+	utils::freeze_if_enforced(
+		&accounts.collection,
+		&accounts.recipient_token_account,
+		&accounts.mint.to_account_info(),
+		&accounts.freeze_authority.to_account_info(),
+		ctx.bumps.freeze_authority,
+		&accounts.token_program.to_account_info(),
+	)?;
+
+	let price = accounts.auction.highest_bid;
+	if price > 0 {
+		let escrow = accounts.auction.to_account_info();
+		let royalty = pay_royalties(&accounts.collection, price, ctx.remaining_accounts, |creator, share| {
+			transfer_lamports_from_program_account(&escrow, creator, share)
+		})?;
+		transfer_lamports_from_program_account(&escrow, &accounts.seller.to_account_info(), price - royalty)?;
+	}
+
+	ctx.accounts.metadata.owner = ctx.accounts.recipient.key();
+
+	Ok(())
+}
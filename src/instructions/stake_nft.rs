@@ -0,0 +1,193 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct StakeNft<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		pub owner: Signer<'info>,
+
+		pub mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"metadata",
+				mint.key().as_ref(),
+			],
+			bump,
+			constraint = metadata.owner == owner.key() @ MetaplexNftProgramError::NotNftOwner,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		#[account(
+			address = metadata.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			seeds = [
+				b"stake_pool",
+				metadata.collection.as_ref(),
+			],
+			bump = stake_pool.bump,
+		)]
+		pub stake_pool: Account<'info, StakePool>,
+
+		#[account(
+			init,
+			space=121,
+			payer=fee_payer,
+			seeds = [
+				b"stake_entry",
+				mint.key().as_ref(),
+			],
+			bump,
+		)]
+		pub stake_entry: Account<'info, StakeEntry>,
+
+		#[account(
+			mut,
+			associated_token::mint = mint,
+			associated_token::authority = owner,
+			associated_token::token_program = token_program,
+		)]
+		pub owner_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			seeds = [
+				b"freeze_authority",
+			],
+			bump,
+		)]
+		/// CHECK: PDA used only as the mints' freeze authority
+		pub freeze_authority: UncheckedAccount<'info>,
+
+		#[account(
+			seeds = [
+				b"rule_set",
+				metadata.collection.as_ref(),
+			],
+			bump,
+		)]
+		/// CHECK: only read when the collection has a rule set
+		pub rule_set: UncheckedAccount<'info>,
+
+		#[account(
+			address = anchor_lang::solana_program::sysvar::instructions::ID,
+		)]
+		/// CHECK: the instructions sysvar
+		pub instructions: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+	}
+
+	impl<'info> StakeNft<'info> {
+		pub fn cpi_csl_spl_token_approve(&self, amount: u64) -> Result<()> {
+			anchor_spl::token::approve(
+				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::Approve {
+						to: self.owner_token_account.to_account_info(),
+						delegate: self.stake_entry.to_account_info(),
+						authority: self.owner.to_account_info()
+					}
+				),
+				amount, 
+			)
+		}
+		pub fn cpi_csl_spl_token_freeze_account(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::freeze_account(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::FreezeAccount {
+						account: self.owner_token_account.to_account_info(),
+						mint: self.mint.to_account_info(),
+						authority: self.freeze_authority.to_account_info()
+					},
+					signer_seeds,
+				),
+			)
+		}
+	}
+
+
+/// Stakes an NFT without moving it: the stake entry becomes delegate of the owner's token account, which stays frozen until unstaked
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[signer]` owner: [AccountInfo] The owner of the NFT
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[]` stake_pool: [StakePool] The stake pool of the NFT's collection
+/// 6. `[writable]` stake_entry: [StakeEntry] The stake entry to initialize
+/// 7. `[writable]` owner_token_account: [TokenAccount] The owner's token account holding the NFT
+/// 8. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 9. `[]` rule_set: [AccountInfo] The rule set of the NFT's collection
+/// 10. `[]` instructions: [AccountInfo] Instructions sysvar, to find the calling program
+/// 11. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 12. `[]` token_program: [AccountInfo] SPL Token program
+/// 13. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 14. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+pub fn handler(
+	ctx: Context<StakeNft>,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	let now = Clock::get()?.unix_timestamp;
+	ctx.accounts.metadata.assert_transferable(now)?;
+	ctx.accounts.metadata.assert_not_delegated()?;
+	utils::assert_rule_set_permits(
+		&ctx.accounts.rule_set,
+		&ctx.accounts.instructions,
+		None,
+	)?;
+
+	// Tokens of royalty-enforced collections are frozen at rest, and the
+	// token program does not approve delegates on frozen accounts.
+	utils::thaw_if_frozen(
+		&ctx.accounts.owner_token_account,
+		&ctx.accounts.mint.to_account_info(),
+		&ctx.accounts.freeze_authority.to_account_info(),
+		ctx.bumps.freeze_authority,
+		&ctx.accounts.token_program.to_account_info(),
+	)?;
+	ctx.accounts.cpi_csl_spl_token_approve(1)?;
+	ctx.accounts.cpi_csl_spl_token_freeze_account(&[&[
+		b"freeze_authority",
+		&[ctx.bumps.freeze_authority],
+	]])?;
+
+	let stake_entry = &mut ctx.accounts.stake_entry;
+	stake_entry.owner = ctx.accounts.owner.key();
+	stake_entry.mint = ctx.accounts.mint.key();
+	stake_entry.stake_pool = ctx.accounts.stake_pool.key();
+	stake_entry.staked_at = now;
+	stake_entry.last_claimed_at = now;
+	stake_entry.bump = ctx.bumps.stake_entry;
+
+	ctx.accounts.metadata.staked = true;
+
+	Ok(())
+}
@@ -0,0 +1,185 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::error::MetaplexNftProgramError;
+use crate::utils::transfer_lamports_from_program_account;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct TakeLoan<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub borrower: Signer<'info>,
+
+		pub mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"metadata",
+				mint.key().as_ref(),
+			],
+			bump,
+			constraint = metadata.owner == borrower.key() @ MetaplexNftProgramError::NotNftOwner,
+			constraint = metadata.collection == loan.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		#[account(
+			address = metadata.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			mut,
+			constraint = !loan.is_taken() @ MetaplexNftProgramError::LoanAlreadyTaken,
+			seeds = [
+				b"loan",
+				loan.lender.as_ref(),
+				loan.offer_id.to_le_bytes().as_ref(),
+			],
+			bump = loan.bump,
+		)]
+		pub loan: Account<'info, Loan>,
+
+		#[account(
+			mut,
+			associated_token::mint = mint,
+			associated_token::authority = borrower,
+			associated_token::token_program = token_program,
+		)]
+		pub borrower_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			init,
+			payer = fee_payer,
+			associated_token::mint = mint,
+			associated_token::authority = loan,
+			associated_token::token_program = token_program,
+		)]
+		pub escrow_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			seeds = [
+				b"freeze_authority",
+			],
+			bump,
+		)]
+		/// CHECK: PDA used only as the mints' freeze authority
+		pub freeze_authority: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+		pub associated_token_program: Program<'info, AssociatedToken>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+	}
+
+	impl<'info> TakeLoan<'info> {
+		pub fn cpi_csl_spl_token_transfer_checked(&self, amount: u64, decimals: u8) -> Result<()> {
+			anchor_spl::token::transfer_checked(
+				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::TransferChecked {
+						from: self.borrower_token_account.to_account_info(),
+						mint: self.mint.to_account_info(),
+						to: self.escrow_token_account.to_account_info(),
+						authority: self.borrower.to_account_info()
+					}
+				),
+				amount, 
+				decimals, 
+			)
+		}
+	}
+
+
+/// Accepts a loan offer: the NFT moves into an escrow owned by the loan and the principal goes to the borrower
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` borrower: [AccountInfo] The owner of the NFT used as collateral
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[writable]` loan: [Loan] The loan offer to accept
+/// 6. `[writable]` borrower_token_account: [TokenAccount] The borrower's token account holding the NFT
+/// 7. `[writable]` escrow_token_account: [TokenAccount] Escrow token account owned by the loan
+/// 8. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 9. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 10. `[]` token_program: [AccountInfo] SPL Token program
+/// 11. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 12. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 13. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Data:
+/// - expected_amount: [u64] Principal the borrower agreed to; the loan fails if the offer differs
+/// - expected_duration: [i64] Repayment period the borrower agreed to; the loan fails if the offer differs
+/// - expected_interest: [u64] Interest the borrower agreed to; the loan fails if the offer differs
+pub fn handler(
+	ctx: Context<TakeLoan>,
+	expected_amount: u64,
+	expected_duration: i64,
+	expected_interest: u64,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	let loan = &ctx.accounts.loan;
+	require!(loan.amount == expected_amount, MetaplexNftProgramError::LoanTermsChanged);
+	require!(loan.duration == expected_duration, MetaplexNftProgramError::LoanTermsChanged);
+	require!(loan.interest == expected_interest, MetaplexNftProgramError::LoanTermsChanged);
+
+	let now = Clock::get()?.unix_timestamp;
+	ctx.accounts.metadata.assert_transferable(now)?;
+	ctx.accounts.metadata.assert_not_delegated()?;
+
+	utils::thaw_if_frozen(
+		&ctx.accounts.borrower_token_account,
+		&ctx.accounts.mint.to_account_info(),
+		&ctx.accounts.freeze_authority.to_account_info(),
+		ctx.bumps.freeze_authority,
+		&ctx.accounts.token_program.to_account_info(),
+	)?;
+	ctx.accounts.cpi_csl_spl_token_transfer_checked(
+		1,
+		ctx.accounts.mint.decimals,
+	)?;
+	transfer_lamports_from_program_account(
+		&ctx.accounts.loan.to_account_info(),
+		&ctx.accounts.borrower.to_account_info(),
+		ctx.accounts.loan.amount,
+	)?;
+
+	let loan = &mut ctx.accounts.loan;
+	loan.borrower = ctx.accounts.borrower.key();
+	loan.mint = ctx.accounts.mint.key();
+	loan.start_time = now;
+
+	// The escrow holds the collateral, so the loan PDA owns the NFT until it
+	// is repaid or foreclosed.
+	ctx.accounts.metadata.owner = loan.key();
+
+	Ok(())
+}
@@ -0,0 +1,121 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct ThawNft<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			has_one = authority @ MetaplexNftProgramError::InvalidAuthority,
+			seeds = [
+				b"collection",
+				collection.seed_authority.as_ref(),
+			],
+			bump,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		pub authority: Signer<'info>,
+
+		pub mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"metadata",
+				mint.key().as_ref(),
+			],
+			bump,
+			constraint = metadata.collection == collection.key() @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		#[account(
+			address = metadata.owner @ MetaplexNftProgramError::NotNftOwner,
+		)]
+		/// CHECK: the NFT's current holder, a wallet or one of the program's escrows
+		pub holder: UncheckedAccount<'info>,
+
+		#[account(
+			mut,
+			token::mint = mint,
+			token::authority = holder,
+			constraint = holder_token_account.amount == 1 @ MetaplexNftProgramError::NotNftOwner,
+		)]
+		pub holder_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			seeds = [
+				b"freeze_authority",
+			],
+			bump,
+		)]
+		/// CHECK: PDA used only as the mints' freeze authority
+		pub freeze_authority: UncheckedAccount<'info>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+	}
+
+	impl<'info> ThawNft<'info> {
+		pub fn cpi_csl_spl_token_thaw_account(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::thaw_account(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::ThawAccount {
+						account: self.holder_token_account.to_account_info(),
+						mint: self.mint.to_account_info(),
+						authority: self.freeze_authority.to_account_info()
+					},
+					signer_seeds,
+				),
+			)
+		}
+	}
+
+
+/// Lifts a freeze placed by the collection authority
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+/// 3. `[]` mint: [Mint] The mint account of the NFT
+/// 4. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 5. `[]` holder: [AccountInfo] The NFT's current holder, a wallet or one of the program's escrows
+/// 6. `[writable]` holder_token_account: [TokenAccount] The holder's token account holding the NFT
+/// 7. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 8. `[]` token_program: [AccountInfo] SPL Token program
+/// 9. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+pub fn handler(
+	ctx: Context<ThawNft>,
+) -> Result<()> {
+	require!(ctx.accounts.metadata.frozen, MetaplexNftProgramError::NftNotFrozen);
+
+	// Wallets keep tokens of royalty-enforced and soulbound collections frozen
+	// at rest, and in-place listings and stakes keep theirs frozen while the
+	// delegate is approved; the program's escrows hold tokens thawed.
+	let holder_token_account = &ctx.accounts.holder_token_account;
+	let stays_frozen = holder_token_account.delegate.is_some()
+		|| (ctx.accounts.collection.keeps_tokens_frozen() && ctx.accounts.holder.owner != &crate::ID);
+	if holder_token_account.is_frozen() && !stays_frozen {
+		ctx.accounts.cpi_csl_spl_token_thaw_account(&[&[
+			b"freeze_authority",
+			&[ctx.bumps.freeze_authority],
+		]])?;
+	}
+
+	ctx.accounts.metadata.frozen = false;
+
+	Ok(())
+}
@@ -0,0 +1,251 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::error::MetaplexNftProgramError;
+use crate::utils::{pay_royalties, transfer_lamports};
+use anchor_lang::solana_program::instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT};
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		price: u64,
+	)]
+	pub struct TransferNft<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		pub mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"metadata",
+				mint.key().as_ref(),
+			],
+			bump,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		/// CHECK: must be the NFT's recorded owner, checked in the handler
+		pub owner: UncheckedAccount<'info>,
+
+		/// CHECK: implement manual checks if needed
+		pub new_owner: UncheckedAccount<'info>,
+
+		#[account(
+			mut,
+			owner=Pubkey::from_str("11111111111111111111111111111111").unwrap(),
+		)]
+		pub funding: Signer<'info>,
+
+		#[account(
+			init,
+			payer = funding,
+			associated_token::mint = mint,
+			associated_token::authority = wallet,
+			associated_token::token_program = token_program,
+		)]
+		pub assoc_token_account: Account<'info, TokenAccount>,
+
+		/// CHECK: implement manual checks if needed
+		pub wallet: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+
+		pub token_program: Program<'info, Token>,
+
+		#[account(
+			mut,
+			token::mint = mint,
+			token::authority = owner,
+		)]
+		pub source: Account<'info, TokenAccount>,
+
+		#[account(
+			mut,
+			token::mint = mint,
+			token::authority = new_owner,
+		)]
+		pub destination: Account<'info, TokenAccount>,
+
+		pub authority: Signer<'info>,
+
+		pub associated_token_program: Program<'info, AssociatedToken>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+		#[account(
+			address = metadata.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			seeds = [
+				b"freeze_authority",
+			],
+			bump,
+		)]
+		/// CHECK: PDA used only as the mints' freeze authority
+		pub freeze_authority: UncheckedAccount<'info>,
+
+		#[account(
+			seeds = [
+				b"rule_set",
+				collection.key().as_ref(),
+			],
+			bump,
+		)]
+		/// CHECK: only read when the collection has a rule set
+		pub rule_set: UncheckedAccount<'info>,
+
+		#[account(
+			address = anchor_lang::solana_program::sysvar::instructions::ID,
+		)]
+		/// CHECK: the instructions sysvar
+		pub instructions: UncheckedAccount<'info>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+	}
+
+	impl<'info> TransferNft<'info> {
+		pub fn cpi_csl_spl_token_transfer_checked(&self, amount: u64, decimals: u8) -> Result<()> {
+			anchor_spl::token::transfer_checked(
+				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::TransferChecked {
+						from: self.source.to_account_info(),
+						mint: self.mint.to_account_info(),
+						to: self.destination.to_account_info(),
+						authority: self.authority.to_account_info()
+					}
+				),
+				amount, 
+				decimals, 
+			)
+		}
+		pub fn cpi_csl_spl_token_revoke(&self) -> Result<()> {
+			anchor_spl::token::revoke(
+				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::Revoke {
+						source: self.source.to_account_info(),
+						authority: self.authority.to_account_info()
+					}
+				),
+			)
+		}
+	}
+
+
+/// Transfers an NFT to another wallet
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` mint: [Mint] The mint account of the NFT
+/// 2. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 3. `[]` owner: [AccountInfo] The current owner of the NFT
+/// 4. `[]` new_owner: [AccountInfo] The new owner of the NFT; must sign zero-price transfers of royalty-enforced NFTs
+/// 5. `[writable, signer]` funding: [AccountInfo] Funding account (must be a system account)
+/// 6. `[writable]` assoc_token_account: [AccountInfo] Associated token account address to be created
+/// 7. `[]` wallet: [AccountInfo] Wallet address for the new associated token account
+/// 8. `[]` system_program: [AccountInfo] System program
+/// 9. `[]` token_program: [AccountInfo] SPL Token program
+/// 10. `[writable]` source: [TokenAccount] The source account.
+/// 11. `[writable]` destination: [TokenAccount] The destination account.
+/// 12. `[signer]` authority: [AccountInfo] The owner of the NFT, or its approved delegate
+/// 13. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 14. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 15. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 16. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 17. `[]` rule_set: [AccountInfo] The rule set of the NFT's collection
+/// 18. `[]` instructions: [AccountInfo] Instructions sysvar, to find the calling program
+/// 19. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Remaining accounts:
+/// - `[writable]` creators: the collection creators, in the order stored on the collection; required when a price is declared for a royalty-enforced collection
+///
+/// Data:
+/// - price: [u64] Sale price in lamports the transfer settles, or 0 for a gift or a move between own wallets co-signed by the new owner; royalty-enforced collections charge the royalty on it to the funding account
+pub fn handler<'info>(
+	ctx: Context<'_, '_, 'info, 'info, TransferNft<'info>>,
+	price: u64,
+) -> Result<()> {
+	let accounts = &ctx.accounts;
+	utils::assert_not_paused(&accounts.collection, &accounts.program_config)?;
+	require_keys_eq!(accounts.owner.key(), accounts.metadata.owner, MetaplexNftProgramError::NotNftOwner);
+	let by_delegate = accounts.metadata.has_delegate()
+		&& accounts.authority.key() == accounts.metadata.delegate;
+	require!(
+		by_delegate || accounts.authority.key() == accounts.owner.key(),
+		MetaplexNftProgramError::InvalidTransferAuthority
+	);
+	accounts.metadata.assert_transferable(Clock::get()?.unix_timestamp)?;
+	utils::assert_rule_set_permits(
+		&accounts.rule_set,
+		&accounts.instructions,
+		by_delegate.then_some(accounts.authority.as_ref()),
+	)?;
+
+	if accounts.collection.enforce_royalties {
+		if price == 0 {
+			// Gifts and moves between own wallets skip the royalty, but only
+			// when both wallets sign directly rather than through another program.
+			require!(
+				accounts.new_owner.is_signer
+					&& get_stack_height() == TRANSACTION_LEVEL_STACK_HEIGHT,
+				MetaplexNftProgramError::RoyaltyRequired
+			);
+		} else {
+			let funding = accounts.funding.to_account_info();
+			let system_program = accounts.system_program.to_account_info();
+			pay_royalties(&accounts.collection, price, ctx.remaining_accounts, |creator, amount| {
+				transfer_lamports(&funding, creator, &system_program, amount)
+			})?;
+		}
+	}
+
+	utils::thaw_if_frozen(
+		&accounts.source,
+		&accounts.mint.to_account_info(),
+		&accounts.freeze_authority.to_account_info(),
+		ctx.bumps.freeze_authority,
+		&accounts.token_program.to_account_info(),
+	)?;
+	accounts.cpi_csl_spl_token_transfer_checked(
+		1,
+		accounts.mint.decimals,
+	)?;
+	// A delegate's approval is used up by its transfer; one left behind by an
+	// owner transfer is revoked so it cannot follow the NFT back.
+	if accounts.metadata.has_delegate() && !by_delegate {
+		accounts.cpi_csl_spl_token_revoke()?;
+	}
+	utils::freeze_if_enforced(
+		&accounts.collection,
+		&accounts.destination,
+		&accounts.mint.to_account_info(),
+		&accounts.freeze_authority.to_account_info(),
+		ctx.bumps.freeze_authority,
+		&accounts.token_program.to_account_info(),
+	)?;
+
+	let metadata = &mut ctx.accounts.metadata;
+	metadata.owner = ctx.accounts.new_owner.key();
+	metadata.delegate = Pubkey::default();
+
+	Ok(())
+}
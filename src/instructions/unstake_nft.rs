@@ -0,0 +1,220 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct UnstakeNft<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub owner: Signer<'info>,
+
+		pub mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"metadata",
+				mint.key().as_ref(),
+			],
+			bump,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		#[account(
+			address = metadata.collection @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			seeds = [
+				b"stake_pool",
+				stake_pool.collection.as_ref(),
+			],
+			bump = stake_pool.bump,
+		)]
+		pub stake_pool: Account<'info, StakePool>,
+
+		#[account(
+			mut,
+			close = owner,
+			has_one = owner,
+			has_one = mint,
+			has_one = stake_pool,
+			seeds = [
+				b"stake_entry",
+				mint.key().as_ref(),
+			],
+			bump = stake_entry.bump,
+		)]
+		pub stake_entry: Account<'info, StakeEntry>,
+
+		#[account(
+			mut,
+			associated_token::mint = mint,
+			associated_token::authority = owner,
+			associated_token::token_program = token_program,
+		)]
+		pub owner_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			mut,
+			address = stake_pool.reward_mint,
+		)]
+		pub reward_mint: Account<'info, Mint>,
+
+		#[account(
+			init_if_needed,
+			payer = fee_payer,
+			associated_token::mint = reward_mint,
+			associated_token::authority = owner,
+			associated_token::token_program = token_program,
+		)]
+		pub owner_reward_account: Account<'info, TokenAccount>,
+
+		#[account(
+			seeds = [
+				b"freeze_authority",
+			],
+			bump,
+		)]
+		/// CHECK: PDA used only as the mints' freeze authority
+		pub freeze_authority: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+		pub associated_token_program: Program<'info, AssociatedToken>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+	}
+
+	impl<'info> UnstakeNft<'info> {
+		pub fn cpi_csl_spl_token_mint_to(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::mint_to(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::MintTo {
+						mint: self.reward_mint.to_account_info(),
+						to: self.owner_reward_account.to_account_info(),
+						authority: self.stake_pool.to_account_info()
+					},
+					signer_seeds,
+				),
+				amount, 
+			)
+		}
+		pub fn cpi_csl_spl_token_thaw_account(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::thaw_account(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::ThawAccount {
+						account: self.owner_token_account.to_account_info(),
+						mint: self.mint.to_account_info(),
+						authority: self.freeze_authority.to_account_info()
+					},
+					signer_seeds,
+				),
+			)
+		}
+		pub fn cpi_csl_spl_token_freeze_account(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::freeze_account(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::FreezeAccount {
+						account: self.owner_token_account.to_account_info(),
+						mint: self.mint.to_account_info(),
+						authority: self.freeze_authority.to_account_info()
+					},
+					signer_seeds,
+				),
+			)
+		}
+		pub fn cpi_csl_spl_token_revoke(&self) -> Result<()> {
+			anchor_spl::token::revoke(
+				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::Revoke {
+						source: self.owner_token_account.to_account_info(),
+						authority: self.owner.to_account_info()
+					}
+				),
+			)
+		}
+	}
+
+
+/// Pays the pending rewards, thaws the NFT and closes its stake entry
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` owner: [AccountInfo] The owner of the staked NFT
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[]` stake_pool: [StakePool] The stake pool of the NFT's collection
+/// 6. `[writable]` stake_entry: [StakeEntry] The stake entry to close
+/// 7. `[writable]` owner_token_account: [TokenAccount] The owner's token account holding the NFT
+/// 8. `[writable]` reward_mint: [Mint] The pool's reward token mint
+/// 9. `[writable]` owner_reward_account: [TokenAccount] The owner's reward token account, created if needed
+/// 10. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 11. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 12. `[]` token_program: [AccountInfo] SPL Token program
+/// 13. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 14. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 15. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+pub fn handler(
+	ctx: Context<UnstakeNft>,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	let now = Clock::get()?.unix_timestamp;
+	let stake_pool = &ctx.accounts.stake_pool;
+	let rewards = ctx.accounts.stake_entry.pending_rewards(stake_pool.reward_rate, now)?;
+
+	if rewards > 0 {
+		ctx.accounts.cpi_csl_spl_token_mint_to(rewards, &[&[
+			b"stake_pool",
+			stake_pool.collection.as_ref(),
+			&[stake_pool.bump],
+		]])?;
+	}
+
+	// The token program only revokes on thawed accounts; tokens of
+	// royalty-enforced collections and NFTs frozen by the collection
+	// authority go back to being frozen.
+	ctx.accounts.cpi_csl_spl_token_thaw_account(&[&[
+		b"freeze_authority",
+		&[ctx.bumps.freeze_authority],
+	]])?;
+	ctx.accounts.cpi_csl_spl_token_revoke()?;
+	if ctx.accounts.collection.enforce_royalties || ctx.accounts.metadata.frozen {
+		ctx.accounts.cpi_csl_spl_token_freeze_account(&[&[
+			b"freeze_authority",
+			&[ctx.bumps.freeze_authority],
+		]])?;
+	}
+
+	ctx.accounts.metadata.staked = false;
+
+	Ok(())
+}
@@ -0,0 +1,124 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::Mint;
+
+use crate::error::MetaplexNftProgramError;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		uri: String,
+		content_hash: Option<[u8; 32]>,
+	)]
+	pub struct UpdateNftMetadata<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			has_one = authority @ MetaplexNftProgramError::InvalidAuthority,
+			seeds = [
+				b"collection",
+				collection.seed_authority.as_ref(),
+			],
+			bump,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"metadata",
+				mint.key().as_ref(),
+			],
+			bump,
+			constraint = metadata.collection == collection.key() @ MetaplexNftProgramError::CollectionNotFound,
+		)]
+		pub metadata: Account<'info, NftMint>,
+
+		pub mint: Account<'info, Mint>,
+
+		pub authority: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		/// CHECK: only read once the program config has been initialized
+		pub program_config: UncheckedAccount<'info>,
+
+		#[account(
+			init_if_needed,
+			space=2585,
+			payer=fee_payer,
+			seeds = [
+				b"history",
+				mint.key().as_ref(),
+			],
+			bump,
+		)]
+		pub history: Account<'info, MetadataHistory>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Updates the metadata of an existing NFT
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 2. `[writable]` metadata: [NftMint] The metadata account to update
+/// 3. `[]` mint: [Mint] The mint account of the NFT
+/// 4. `[signer]` authority: [AccountInfo] The authority of the collection
+/// 5. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+/// 6. `[writable]` history: [MetadataHistory] The NFT's revision history, created if needed
+/// 7. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - name: [String] New name of the NFT
+/// - uri: [String] New URI to the NFT metadata, or its suffix when the collection has a base URI
+/// - content_hash: [Option<[u8; 32]>] SHA-256 hash of the new NFT metadata JSON, if committed
+pub fn handler(
+	ctx: Context<UpdateNftMetadata>,
+	name: String,
+	uri: String,
+	content_hash: Option<[u8; 32]>,
+) -> Result<()> {
+	utils::assert_not_paused(&ctx.accounts.collection, &ctx.accounts.program_config)?;
+	require!(name.len() <= MAX_NAME_LENGTH, MetaplexNftProgramError::InvalidMetadata);
+	ctx.accounts.collection.validate_nft_uri(&uri, ctx.accounts.metadata.item_number)?;
+	require!(!ctx.accounts.metadata.frozen, MetaplexNftProgramError::NftFrozen);
+	require!(ctx.accounts.metadata.revealed, MetaplexNftProgramError::NftNotRevealed);
+	require!(
+		ctx.accounts.collection.is_mutable && ctx.accounts.metadata.is_mutable,
+		MetaplexNftProgramError::MetadataImmutable
+	);
+
+	let metadata = &mut ctx.accounts.metadata;
+	let history = &mut ctx.accounts.history;
+	history.mint = metadata.mint;
+	history.bump = ctx.bumps.history;
+	history.record(MetadataRevision {
+		revision: metadata.revision,
+		name: metadata.name.clone(),
+		uri: metadata.uri.clone(),
+		content_hash: metadata.content_hash,
+		updater: ctx.accounts.authority.key(),
+		slot: Clock::get()?.slot,
+	});
+
+	metadata.revision = metadata.revision
+		.checked_add(1)
+		.ok_or(MetaplexNftProgramError::ArithmeticOverflow)?;
+	metadata.name = name;
+	metadata.uri = uri;
+	metadata.content_hash = content_hash;
+
+	Ok(())
+}
@@ -0,0 +1,59 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use crate::utils::transfer_lamports_from_program_account;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct WithdrawBid<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub bidder: Signer<'info>,
+
+		#[account(
+			mut,
+			close = payer,
+			has_one = bidder,
+			has_one = payer,
+			seeds = [
+				b"bid_refund",
+				bid_refund.auction.as_ref(),
+				bidder.key().as_ref(),
+			],
+			bump = bid_refund.bump,
+		)]
+		pub bid_refund: Account<'info, BidRefund>,
+
+		#[account(
+			mut,
+		)]
+		/// CHECK: matched against the refund account
+		pub payer: UncheckedAccount<'info>,
+	}
+
+/// Withdraws the lamports credited to an outbid auction bidder, closing the refund account
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` bidder: [AccountInfo] The outbid bidder
+/// 2. `[writable]` bid_refund: [BidRefund] The bidder's refund account for the auction
+/// 3. `[writable]` payer: [AccountInfo] Paid the refund account's rent, which closing it returns
+pub fn handler(
+	ctx: Context<WithdrawBid>,
+) -> Result<()> {
+	// The outbid amounts go to the bidder; closing the refund account then
+	// returns its rent to whoever paid it.
+	transfer_lamports_from_program_account(
+		&ctx.accounts.bid_refund.to_account_info(),
+		&ctx.accounts.bidder.to_account_info(),
+		ctx.accounts.bid_refund.amount,
+	)?;
+	Ok(())
+}
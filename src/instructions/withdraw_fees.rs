@@ -0,0 +1,76 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+use crate::events::FeesWithdrawn;
+use crate::utils::transfer_lamports_from_program_account;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct WithdrawFees<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			has_one = admin @ MetaplexNftProgramError::InvalidAuthority,
+			has_one = treasury,
+			seeds = [
+				b"program_config",
+			],
+			bump = program_config.bump,
+		)]
+		pub program_config: Account<'info, ProgramConfig>,
+
+		pub admin: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"treasury",
+			],
+			bump = treasury.bump,
+		)]
+		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			mut,
+		)]
+		/// CHECK: any account may receive the fees
+		pub destination: UncheckedAccount<'info>,
+	}
+
+/// Sweeps the protocol fees collected in the treasury
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` program_config: [ProgramConfig] The program config
+/// 2. `[signer]` admin: [AccountInfo] The program admin
+/// 3. `[writable]` treasury: [Treasury] The fee treasury
+/// 4. `[writable]` destination: [AccountInfo] Receives the fees
+pub fn handler(
+	ctx: Context<WithdrawFees>,
+) -> Result<()> {
+	// The treasury keeps its rent-exempt minimum.
+	let treasury = ctx.accounts.treasury.to_account_info();
+	let rent_exempt = Rent::get()?.minimum_balance(treasury.data_len());
+	let amount = treasury.lamports().saturating_sub(rent_exempt);
+	require!(amount > 0, MetaplexNftProgramError::NoFeesToWithdraw);
+
+	transfer_lamports_from_program_account(
+		&treasury,
+		&ctx.accounts.destination.to_account_info(),
+		amount,
+	)?;
+
+	emit!(FeesWithdrawn {
+		admin: ctx.accounts.admin.key(),
+		destination: ctx.accounts.destination.key(),
+		amount,
+	});
+
+	Ok(())
+}
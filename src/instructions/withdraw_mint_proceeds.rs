@@ -0,0 +1,79 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+use crate::utils::transfer_lamports_from_program_account;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct WithdrawMintProceeds<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			has_one = authority @ MetaplexNftProgramError::InvalidAuthority,
+			seeds = [
+				b"collection",
+				collection.seed_authority.as_ref(),
+			],
+			bump,
+		)]
+		pub collection: Account<'info, NftCollection>,
+
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = collection,
+			seeds = [
+				b"mint_config",
+				collection.key().as_ref(),
+			],
+			bump = mint_config.bump,
+		)]
+		pub mint_config: Account<'info, MintConfig>,
+
+		#[account(
+			mut,
+		)]
+		/// CHECK: any account chosen by the collection authority
+		pub destination: UncheckedAccount<'info>,
+	}
+
+/// Withdraws the mint proceeds at the clearing price once the drop has concluded, leaving the rebates for the minters
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` collection: [NftCollection] The collection the drop belongs to
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+/// 3. `[writable]` mint_config: [MintConfig] The collection's mint pricing
+/// 4. `[writable]` destination: [AccountInfo] Receives the proceeds
+pub fn handler(
+	ctx: Context<WithdrawMintProceeds>,
+) -> Result<()> {
+	let clearing_price = ctx.accounts.mint_config
+		.clearing_price(Clock::get()?.unix_timestamp)
+		.ok_or(MetaplexNftProgramError::MintNotConcluded)?;
+
+	// Everything above the clearing price belongs to the minters as rebates,
+	// and batch mints by the authority were never paid for.
+	let proceeds = ctx.accounts.mint_config.paid_mints
+		.checked_mul(clearing_price)
+		.ok_or(MetaplexNftProgramError::ArithmeticOverflow)?;
+	let amount = proceeds - ctx.accounts.mint_config.withdrawn;
+	if amount > 0 {
+		transfer_lamports_from_program_account(
+			&ctx.accounts.mint_config.to_account_info(),
+			&ctx.accounts.destination.to_account_info(),
+			amount,
+		)?;
+	}
+
+	ctx.accounts.mint_config.withdrawn = proceeds;
+
+	Ok(())
+}
@@ -1,11 +1,14 @@
+// Anchor's generated IDL instructions still resize accounts with the deprecated `realloc`.
+#![allow(deprecated)]
 
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 use anchor_lang::prelude::*;
-use std::str::FromStr;
 
 pub use constants::*;
 pub use instructions::*;
@@ -29,8 +32,10 @@ pub mod metaplex_nft_program {
 /// - name: [String] Name of the collection
 /// - symbol: [String] Symbol of the collection
 /// - uri: [String] URI to the collection metadata
-	pub fn initialize_collection(ctx: Context<InitializeCollection>, name: String, symbol: String, uri: String) -> Result<()> {
-		initialize_collection::handler(ctx, name, symbol, uri)
+/// - content_hash: [Option<[u8; 32]>] SHA-256 hash of the collection metadata JSON, if committed
+/// - reveal_root: [Option<[u8; 32]>] Merkle root of the final NFT metadata for a placeholder launch, revealed later with `reveal`
+	pub fn initialize_collection(ctx: Context<InitializeCollection>, name: String, symbol: String, uri: String, content_hash: Option<[u8; 32]>, reveal_root: Option<[u8; 32]>) -> Result<()> {
+		initialize_collection::handler(ctx, name, symbol, uri, content_hash, reveal_root)
 	}
 
 /// Mints a new NFT to a collection
@@ -48,14 +53,21 @@ pub mod metaplex_nft_program {
 /// 9. `[]` wallet: [AccountInfo] Wallet address for the new associated token account
 /// 10. `[]` token_program: [AccountInfo] SPL Token program
 /// 11. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
-/// 12. `[]` csl_spl_assoc_token_v0_0_0: [AccountInfo] Auto-generated, CslSplAssocTokenProgram v0.0.0
+/// 12. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 13. `[writable]` mint_config: [MintConfig] The collection's mint pricing; may be uninitialized for free mints
+/// 14. `[writable]` mint_receipt: [MintReceipt] (optional) Records the price paid; required when the collection has mint pricing
+/// 15. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 16. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+/// 17. `[writable]` treasury: [AccountInfo] The fee treasury, credited with the protocol fee once the program config exists
+/// 18. `[writable]` item_index: [ItemIndex] The collection's item index entry for the new NFT, at the collection's current nft_count
 ///
 /// Data:
 /// - name: [String] Name of the NFT
 /// - symbol: [String] Symbol of the NFT
-/// - uri: [String] URI to the NFT metadata
-	pub fn mint_nft(ctx: Context<MintNft>, name: String, symbol: String, uri: String) -> Result<()> {
-		mint_nft::handler(ctx, name, symbol, uri)
+/// - uri: [String] URI to the NFT metadata, or its suffix when the collection has a base URI
+/// - content_hash: [Option<[u8; 32]>] SHA-256 hash of the NFT metadata JSON, if committed
+	pub fn mint_nft(ctx: Context<MintNft>, name: String, symbol: String, uri: String, content_hash: Option<[u8; 32]>) -> Result<()> {
+		mint_nft::handler(ctx, name, symbol, uri, content_hash)
 	}
 
 /// Updates the metadata of an existing NFT
@@ -66,12 +78,16 @@ pub mod metaplex_nft_program {
 /// 2. `[writable]` metadata: [NftMint] The metadata account to update
 /// 3. `[]` mint: [Mint] The mint account of the NFT
 /// 4. `[signer]` authority: [AccountInfo] The authority of the collection
+/// 5. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+/// 6. `[writable]` history: [MetadataHistory] The NFT's revision history, created if needed
+/// 7. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - name: [String] New name of the NFT
-/// - uri: [String] New URI to the NFT metadata
-	pub fn update_nft_metadata(ctx: Context<UpdateNftMetadata>, name: String, uri: String) -> Result<()> {
-		update_nft_metadata::handler(ctx, name, uri)
+/// - uri: [String] New URI to the NFT metadata, or its suffix when the collection has a base URI
+/// - content_hash: [Option<[u8; 32]>] SHA-256 hash of the new NFT metadata JSON, if committed
+	pub fn update_nft_metadata(ctx: Context<UpdateNftMetadata>, name: String, uri: String, content_hash: Option<[u8; 32]>) -> Result<()> {
+		update_nft_metadata::handler(ctx, name, uri, content_hash)
 	}
 
 /// Transfers an NFT to another wallet
@@ -80,20 +96,1005 @@ pub mod metaplex_nft_program {
 /// 0. `[writable, signer]` fee_payer: [AccountInfo] 
 /// 1. `[]` mint: [Mint] The mint account of the NFT
 /// 2. `[writable]` metadata: [NftMint] The metadata account of the NFT
-/// 3. `[signer]` owner: [AccountInfo] The current owner of the NFT
-/// 4. `[]` new_owner: [AccountInfo] The new owner of the NFT
+/// 3. `[]` owner: [AccountInfo] The current owner of the NFT
+/// 4. `[]` new_owner: [AccountInfo] The new owner of the NFT; must sign zero-price transfers of royalty-enforced NFTs
 /// 5. `[writable, signer]` funding: [AccountInfo] Funding account (must be a system account)
 /// 6. `[writable]` assoc_token_account: [AccountInfo] Associated token account address to be created
 /// 7. `[]` wallet: [AccountInfo] Wallet address for the new associated token account
 /// 8. `[]` system_program: [AccountInfo] System program
 /// 9. `[]` token_program: [AccountInfo] SPL Token program
-/// 10. `[writable]` source: [AccountInfo] The source account.
-/// 11. `[writable]` destination: [AccountInfo] The destination account.
-/// 12. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
-/// 13. `[]` csl_spl_assoc_token_v0_0_0: [AccountInfo] Auto-generated, CslSplAssocTokenProgram v0.0.0
+/// 10. `[writable]` source: [TokenAccount] The source account.
+/// 11. `[writable]` destination: [TokenAccount] The destination account.
+/// 12. `[signer]` authority: [AccountInfo] The owner of the NFT, or its approved delegate
+/// 13. `[]` associated_token_program: [AccountInfo] Associated Token program
 /// 14. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
-	pub fn transfer_nft(ctx: Context<TransferNft>) -> Result<()> {
-		transfer_nft::handler(ctx)
+/// 15. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 16. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 17. `[]` rule_set: [AccountInfo] The rule set of the NFT's collection
+/// 18. `[]` instructions: [AccountInfo] Instructions sysvar, to find the calling program
+/// 19. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Remaining accounts:
+/// - `[writable]` creators: the collection creators, in the order stored on the collection; required when a price is declared for a royalty-enforced collection
+///
+/// Data:
+/// - price: [u64] Sale price in lamports the transfer settles, or 0 for a gift or a move between own wallets co-signed by the new owner; royalty-enforced collections charge the royalty on it to the funding account
+	pub fn transfer_nft<'info>(ctx: Context<'_, '_, 'info, 'info, TransferNft<'info>>, price: u64) -> Result<()> {
+		transfer_nft::handler(ctx, price)
+	}
+
+/// Mints several NFTs to a collection in a single instruction
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` collection: [NftCollection] The collection to mint the NFTs to
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+/// 3. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 5. `[]` token_program: [AccountInfo] SPL Token program
+/// 6. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 7. `[]` csl_spl_assoc_token_v0_0_0: [AccountInfo] Auto-generated, CslSplAssocTokenProgram v0.0.0
+/// 8. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+/// 9. `[writable]` treasury: [AccountInfo] The fee treasury, credited with the protocol fee once the program config exists
+/// 10. `[writable]` mint_config: [MintConfig] The collection's mint pricing, whose supply cap the batch counts against; may be uninitialized for free mints
+///
+/// Data:
+/// - entries: [Vec<MintBatchEntry>] One entry per NFT; each consumes mint, metadata, assoc_token_account, wallet and item_index from the remaining accounts, in that order
+	pub fn mint_batch<'info>(ctx: Context<'_, '_, 'info, 'info, MintBatch<'info>>, entries: Vec<MintBatchEntry>) -> Result<()> {
+		mint_batch::handler(ctx, entries)
+	}
+
+/// Sets the royalty paid to the collection creators on marketplace sales
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` collection: [NftCollection] The collection to configure
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+///
+/// Data:
+/// - seller_fee_basis_points: [u16] Royalty in basis points of the sale price
+/// - creators: [Vec<CollectionCreator>] Creators sharing the royalty; shares must add up to 100
+	pub fn set_collection_royalties(ctx: Context<SetCollectionRoyalties>, seller_fee_basis_points: u16, creators: Vec<CollectionCreator>) -> Result<()> {
+		set_collection_royalties::handler(ctx, seller_fee_basis_points, creators)
+	}
+
+/// Lists an NFT for sale at a fixed price, moving it into an escrow owned by the listing
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` seller: [AccountInfo] The owner of the NFT
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[writable]` listing: [Listing] The listing account to initialize
+/// 6. `[writable]` seller_token_account: [TokenAccount] The seller's token account holding the NFT
+/// 7. `[writable]` escrow_token_account: [TokenAccount] Escrow token account owned by the listing
+/// 8. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 9. `[]` system_program: [AccountInfo] System program
+/// 10. `[]` token_program: [AccountInfo] SPL Token program
+/// 11. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 12. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 13. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Data:
+/// - price: [u64] Sale price in lamports
+	pub fn list_nft(ctx: Context<ListNft>, price: u64) -> Result<()> {
+		list_nft::handler(ctx, price)
+	}
+
+/// Cancels a listing and returns the escrowed NFT to the seller
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` seller: [AccountInfo] The seller that created the listing
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[writable]` listing: [Listing] The listing to close
+/// 6. `[writable]` seller_token_account: [TokenAccount] The seller's token account
+/// 7. `[writable]` escrow_token_account: [TokenAccount] Escrow token account owned by the listing
+/// 8. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 9. `[]` token_program: [AccountInfo] SPL Token program
+/// 10. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+	pub fn delist_nft(ctx: Context<DelistNft>) -> Result<()> {
+		delist_nft::handler(ctx)
+	}
+
+/// Buys a listed NFT, paying the seller and the collection royalties
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` buyer: [AccountInfo] The buyer of the NFT
+/// 2. `[writable]` seller: [AccountInfo] The seller recorded on the listing
+/// 3. `[]` mint: [Mint] The mint account of the NFT
+/// 4. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 5. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 6. `[writable]` listing: [Listing] The listing to fill
+/// 7. `[writable]` listing_token_account: [TokenAccount] The token account recorded on the listing: the escrow, or the seller's account for in-place listings
+/// 8. `[writable]` buyer_token_account: [TokenAccount] The buyer's token account, created if needed
+/// 9. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 10. `[]` system_program: [AccountInfo] System program
+/// 11. `[]` token_program: [AccountInfo] SPL Token program
+/// 12. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 13. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 14. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Remaining accounts:
+/// - `[writable]` creators: the collection creators, in the order stored on the collection
+///
+/// Data:
+/// - max_price: [u64] Highest price in lamports the buyer accepts; guards against the seller raising the price
+	pub fn buy_nft<'info>(ctx: Context<'_, '_, 'info, 'info, BuyNft<'info>>, max_price: u64) -> Result<()> {
+		buy_nft::handler(ctx, max_price)
+	}
+
+/// Lists an NFT for sale without escrow: the listing becomes delegate of the seller's token account, which is frozen until the NFT is sold or delisted
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` seller: [AccountInfo] The owner of the NFT
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT, marked as listed
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[writable]` listing: [Listing] The listing account to initialize
+/// 6. `[writable]` seller_token_account: [TokenAccount] The seller's token account holding the NFT
+/// 7. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 8. `[]` rule_set: [AccountInfo] The rule set of the NFT's collection
+/// 9. `[]` instructions: [AccountInfo] Instructions sysvar, to find the calling program
+/// 10. `[]` system_program: [AccountInfo] System program
+/// 11. `[]` token_program: [AccountInfo] SPL Token program
+/// 12. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 13. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Data:
+/// - price: [u64] Sale price in lamports
+	pub fn list_nft_in_place(ctx: Context<ListNftInPlace>, price: u64) -> Result<()> {
+		list_nft_in_place::handler(ctx, price)
+	}
+
+/// Cancels an in-place listing, thawing the seller's token account and revoking the listing delegate
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` seller: [AccountInfo] The seller that created the listing
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT, no longer marked as listed
+/// 4. `[writable]` listing: [Listing] The listing to close
+/// 5. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 6. `[writable]` seller_token_account: [TokenAccount] The seller's token account
+/// 7. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 8. `[]` token_program: [AccountInfo] SPL Token program
+/// 9. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+	pub fn delist_nft_in_place(ctx: Context<DelistNftInPlace>) -> Result<()> {
+		delist_nft_in_place::handler(ctx)
+	}
+
+/// Places an offer on a specific NFT, or on any NFT of a collection, escrowing the offered lamports in the bid account
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` bidder: [AccountInfo] The account making the offer
+/// 2. `[]` collection: [NftCollection] The collection the offer targets
+/// 3. `[writable]` bid: [Bid] The bid account to initialize
+/// 4. `[]` system_program: [AccountInfo] System program
+/// 5. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Data:
+/// - mint: [Option<Pubkey>] The NFT the offer is for, or none for any NFT of the collection
+/// - amount: [u64] Offered amount in lamports
+/// - expires_at: [i64] Unix timestamp after which the offer can no longer be accepted
+	pub fn place_bid(ctx: Context<PlaceBid>, mint: Option<Pubkey>, amount: u64, expires_at: i64) -> Result<()> {
+		place_bid::handler(ctx, mint, amount, expires_at)
+	}
+
+/// Cancels an offer and refunds the escrowed lamports to the bidder
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` bidder: [AccountInfo] The account that made the offer
+/// 2. `[writable]` bid: [Bid] The bid to cancel
+	pub fn cancel_bid(ctx: Context<CancelBid>) -> Result<()> {
+		cancel_bid::handler(ctx)
+	}
+
+/// Accepts an offer: the NFT goes to the bidder and the escrowed lamports, minus royalties, go to the seller
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` seller: [AccountInfo] The current owner of the NFT
+/// 2. `[writable]` bidder: [AccountInfo] The bidder recorded on the bid
+/// 3. `[]` mint: [Mint] The mint account of the NFT
+/// 4. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 5. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 6. `[writable]` bid: [Bid] The bid to accept
+/// 7. `[writable]` seller_token_account: [TokenAccount] The seller's token account holding the NFT
+/// 8. `[writable]` bidder_token_account: [TokenAccount] The bidder's token account, created if needed
+/// 9. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 10. `[]` system_program: [AccountInfo] System program
+/// 11. `[]` token_program: [AccountInfo] SPL Token program
+/// 12. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 13. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 14. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Remaining accounts:
+/// - `[writable]` creators: the collection creators, in the order stored on the collection
+///
+/// Data:
+/// - min_amount: [u64] Lowest bid amount in lamports the seller accepts; guards against the bidder lowering the bid
+	pub fn accept_bid<'info>(ctx: Context<'_, '_, 'info, 'info, AcceptBid<'info>>, min_amount: u64) -> Result<()> {
+		accept_bid::handler(ctx, min_amount)
+	}
+
+/// Puts an NFT up for an English auction, moving it into an escrow owned by the auction
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` seller: [AccountInfo] The owner of the NFT
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[writable]` auction: [Auction] The auction account to initialize
+/// 6. `[writable]` seller_token_account: [TokenAccount] The seller's token account holding the NFT
+/// 7. `[writable]` escrow_token_account: [TokenAccount] Escrow token account owned by the auction
+/// 8. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 9. `[]` system_program: [AccountInfo] System program
+/// 10. `[]` token_program: [AccountInfo] SPL Token program
+/// 11. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 12. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 13. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Data:
+/// - reserve_price: [u64] Lowest accepted bid in lamports
+/// - start_time: [i64] Unix timestamp at which bidding opens
+/// - end_time: [i64] Unix timestamp at which bidding closes
+/// - min_increment: [u64] Minimum amount in lamports by which a bid must beat the current highest bid
+/// - extension_window: [i64] Seconds before the end in which a new bid extends the auction to this many seconds from the bid
+	pub fn create_auction(ctx: Context<CreateAuction>, reserve_price: u64, start_time: i64, end_time: i64, min_increment: u64, extension_window: i64) -> Result<()> {
+		create_auction::handler(ctx, reserve_price, start_time, end_time, min_increment, extension_window)
+	}
+
+/// Bids on an auction, escrowing the bid and crediting the outbid amount to the previous highest bidder's refund account
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` bidder: [AccountInfo] The account placing the bid
+/// 2. `[writable]` auction: [Auction] The auction to bid on
+/// 3. `[]` collection: [NftCollection] The collection of the auctioned NFT
+/// 4. `[writable]` previous_bid_refund: [BidRefund] Refund account of the current highest bidder, credited with the outbid amount and created if needed; required once the auction has a bid
+/// 5. `[]` system_program: [AccountInfo] System program
+/// 6. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Data:
+/// - amount: [u64] Bid in lamports
+	pub fn place_auction_bid(ctx: Context<PlaceAuctionBid>, amount: u64) -> Result<()> {
+		place_auction_bid::handler(ctx, amount)
+	}
+
+/// Settles an ended auction: the NFT goes to the winner, or back to the seller without bids, and the seller and creators are paid
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Anyone; pays for the recipient's token account if needed
+/// 1. `[writable]` seller: [AccountInfo] The seller recorded on the auction
+/// 2. `[]` recipient: [AccountInfo] The highest bidder, or the seller if there were no bids
+/// 3. `[]` mint: [Mint] The mint account of the NFT
+/// 4. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 5. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 6. `[writable]` auction: [Auction] The auction to settle
+/// 7. `[writable]` escrow_token_account: [TokenAccount] Escrow token account owned by the auction
+/// 8. `[writable]` recipient_token_account: [TokenAccount] The recipient's token account, created if needed
+/// 9. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 10. `[]` system_program: [AccountInfo] System program
+/// 11. `[]` token_program: [AccountInfo] SPL Token program
+/// 12. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 13. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 14. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Remaining accounts:
+/// - `[writable]` creators: the collection creators, in the order stored on the collection
+	pub fn settle_auction<'info>(ctx: Context<'_, '_, 'info, 'info, SettleAuction<'info>>) -> Result<()> {
+		settle_auction::handler(ctx)
+	}
+
+/// Configures a falling mint price for the collection; can only be changed before the first mint
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` collection: [NftCollection] The collection to configure
+/// 2. `[writable]` mint_config: [MintConfig] The mint pricing account, created if needed
+/// 3. `[signer]` authority: [AccountInfo] The authority of the collection
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - start_price: [u64] Mint price in lamports when minting opens
+/// - end_price: [u64] Floor the price never falls below
+/// - start_time: [i64] Unix timestamp at which minting opens
+/// - decay_interval: [i64] Seconds between two price decreases
+/// - price_step: [u64] Lamports the price falls by every decay interval
+/// - max_supply: [u64] Number of NFTs sold through this drop
+	pub fn set_mint_config(ctx: Context<SetMintConfig>, start_price: u64, end_price: u64, start_time: i64, decay_interval: i64, price_step: u64, max_supply: u64) -> Result<()> {
+		set_mint_config::handler(ctx, start_price, end_price, start_time, decay_interval, price_step, max_supply)
+	}
+
+/// Refunds a minter the difference between the price they paid and the final clearing price, closing their mint receipt
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Anyone; the rebate always goes to the minter
+/// 1. `[writable]` minter: [AccountInfo] The account that paid for the mint
+/// 2. `[writable]` mint_config: [MintConfig] The collection's mint pricing
+/// 3. `[writable]` mint_receipt: [MintReceipt] The receipt of the mint to refund
+	pub fn claim_mint_rebate(ctx: Context<ClaimMintRebate>) -> Result<()> {
+		claim_mint_rebate::handler(ctx)
+	}
+
+/// Withdraws the mint proceeds at the clearing price once the drop has concluded, leaving the rebates for the minters
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` collection: [NftCollection] The collection the drop belongs to
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+/// 3. `[writable]` mint_config: [MintConfig] The collection's mint pricing
+/// 4. `[writable]` destination: [AccountInfo] Receives the proceeds
+	pub fn withdraw_mint_proceeds(ctx: Context<WithdrawMintProceeds>) -> Result<()> {
+		withdraw_mint_proceeds::handler(ctx)
+	}
+
+/// Offers the usage rights of an NFT for rent at a daily price, without giving up ownership
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[signer]` owner: [AccountInfo] The owner of the NFT
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[writable]` rental_offer: [RentalOffer] The rental offer to initialize
+/// 6. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 7. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Data:
+/// - price_per_day: [u64] Rent in lamports per day
+/// - max_days: [u32] Longest rental the owner accepts, in days
+	pub fn create_rental_offer(ctx: Context<CreateRentalOffer>, price_per_day: u64, max_days: u32) -> Result<()> {
+		create_rental_offer::handler(ctx, price_per_day, max_days)
+	}
+
+/// Rents an NFT, paying the owner and becoming its user until the rental expires
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` renter: [AccountInfo] The account renting the NFT
+/// 2. `[writable]` owner: [AccountInfo] The owner of the NFT, who receives the rent
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[]` rental_offer: [RentalOffer] The rental offer to take
+/// 6. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 7. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Data:
+/// - days: [u32] Rental duration in days
+/// - expected_price_per_day: [u64] Daily price the renter agreed to; the rental fails if the offer differs
+/// - expected_max_days: [u32] Maximum duration the renter saw on the offer; the rental fails if the offer differs
+	pub fn rent_nft(ctx: Context<RentNft>, days: u32, expected_price_per_day: u64, expected_max_days: u32) -> Result<()> {
+		rent_nft::handler(ctx, days, expected_price_per_day, expected_max_days)
+	}
+
+/// Clears the user of an NFT once its rental has expired; callable by anyone
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` metadata: [NftMint] The metadata account of the rented NFT
+	pub fn end_rental(ctx: Context<EndRental>) -> Result<()> {
+		end_rental::handler(ctx)
+	}
+
+/// Withdraws a rental offer so the NFT can no longer be rented
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` owner: [AccountInfo] The owner who created the offer
+/// 2. `[writable]` rental_offer: [RentalOffer] The rental offer to close
+	pub fn close_rental_offer(ctx: Context<CloseRentalOffer>) -> Result<()> {
+		close_rental_offer::handler(ctx)
+	}
+
+/// Creates the staking pool of a collection; the reward mint must already have the pool PDA as mint authority
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` collection: [NftCollection] The collection whose NFTs can be staked
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+/// 3. `[writable]` stake_pool: [StakePool] The stake pool to initialize
+/// 4. `[]` reward_mint: [Mint] The pool's reward token mint
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - reward_rate: [u64] Reward tokens, in base units, earned per staked NFT per second
+	pub fn create_stake_pool(ctx: Context<CreateStakePool>, reward_rate: u64) -> Result<()> {
+		create_stake_pool::handler(ctx, reward_rate)
+	}
+
+/// Stakes an NFT without moving it: the stake entry becomes delegate of the owner's token account, which stays frozen until unstaked
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[signer]` owner: [AccountInfo] The owner of the NFT
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[]` stake_pool: [StakePool] The stake pool of the NFT's collection
+/// 6. `[writable]` stake_entry: [StakeEntry] The stake entry to initialize
+/// 7. `[writable]` owner_token_account: [TokenAccount] The owner's token account holding the NFT
+/// 8. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 9. `[]` rule_set: [AccountInfo] The rule set of the NFT's collection
+/// 10. `[]` instructions: [AccountInfo] Instructions sysvar, to find the calling program
+/// 11. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 12. `[]` token_program: [AccountInfo] SPL Token program
+/// 13. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 14. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+	pub fn stake_nft(ctx: Context<StakeNft>) -> Result<()> {
+		stake_nft::handler(ctx)
+	}
+
+/// Mints the rewards a staked NFT has accrued since its last claim
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[signer]` owner: [AccountInfo] The owner of the staked NFT
+/// 2. `[]` stake_pool: [StakePool] The stake pool of the NFT's collection
+/// 3. `[]` collection: [NftCollection] The collection of the stake pool
+/// 4. `[writable]` stake_entry: [StakeEntry] The stake entry of the NFT
+/// 5. `[writable]` reward_mint: [Mint] The pool's reward token mint
+/// 6. `[writable]` owner_reward_account: [TokenAccount] The owner's reward token account, created if needed
+/// 7. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 8. `[]` token_program: [AccountInfo] SPL Token program
+/// 9. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 10. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 11. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+	pub fn claim_stake_rewards(ctx: Context<ClaimStakeRewards>) -> Result<()> {
+		claim_stake_rewards::handler(ctx)
+	}
+
+/// Pays the pending rewards, thaws the NFT and closes its stake entry
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` owner: [AccountInfo] The owner of the staked NFT
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[]` stake_pool: [StakePool] The stake pool of the NFT's collection
+/// 6. `[writable]` stake_entry: [StakeEntry] The stake entry to close
+/// 7. `[writable]` owner_token_account: [TokenAccount] The owner's token account holding the NFT
+/// 8. `[writable]` reward_mint: [Mint] The pool's reward token mint
+/// 9. `[writable]` owner_reward_account: [TokenAccount] The owner's reward token account, created if needed
+/// 10. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 11. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 12. `[]` token_program: [AccountInfo] SPL Token program
+/// 13. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 14. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 15. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+	pub fn unstake_nft(ctx: Context<UnstakeNft>) -> Result<()> {
+		unstake_nft::handler(ctx)
+	}
+
+/// Locks an NFT in a vault and mints a fixed supply of fungible shares to the depositor
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[signer]` owner: [AccountInfo] The owner of the NFT
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[writable]` vault: [Vault] The vault to initialize
+/// 6. `[writable]` share_mint: [Mint] The share mint to initialize
+/// 7. `[writable]` owner_token_account: [TokenAccount] The owner's token account holding the NFT
+/// 8. `[writable]` vault_token_account: [TokenAccount] The vault's token account receiving the NFT
+/// 9. `[writable]` owner_share_account: [TokenAccount] The owner's share token account, created if needed
+/// 10. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 11. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 12. `[]` token_program: [AccountInfo] SPL Token program
+/// 13. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 14. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 15. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Data:
+/// - total_shares: [u64] Number of shares minted to the depositor; must be greater than zero
+/// - reserve_price: [u64] Price in lamports of the whole NFT in a buyout
+	pub fn fractionalize_nft(ctx: Context<FractionalizeNft>, total_shares: u64, reserve_price: u64) -> Result<()> {
+		fractionalize_nft::handler(ctx, total_shares, reserve_price)
+	}
+
+/// Buys the NFT out of a vault: a share holder burns their shares and pays the reserve price for the remaining shares into the vault
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` buyer: [AccountInfo] A share holder buying the NFT
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[writable]` vault: [Vault] The vault holding the NFT
+/// 6. `[writable]` share_mint: [Mint] The vault's share mint
+/// 7. `[writable]` buyer_share_account: [TokenAccount] The buyer's share token account
+/// 8. `[writable]` vault_token_account: [TokenAccount] The vault's token account holding the NFT
+/// 9. `[writable]` buyer_token_account: [TokenAccount] The buyer's token account receiving the NFT, created if needed
+/// 10. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 11. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 12. `[]` token_program: [AccountInfo] SPL Token program
+/// 13. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 14. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 15. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Remaining accounts:
+/// - `[writable]` creators: the collection creators, in the order stored on the collection; required for royalty-enforced collections
+	pub fn buyout_vault<'info>(ctx: Context<'_, '_, 'info, 'info, BuyoutVault<'info>>) -> Result<()> {
+		buyout_vault::handler(ctx)
+	}
+
+/// Burns a holder's shares of a bought-out vault and pays out their part of the buyout proceeds
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` holder: [AccountInfo] The share holder
+/// 2. `[writable]` vault: [Vault] The bought-out vault
+/// 3. `[writable]` share_mint: [Mint] The vault's share mint
+/// 4. `[writable]` holder_share_account: [TokenAccount] The holder's share token account
+/// 5. `[]` token_program: [AccountInfo] SPL Token program
+/// 6. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+	pub fn redeem_shares(ctx: Context<RedeemShares>) -> Result<()> {
+		redeem_shares::handler(ctx)
+	}
+
+/// Offers a loan against any NFT of a collection, escrowing the principal in the loan account
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` lender: [AccountInfo] The account lending the SOL
+/// 2. `[]` collection: [NftCollection] The collection accepted as collateral
+/// 3. `[writable]` loan: [Loan] The loan account to initialize
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 5. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Data:
+/// - offer_id: [u64] Lender-chosen identifier distinguishing their offers
+/// - amount: [u64] Principal in lamports
+/// - duration: [i64] Seconds the borrower has to repay once the loan is taken
+/// - interest: [u64] Interest in lamports owed on top of the principal
+	pub fn create_loan_offer(ctx: Context<CreateLoanOffer>, offer_id: u64, amount: u64, duration: i64, interest: u64) -> Result<()> {
+		create_loan_offer::handler(ctx, offer_id, amount, duration, interest)
+	}
+
+/// Withdraws a loan offer that has not been taken, refunding the principal to the lender
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` lender: [AccountInfo] The lender who made the offer
+/// 2. `[writable]` loan: [Loan] The loan offer to cancel
+	pub fn cancel_loan_offer(ctx: Context<CancelLoanOffer>) -> Result<()> {
+		cancel_loan_offer::handler(ctx)
+	}
+
+/// Accepts a loan offer: the NFT moves into an escrow owned by the loan and the principal goes to the borrower
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` borrower: [AccountInfo] The owner of the NFT used as collateral
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[writable]` loan: [Loan] The loan offer to accept
+/// 6. `[writable]` borrower_token_account: [TokenAccount] The borrower's token account holding the NFT
+/// 7. `[writable]` escrow_token_account: [TokenAccount] Escrow token account owned by the loan
+/// 8. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 9. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 10. `[]` token_program: [AccountInfo] SPL Token program
+/// 11. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 12. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 13. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Data:
+/// - expected_amount: [u64] Principal the borrower agreed to; the loan fails if the offer differs
+/// - expected_duration: [i64] Repayment period the borrower agreed to; the loan fails if the offer differs
+/// - expected_interest: [u64] Interest the borrower agreed to; the loan fails if the offer differs
+	pub fn take_loan(ctx: Context<TakeLoan>, expected_amount: u64, expected_duration: i64, expected_interest: u64) -> Result<()> {
+		take_loan::handler(ctx, expected_amount, expected_duration, expected_interest)
+	}
+
+/// Repays a loan with interest before its deadline and returns the NFT to the borrower
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` borrower: [AccountInfo] The borrower of the loan
+/// 2. `[writable]` lender: [AccountInfo] The lender, who receives the repayment
+/// 3. `[]` mint: [Mint] The mint account of the NFT
+/// 4. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 5. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 6. `[writable]` loan: [Loan] The loan to repay
+/// 7. `[writable]` escrow_token_account: [TokenAccount] Escrow token account owned by the loan
+/// 8. `[writable]` borrower_token_account: [TokenAccount] The borrower's token account receiving the NFT
+/// 9. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 10. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 11. `[]` token_program: [AccountInfo] SPL Token program
+/// 12. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+	pub fn repay_loan(ctx: Context<RepayLoan>) -> Result<()> {
+		repay_loan::handler(ctx)
+	}
+
+/// Claims the collateral of a loan that was not repaid before its deadline
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` lender: [AccountInfo] The lender of the loan
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[writable]` loan: [Loan] The defaulted loan
+/// 6. `[writable]` escrow_token_account: [TokenAccount] Escrow token account owned by the loan
+/// 7. `[writable]` lender_token_account: [TokenAccount] The lender's token account receiving the NFT, created if needed
+/// 8. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 9. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 10. `[]` token_program: [AccountInfo] SPL Token program
+/// 11. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 12. `[]` associated_token_program: [AccountInfo] Associated Token program
+/// 13. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Remaining accounts:
+/// - `[writable]` creators: the collection creators, in the order stored on the collection; required for royalty-enforced collections
+	pub fn foreclose_loan<'info>(ctx: Context<'_, '_, 'info, 'info, ForecloseLoan<'info>>) -> Result<()> {
+		foreclose_loan::handler(ctx)
+	}
+
+/// Turns on royalty enforcement for a collection: its tokens are frozen from mint and only move through this program. Must be done before the first mint and cannot be undone
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` collection: [NftCollection] The collection to configure
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+	pub fn enable_royalty_enforcement(ctx: Context<EnableRoyaltyEnforcement>) -> Result<()> {
+		enable_royalty_enforcement::handler(ctx)
+	}
+
+/// Sets the programs allowed, or denied, to act as transfer authority or delegate for a collection's NFTs
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` collection: [NftCollection] The collection to configure
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+/// 3. `[writable]` rule_set: [RuleSet] The collection's rule set, created if needed
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - mode: [RuleSetMode] Whether the listed programs are the only ones allowed, or the ones denied
+/// - programs: [Vec<Pubkey>] The program IDs the rule set lists
+	pub fn set_rule_set(ctx: Context<SetRuleSet>, mode: RuleSetMode, programs: Vec<Pubkey>) -> Result<()> {
+		set_rule_set::handler(ctx, mode, programs)
+	}
+
+/// Makes a collection soulbound: its NFTs are frozen from mint and can never be transferred, only burned by their owner. Must be done before the first mint and cannot be undone
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` collection: [NftCollection] The collection to configure
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+	pub fn make_collection_soulbound(ctx: Context<MakeCollectionSoulbound>) -> Result<()> {
+		make_collection_soulbound::handler(ctx)
+	}
+
+/// Burns an NFT held in its owner's wallet, closing its token and metadata accounts
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` owner: [AccountInfo] The owner of the NFT; receives the closed accounts' rent
+/// 2. `[writable]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[writable]` collection: [NftCollection] The collection the NFT belongs to
+/// 5. `[writable]` owner_token_account: [TokenAccount] The owner's token account holding the NFT
+/// 6. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 7. `[]` token_program: [AccountInfo] SPL Token program
+/// 8. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 9. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+	pub fn burn_nft(ctx: Context<BurnNft>) -> Result<()> {
+		burn_nft::handler(ctx)
+	}
+
+/// Approves a delegate that may sign transfer_nft in place of the owner until the NFT is transferred or the delegation revoked
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[signer]` owner: [AccountInfo] The owner of the NFT
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[writable]` owner_token_account: [TokenAccount] The owner's token account holding the NFT
+/// 5. `[]` delegate: [AccountInfo] The account to approve as transfer delegate; it, or the program owning it, must pass the collection's rule set
+/// 6. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 7. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 8. `[]` rule_set: [AccountInfo] The rule set of the NFT's collection
+/// 9. `[]` instructions: [AccountInfo] Instructions sysvar, to find the calling program
+/// 10. `[]` token_program: [AccountInfo] SPL Token program
+/// 11. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 12. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+	pub fn approve_delegate(ctx: Context<ApproveDelegate>) -> Result<()> {
+		approve_delegate::handler(ctx)
+	}
+
+/// Revokes the NFT's transfer delegate
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[signer]` owner: [AccountInfo] The owner of the NFT
+/// 2. `[]` mint: [Mint] The mint account of the NFT
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 4. `[writable]` owner_token_account: [TokenAccount] The owner's token account holding the NFT
+/// 5. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 6. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 7. `[]` token_program: [AccountInfo] SPL Token program
+/// 8. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+	pub fn revoke_delegate(ctx: Context<RevokeDelegate>) -> Result<()> {
+		revoke_delegate::handler(ctx)
+	}
+
+/// Freezes an NFT on behalf of its collection authority, e.g. while it is reported stolen or disputed; it cannot be transferred, listed or updated until thawed
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+/// 3. `[]` mint: [Mint] The mint account of the NFT
+/// 4. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 5. `[]` holder: [AccountInfo] The NFT's current holder, a wallet or one of the program's escrows
+/// 6. `[writable]` holder_token_account: [TokenAccount] The holder's token account holding the NFT
+/// 7. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 8. `[]` token_program: [AccountInfo] SPL Token program
+/// 9. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+	pub fn freeze_nft(ctx: Context<FreezeNft>) -> Result<()> {
+		freeze_nft::handler(ctx)
+	}
+
+/// Lifts a freeze placed by the collection authority
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` collection: [NftCollection] The collection the NFT belongs to
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+/// 3. `[]` mint: [Mint] The mint account of the NFT
+/// 4. `[writable]` metadata: [NftMint] The metadata account of the NFT
+/// 5. `[]` holder: [AccountInfo] The NFT's current holder, a wallet or one of the program's escrows
+/// 6. `[writable]` holder_token_account: [TokenAccount] The holder's token account holding the NFT
+/// 7. `[]` freeze_authority: [AccountInfo] PDA set as freeze authority on the program's mints
+/// 8. `[]` token_program: [AccountInfo] SPL Token program
+/// 9. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+	pub fn thaw_nft(ctx: Context<ThawNft>) -> Result<()> {
+		thaw_nft::handler(ctx)
+	}
+
+/// Creates the program-wide config and sets its admin; must be signed by the program's upgrade authority
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` program_config: [ProgramConfig] The program config account to initialize
+/// 2. `[writable]` treasury: [Treasury] The fee treasury to initialize
+/// 3. `[signer]` upgrade_authority: [AccountInfo] The program's upgrade authority
+/// 4. `[]` program: [AccountInfo] This program
+/// 5. `[]` program_data: [ProgramData] This program's program data account
+/// 6. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - admin: [Pubkey] The key allowed to pause the program and to hand over the admin role
+	pub fn initialize_program_config(ctx: Context<InitializeProgramConfig>, admin: Pubkey) -> Result<()> {
+		initialize_program_config::handler(ctx, admin)
+	}
+
+/// Hands the program admin role over to a new key
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` program_config: [ProgramConfig] The program config
+/// 2. `[signer]` admin: [AccountInfo] The current program admin
+///
+/// Data:
+/// - new_admin: [Pubkey] The new program admin
+	pub fn set_program_admin(ctx: Context<SetProgramAdmin>, new_admin: Pubkey) -> Result<()> {
+		set_program_admin::handler(ctx, new_admin)
+	}
+
+/// Pauses or resumes minting, trading and other NFT activity across every collection
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` program_config: [ProgramConfig] The program config
+/// 2. `[signer]` admin: [AccountInfo] The program admin
+///
+/// Data:
+/// - paused: [bool] Whether the program is paused
+	pub fn set_program_paused(ctx: Context<SetProgramPaused>, paused: bool) -> Result<()> {
+		set_program_paused::handler(ctx, paused)
+	}
+
+/// Pauses or resumes minting, trading and other NFT activity for a collection
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` collection: [NftCollection] The collection to pause or resume
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+///
+/// Data:
+/// - paused: [bool] Whether the collection is paused
+	pub fn set_collection_paused(ctx: Context<SetCollectionPaused>, paused: bool) -> Result<()> {
+		set_collection_paused::handler(ctx, paused)
+	}
+
+/// Sets the protocol fee charged on every mint: a flat amount plus a share of the mint price
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` program_config: [ProgramConfig] The program config
+/// 2. `[signer]` admin: [AccountInfo] The program admin
+///
+/// Data:
+/// - mint_fee_lamports: [u64] Flat fee in lamports charged per minted NFT
+/// - mint_fee_bps: [u16] Fee in basis points of the mint price
+	pub fn set_protocol_fee(ctx: Context<SetProtocolFee>, mint_fee_lamports: u64, mint_fee_bps: u16) -> Result<()> {
+		set_protocol_fee::handler(ctx, mint_fee_lamports, mint_fee_bps)
+	}
+
+/// Sweeps the protocol fees collected in the treasury
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` program_config: [ProgramConfig] The program config
+/// 2. `[signer]` admin: [AccountInfo] The program admin
+/// 3. `[writable]` treasury: [Treasury] The fee treasury
+/// 4. `[writable]` destination: [AccountInfo] Receives the fees
+	pub fn withdraw_fees(ctx: Context<WithdrawFees>) -> Result<()> {
+		withdraw_fees::handler(ctx)
+	}
+
+/// Creates an M-of-N multisig that can act as a collection authority by executing approved proposals
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[signer]` creator: [AccountInfo] The creator of the multisig; only used to derive its address
+/// 2. `[writable]` multisig: [Multisig] The multisig account to initialize
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - nonce: [u64] Creator-chosen identifier distinguishing their multisigs
+/// - signers: [Vec<Pubkey>] The member keys, at most 10
+/// - threshold: [u8] Number of member approvals a proposal needs
+	pub fn create_multisig(ctx: Context<CreateMultisig>, nonce: u64, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+		create_multisig::handler(ctx, nonce, signers, threshold)
+	}
+
+/// Replaces the members and threshold of a multisig; only callable by the multisig itself through an executed proposal
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` multisig: [Multisig] The multisig to update; must sign
+///
+/// Data:
+/// - signers: [Vec<Pubkey>] The new member keys, at most 10
+/// - threshold: [u8] The new number of approvals a proposal needs
+	pub fn set_multisig_signers(ctx: Context<SetMultisigSigners>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+		set_multisig_signers::handler(ctx, signers, threshold)
+	}
+
+/// Proposes an instruction of this program for the multisig to sign, such as a mint, metadata update or pause; the proposer's approval is recorded
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[signer]` proposer: [AccountInfo] A member of the multisig
+/// 2. `[writable]` multisig: [Multisig] The multisig the proposal is for
+/// 3. `[writable]` proposal: [Proposal] The proposal account to initialize, at the multisig's next proposal index
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - accounts: [Vec<ProposalAccountMeta>] Accounts of the proposed instruction; the multisig is marked as signer
+/// - data: [Vec<u8>] Data of the proposed instruction
+	pub fn create_proposal(ctx: Context<CreateProposal>, accounts: Vec<ProposalAccountMeta>, data: Vec<u8>) -> Result<()> {
+		create_proposal::handler(ctx, accounts, data)
+	}
+
+/// Approves a pending proposal as a member of the multisig
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[signer]` signer: [AccountInfo] A member of the multisig
+/// 2. `[]` multisig: [Multisig] The multisig the proposal is for
+/// 3. `[writable]` proposal: [Proposal] The proposal to approve
+	pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+		approve_proposal::handler(ctx)
+	}
+
+/// Executes a proposal once enough members approved it, signing the proposed instruction as the multisig
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [Multisig] The multisig the proposal is for
+/// 2. `[writable]` proposal: [Proposal] The proposal to execute
+/// 3. `[]` program: [AccountInfo] This program, invoked with the proposed instruction
+///
+/// Remaining accounts:
+/// - accounts: the proposed instruction's accounts, in the order stored on the proposal
+	pub fn execute_proposal<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteProposal<'info>>) -> Result<()> {
+		execute_proposal::handler(ctx)
+	}
+
+/// Permanently locks the metadata of a single NFT, or of the whole collection when no NFT is given
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` collection: [NftCollection] The collection to lock, or that the NFT belongs to
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+/// 3. `[writable]` metadata: [NftMint] The metadata account of the NFT to lock, omitted to lock the collection
+	pub fn lock_metadata(ctx: Context<LockMetadata>) -> Result<()> {
+		lock_metadata::handler(ctx)
+	}
+
+/// Sets the maximum length and allowed schemes of metadata URIs in a collection
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` collection: [NftCollection] The collection to configure
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+///
+/// Data:
+/// - max_uri_length: [u16] Maximum URI length, at most the program-wide limit
+/// - allowed_schemes: [Vec<String>] Lowercase URI schemes allowed, such as https, ipfs or ar
+	pub fn set_uri_rules(ctx: Context<SetUriRules>, max_uri_length: u16, allowed_schemes: Vec<String>) -> Result<()> {
+		set_uri_rules::handler(ctx, max_uri_length, allowed_schemes)
+	}
+
+/// Replaces the placeholder metadata of minted, unfrozen and mutable NFTs with their committed final name, uri and content hash
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` collection: [NftCollection] The collection holding the reveal commitment
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+/// 3. `[]` program_config: [AccountInfo] The program config, checked for a program-wide pause
+///
+/// Remaining accounts:
+/// - `[writable]` metadata: one NFT metadata account per entry, in the same order
+///
+/// Data:
+/// - entries: [Vec<RevealEntry>] The revealed metadata and Merkle proof of each NFT; can be split across several transactions
+	pub fn reveal<'info>(ctx: Context<'_, '_, 'info, 'info, Reveal<'info>>, entries: Vec<RevealEntry>) -> Result<()> {
+		reveal::handler(ctx, entries)
+	}
+
+/// Sets or changes the base URI that the collection's NFT URIs are resolved against, re-pointing every NFT at once; not while any NFT is locked
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` collection: [NftCollection] The collection to configure, resized to fit the base URI
+/// 2. `[signer]` authority: [AccountInfo] The authority of the collection
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - base_uri: [String] Base URI, e.g. https://example.com/items/; empty to store full URIs, only possible before the first mint
+	pub fn set_base_uri(ctx: Context<SetBaseUri>, base_uri: String) -> Result<()> {
+		set_base_uri::handler(ctx, base_uri)
+	}
+
+/// Withdraws the lamports credited to an outbid auction bidder, closing the refund account
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable, signer]` bidder: [AccountInfo] The outbid bidder
+/// 2. `[writable]` bid_refund: [BidRefund] The bidder's refund account for the auction
+/// 3. `[writable]` payer: [AccountInfo] Paid the refund account's rent, which closing it returns
+	pub fn withdraw_bid(ctx: Context<WithdrawBid>) -> Result<()> {
+		withdraw_bid::handler(ctx)
+	}
+
+/// Hands a collection over to a new authority, such as a multisig; the collection keeps its address
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` collection: [NftCollection] The collection to hand over
+/// 2. `[signer]` authority: [AccountInfo] The current authority of the collection
+///
+/// Data:
+/// - new_authority: [Pubkey] The new collection authority
+	pub fn set_collection_authority(ctx: Context<SetCollectionAuthority>, new_authority: Pubkey) -> Result<()> {
+		set_collection_authority::handler(ctx, new_authority)
 	}
 
 
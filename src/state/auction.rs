@@ -0,0 +1,29 @@
+
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct Auction {
+	pub seller: Pubkey,
+	pub mint: Pubkey,
+	pub collection: Pubkey,
+	pub reserve_price: u64,
+	pub start_time: i64,
+	pub end_time: i64,
+	pub min_increment: u64,
+	pub extension_window: i64,
+	pub highest_bidder: Pubkey,
+	pub highest_bid: u64,
+	pub bump: u8,
+}
+
+impl Auction {
+	/// Whoever receives the NFT at settlement: the winning bidder, or the
+	/// seller when nobody bid.
+	pub fn recipient(&self) -> Pubkey {
+		if self.highest_bid > 0 {
+			self.highest_bidder
+		} else {
+			self.seller
+		}
+	}
+}
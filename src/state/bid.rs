@@ -0,0 +1,20 @@
+
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct Bid {
+	pub bidder: Pubkey,
+	pub collection: Pubkey,
+	pub mint: Option<Pubkey>,
+	pub amount: u64,
+	pub expires_at: i64,
+	pub bump: u8,
+}
+
+impl Bid {
+	/// The key the bid PDA is seeded with: the targeted mint, or the
+	/// collection for collection-wide offers.
+	pub fn target(&self) -> Pubkey {
+		self.mint.unwrap_or(self.collection)
+	}
+}
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+/// Lamports owed to an outbid auction bidder, withdrawn with `withdraw_bid`.
+#[account]
+pub struct BidRefund {
+	pub auction: Pubkey,
+	pub bidder: Pubkey,
+	pub amount: u64,
+	/// Paid the account's rent when the bidder was first outbid, and gets it
+	/// back once the refund is withdrawn.
+	pub payer: Pubkey,
+	pub bump: u8,
+}
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+/// Maps an item number of a collection to its mint, so items can be looked up
+/// and enumerated by number. Entries of burned NFTs keep pointing at the mint.
+#[account]
+pub struct ItemIndex {
+	pub collection: Pubkey,
+	pub item_number: u64,
+	pub mint: Pubkey,
+	pub bump: u8,
+}
@@ -0,0 +1,13 @@
+
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct Listing {
+	pub seller: Pubkey,
+	pub mint: Pubkey,
+	pub collection: Pubkey,
+	pub price: u64,
+	pub token_account: Pubkey,
+	pub escrowless: bool,
+	pub bump: u8,
+}
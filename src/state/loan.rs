@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct Loan {
+	pub lender: Pubkey,
+	pub collection: Pubkey,
+	pub offer_id: u64,
+	pub amount: u64,
+	pub duration: i64,
+	pub interest: u64,
+	pub borrower: Pubkey,
+	pub mint: Pubkey,
+	pub start_time: i64,
+	pub bump: u8,
+}
+
+impl Loan {
+	/// Whether a borrower has accepted the offer.
+	pub fn is_taken(&self) -> bool {
+		self.borrower != Pubkey::default()
+	}
+
+	/// Last moment at which the loan can be repaid.
+	pub fn deadline(&self) -> i64 {
+		self.start_time.saturating_add(self.duration)
+	}
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::MAX_METADATA_HISTORY;
+
+/// An NFT's metadata as it was before an update.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct MetadataRevision {
+	pub revision: u32,
+	pub name: String,
+	pub uri: String,
+	pub content_hash: Option<[u8; 32]>,
+	/// The authority that replaced this revision.
+	pub updater: Pubkey,
+	/// The slot in which this revision was replaced.
+	pub slot: u64,
+}
+
+#[account]
+pub struct MetadataHistory {
+	pub mint: Pubkey,
+	/// Number of revisions recorded, including those since overwritten.
+	pub total: u32,
+	/// Ring buffer of the last `MAX_METADATA_HISTORY` revisions.
+	pub entries: Vec<MetadataRevision>,
+	pub bump: u8,
+}
+
+impl MetadataHistory {
+	/// Appends `entry`, overwriting the oldest one once the buffer is full.
+	pub fn record(&mut self, entry: MetadataRevision) {
+		if self.entries.len() < MAX_METADATA_HISTORY {
+			self.entries.push(entry);
+		} else {
+			let oldest = self.total as usize % MAX_METADATA_HISTORY;
+			self.entries[oldest] = entry;
+		}
+		self.total = self.total.wrapping_add(1);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn revision(revision: u32) -> MetadataRevision {
+		MetadataRevision {
+			revision,
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn record_fills_then_overwrites_oldest() {
+		let mut history = MetadataHistory {
+			mint: Pubkey::default(),
+			total: 0,
+			entries: Vec::new(),
+			bump: 0,
+		};
+
+		for n in 0..MAX_METADATA_HISTORY as u32 {
+			history.record(revision(n));
+		}
+		assert_eq!(history.entries.len(), MAX_METADATA_HISTORY);
+		assert_eq!(history.entries[0].revision, 0);
+
+		for n in 0..3 {
+			history.record(revision(MAX_METADATA_HISTORY as u32 + n));
+		}
+		assert_eq!(history.entries.len(), MAX_METADATA_HISTORY);
+		assert_eq!(history.total, MAX_METADATA_HISTORY as u32 + 3);
+		let revisions: Vec<u32> = history.entries.iter().map(|entry| entry.revision).collect();
+		assert_eq!(revisions, [8, 9, 10, 3, 4, 5, 6, 7]);
+	}
+}
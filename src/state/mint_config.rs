@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+
+#[account]
+pub struct MintConfig {
+	pub collection: Pubkey,
+	pub start_price: u64,
+	pub end_price: u64,
+	pub start_time: i64,
+	pub decay_interval: i64,
+	pub price_step: u64,
+	pub max_supply: u64,
+	pub minted: u64,
+	/// Mints paid for through `mint_nft`; batch mints by the authority count
+	/// against the supply but bring in no proceeds.
+	pub paid_mints: u64,
+	pub last_price: u64,
+	pub withdrawn: u64,
+	pub bump: u8,
+}
+
+impl MintConfig {
+	/// Reads the mint config stored at `info`, or `None` when the collection
+	/// mints for free.
+	pub fn load(info: &AccountInfo) -> Result<Option<MintConfig>> {
+		if info.owner != &crate::ID {
+			return Ok(None);
+		}
+		let mut data: &[u8] = &info.try_borrow_data()?;
+		Ok(Some(MintConfig::try_deserialize(&mut data)?))
+	}
+
+	/// Writes the config back to `info` after a mint.
+	pub fn store(&self, info: &AccountInfo) -> Result<()> {
+		self.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])
+	}
+
+	/// Counts `count` more mints against the supply cap.
+	pub fn record_mints(&mut self, count: u64) -> Result<()> {
+		let minted = self.minted
+			.checked_add(count)
+			.ok_or(MetaplexNftProgramError::ArithmeticOverflow)?;
+		require!(minted <= self.max_supply, MetaplexNftProgramError::MintSoldOut);
+		self.minted = minted;
+		Ok(())
+	}
+
+	/// Counts a mint paid for at the current price.
+	pub fn record_paid_mint(&mut self) -> Result<()> {
+		self.record_mints(1)?;
+		self.paid_mints = self.paid_mints
+			.checked_add(1)
+			.ok_or(MetaplexNftProgramError::ArithmeticOverflow)?;
+		Ok(())
+	}
+
+	/// Mint price at `now`: the start price lowered by one step per elapsed
+	/// decay interval, never below the end price.
+	pub fn price_at(&self, now: i64) -> Result<u64> {
+		require!(now >= self.start_time, MetaplexNftProgramError::MintNotStarted);
+		let intervals = ((now - self.start_time) / self.decay_interval) as u64;
+		let decay = intervals.saturating_mul(self.price_step);
+		Ok(self.start_price.saturating_sub(decay).max(self.end_price))
+	}
+
+	/// Final price everyone pays once the sale has concluded, either because
+	/// it sold out or because the price reached its floor. `None` while the
+	/// price can still fall.
+	pub fn clearing_price(&self, now: i64) -> Option<u64> {
+		if self.minted >= self.max_supply {
+			Some(self.last_price)
+		} else if now >= self.start_time && self.price_at(now).ok()? == self.end_price {
+			Some(self.end_price)
+		} else {
+			None
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn config() -> MintConfig {
+		MintConfig {
+			collection: Pubkey::default(),
+			start_price: 1_000,
+			end_price: 400,
+			start_time: 100,
+			decay_interval: 10,
+			price_step: 100,
+			max_supply: 5,
+			minted: 0,
+			paid_mints: 0,
+			last_price: 0,
+			withdrawn: 0,
+			bump: 0,
+		}
+	}
+
+	#[test]
+	fn price_at_start_of_curve() {
+		let config = config();
+		assert_eq!(config.price_at(100).unwrap(), 1_000);
+		assert_eq!(config.price_at(109).unwrap(), 1_000);
+		assert_eq!(config.price_at(110).unwrap(), 900);
+		assert_eq!(config.price_at(99).unwrap_err(), MetaplexNftProgramError::MintNotStarted.into());
+	}
+
+	#[test]
+	fn price_at_end_of_curve() {
+		let config = config();
+		assert_eq!(config.price_at(150).unwrap(), 500);
+		assert_eq!(config.price_at(160).unwrap(), 400);
+		assert_eq!(config.price_at(i64::MAX).unwrap(), 400);
+	}
+
+	#[test]
+	fn clearing_price_while_falling() {
+		let config = config();
+		assert_eq!(config.clearing_price(99), None);
+		assert_eq!(config.clearing_price(100), None);
+		assert_eq!(config.clearing_price(159), None);
+	}
+
+	#[test]
+	fn clearing_price_at_floor() {
+		let config = config();
+		assert_eq!(config.clearing_price(160), Some(400));
+	}
+
+	#[test]
+	fn clearing_price_when_sold_out() {
+		let mut config = config();
+		config.minted = config.max_supply;
+		config.last_price = 700;
+		assert_eq!(config.clearing_price(100), Some(700));
+		assert_eq!(config.clearing_price(160), Some(700));
+	}
+
+	#[test]
+	fn record_mints_stops_at_max_supply() {
+		let mut config = config();
+		config.record_mints(4).unwrap();
+		config.record_mints(1).unwrap();
+		assert_eq!(config.minted, 5);
+		assert_eq!(config.record_mints(1).unwrap_err(), MetaplexNftProgramError::MintSoldOut.into());
+		assert_eq!(config.minted, 5);
+	}
+
+	#[test]
+	fn record_paid_mint_counts_towards_supply() {
+		let mut config = config();
+		config.record_mints(4).unwrap();
+		config.record_paid_mint().unwrap();
+		assert_eq!(config.minted, 5);
+		assert_eq!(config.paid_mints, 1);
+		assert_eq!(config.record_paid_mint().unwrap_err(), MetaplexNftProgramError::MintSoldOut.into());
+		assert_eq!(config.paid_mints, 1);
+	}
+}
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct MintReceipt {
+	pub minter: Pubkey,
+	pub mint_config: Pubkey,
+	pub mint: Pubkey,
+	pub price: u64,
+}
@@ -0,0 +1,43 @@
+
+
+pub mod nft_collection;
+pub mod nft_mint;
+pub mod listing;
+pub mod bid;
+pub mod auction;
+pub mod mint_config;
+pub mod mint_receipt;
+pub mod rental_offer;
+pub mod stake_pool;
+pub mod stake_entry;
+pub mod vault;
+pub mod loan;
+pub mod rule_set;
+pub mod program_config;
+pub mod treasury;
+pub mod multisig;
+pub mod proposal;
+pub mod metadata_history;
+pub mod item_index;
+pub mod bid_refund;
+
+pub use nft_collection::*;
+pub use nft_mint::*;
+pub use listing::*;
+pub use bid::*;
+pub use auction::*;
+pub use mint_config::*;
+pub use mint_receipt::*;
+pub use rental_offer::*;
+pub use stake_pool::*;
+pub use stake_entry::*;
+pub use vault::*;
+pub use loan::*;
+pub use rule_set::*;
+pub use program_config::*;
+pub use treasury::*;
+pub use multisig::*;
+pub use proposal::*;
+pub use metadata_history::*;
+pub use item_index::*;
+pub use bid_refund::*;
\ No newline at end of file
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+use crate::MAX_MULTISIG_SIGNERS;
+
+#[account]
+pub struct Multisig {
+	pub creator: Pubkey,
+	pub nonce: u64,
+	pub signers: Vec<Pubkey>,
+	pub threshold: u8,
+	pub proposal_count: u64,
+	pub bump: u8,
+}
+
+impl Multisig {
+	/// Fails unless `signers` are distinct, at most `MAX_MULTISIG_SIGNERS`, and
+	/// `threshold` of them can be reached.
+	pub fn validate_signers(signers: &[Pubkey], threshold: u8) -> Result<()> {
+		require!(signers.len() <= MAX_MULTISIG_SIGNERS, MetaplexNftProgramError::InvalidMultisig);
+		require!(
+			threshold > 0 && threshold as usize <= signers.len(),
+			MetaplexNftProgramError::InvalidMultisig
+		);
+		for (index, signer) in signers.iter().enumerate() {
+			require!(!signers[..index].contains(signer), MetaplexNftProgramError::InvalidMultisig);
+		}
+		Ok(())
+	}
+
+	pub fn assert_signer(&self, key: &Pubkey) -> Result<()> {
+		require!(self.signers.contains(key), MetaplexNftProgramError::NotMultisigSigner);
+		Ok(())
+	}
+}
@@ -0,0 +1,303 @@
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::error::MetaplexNftProgramError;
+use crate::{MAX_CREATORS, MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH, MAX_URI_SCHEMES, MAX_URI_SCHEME_LENGTH, MAX_URI_SUFFIX_LENGTH};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct CollectionCreator {
+	pub address: Pubkey,
+	pub share: u8,
+}
+
+#[account]
+pub struct NftCollection {
+	pub authority: Pubkey,
+	pub name: String,
+	pub symbol: String,
+	pub uri: String,
+	pub verified: bool,
+	pub nft_count: u64,
+	pub seller_fee_basis_points: u16,
+	pub creators: Vec<CollectionCreator>,
+	pub enforce_royalties: bool,
+	pub soulbound: bool,
+	pub paused: bool,
+	pub is_mutable: bool,
+	pub max_uri_length: u16,
+	pub allowed_uri_schemes: Vec<String>,
+	pub content_hash: Option<[u8; 32]>,
+	pub reveal_root: Option<[u8; 32]>,
+	/// When set, NFTs store only a URI suffix, or nothing to use `<index>.json`.
+	pub base_uri: String,
+	/// Authority the collection was created by. The collection's address is
+	/// derived from it, so it stays fixed when the authority changes.
+	pub seed_authority: Pubkey,
+	/// Number of NFTs with locked metadata; while any exist, the base URI
+	/// they resolve against cannot change.
+	pub locked_nft_count: u64,
+}
+
+impl NftCollection {
+	/// Account size with an empty base URI; a base URI adds its length.
+	pub const SPACE: usize = 8 // discriminator
+		+ 32 // authority
+		+ 4 + MAX_NAME_LENGTH // name
+		+ 4 + MAX_SYMBOL_LENGTH // symbol
+		+ 4 + MAX_URI_LENGTH // uri
+		+ 1 // verified
+		+ 8 // nft_count
+		+ 2 // seller_fee_basis_points
+		+ 4 + MAX_CREATORS * (32 + 1) // creators
+		+ 1 + 1 + 1 + 1 // enforce_royalties, soulbound, paused, is_mutable
+		+ 2 // max_uri_length
+		+ 4 + MAX_URI_SCHEMES * (4 + MAX_URI_SCHEME_LENGTH) // allowed_uri_schemes
+		+ 1 + 32 // content_hash
+		+ 1 + 32 // reveal_root
+		+ 4 // base_uri
+		+ 32 // seed_authority
+		+ 8; // locked_nft_count
+
+	/// Royalty owed to the collection creators on a sale of `price` lamports.
+	pub fn royalty_amount(&self, price: u64) -> Result<u64> {
+		let royalty = (price as u128)
+			.checked_mul(self.seller_fee_basis_points as u128)
+			.ok_or(MetaplexNftProgramError::ArithmeticOverflow)?
+			/ 10_000;
+		Ok(royalty as u64)
+	}
+
+	/// Whether the collection's tokens stay frozen in their holders' wallets.
+	pub fn keeps_tokens_frozen(&self) -> bool {
+		self.enforce_royalties || self.soulbound
+	}
+
+	/// Checks a metadata URI against the collection's length limit and allowed
+	/// schemes, rejecting whitespace and control characters.
+	pub fn validate_uri(&self, uri: &str) -> Result<()> {
+		require!(uri.len() <= self.max_uri_length as usize, MetaplexNftProgramError::UriTooLong);
+		require!(
+			!uri.chars().any(|c| c.is_whitespace() || c.is_control()),
+			MetaplexNftProgramError::UriInvalidCharacter
+		);
+
+		let scheme = match uri.split_once("://") {
+			Some((scheme, _)) if !scheme.is_empty() => scheme,
+			_ => return err!(MetaplexNftProgramError::UriMissingScheme),
+		};
+		require!(
+			self.allowed_uri_schemes.iter().any(|allowed| allowed.eq_ignore_ascii_case(scheme)),
+			MetaplexNftProgramError::UriSchemeNotAllowed
+		);
+
+		Ok(())
+	}
+
+	/// Full metadata URI of the NFT minted at `index` that stores `uri`.
+	pub fn resolve_uri(&self, uri: &str, index: u64) -> String {
+		if self.base_uri.is_empty() {
+			uri.to_string()
+		} else if uri.is_empty() {
+			format!("{}{}.json", self.base_uri, index)
+		} else {
+			format!("{}{}", self.base_uri, uri)
+		}
+	}
+
+	/// Checks the `uri` an NFT minted at `index` would store: a full URI, or a
+	/// suffix of at most `MAX_URI_SUFFIX_LENGTH` when the collection has a
+	/// base URI. The resolved URI must pass `validate_uri`.
+	pub fn validate_nft_uri(&self, uri: &str, index: u64) -> Result<()> {
+		if !self.base_uri.is_empty() {
+			require!(uri.len() <= MAX_URI_SUFFIX_LENGTH, MetaplexNftProgramError::UriTooLong);
+		}
+		self.validate_uri(&self.resolve_uri(uri, index))
+	}
+
+	/// Symbol stored on NFTs minted to the collection; NFTs of collections with
+	/// a base URI take the collection's symbol instead of a copy.
+	pub fn nft_symbol(&self) -> String {
+		if self.base_uri.is_empty() {
+			self.symbol.clone()
+		} else {
+			String::new()
+		}
+	}
+
+	/// Checks revealed metadata for the NFT minted at `index` against the
+	/// collection's reveal commitment, a Merkle root over `reveal_leaf`s with
+	/// sorted-pair internal nodes.
+	pub fn verify_reveal(&self, index: u64, name: &str, uri: &str, content_hash: Option<[u8; 32]>, proof: &[[u8; 32]]) -> Result<()> {
+		let root = self.reveal_root.ok_or(MetaplexNftProgramError::NoRevealCommitment)?;
+		let computed = proof.iter().fold(reveal_leaf(index, name, uri, content_hash), |node, sibling| {
+			let (left, right) = if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+			hashv(&[&[1], &left, &right]).to_bytes()
+		});
+		require!(computed == root, MetaplexNftProgramError::InvalidRevealProof);
+		Ok(())
+	}
+}
+
+/// Leaf committing to the final metadata of the NFT minted at `index`.
+pub fn reveal_leaf(index: u64, name: &str, uri: &str, content_hash: Option<[u8; 32]>) -> [u8; 32] {
+	let content_hash = content_hash.as_ref().map_or(&[][..], |hash| &hash[..]);
+	hashv(&[
+		&[0],
+		&index.to_le_bytes(),
+		&(name.len() as u32).to_le_bytes(),
+		name.as_bytes(),
+		&(uri.len() as u32).to_le_bytes(),
+		uri.as_bytes(),
+		&[!content_hash.is_empty() as u8],
+		content_hash,
+	])
+	.to_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn collection() -> NftCollection {
+		NftCollection {
+			authority: Pubkey::default(),
+			name: String::new(),
+			symbol: String::new(),
+			uri: String::new(),
+			verified: false,
+			nft_count: 0,
+			seller_fee_basis_points: 0,
+			creators: Vec::new(),
+			enforce_royalties: false,
+			soulbound: false,
+			paused: false,
+			is_mutable: true,
+			max_uri_length: 24,
+			allowed_uri_schemes: vec!["https".to_string(), "ipfs".to_string()],
+			content_hash: None,
+			reveal_root: None,
+			base_uri: String::new(),
+			seed_authority: Pubkey::default(),
+			locked_nft_count: 0,
+		}
+	}
+
+	fn node(left: [u8; 32], right: [u8; 32]) -> [u8; 32] {
+		let (left, right) = if left <= right { (left, right) } else { (right, left) };
+		hashv(&[&[1], &left, &right]).to_bytes()
+	}
+
+	#[test]
+	fn space_fits_largest_collection() {
+		let mut collection = collection();
+		collection.name = "n".repeat(MAX_NAME_LENGTH);
+		collection.symbol = "s".repeat(MAX_SYMBOL_LENGTH);
+		collection.uri = "u".repeat(MAX_URI_LENGTH);
+		collection.creators = vec![CollectionCreator::default(); MAX_CREATORS];
+		collection.allowed_uri_schemes = vec!["s".repeat(MAX_URI_SCHEME_LENGTH); MAX_URI_SCHEMES];
+		collection.content_hash = Some([0; 32]);
+		collection.reveal_root = Some([0; 32]);
+		collection.base_uri = "https://example.com/".to_string();
+
+		let mut data = Vec::new();
+		collection.try_serialize(&mut data).unwrap();
+		assert_eq!(data.len(), NftCollection::SPACE + collection.base_uri.len());
+	}
+
+	#[test]
+	fn royalty_amount_at_full_basis_points() {
+		let mut collection = collection();
+		collection.seller_fee_basis_points = 10_000;
+		assert_eq!(collection.royalty_amount(1_234).unwrap(), 1_234);
+		assert_eq!(collection.royalty_amount(u64::MAX).unwrap(), u64::MAX);
+		collection.seller_fee_basis_points = 0;
+		assert_eq!(collection.royalty_amount(u64::MAX).unwrap(), 0);
+	}
+
+	#[test]
+	fn validate_uri_length() {
+		let collection = collection();
+		assert!(collection.validate_uri("https://example.com/1234").is_ok());
+		assert_eq!(
+			collection.validate_uri("https://example.com/12345").unwrap_err(),
+			MetaplexNftProgramError::UriTooLong.into()
+		);
+	}
+
+	#[test]
+	fn validate_uri_characters() {
+		let collection = collection();
+		assert_eq!(
+			collection.validate_uri("https://a b").unwrap_err(),
+			MetaplexNftProgramError::UriInvalidCharacter.into()
+		);
+		assert_eq!(
+			collection.validate_uri("https://a\u{7}").unwrap_err(),
+			MetaplexNftProgramError::UriInvalidCharacter.into()
+		);
+	}
+
+	#[test]
+	fn validate_uri_scheme() {
+		let collection = collection();
+		assert!(collection.validate_uri("IPFS://cid").is_ok());
+		assert_eq!(
+			collection.validate_uri("example.com/1").unwrap_err(),
+			MetaplexNftProgramError::UriMissingScheme.into()
+		);
+		assert_eq!(
+			collection.validate_uri("://example.com").unwrap_err(),
+			MetaplexNftProgramError::UriMissingScheme.into()
+		);
+		assert_eq!(
+			collection.validate_uri("ar://tx").unwrap_err(),
+			MetaplexNftProgramError::UriSchemeNotAllowed.into()
+		);
+	}
+
+	#[test]
+	fn resolve_uri_with_and_without_base() {
+		let mut collection = collection();
+		assert_eq!(collection.resolve_uri("ipfs://cid", 7), "ipfs://cid");
+		collection.base_uri = "https://example.com/".to_string();
+		assert_eq!(collection.resolve_uri("", 7), "https://example.com/7.json");
+		assert_eq!(collection.resolve_uri("seven.json", 7), "https://example.com/seven.json");
+	}
+
+	#[test]
+	fn verify_reveal_accepts_valid_proofs() {
+		let leaves = [
+			reveal_leaf(0, "Zero", "ipfs://0", None),
+			reveal_leaf(1, "One", "ipfs://1", Some([1; 32])),
+			reveal_leaf(2, "Two", "ipfs://2", None),
+		];
+		let pair = node(leaves[0], leaves[1]);
+		let mut collection = collection();
+		collection.reveal_root = Some(node(pair, leaves[2]));
+
+		assert!(collection.verify_reveal(0, "Zero", "ipfs://0", None, &[leaves[1], leaves[2]]).is_ok());
+		assert!(collection.verify_reveal(1, "One", "ipfs://1", Some([1; 32]), &[leaves[0], leaves[2]]).is_ok());
+		assert!(collection.verify_reveal(2, "Two", "ipfs://2", None, &[pair]).is_ok());
+	}
+
+	#[test]
+	fn verify_reveal_rejects_bad_proofs() {
+		let leaves = [reveal_leaf(0, "Zero", "ipfs://0", None), reveal_leaf(1, "One", "ipfs://1", Some([1; 32]))];
+		let mut collection = collection();
+		assert_eq!(
+			collection.verify_reveal(0, "Zero", "ipfs://0", None, &[leaves[1]]).unwrap_err(),
+			MetaplexNftProgramError::NoRevealCommitment.into()
+		);
+
+		collection.reveal_root = Some(node(leaves[0], leaves[1]));
+		let invalid = MetaplexNftProgramError::InvalidRevealProof.into();
+		assert_eq!(collection.verify_reveal(0, "Zero", "ipfs://1", None, &[leaves[1]]).unwrap_err(), invalid);
+		assert_eq!(collection.verify_reveal(0, "Zer", "oipfs://0", None, &[leaves[1]]).unwrap_err(), invalid);
+		assert_eq!(collection.verify_reveal(1, "Zero", "ipfs://0", None, &[leaves[1]]).unwrap_err(), invalid);
+		assert_eq!(collection.verify_reveal(0, "Zero", "ipfs://0", None, &[]).unwrap_err(), invalid);
+		assert_eq!(collection.verify_reveal(0, "Zero", "ipfs://0", None, &[leaves[1], leaves[1]]).unwrap_err(), invalid);
+		assert_eq!(collection.verify_reveal(0, "Zero", "ipfs://0", Some([0; 32]), &[leaves[1]]).unwrap_err(), invalid);
+		assert_eq!(collection.verify_reveal(1, "One", "ipfs://1", None, &[leaves[0]]).unwrap_err(), invalid);
+	}
+}
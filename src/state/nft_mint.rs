@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+use crate::state::NftCollection;
+use crate::{MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH, MAX_URI_SUFFIX_LENGTH};
+
+#[account]
+pub struct NftMint {
+	pub mint: Pubkey,
+	pub owner: Pubkey,
+	pub name: String,
+	pub symbol: String,
+	pub uri: String,
+	pub collection: Pubkey,
+	pub user: Pubkey,
+	pub expires_at: i64,
+	pub staked: bool,
+	pub soulbound: bool,
+	pub delegate: Pubkey,
+	pub frozen: bool,
+	pub is_mutable: bool,
+	pub content_hash: Option<[u8; 32]>,
+	pub revision: u32,
+	/// Position of the NFT in its collection, in mint order; also its leaf in
+	/// the collection's reveal commitment.
+	pub item_number: u64,
+	/// False while the NFT still shows placeholder metadata.
+	pub revealed: bool,
+	/// True while an in-place listing is the delegate of the owner's token account.
+	pub listed: bool,
+}
+
+impl NftMint {
+	/// Account size of an NFT storing a full symbol and URI.
+	pub const SPACE: usize = 8 // discriminator
+		+ 32 + 32 // mint, owner
+		+ 4 + MAX_NAME_LENGTH // name
+		+ 4 + MAX_SYMBOL_LENGTH // symbol
+		+ 4 + MAX_URI_LENGTH // uri
+		+ 32 + 32 // collection, user
+		+ 8 // expires_at
+		+ 1 + 1 // staked, soulbound
+		+ 32 // delegate
+		+ 1 + 1 // frozen, is_mutable
+		+ 1 + 32 // content_hash
+		+ 4 // revision
+		+ 8 // item_number
+		+ 1 + 1; // revealed, listed
+
+	/// Account size of an NFT minted to `collection`. NFTs of collections with
+	/// a base URI store no symbol and at most a URI suffix.
+	pub fn space(collection: &NftCollection) -> usize {
+		if collection.base_uri.is_empty() {
+			Self::SPACE
+		} else {
+			Self::SPACE - MAX_SYMBOL_LENGTH - MAX_URI_LENGTH + MAX_URI_SUFFIX_LENGTH
+		}
+	}
+
+	/// Whether usage rights are currently rented out to `user`.
+	pub fn is_rented(&self, now: i64) -> bool {
+		self.user != Pubkey::default() && now < self.expires_at
+	}
+
+	/// Fails if the NFT may not change hands at `now`.
+	pub fn assert_transferable(&self, now: i64) -> Result<()> {
+		require!(!self.soulbound, MetaplexNftProgramError::Soulbound);
+		require!(!self.frozen, MetaplexNftProgramError::NftFrozen);
+		require!(!self.is_rented(now), MetaplexNftProgramError::RentalActive);
+		require!(!self.staked, MetaplexNftProgramError::NftStaked);
+		require!(!self.listed, MetaplexNftProgramError::NftListed);
+		Ok(())
+	}
+
+	/// Whether a transfer delegate is currently approved.
+	pub fn has_delegate(&self) -> bool {
+		self.delegate != Pubkey::default()
+	}
+
+	/// Fails while a transfer delegate is approved; paths that move the NFT
+	/// on the owner's signature alone require the delegation to be revoked.
+	pub fn assert_not_delegated(&self) -> Result<()> {
+		require!(!self.has_delegate(), MetaplexNftProgramError::NftDelegated);
+		Ok(())
+	}
+}
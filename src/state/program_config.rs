@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+
+#[account]
+pub struct ProgramConfig {
+	pub admin: Pubkey,
+	pub paused: bool,
+	pub bump: u8,
+	pub mint_fee_lamports: u64,
+	pub mint_fee_bps: u16,
+	pub treasury: Pubkey,
+}
+
+impl ProgramConfig {
+	/// Reads the program config stored at `info`, or `None` until the upgrade
+	/// authority has initialized it.
+	pub fn load(info: &AccountInfo) -> Result<Option<ProgramConfig>> {
+		if info.owner != &crate::ID {
+			return Ok(None);
+		}
+		let mut data: &[u8] = &info.try_borrow_data()?;
+		Ok(Some(ProgramConfig::try_deserialize(&mut data)?))
+	}
+
+	/// Whether the program-wide pause is on; false until the config exists.
+	pub fn is_paused(info: &AccountInfo) -> Result<bool> {
+		Ok(ProgramConfig::load(info)?.is_some_and(|config| config.paused))
+	}
+
+	/// Protocol fee owed on a mint sold for `price` lamports.
+	pub fn mint_fee(&self, price: u64) -> Result<u64> {
+		let fee = (price as u128)
+			.checked_mul(self.mint_fee_bps as u128)
+			.ok_or(MetaplexNftProgramError::ArithmeticOverflow)?
+			/ 10_000;
+		self.mint_fee_lamports
+			.checked_add(fee as u64)
+			.ok_or(error!(MetaplexNftProgramError::ArithmeticOverflow))
+	}
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ProposalAccountMeta {
+	pub pubkey: Pubkey,
+	pub is_signer: bool,
+	pub is_writable: bool,
+}
+
+#[account]
+pub struct Proposal {
+	pub multisig: Pubkey,
+	pub index: u64,
+	pub proposer: Pubkey,
+	pub accounts: Vec<ProposalAccountMeta>,
+	pub data: Vec<u8>,
+	pub approvals: Vec<Pubkey>,
+	pub executed: bool,
+	pub bump: u8,
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct RentalOffer {
+	pub owner: Pubkey,
+	pub mint: Pubkey,
+	pub price_per_day: u64,
+	pub max_days: u32,
+	pub bump: u8,
+}
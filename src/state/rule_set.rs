@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RuleSetMode {
+	/// Only the listed programs may act on the collection's NFTs.
+	#[default]
+	Allow,
+	/// Every program except the listed ones may act on the collection's NFTs.
+	Deny,
+}
+
+#[account]
+pub struct RuleSet {
+	pub collection: Pubkey,
+	pub mode: RuleSetMode,
+	pub programs: Vec<Pubkey>,
+	pub bump: u8,
+}
+
+impl RuleSet {
+	/// Reads the rule set stored at `info`, or `None` when the collection has
+	/// not created one.
+	pub fn load(info: &AccountInfo) -> Result<Option<RuleSet>> {
+		if info.owner != &crate::ID {
+			return Ok(None);
+		}
+		let mut data: &[u8] = &info.try_borrow_data()?;
+		Ok(Some(RuleSet::try_deserialize(&mut data)?))
+	}
+
+	/// Fails if `program` may not act as transfer authority or delegate.
+	pub fn assert_permitted(&self, program: &Pubkey) -> Result<()> {
+		self.assert_listing_permitted(self.programs.contains(program))
+	}
+
+	/// Fails if `delegate` may not hold a delegation. It counts as listed when
+	/// either its own key or the program owning it is listed, so a program is
+	/// caught whether it is approved directly or through an account it owns.
+	/// Under an allowlist a plain wallet is therefore only accepted when it is
+	/// listed itself.
+	pub fn assert_delegate_permitted(&self, delegate: &AccountInfo) -> Result<()> {
+		self.assert_listing_permitted(
+			self.programs.contains(delegate.key) || self.programs.contains(delegate.owner),
+		)
+	}
+
+	fn assert_listing_permitted(&self, listed: bool) -> Result<()> {
+		let permitted = match self.mode {
+			RuleSetMode::Allow => listed,
+			RuleSetMode::Deny => !listed,
+		};
+		require!(permitted, MetaplexNftProgramError::ProgramNotPermitted);
+		Ok(())
+	}
+}
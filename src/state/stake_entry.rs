@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::error::MetaplexNftProgramError;
+
+#[account]
+pub struct StakeEntry {
+	pub owner: Pubkey,
+	pub mint: Pubkey,
+	pub stake_pool: Pubkey,
+	pub staked_at: i64,
+	pub last_claimed_at: i64,
+	pub bump: u8,
+}
+
+impl StakeEntry {
+	/// Rewards accrued since the last claim at `reward_rate` base units per second.
+	pub fn pending_rewards(&self, reward_rate: u64, now: i64) -> Result<u64> {
+		let elapsed = now.saturating_sub(self.last_claimed_at).max(0) as u64;
+		elapsed
+			.checked_mul(reward_rate)
+			.ok_or(MetaplexNftProgramError::ArithmeticOverflow.into())
+	}
+}
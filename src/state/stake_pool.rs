@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct StakePool {
+	pub collection: Pubkey,
+	pub reward_mint: Pubkey,
+	pub reward_rate: u64,
+	pub bump: u8,
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct Vault {
+	pub curator: Pubkey,
+	pub mint: Pubkey,
+	pub share_mint: Pubkey,
+	pub total_shares: u64,
+	pub reserve_price: u64,
+	pub bought_out: bool,
+	pub buyer: Pubkey,
+	pub outstanding_shares: u64,
+	pub proceeds: u64,
+	pub bump: u8,
+}
@@ -0,0 +1,240 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use anchor_lang::solana_program::sysvar::instructions::{load_current_index_checked, load_instruction_at_checked};
+
+use crate::error::MetaplexNftProgramError;
+use crate::state::{NftCollection, ProgramConfig, RuleSet};
+
+/// Moves `amount` lamports out of a signer's system account.
+pub fn transfer_lamports<'info>(
+	from: &AccountInfo<'info>,
+	to: &AccountInfo<'info>,
+	system_program: &AccountInfo<'info>,
+	amount: u64,
+) -> Result<()> {
+	anchor_lang::system_program::transfer(
+		CpiContext::new(system_program.clone(),
+			anchor_lang::system_program::Transfer {
+				from: from.clone(),
+				to: to.clone()
+			}
+		),
+		amount,
+	)
+}
+
+/// Moves `amount` lamports out of an account owned by this program, such as an
+/// escrow PDA. The source must stay rent exempt unless it is closed afterwards.
+pub fn transfer_lamports_from_program_account<'info>(
+	from: &AccountInfo<'info>,
+	to: &AccountInfo<'info>,
+	amount: u64,
+) -> Result<()> {
+	from.sub_lamports(amount)?;
+	to.add_lamports(amount)?;
+	Ok(())
+}
+
+/// Creates a PDA owned by this program the way Anchor's `init` does: an
+/// address someone funded in advance is topped up to rent exemption, then
+/// allocated and assigned, where `create_account` would fail.
+pub fn create_pda_account<'info>(
+	payer: &AccountInfo<'info>,
+	account: &AccountInfo<'info>,
+	system_program: &AccountInfo<'info>,
+	space: usize,
+	signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+	let rent = Rent::get()?.minimum_balance(space);
+	let lamports = account.lamports();
+	if lamports == 0 {
+		return anchor_lang::system_program::create_account(
+			CpiContext::new_with_signer(system_program.clone(),
+				anchor_lang::system_program::CreateAccount {
+					from: payer.clone(),
+					to: account.clone()
+				},
+				signer_seeds,
+			),
+			rent,
+			space as u64,
+			&crate::ID,
+		);
+	}
+
+	if rent > lamports {
+		transfer_lamports(payer, account, system_program, rent - lamports)?;
+	}
+	anchor_lang::system_program::allocate(
+		CpiContext::new_with_signer(system_program.clone(),
+			anchor_lang::system_program::Allocate {
+				account_to_allocate: account.clone()
+			},
+			signer_seeds,
+		),
+		space as u64,
+	)?;
+	anchor_lang::system_program::assign(
+		CpiContext::new_with_signer(system_program.clone(),
+			anchor_lang::system_program::Assign {
+				account_to_assign: account.clone()
+			},
+			signer_seeds,
+		),
+		&crate::ID,
+	)
+}
+
+/// Resizes an account owned by this program to `space` bytes, topping its
+/// rent up from `payer` or refunding the excess to it.
+pub fn resize_program_account<'info>(
+	account: &AccountInfo<'info>,
+	payer: &AccountInfo<'info>,
+	system_program: &AccountInfo<'info>,
+	space: usize,
+) -> Result<()> {
+	let rent = Rent::get()?.minimum_balance(space);
+	let lamports = account.lamports();
+	if rent > lamports {
+		transfer_lamports(payer, account, system_program, rent - lamports)?;
+	} else {
+		transfer_lamports_from_program_account(account, payer, lamports - rent)?;
+	}
+	account.resize(space)?;
+	Ok(())
+}
+
+/// Splits the royalty on a sale of `price` lamports between the collection
+/// creators, paying each share through `pay`. `creator_accounts` must list the
+/// creators in the order they are stored on the collection; the last creator
+/// receives any rounding remainder. Returns the total royalty paid.
+pub fn pay_royalties<'info>(
+	collection: &NftCollection,
+	price: u64,
+	creator_accounts: &[AccountInfo<'info>],
+	mut pay: impl FnMut(&AccountInfo<'info>, u64) -> Result<()>,
+) -> Result<u64> {
+	let royalty = collection.royalty_amount(price)?;
+	if royalty == 0 {
+		return Ok(0);
+	}
+	require!(!collection.creators.is_empty(), MetaplexNftProgramError::InvalidRoyaltyConfig);
+	require!(
+		creator_accounts.len() >= collection.creators.len(),
+		MetaplexNftProgramError::InvalidCreatorAccount
+	);
+
+	let mut paid: u64 = 0;
+	let last = collection.creators.len() - 1;
+	for (index, (creator, account)) in collection.creators.iter().zip(creator_accounts).enumerate() {
+		require_keys_eq!(account.key(), creator.address, MetaplexNftProgramError::InvalidCreatorAccount);
+		let share = if index == last {
+			royalty - paid
+		} else {
+			(royalty as u128 * creator.share as u128 / 100) as u64
+		};
+		if share > 0 {
+			pay(account, share)?;
+		}
+		paid += share;
+	}
+
+	Ok(royalty)
+}
+
+/// Thaws a wallet's token account before the program moves an NFT out of it.
+/// Tokens of royalty-enforced collections are kept frozen at rest.
+pub fn thaw_if_frozen<'info>(
+	token_account: &Account<'info, TokenAccount>,
+	mint: &AccountInfo<'info>,
+	freeze_authority: &AccountInfo<'info>,
+	freeze_authority_bump: u8,
+	token_program: &AccountInfo<'info>,
+) -> Result<()> {
+	if !token_account.is_frozen() {
+		return Ok(());
+	}
+	anchor_spl::token::thaw_account(
+		CpiContext::new_with_signer(token_program.clone(),
+			anchor_spl::token::ThawAccount {
+				account: token_account.to_account_info(),
+				mint: mint.clone(),
+				authority: freeze_authority.clone()
+			},
+			&[&[b"freeze_authority", &[freeze_authority_bump]]],
+		),
+	)
+}
+
+/// Freezes a wallet's token account after the program delivered an NFT of a
+/// royalty-enforced collection to it, so it can only leave through this
+/// program again.
+pub fn freeze_if_enforced<'info>(
+	collection: &NftCollection,
+	token_account: &Account<'info, TokenAccount>,
+	mint: &AccountInfo<'info>,
+	freeze_authority: &AccountInfo<'info>,
+	freeze_authority_bump: u8,
+	token_program: &AccountInfo<'info>,
+) -> Result<()> {
+	if !collection.enforce_royalties || token_account.is_frozen() {
+		return Ok(());
+	}
+	anchor_spl::token::freeze_account(
+		CpiContext::new_with_signer(token_program.clone(),
+			anchor_spl::token::FreezeAccount {
+				account: token_account.to_account_info(),
+				mint: mint.clone(),
+				authority: freeze_authority.clone()
+			},
+			&[&[b"freeze_authority", &[freeze_authority_bump]]],
+		),
+	)
+}
+
+/// Applies a collection's rule set, if it has one, to the program that
+/// invoked the current instruction, read from the instructions sysvar, and
+/// to `delegate` when one is being approved or is acting.
+/// Signers are not checked by their owner: a program signs with PDAs, which
+/// are owned by the System Program like any wallet.
+pub fn assert_rule_set_permits(
+	rule_set: &AccountInfo,
+	instructions: &AccountInfo,
+	delegate: Option<&AccountInfo>,
+) -> Result<()> {
+	let Some(rule_set) = RuleSet::load(rule_set)? else {
+		return Ok(());
+	};
+
+	if let Some(delegate) = delegate {
+		rule_set.assert_delegate_permitted(delegate)?;
+	}
+
+	// The top-level instruction names the program that called into us, if any.
+	let current_index = load_current_index_checked(instructions)?;
+	let caller = load_instruction_at_checked(current_index as usize, instructions)?.program_id;
+	if caller != crate::ID {
+		rule_set.assert_permitted(&caller)?;
+	}
+
+	Ok(())
+}
+
+/// Fails while either the collection or the whole program is paused.
+///
+/// Every instruction that mints, moves, trades, rents, lends, stakes or
+/// rewrites an NFT checks this. Left open on purpose are:
+/// - authority and admin configuration, the pause switches and the multisig
+///   flow, which are how an incident is handled;
+/// - exits that only hand back the caller's own NFT or lamports and close
+///   their own open position: `delist_nft`, `delist_nft_in_place`,
+///   `cancel_bid`, `withdraw_bid`, `cancel_loan_offer`, `close_rental_offer`,
+///   `end_rental`, `revoke_delegate`, `redeem_shares`, `claim_mint_rebate`
+///   and `repay_loan`, the last so that a pause cannot push a borrower into
+///   default.
+pub fn assert_not_paused(collection: &NftCollection, program_config: &AccountInfo) -> Result<()> {
+	require!(!collection.paused, MetaplexNftProgramError::CollectionPaused);
+	require!(!ProgramConfig::is_paused(program_config)?, MetaplexNftProgramError::ProgramPaused);
+	Ok(())
+}
@@ -1,8 +0,0 @@
-
-use anchor_lang::prelude::*;
-
-pub mod nft_collection;
-pub mod nft_mint;
-
-pub use nft_collection::*;
-pub use nft_mint::*;
@@ -0,0 +1,125 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftMint};
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::{tokio, BanksClientError, ProgramTestContext},
+	solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+async fn accept(
+	context: &mut ProgramTestContext,
+	seller: &Keypair,
+	bidder: Pubkey,
+	collection: Pubkey,
+	nft: &Nft,
+	min_amount: u64,
+	creators: &[Pubkey],
+) -> Result<(), BanksClientError> {
+	let transaction = metaplex_nft_program_ix_interface::accept_bid_ix_setup(
+		&context.payer,
+		seller,
+		bidder,
+		nft.mint,
+		nft.metadata,
+		collection,
+		bid_pda(&bidder, &collection),
+		nft.token_account,
+		token_account_address(&bidder, &nft.mint),
+		freeze_authority_pda(),
+		SYSTEM_PROGRAM,
+		TOKEN_PROGRAM,
+		TOKEN_PROGRAM,
+		ASSOCIATED_TOKEN_PROGRAM,
+		program_config_pda(),
+		min_amount,
+		creators.iter().map(|creator| AccountMeta::new(*creator, false)).collect(),
+		context.last_blockhash,
+	);
+	process(context, transaction).await
+}
+
+#[tokio::test]
+async fn accept_bid_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let seller = funded_keypair(&mut context);
+	let bidder = funded_keypair(&mut context);
+	let creator = funded_keypair(&mut context).pubkey();
+	let collection = create_collection(&mut context, &authority).await;
+	set_royalties(&mut context, collection, &authority, 500, &[creator]).await;
+	let nft = mint_nft(&mut context, collection, &authority, &seller).await;
+	let expires_at = unix_timestamp(&mut context).await + 3_600;
+	place_bid(&mut context, collection, &bidder, None, 1_000_000_000, expires_at).await.unwrap();
+
+	let bid = bid_pda(&bidder.pubkey(), &collection);
+	let bid_rent = lamports(&mut context, bid).await - 1_000_000_000;
+	let bidder_before = lamports(&mut context, bidder.pubkey()).await;
+	let seller_before = lamports(&mut context, seller.pubkey()).await;
+	accept(&mut context, &seller, bidder.pubkey(), collection, &nft, 1_000_000_000, &[creator]).await.unwrap();
+
+	let bidder_token_account = token_account_address(&bidder.pubkey(), &nft.mint);
+	let token_rent = lamports(&mut context, bidder_token_account).await;
+	assert_eq!(token_account(&mut context, bidder_token_account).await.amount, 1);
+	assert_eq!(token_account(&mut context, nft.token_account).await.amount, 0);
+	assert!(!account_exists(&mut context, bid).await);
+	assert_eq!(lamports(&mut context, seller.pubkey()).await, seller_before + 950_000_000 - token_rent);
+	assert_eq!(lamports(&mut context, creator).await, LAMPORTS + 50_000_000);
+	assert_eq!(lamports(&mut context, bidder.pubkey()).await, bidder_before + bid_rent);
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert_eq!(metadata.owner, bidder.pubkey());
+}
+
+#[tokio::test]
+async fn accept_bid_ix_rejects_expired_bid() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let seller = funded_keypair(&mut context);
+	let bidder = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &seller).await;
+	let expires_at = unix_timestamp(&mut context).await + 3_600;
+	place_bid(&mut context, collection, &bidder, None, 1_000_000_000, expires_at).await.unwrap();
+
+	set_unix_timestamp(&mut context, expires_at + 1).await;
+	let result = accept(&mut context, &seller, bidder.pubkey(), collection, &nft, 1_000_000_000, &[]).await;
+	assert_error(result, MetaplexNftProgramError::BidExpired);
+}
+
+#[tokio::test]
+async fn accept_bid_ix_rejects_bid_below_minimum() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let seller = funded_keypair(&mut context);
+	let bidder = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &seller).await;
+	let expires_at = unix_timestamp(&mut context).await + 3_600;
+	place_bid(&mut context, collection, &bidder, Some(nft.mint), 1_000_000, expires_at).await.unwrap();
+
+	let transaction = metaplex_nft_program_ix_interface::accept_bid_ix_setup(
+		&context.payer,
+		&seller,
+		bidder.pubkey(),
+		nft.mint,
+		nft.metadata,
+		collection,
+		bid_pda(&bidder.pubkey(), &nft.mint),
+		nft.token_account,
+		token_account_address(&bidder.pubkey(), &nft.mint),
+		freeze_authority_pda(),
+		SYSTEM_PROGRAM,
+		TOKEN_PROGRAM,
+		TOKEN_PROGRAM,
+		ASSOCIATED_TOKEN_PROGRAM,
+		program_config_pda(),
+		2_000_000,
+		Vec::new(),
+		context.last_blockhash,
+	);
+	let result = process(&mut context, transaction).await;
+	assert_error(result, MetaplexNftProgramError::BidBelowMinimum);
+}
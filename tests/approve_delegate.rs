@@ -0,0 +1,90 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftMint};
+use {
+	anchor_lang::solana_program::program_option::COption,
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::tokio,
+	solana_sdk::{signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn approve_delegate_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let delegate = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	approve_delegate(&mut context, collection, &nft, &owner, delegate.pubkey()).await.unwrap();
+
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert_eq!(metadata.delegate, delegate.pubkey());
+	let token = token_account(&mut context, nft.token_account).await;
+	assert_eq!(token.delegate, COption::Some(delegate.pubkey()));
+	assert!(token.is_frozen());
+}
+
+#[tokio::test]
+async fn approve_delegate_ix_lets_delegate_transfer() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let delegate = funded_keypair(&mut context);
+	let recipient = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	approve_delegate(&mut context, collection, &nft, &owner, delegate.pubkey()).await.unwrap();
+
+	let destination = create_token_account(&mut context, &recipient.pubkey(), &nft.mint).await;
+	let wallet = Keypair::new().pubkey();
+	let transaction = metaplex_nft_program_ix_interface::transfer_nft_ix_setup(
+		&context.payer,
+		nft.mint,
+		nft.metadata,
+		owner.pubkey(),
+		recipient.pubkey(),
+		&delegate,
+		token_account_address(&wallet, &nft.mint),
+		wallet,
+		SYSTEM_PROGRAM,
+		TOKEN_PROGRAM,
+		nft.token_account,
+		destination,
+		&delegate,
+		ASSOCIATED_TOKEN_PROGRAM,
+		TOKEN_PROGRAM,
+		collection,
+		freeze_authority_pda(),
+		rule_set_pda(&collection),
+		INSTRUCTIONS_SYSVAR,
+		program_config_pda(),
+		0,
+		Vec::new(),
+		context.last_blockhash,
+	);
+	process(&mut context, transaction).await.unwrap();
+
+	assert_eq!(token_account(&mut context, destination).await.amount, 1);
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert_eq!(metadata.owner, recipient.pubkey());
+	assert_eq!(metadata.delegate, Default::default());
+}
+
+#[tokio::test]
+async fn approve_delegate_ix_rejects_other_owner() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let impostor = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	let result = approve_delegate(&mut context, collection, &nft, &impostor, Keypair::new().pubkey()).await;
+	assert_error(result, MetaplexNftProgramError::NotNftOwner);
+}
@@ -0,0 +1,63 @@
+pub mod common;
+
+use metaplex_nft_program::{accounts, error::MetaplexNftProgramError, instruction, Proposal};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn approve_proposal_ix_success() {
+	let mut context = start().await;
+	let creator = Keypair::new();
+	let members = [Keypair::new(), Keypair::new()];
+	let multisig = multisig_pda(&creator.pubkey(), 0);
+	create_multisig(&mut context, &creator, 0, &[members[0].pubkey(), members[1].pubkey()], 2).await.unwrap();
+	let instruction = program_instruction(
+		accounts::SetCollectionPaused { fee_payer: context.payer.pubkey(), collection: Pubkey::new_unique(), authority: multisig },
+		instruction::SetCollectionPaused { paused: true },
+	);
+	let proposal = create_proposal(&mut context, multisig, &members[0], &instruction).await.unwrap();
+
+	approve_proposal(&mut context, multisig, proposal, &members[1]).await.unwrap();
+
+	let state: Proposal = get_account(&mut context, proposal).await;
+	assert_eq!(state.approvals, vec![members[0].pubkey(), members[1].pubkey()]);
+}
+
+#[tokio::test]
+async fn approve_proposal_ix_rejects_repeated_approval() {
+	let mut context = start().await;
+	let creator = Keypair::new();
+	let member = Keypair::new();
+	let multisig = multisig_pda(&creator.pubkey(), 0);
+	create_multisig(&mut context, &creator, 0, &[member.pubkey(), Pubkey::new_unique()], 2).await.unwrap();
+	let instruction = program_instruction(
+		accounts::SetCollectionPaused { fee_payer: context.payer.pubkey(), collection: Pubkey::new_unique(), authority: multisig },
+		instruction::SetCollectionPaused { paused: true },
+	);
+	let proposal = create_proposal(&mut context, multisig, &member, &instruction).await.unwrap();
+
+	let result = approve_proposal(&mut context, multisig, proposal, &member).await;
+	assert_error(result, MetaplexNftProgramError::AlreadyApproved);
+}
+
+#[tokio::test]
+async fn approve_proposal_ix_rejects_non_member() {
+	let mut context = start().await;
+	let creator = Keypair::new();
+	let member = Keypair::new();
+	let outsider = Keypair::new();
+	let multisig = multisig_pda(&creator.pubkey(), 0);
+	create_multisig(&mut context, &creator, 0, &[member.pubkey(), Pubkey::new_unique()], 2).await.unwrap();
+	let instruction = program_instruction(
+		accounts::SetCollectionPaused { fee_payer: context.payer.pubkey(), collection: Pubkey::new_unique(), authority: multisig },
+		instruction::SetCollectionPaused { paused: true },
+	);
+	let proposal = create_proposal(&mut context, multisig, &member, &instruction).await.unwrap();
+
+	let result = approve_proposal(&mut context, multisig, proposal, &outsider).await;
+	assert_error(result, MetaplexNftProgramError::NotMultisigSigner);
+}
@@ -0,0 +1,52 @@
+pub mod common;
+
+use metaplex_nft_program::error::MetaplexNftProgramError;
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::signature::Keypair,
+};
+
+
+#[tokio::test]
+async fn burn_nft_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	burn_nft(&mut context, collection, &nft, &owner).await.unwrap();
+
+	assert!(!account_exists(&mut context, nft.token_account).await);
+	assert!(!account_exists(&mut context, nft.metadata).await);
+	assert_eq!(mint_account(&mut context, nft.mint).await.supply, 0);
+}
+
+#[tokio::test]
+async fn burn_nft_ix_burns_soulbound_nft() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	make_collection_soulbound(&mut context, collection, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	burn_nft(&mut context, collection, &nft, &owner).await.unwrap();
+
+	assert!(!account_exists(&mut context, nft.metadata).await);
+	assert_eq!(mint_account(&mut context, nft.mint).await.supply, 0);
+}
+
+#[tokio::test]
+async fn burn_nft_ix_rejects_listed_nft() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	list_nft_in_place(&mut context, collection, &nft, &owner, 1_000_000_000).await.unwrap();
+
+	let result = burn_nft(&mut context, collection, &nft, &owner).await;
+	assert_error(result, MetaplexNftProgramError::NftListed);
+}
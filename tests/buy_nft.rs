@@ -0,0 +1,86 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftMint};
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::{tokio, BanksClientError, ProgramTestContext},
+	solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+async fn buy(
+	context: &mut ProgramTestContext,
+	buyer: &Keypair,
+	seller: Pubkey,
+	collection: Pubkey,
+	nft: &Nft,
+	max_price: u64,
+	creators: &[Pubkey],
+) -> Result<(), BanksClientError> {
+	let listing = listing_pda(&nft.mint);
+	let transaction = metaplex_nft_program_ix_interface::buy_nft_ix_setup(
+		&context.payer,
+		buyer,
+		seller,
+		nft.mint,
+		nft.metadata,
+		collection,
+		listing,
+		token_account_address(&listing, &nft.mint),
+		token_account_address(&buyer.pubkey(), &nft.mint),
+		freeze_authority_pda(),
+		SYSTEM_PROGRAM,
+		TOKEN_PROGRAM,
+		TOKEN_PROGRAM,
+		ASSOCIATED_TOKEN_PROGRAM,
+		program_config_pda(),
+		max_price,
+		creators.iter().map(|creator| AccountMeta::new(*creator, false)).collect(),
+		context.last_blockhash,
+	);
+	process(context, transaction).await
+}
+
+#[tokio::test]
+async fn buy_nft_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let seller = funded_keypair(&mut context);
+	let buyer = funded_keypair(&mut context);
+	let creator = funded_keypair(&mut context).pubkey();
+	let collection = create_collection(&mut context, &authority).await;
+	set_royalties(&mut context, collection, &authority, 500, &[creator]).await;
+	let nft = mint_nft(&mut context, collection, &authority, &seller).await;
+	list_nft(&mut context, collection, &nft, &seller, 1_000_000_000).await.unwrap();
+
+	let listing = listing_pda(&nft.mint);
+	let rent = lamports(&mut context, listing).await + lamports(&mut context, token_account_address(&listing, &nft.mint)).await;
+	let seller_before = lamports(&mut context, seller.pubkey()).await;
+	let creator_before = lamports(&mut context, creator).await;
+	buy(&mut context, &buyer, seller.pubkey(), collection, &nft, 1_000_000_000, &[creator]).await.unwrap();
+
+	assert_eq!(lamports(&mut context, creator).await - creator_before, 50_000_000);
+	assert_eq!(lamports(&mut context, seller.pubkey()).await - seller_before, 950_000_000 + rent);
+	let buyer_token_account = token_account_address(&buyer.pubkey(), &nft.mint);
+	assert_eq!(token_account(&mut context, buyer_token_account).await.amount, 1);
+	assert!(!account_exists(&mut context, listing).await);
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert_eq!(metadata.owner, buyer.pubkey());
+}
+
+#[tokio::test]
+async fn buy_nft_ix_rejects_price_above_max_price() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let seller = funded_keypair(&mut context);
+	let buyer = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &seller).await;
+	list_nft(&mut context, collection, &nft, &seller, 1_000_000_000).await.unwrap();
+
+	let result = buy(&mut context, &buyer, seller.pubkey(), collection, &nft, 999_999_999, &[]).await;
+	assert_error(result, MetaplexNftProgramError::PriceAboveMaximum);
+	assert_eq!(token_account(&mut context, token_account_address(&listing_pda(&nft.mint), &nft.mint)).await.amount, 1);
+}
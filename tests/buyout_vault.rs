@@ -0,0 +1,87 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftMint, Vault};
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::{tokio, BanksClientError, ProgramTestContext},
+	solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+async fn buyout(context: &mut ProgramTestContext, collection: Pubkey, nft: &Nft, buyer: &Keypair, creators: &[Pubkey]) -> Result<(), BanksClientError> {
+	let vault = vault_pda(&nft.mint);
+	let share_mint = share_mint_pda(&vault);
+	let transaction = metaplex_nft_program_ix_interface::buyout_vault_ix_setup(
+		&context.payer,
+		buyer,
+		nft.mint,
+		nft.metadata,
+		collection,
+		vault,
+		share_mint,
+		token_account_address(&buyer.pubkey(), &share_mint),
+		token_account_address(&vault, &nft.mint),
+		token_account_address(&buyer.pubkey(), &nft.mint),
+		freeze_authority_pda(),
+		SYSTEM_PROGRAM,
+		TOKEN_PROGRAM,
+		TOKEN_PROGRAM,
+		ASSOCIATED_TOKEN_PROGRAM,
+		program_config_pda(),
+		creators.iter().map(|creator| AccountMeta::new(*creator, false)).collect(),
+		context.last_blockhash,
+	);
+	process(context, transaction).await
+}
+
+#[tokio::test]
+async fn buyout_vault_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let curator = funded_keypair(&mut context);
+	let buyer = funded_keypair(&mut context);
+	let creator = funded_keypair(&mut context).pubkey();
+	let collection = create_collection(&mut context, &authority).await;
+	set_royalties(&mut context, collection, &authority, 500, &[creator]).await;
+	enable_royalty_enforcement(&mut context, collection, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &curator).await;
+	fractionalize_nft(&mut context, collection, &nft, &curator, 100, 1_000_000_000).await.unwrap();
+	let vault_address = vault_pda(&nft.mint);
+	transfer_tokens(&mut context, &share_mint_pda(&vault_address), &curator, &buyer.pubkey(), 60).await;
+
+	let vault_before = lamports(&mut context, vault_address).await;
+	buyout(&mut context, collection, &nft, &buyer, &[creator]).await.unwrap();
+
+	// The royalty on the 40 outstanding shares comes on top of their price.
+	assert_eq!(lamports(&mut context, vault_address).await, vault_before + 400_000_000);
+	assert_eq!(lamports(&mut context, creator).await, LAMPORTS + 20_000_000);
+	let buyer_token = token_account(&mut context, token_account_address(&buyer.pubkey(), &nft.mint)).await;
+	assert_eq!(buyer_token.amount, 1);
+	assert!(buyer_token.is_frozen());
+	assert_eq!(mint_account(&mut context, share_mint_pda(&vault_address)).await.supply, 40);
+	let vault: Vault = get_account(&mut context, vault_address).await;
+	assert!(vault.bought_out);
+	assert_eq!(vault.buyer, buyer.pubkey());
+	assert_eq!(vault.outstanding_shares, 40);
+	assert_eq!(vault.proceeds, 400_000_000);
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert_eq!(metadata.owner, buyer.pubkey());
+}
+
+#[tokio::test]
+async fn buyout_vault_ix_rejects_bought_out_vault() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let curator = funded_keypair(&mut context);
+	let buyer = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &curator).await;
+	fractionalize_nft(&mut context, collection, &nft, &curator, 100, 1_000_000_000).await.unwrap();
+	transfer_tokens(&mut context, &share_mint_pda(&vault_pda(&nft.mint)), &curator, &buyer.pubkey(), 60).await;
+	buyout(&mut context, collection, &nft, &buyer, &[]).await.unwrap();
+
+	let result = buyout(&mut context, collection, &nft, &curator, &[]).await;
+	assert_error(result, MetaplexNftProgramError::VaultBoughtOut);
+}
@@ -0,0 +1,54 @@
+pub mod common;
+
+use anchor_lang::error::ErrorCode;
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::tokio,
+	solana_sdk::{signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn cancel_bid_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let bidder = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let expires_at = unix_timestamp(&mut context).await + 3_600;
+	place_bid(&mut context, collection, &bidder, None, 1_000_000, expires_at).await.unwrap();
+
+	let bid = bid_pda(&bidder.pubkey(), &collection);
+	let transaction = metaplex_nft_program_ix_interface::cancel_bid_ix_setup(
+		&context.payer,
+		&bidder,
+		bid,
+		context.last_blockhash,
+	);
+	process(&mut context, transaction).await.unwrap();
+
+	assert!(!account_exists(&mut context, bid).await);
+	assert_eq!(lamports(&mut context, bidder.pubkey()).await, LAMPORTS);
+}
+
+#[tokio::test]
+async fn cancel_bid_ix_rejects_another_signer() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let bidder = funded_keypair(&mut context);
+	let other = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let expires_at = unix_timestamp(&mut context).await + 3_600;
+	place_bid(&mut context, collection, &bidder, None, 1_000_000, expires_at).await.unwrap();
+
+	let transaction = metaplex_nft_program_ix_interface::cancel_bid_ix_setup(
+		&context.payer,
+		&other,
+		bid_pda(&bidder.pubkey(), &collection),
+		context.last_blockhash,
+	);
+	let result = process(&mut context, transaction).await;
+	assert_error(result, ErrorCode::ConstraintSeeds);
+}
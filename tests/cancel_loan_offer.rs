@@ -0,0 +1,55 @@
+pub mod common;
+
+use metaplex_nft_program::error::MetaplexNftProgramError;
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::tokio,
+	solana_sdk::{signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn cancel_loan_offer_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let lender = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	create_loan_offer(&mut context, collection, &lender, 7, 1_000_000_000, 86_400, 50_000_000).await.unwrap();
+
+	let loan = loan_pda(&lender.pubkey(), 7);
+	let transaction = metaplex_nft_program_ix_interface::cancel_loan_offer_ix_setup(
+		&context.payer,
+		&lender,
+		loan,
+		context.last_blockhash,
+	);
+	process(&mut context, transaction).await.unwrap();
+
+	assert!(!account_exists(&mut context, loan).await);
+	assert_eq!(lamports(&mut context, lender.pubkey()).await, LAMPORTS);
+}
+
+#[tokio::test]
+async fn cancel_loan_offer_ix_rejects_taken_loan() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let lender = funded_keypair(&mut context);
+	let borrower = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &borrower).await;
+	create_loan_offer(&mut context, collection, &lender, 7, 1_000_000_000, 86_400, 50_000_000).await.unwrap();
+	let loan = loan_pda(&lender.pubkey(), 7);
+	take_loan(&mut context, collection, &nft, &borrower, loan).await.unwrap();
+
+	let transaction = metaplex_nft_program_ix_interface::cancel_loan_offer_ix_setup(
+		&context.payer,
+		&lender,
+		loan,
+		context.last_blockhash,
+	);
+	let result = process(&mut context, transaction).await;
+	assert_error(result, MetaplexNftProgramError::LoanAlreadyTaken);
+}
@@ -0,0 +1,58 @@
+pub mod common;
+
+use metaplex_nft_program::error::MetaplexNftProgramError;
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::{tokio, BanksClientError, ProgramTestContext},
+	solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+async fn claim(context: &mut ProgramTestContext, collection: Pubkey, minter: Pubkey, nft: &Nft) -> Result<(), BanksClientError> {
+	let transaction = metaplex_nft_program_ix_interface::claim_mint_rebate_ix_setup(
+		&context.payer,
+		minter,
+		mint_config_pda(&collection),
+		mint_receipt_pda(&nft.mint),
+		context.last_blockhash,
+	);
+	process(context, transaction).await
+}
+
+#[tokio::test]
+async fn claim_mint_rebate_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let early_minter = funded_keypair(&mut context);
+	let late_minter = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let now = unix_timestamp(&mut context).await;
+	set_mint_config(&mut context, collection, &authority, 1_000_000_000, 500_000_000, now, 100_000_000, 2).await.unwrap();
+	let nft = mint_nft_paid(&mut context, collection, &authority, &early_minter).await.unwrap();
+	set_unix_timestamp(&mut context, now + 200).await;
+	mint_nft_paid(&mut context, collection, &authority, &late_minter).await.unwrap();
+
+	// The drop sold out at 0.8 SOL, so the early minter is owed 0.2 SOL.
+	let receipt_rent = lamports(&mut context, mint_receipt_pda(&nft.mint)).await;
+	let before = lamports(&mut context, early_minter.pubkey()).await;
+	claim(&mut context, collection, early_minter.pubkey(), &nft).await.unwrap();
+
+	assert_eq!(lamports(&mut context, early_minter.pubkey()).await, before + 200_000_000 + receipt_rent);
+	assert!(!account_exists(&mut context, mint_receipt_pda(&nft.mint)).await);
+}
+
+#[tokio::test]
+async fn claim_mint_rebate_ix_rejects_running_drop() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let minter = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let now = unix_timestamp(&mut context).await;
+	set_mint_config(&mut context, collection, &authority, 1_000_000_000, 500_000_000, now, 100_000_000, 10).await.unwrap();
+	let nft = mint_nft_paid(&mut context, collection, &authority, &minter).await.unwrap();
+
+	let result = claim(&mut context, collection, minter.pubkey(), &nft).await;
+	assert_error(result, MetaplexNftProgramError::MintNotConcluded);
+}
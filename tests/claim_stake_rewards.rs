@@ -0,0 +1,67 @@
+pub mod common;
+
+use anchor_lang::error::ErrorCode;
+use metaplex_nft_program::StakeEntry;
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::{tokio, BanksClientError, ProgramTestContext},
+	solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+async fn claim(context: &mut ProgramTestContext, collection: Pubkey, reward_mint: Pubkey, nft: &Nft, owner: &Keypair) -> Result<(), BanksClientError> {
+	let transaction = metaplex_nft_program_ix_interface::claim_stake_rewards_ix_setup(
+		&context.payer,
+		owner,
+		stake_pool_pda(&collection),
+		collection,
+		stake_entry_pda(&nft.mint),
+		reward_mint,
+		token_account_address(&owner.pubkey(), &reward_mint),
+		SYSTEM_PROGRAM,
+		TOKEN_PROGRAM,
+		TOKEN_PROGRAM,
+		ASSOCIATED_TOKEN_PROGRAM,
+		program_config_pda(),
+		context.last_blockhash,
+	);
+	process(context, transaction).await
+}
+
+#[tokio::test]
+async fn claim_stake_rewards_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let reward_mint = create_stake_pool(&mut context, collection, &authority, 10).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	let now = unix_timestamp(&mut context).await;
+	stake_nft(&mut context, collection, &nft, &owner).await.unwrap();
+
+	set_unix_timestamp(&mut context, now + 100).await;
+	claim(&mut context, collection, reward_mint, &nft, &owner).await.unwrap();
+
+	let rewards = token_account(&mut context, token_account_address(&owner.pubkey(), &reward_mint)).await;
+	assert_eq!(rewards.amount, 1_000);
+	let stake_entry: StakeEntry = get_account(&mut context, stake_entry_pda(&nft.mint)).await;
+	assert_eq!(stake_entry.last_claimed_at, now + 100);
+	assert_eq!(stake_entry.staked_at, now);
+}
+
+#[tokio::test]
+async fn claim_stake_rewards_ix_rejects_another_owner() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let other = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let reward_mint = create_stake_pool(&mut context, collection, &authority, 10).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	stake_nft(&mut context, collection, &nft, &owner).await.unwrap();
+
+	let result = claim(&mut context, collection, reward_mint, &nft, &other).await;
+	assert_error(result, ErrorCode::ConstraintHasOne);
+}
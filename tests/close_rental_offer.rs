@@ -0,0 +1,55 @@
+pub mod common;
+
+use anchor_lang::error::ErrorCode;
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::tokio,
+	solana_sdk::{signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn close_rental_offer_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	create_rental_offer(&mut context, collection, &nft, &owner, 10_000_000, 7).await.unwrap();
+
+	let rental_offer = rental_offer_pda(&nft.mint, &owner.pubkey());
+	let rent = lamports(&mut context, rental_offer).await;
+	let transaction = metaplex_nft_program_ix_interface::close_rental_offer_ix_setup(
+		&context.payer,
+		&owner,
+		rental_offer,
+		context.last_blockhash,
+	);
+	process(&mut context, transaction).await.unwrap();
+
+	assert!(!account_exists(&mut context, rental_offer).await);
+	assert_eq!(lamports(&mut context, owner.pubkey()).await, LAMPORTS + rent);
+}
+
+#[tokio::test]
+async fn close_rental_offer_ix_rejects_another_signer() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let other = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	create_rental_offer(&mut context, collection, &nft, &owner, 10_000_000, 7).await.unwrap();
+
+	let transaction = metaplex_nft_program_ix_interface::close_rental_offer_ix_setup(
+		&context.payer,
+		&other,
+		rental_offer_pda(&nft.mint, &owner.pubkey()),
+		context.last_blockhash,
+	);
+	let result = process(&mut context, transaction).await;
+	assert_error(result, ErrorCode::ConstraintHasOne);
+}
@@ -0,0 +1,3607 @@
+// The instruction builders below take one argument per account, in the
+// generated style, and build their transactions the same way.
+#![allow(
+	clippy::too_many_arguments,
+	clippy::needless_return,
+	clippy::redundant_field_names,
+	clippy::ptr_arg,
+	clippy::let_and_return,
+	clippy::macro_metavars_in_unsafe,
+	clippy::clone_on_copy,
+)]
+
+use {
+	metaplex_nft_program::{
+			entry,
+			ID as PROGRAM_ID,
+	},
+	solana_sdk::{
+		entrypoint::{ProcessInstruction, ProgramResult},
+		pubkey::Pubkey,
+	},
+	anchor_lang::prelude::AccountInfo,
+	solana_program_test::*,
+};
+
+// Type alias for the entry function pointer used to convert the entry function into a ProcessInstruction function pointer.
+pub type ProgramEntry = for<'info> fn(
+	program_id: &Pubkey,
+	accounts: &'info [AccountInfo<'info>],
+	instruction_data: &[u8],
+) -> ProgramResult;
+
+// Macro to convert the entry function into a ProcessInstruction function pointer.
+#[macro_export]
+macro_rules! convert_entry {
+	($entry:expr) => {
+		// Use unsafe block to perform memory transmutation.
+		unsafe { core::mem::transmute::<ProgramEntry, ProcessInstruction>($entry) }
+	};
+}
+
+pub fn get_program_test() -> ProgramTest {
+	let program_test = ProgramTest::new(
+		"metaplex_nft_program",
+		PROGRAM_ID,
+		processor!(convert_entry!(entry)),
+	);
+	program_test
+}
+
+/// Helpers shared by the instruction tests for setting up real program state
+/// and inspecting it afterwards.
+pub mod fixtures {
+
+	use {
+		super::{get_program_test, metaplex_nft_program_ix_interface},
+		anchor_lang::{
+			solana_program::{program_option::COption, program_pack::Pack},
+			system_program,
+			AccountDeserialize,
+			AccountSerialize,
+			InstructionData,
+			ToAccountMetas,
+		},
+		anchor_spl::{
+			associated_token::{
+				get_associated_token_address,
+				spl_associated_token_account::instruction::create_associated_token_account,
+			},
+			token::{spl_token, Mint, TokenAccount},
+		},
+		metaplex_nft_program::{Auction, CollectionCreator, Loan, MintBatchEntry, Multisig, NftCollection, ProgramConfig, Proposal, ProposalAccountMeta, RentalOffer, RevealEntry, RuleSetMode, Treasury, ID as PROGRAM_ID},
+		solana_program_test::{BanksClientError, ProgramTestContext},
+		solana_sdk::{
+			account::AccountSharedData,
+			clock::Clock,
+			instruction::{AccountMeta, Instruction, InstructionError},
+			pubkey::Pubkey,
+			signature::Keypair,
+			signer::Signer,
+			transaction::{Transaction, TransactionError},
+		},
+	};
+
+	pub const SYSTEM_PROGRAM: Pubkey = system_program::ID;
+	pub const TOKEN_PROGRAM: Pubkey = anchor_spl::token::ID;
+	pub const ASSOCIATED_TOKEN_PROGRAM: Pubkey = anchor_spl::associated_token::ID;
+
+	pub const INSTRUCTIONS_SYSVAR: Pubkey = anchor_lang::solana_program::sysvar::instructions::ID;
+	pub const LAMPORTS: u64 = 100_000_000_000;
+
+	/// Accounts of an NFT minted by [`mint_nft`].
+	pub struct Nft {
+		pub mint: Pubkey,
+		pub metadata: Pubkey,
+		pub token_account: Pubkey,
+	}
+
+	pub async fn start() -> ProgramTestContext {
+		get_program_test().start_with_context().await
+	}
+
+	pub fn pda(seeds: &[&[u8]]) -> Pubkey {
+		Pubkey::find_program_address(seeds, &PROGRAM_ID).0
+	}
+
+	pub fn collection_pda(seed_authority: &Pubkey) -> Pubkey {
+		pda(&[b"collection", seed_authority.as_ref()])
+	}
+
+	pub fn metadata_pda(mint: &Pubkey) -> Pubkey {
+		pda(&[b"metadata", mint.as_ref()])
+	}
+
+	pub fn item_index_pda(collection: &Pubkey, item_number: u64) -> Pubkey {
+		pda(&[b"item", collection.as_ref(), &item_number.to_le_bytes()])
+	}
+
+	pub fn mint_config_pda(collection: &Pubkey) -> Pubkey {
+		pda(&[b"mint_config", collection.as_ref()])
+	}
+
+	pub fn mint_receipt_pda(mint: &Pubkey) -> Pubkey {
+		pda(&[b"mint_receipt", mint.as_ref()])
+	}
+
+	pub fn freeze_authority_pda() -> Pubkey {
+		pda(&[b"freeze_authority"])
+	}
+
+	pub fn program_config_pda() -> Pubkey {
+		pda(&[b"program_config"])
+	}
+
+	pub fn treasury_pda() -> Pubkey {
+		pda(&[b"treasury"])
+	}
+
+	pub fn listing_pda(mint: &Pubkey) -> Pubkey {
+		pda(&[b"listing", mint.as_ref()])
+	}
+
+	pub fn bid_pda(bidder: &Pubkey, target: &Pubkey) -> Pubkey {
+		pda(&[b"bid", bidder.as_ref(), target.as_ref()])
+	}
+
+	pub fn auction_pda(mint: &Pubkey) -> Pubkey {
+		pda(&[b"auction", mint.as_ref()])
+	}
+
+	pub fn bid_refund_pda(auction: &Pubkey, bidder: &Pubkey) -> Pubkey {
+		pda(&[b"bid_refund", auction.as_ref(), bidder.as_ref()])
+	}
+
+	pub fn rental_offer_pda(mint: &Pubkey, owner: &Pubkey) -> Pubkey {
+		pda(&[b"rental", mint.as_ref(), owner.as_ref()])
+	}
+
+	pub fn stake_pool_pda(collection: &Pubkey) -> Pubkey {
+		pda(&[b"stake_pool", collection.as_ref()])
+	}
+
+	pub fn stake_entry_pda(mint: &Pubkey) -> Pubkey {
+		pda(&[b"stake_entry", mint.as_ref()])
+	}
+
+	pub fn vault_pda(mint: &Pubkey) -> Pubkey {
+		pda(&[b"vault", mint.as_ref()])
+	}
+
+	pub fn share_mint_pda(vault: &Pubkey) -> Pubkey {
+		pda(&[b"shares", vault.as_ref()])
+	}
+
+	pub fn loan_pda(lender: &Pubkey, offer_id: u64) -> Pubkey {
+		pda(&[b"loan", lender.as_ref(), &offer_id.to_le_bytes()])
+	}
+
+	pub fn rule_set_pda(collection: &Pubkey) -> Pubkey {
+		pda(&[b"rule_set", collection.as_ref()])
+	}
+
+	pub fn history_pda(mint: &Pubkey) -> Pubkey {
+		pda(&[b"history", mint.as_ref()])
+	}
+
+	pub fn multisig_pda(creator: &Pubkey, nonce: u64) -> Pubkey {
+		pda(&[b"multisig", creator.as_ref(), &nonce.to_le_bytes()])
+	}
+
+	pub fn proposal_pda(multisig: &Pubkey, index: u64) -> Pubkey {
+		pda(&[b"proposal", multisig.as_ref(), &index.to_le_bytes()])
+	}
+
+	pub fn token_account_address(wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+		get_associated_token_address(wallet, mint)
+	}
+
+	/// Returns a new keypair whose system account holds [`LAMPORTS`].
+	pub fn funded_keypair(context: &mut ProgramTestContext) -> Keypair {
+		let keypair = Keypair::new();
+		context.set_account(&keypair.pubkey(), &AccountSharedData::new(LAMPORTS, 0, &SYSTEM_PROGRAM));
+		keypair
+	}
+
+	/// Creates a zero-decimal SPL mint with `mint_authority` and returns its address.
+	pub async fn create_mint(context: &mut ProgramTestContext, mint_authority: &Pubkey) -> Pubkey {
+		let address = Keypair::new().pubkey();
+		let mint = spl_token::state::Mint {
+			mint_authority: COption::Some(*mint_authority),
+			supply: 0,
+			decimals: 0,
+			is_initialized: true,
+			freeze_authority: COption::None,
+		};
+		let mut data = vec![0; spl_token::state::Mint::LEN];
+		spl_token::state::Mint::pack(mint, &mut data).unwrap();
+		let rent = context.banks_client.get_rent().await.unwrap();
+		let mut account = AccountSharedData::new(rent.minimum_balance(data.len()), data.len(), &TOKEN_PROGRAM);
+		account.set_data_from_slice(&data);
+		context.set_account(&address, &account);
+		address
+	}
+
+	/// Writes the program config and treasury initialize_program_config would
+	/// create for `admin`. The instruction itself cannot run here: it checks
+	/// the upgradeable loader's program data, which a natively loaded program
+	/// does not have.
+	pub async fn initialize_program_config(context: &mut ProgramTestContext, admin: &Pubkey) {
+		let (program_config, program_config_bump) = Pubkey::find_program_address(&[b"program_config"], &PROGRAM_ID);
+		let (treasury, treasury_bump) = Pubkey::find_program_address(&[b"treasury"], &PROGRAM_ID);
+		let config = ProgramConfig {
+			admin: *admin,
+			paused: false,
+			bump: program_config_bump,
+			mint_fee_lamports: 0,
+			mint_fee_bps: 0,
+			treasury,
+		};
+		set_program_account(context, program_config, &config, 84).await;
+		set_program_account(context, treasury, &Treasury { bump: treasury_bump }, 9).await;
+	}
+
+	/// Switches the program-wide pause as `admin`.
+	pub async fn set_program_paused(context: &mut ProgramTestContext, admin: &Keypair, paused: bool) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::set_program_paused_ix_setup(
+			&context.payer,
+			program_config_pda(),
+			admin,
+			paused,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Switches the collection's pause as its `authority`.
+	pub async fn set_collection_paused(context: &mut ProgramTestContext, collection: Pubkey, authority: &Keypair, paused: bool) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::set_collection_paused_ix_setup(
+			&context.payer,
+			collection,
+			authority,
+			paused,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Sets the protocol fee charged on every mint as `admin`.
+	pub async fn set_protocol_fee(context: &mut ProgramTestContext, admin: &Keypair, mint_fee_lamports: u64, mint_fee_bps: u16) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::set_protocol_fee_ix_setup(
+			&context.payer,
+			program_config_pda(),
+			admin,
+			mint_fee_lamports,
+			mint_fee_bps,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	async fn set_program_account<T: AccountSerialize>(context: &mut ProgramTestContext, address: Pubkey, state: &T, space: usize) {
+		let rent = context.banks_client.get_rent().await.unwrap();
+		let mut data = Vec::with_capacity(space);
+		state.try_serialize(&mut data).unwrap();
+		data.resize(space, 0);
+		let mut account = AccountSharedData::new(rent.minimum_balance(space), space, &PROGRAM_ID);
+		account.set_data_from_slice(&data);
+		context.set_account(&address, &account);
+	}
+
+	/// Creates the associated token account of `wallet` for `mint`.
+	pub async fn create_token_account(context: &mut ProgramTestContext, wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+		let transaction = Transaction::new_signed_with_payer(
+			&[create_associated_token_account(&context.payer.pubkey(), wallet, mint, &TOKEN_PROGRAM)],
+			Some(&context.payer.pubkey()),
+			&[&context.payer],
+			context.last_blockhash,
+		);
+		process(context, transaction).await.unwrap();
+		token_account_address(wallet, mint)
+	}
+
+	/// Moves `amount` tokens of `mint` from the associated account of `owner`
+	/// to that of `recipient`, creating the latter.
+	pub async fn transfer_tokens(context: &mut ProgramTestContext, mint: &Pubkey, owner: &Keypair, recipient: &Pubkey, amount: u64) {
+		let transaction = Transaction::new_signed_with_payer(
+			&[
+				create_associated_token_account(&context.payer.pubkey(), recipient, mint, &TOKEN_PROGRAM),
+				spl_token::instruction::transfer(
+					&TOKEN_PROGRAM,
+					&token_account_address(&owner.pubkey(), mint),
+					&token_account_address(recipient, mint),
+					&owner.pubkey(),
+					&[],
+					amount,
+				).unwrap(),
+			],
+			Some(&context.payer.pubkey()),
+			&[&context.payer, owner],
+			context.last_blockhash,
+		);
+		process(context, transaction).await.unwrap();
+	}
+
+	pub async fn process(context: &mut ProgramTestContext, transaction: Transaction) -> Result<(), BanksClientError> {
+		context.banks_client.process_transaction(transaction).await
+	}
+
+	pub async fn get_account<T: AccountDeserialize>(context: &mut ProgramTestContext, address: Pubkey) -> T {
+		let account = context.banks_client.get_account(address).await.unwrap()
+			.unwrap_or_else(|| panic!("account {} does not exist", address));
+		T::try_deserialize(&mut account.data.as_slice()).unwrap()
+	}
+
+	pub async fn account_exists(context: &mut ProgramTestContext, address: Pubkey) -> bool {
+		context.banks_client.get_account(address).await.unwrap().is_some()
+	}
+
+	pub async fn lamports(context: &mut ProgramTestContext, address: Pubkey) -> u64 {
+		context.banks_client.get_balance(address).await.unwrap()
+	}
+
+	pub async fn token_account(context: &mut ProgramTestContext, address: Pubkey) -> TokenAccount {
+		get_account(context, address).await
+	}
+
+	pub async fn mint_account(context: &mut ProgramTestContext, address: Pubkey) -> Mint {
+		get_account(context, address).await
+	}
+
+	pub async fn set_unix_timestamp(context: &mut ProgramTestContext, unix_timestamp: i64) {
+		let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+		clock.unix_timestamp = unix_timestamp;
+		context.set_sysvar(&clock);
+	}
+
+	pub async fn unix_timestamp(context: &mut ProgramTestContext) -> i64 {
+		let clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+		clock.unix_timestamp
+	}
+
+	/// Asserts the transaction failed with the given program or Anchor error.
+	pub fn assert_error(result: Result<(), BanksClientError>, error: impl Into<u32>) {
+		let code = error.into();
+		match result.expect_err("the transaction succeeded").unwrap() {
+			TransactionError::InstructionError(_, InstructionError::Custom(actual)) => assert_eq!(actual, code),
+			other => panic!("expected custom error {}, got {:?}", code, other),
+		}
+	}
+
+	/// Creates a collection with `authority` as its authority and seed authority.
+	pub async fn create_collection(context: &mut ProgramTestContext, authority: &Keypair) -> Pubkey {
+		create_collection_revealing(context, authority, None).await
+	}
+
+	/// Like `create_collection`, for a placeholder launch committed to `reveal_root`.
+	pub async fn create_placeholder_collection(context: &mut ProgramTestContext, authority: &Keypair, reveal_root: [u8; 32]) -> Pubkey {
+		create_collection_revealing(context, authority, Some(reveal_root)).await
+	}
+
+	async fn create_collection_revealing(context: &mut ProgramTestContext, authority: &Keypair, reveal_root: Option<[u8; 32]>) -> Pubkey {
+		let collection = collection_pda(&authority.pubkey());
+		let transaction = metaplex_nft_program_ix_interface::initialize_collection_ix_setup(
+			&context.payer,
+			collection,
+			authority,
+			SYSTEM_PROGRAM,
+			&"Collection".to_string(),
+			&"COL".to_string(),
+			&"https://example.com/collection.json".to_string(),
+			None,
+			reveal_root,
+			context.last_blockhash,
+		);
+		process(context, transaction).await.unwrap();
+		collection
+	}
+
+	/// Mints an NFT of `collection` to `owner`, paid for by the context's payer.
+	pub async fn mint_nft(context: &mut ProgramTestContext, collection: Pubkey, authority: &Keypair, owner: &Keypair) -> Nft {
+		try_mint_nft(context, collection, authority, owner).await.unwrap()
+	}
+
+	/// Like `mint_nft`, for mints that are expected to fail.
+	pub async fn try_mint_nft(context: &mut ProgramTestContext, collection: Pubkey, authority: &Keypair, owner: &Keypair) -> Result<Nft, BanksClientError> {
+		let funding = context.payer.insecure_clone();
+		mint_nft_funded_by(context, collection, authority, owner, &funding, false).await
+	}
+
+	/// Mints an NFT of a collection with mint pricing to `minter`, who pays for it.
+	pub async fn mint_nft_paid(context: &mut ProgramTestContext, collection: Pubkey, authority: &Keypair, minter: &Keypair) -> Result<Nft, BanksClientError> {
+		mint_nft_funded_by(context, collection, authority, minter, minter, true).await
+	}
+
+	async fn mint_nft_funded_by(context: &mut ProgramTestContext, collection: Pubkey, authority: &Keypair, owner: &Keypair, funding: &Keypair, paid: bool) -> Result<Nft, BanksClientError> {
+		let mint = Keypair::new();
+		let nft = Nft {
+			mint: mint.pubkey(),
+			metadata: metadata_pda(&mint.pubkey()),
+			token_account: token_account_address(&owner.pubkey(), &mint.pubkey()),
+		};
+		let state = get_account::<NftCollection>(context, collection).await;
+		let nft_count = state.nft_count;
+		// Under a base URI the NFT stores no suffix and resolves to `<index>.json`.
+		let uri = if state.base_uri.is_empty() { format!("https://example.com/{}.json", nft_count) } else { String::new() };
+		let transaction = metaplex_nft_program_ix_interface::mint_nft_ix_setup(
+			&context.payer,
+			collection,
+			&mint,
+			nft.metadata,
+			authority,
+			owner,
+			SYSTEM_PROGRAM,
+			funding,
+			nft.token_account,
+			owner.pubkey(),
+			TOKEN_PROGRAM,
+			TOKEN_PROGRAM,
+			ASSOCIATED_TOKEN_PROGRAM,
+			mint_config_pda(&collection),
+			paid.then(|| mint_receipt_pda(&mint.pubkey())),
+			freeze_authority_pda(),
+			program_config_pda(),
+			treasury_pda(),
+			item_index_pda(&collection, nft_count),
+			&format!("NFT #{}", nft_count),
+			&"COL".to_string(),
+			&uri,
+			None,
+			context.last_blockhash,
+		);
+		process(context, transaction).await?;
+		Ok(nft)
+	}
+
+	/// Prices the collection's mints from `start_price` at `start_time` down
+	/// to `end_price`, falling by `price_step` every 100 seconds.
+	pub async fn set_mint_config(
+		context: &mut ProgramTestContext,
+		collection: Pubkey,
+		authority: &Keypair,
+		start_price: u64,
+		end_price: u64,
+		start_time: i64,
+		price_step: u64,
+		max_supply: u64,
+	) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::set_mint_config_ix_setup(
+			&context.payer,
+			collection,
+			mint_config_pda(&collection),
+			authority,
+			SYSTEM_PROGRAM,
+			start_price,
+			end_price,
+			start_time,
+			100,
+			price_step,
+			max_supply,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	pub fn batch_entry(name: &str, recipient: Pubkey) -> MintBatchEntry {
+		MintBatchEntry {
+			name: name.to_string(),
+			uri: format!("https://example.com/{}.json", name),
+			recipient,
+			content_hash: None,
+		}
+	}
+
+	/// Remaining accounts of a batch minting `mints[i]` to `wallets[i]`, starting at item `first_item`.
+	pub fn batch_accounts(collection: &Pubkey, first_item: u64, mints: &[&Keypair], wallets: &[Pubkey]) -> Vec<AccountMeta> {
+		let mut accounts = Vec::new();
+		for (offset, (mint, wallet)) in mints.iter().zip(wallets).enumerate() {
+			accounts.push(AccountMeta::new(mint.pubkey(), true));
+			accounts.push(AccountMeta::new(metadata_pda(&mint.pubkey()), false));
+			accounts.push(AccountMeta::new(token_account_address(wallet, &mint.pubkey()), false));
+			accounts.push(AccountMeta::new_readonly(*wallet, false));
+			accounts.push(AccountMeta::new(item_index_pda(collection, first_item + offset as u64), false));
+		}
+		accounts
+	}
+
+	pub async fn mint_batch(
+		context: &mut ProgramTestContext,
+		collection: Pubkey,
+		authority: &Keypair,
+		entries: &Vec<MintBatchEntry>,
+		remaining_accounts: Vec<AccountMeta>,
+		mints: &[&Keypair],
+	) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::mint_batch_ix_setup(
+			&context.payer,
+			collection,
+			authority,
+			freeze_authority_pda(),
+			SYSTEM_PROGRAM,
+			TOKEN_PROGRAM,
+			TOKEN_PROGRAM,
+			ASSOCIATED_TOKEN_PROGRAM,
+			program_config_pda(),
+			treasury_pda(),
+			mint_config_pda(&collection),
+			entries,
+			remaining_accounts,
+			mints,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Sets the collection's royalty to `seller_fee_basis_points`, shared equally by `creators`.
+	pub async fn set_royalties(context: &mut ProgramTestContext, collection: Pubkey, authority: &Keypair, seller_fee_basis_points: u16, creators: &[Pubkey]) {
+		let creators = creators.iter()
+			.map(|address| CollectionCreator { address: *address, share: (100 / creators.len()) as u8 })
+			.collect();
+		let transaction = metaplex_nft_program_ix_interface::set_collection_royalties_ix_setup(
+			&context.payer,
+			collection,
+			authority,
+			seller_fee_basis_points,
+			&creators,
+			context.last_blockhash,
+		);
+		process(context, transaction).await.unwrap();
+	}
+
+	/// Makes the collection's tokens move only through the program, paying royalties.
+	pub async fn enable_royalty_enforcement(context: &mut ProgramTestContext, collection: Pubkey, authority: &Keypair) {
+		let transaction = metaplex_nft_program_ix_interface::enable_royalty_enforcement_ix_setup(
+			&context.payer,
+			collection,
+			authority,
+			context.last_blockhash,
+		);
+		process(context, transaction).await.unwrap();
+	}
+
+	/// Makes the collection's NFTs non-transferable.
+	pub async fn make_collection_soulbound(context: &mut ProgramTestContext, collection: Pubkey, authority: &Keypair) {
+		let transaction = metaplex_nft_program_ix_interface::make_collection_soulbound_ix_setup(
+			&context.payer,
+			collection,
+			authority,
+			context.last_blockhash,
+		);
+		process(context, transaction).await.unwrap();
+	}
+
+	/// Burns `nft`, closing its token and metadata accounts to `owner`.
+	pub async fn burn_nft(context: &mut ProgramTestContext, collection: Pubkey, nft: &Nft, owner: &Keypair) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::burn_nft_ix_setup(
+			&context.payer,
+			owner,
+			nft.mint,
+			nft.metadata,
+			collection,
+			nft.token_account,
+			freeze_authority_pda(),
+			TOKEN_PROGRAM,
+			TOKEN_PROGRAM,
+			program_config_pda(),
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Gives the collection a rule set listing `programs` in `mode`.
+	pub async fn set_rule_set(context: &mut ProgramTestContext, collection: Pubkey, authority: &Keypair, mode: RuleSetMode, programs: Vec<Pubkey>) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::set_rule_set_ix_setup(
+			&context.payer,
+			collection,
+			authority,
+			rule_set_pda(&collection),
+			SYSTEM_PROGRAM,
+			&mode,
+			&programs,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Approves `delegate` to transfer `nft` in place of its owner.
+	pub async fn approve_delegate(context: &mut ProgramTestContext, collection: Pubkey, nft: &Nft, owner: &Keypair, delegate: Pubkey) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::approve_delegate_ix_setup(
+			&context.payer,
+			owner,
+			nft.mint,
+			nft.metadata,
+			nft.token_account,
+			delegate,
+			collection,
+			freeze_authority_pda(),
+			rule_set_pda(&collection),
+			INSTRUCTIONS_SYSVAR,
+			TOKEN_PROGRAM,
+			TOKEN_PROGRAM,
+			program_config_pda(),
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Revokes the delegate approved on `nft`.
+	pub async fn revoke_delegate(context: &mut ProgramTestContext, collection: Pubkey, nft: &Nft, owner: &Keypair) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::revoke_delegate_ix_setup(
+			&context.payer,
+			owner,
+			nft.mint,
+			nft.metadata,
+			nft.token_account,
+			collection,
+			freeze_authority_pda(),
+			TOKEN_PROGRAM,
+			TOKEN_PROGRAM,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Freezes `nft`, held by `holder`, as the collection authority.
+	pub async fn freeze_nft(context: &mut ProgramTestContext, collection: Pubkey, authority: &Keypair, nft: &Nft, holder: &Pubkey) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::freeze_nft_ix_setup(
+			&context.payer,
+			collection,
+			authority,
+			nft.mint,
+			nft.metadata,
+			*holder,
+			token_account_address(holder, &nft.mint),
+			freeze_authority_pda(),
+			TOKEN_PROGRAM,
+			TOKEN_PROGRAM,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Lifts the collection authority's freeze of `nft`, held by `holder`.
+	pub async fn thaw_nft(context: &mut ProgramTestContext, collection: Pubkey, authority: &Keypair, nft: &Nft, holder: &Pubkey) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::thaw_nft_ix_setup(
+			&context.payer,
+			collection,
+			authority,
+			nft.mint,
+			nft.metadata,
+			*holder,
+			token_account_address(holder, &nft.mint),
+			freeze_authority_pda(),
+			TOKEN_PROGRAM,
+			TOKEN_PROGRAM,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Rewrites the name and URI of `nft` as the collection authority.
+	pub async fn update_nft_metadata(context: &mut ProgramTestContext, collection: Pubkey, authority: &Keypair, nft: &Nft, name: &str, uri: &str) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::update_nft_metadata_ix_setup(
+			&context.payer,
+			collection,
+			nft.metadata,
+			nft.mint,
+			authority,
+			program_config_pda(),
+			history_pda(&nft.mint),
+			SYSTEM_PROGRAM,
+			&name.to_string(),
+			&uri.to_string(),
+			None,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Sets the base URI that the NFT URIs of `collection` resolve against.
+	pub async fn set_base_uri(context: &mut ProgramTestContext, collection: Pubkey, authority: &Keypair, base_uri: &str) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::set_base_uri_ix_setup(
+			&context.payer,
+			collection,
+			authority,
+			SYSTEM_PROGRAM,
+			&base_uri.to_string(),
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Reveals the NFTs behind `metadata` with the matching `entries`.
+	pub async fn reveal(context: &mut ProgramTestContext, collection: Pubkey, authority: &Keypair, entries: &[RevealEntry], metadata: &[Pubkey]) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::reveal_ix_setup(
+			&context.payer,
+			collection,
+			authority,
+			program_config_pda(),
+			&entries.to_vec(),
+			metadata.iter().map(|metadata| AccountMeta::new(*metadata, false)).collect(),
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Limits the metadata URIs of `collection` to `max_uri_length` bytes and `allowed_schemes`.
+	pub async fn set_uri_rules(context: &mut ProgramTestContext, collection: Pubkey, authority: &Keypair, max_uri_length: u16, allowed_schemes: &[&str]) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::set_uri_rules_ix_setup(
+			&context.payer,
+			collection,
+			authority,
+			max_uri_length,
+			&allowed_schemes.iter().map(|scheme| scheme.to_string()).collect(),
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Locks the metadata of `metadata`, or of the whole collection when it is `None`.
+	pub async fn lock_metadata(context: &mut ProgramTestContext, collection: Pubkey, authority: &Keypair, metadata: Option<Pubkey>) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::lock_metadata_ix_setup(
+			&context.payer,
+			collection,
+			authority,
+			metadata,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Lists `nft` at `price`, moving its token into the listing's escrow.
+	pub async fn list_nft(context: &mut ProgramTestContext, collection: Pubkey, nft: &Nft, seller: &Keypair, price: u64) -> Result<(), BanksClientError> {
+		let listing = listing_pda(&nft.mint);
+		let transaction = metaplex_nft_program_ix_interface::list_nft_ix_setup(
+			&context.payer,
+			seller,
+			nft.mint,
+			nft.metadata,
+			collection,
+			listing,
+			nft.token_account,
+			token_account_address(&listing, &nft.mint),
+			freeze_authority_pda(),
+			SYSTEM_PROGRAM,
+			TOKEN_PROGRAM,
+			TOKEN_PROGRAM,
+			ASSOCIATED_TOKEN_PROGRAM,
+			program_config_pda(),
+			price,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Lists `nft` at `price`, leaving its token frozen in the seller's account.
+	pub async fn list_nft_in_place(context: &mut ProgramTestContext, collection: Pubkey, nft: &Nft, seller: &Keypair, price: u64) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::list_nft_in_place_ix_setup(
+			&context.payer,
+			seller,
+			nft.mint,
+			nft.metadata,
+			collection,
+			listing_pda(&nft.mint),
+			nft.token_account,
+			freeze_authority_pda(),
+			rule_set_pda(&collection),
+			INSTRUCTIONS_SYSVAR,
+			SYSTEM_PROGRAM,
+			TOKEN_PROGRAM,
+			TOKEN_PROGRAM,
+			program_config_pda(),
+			price,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Places a bid of `amount` on `mint`, or on any NFT of `collection` when `mint` is `None`.
+	pub async fn place_bid(context: &mut ProgramTestContext, collection: Pubkey, bidder: &Keypair, mint: Option<Pubkey>, amount: u64, expires_at: i64) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::place_bid_ix_setup(
+			&context.payer,
+			bidder,
+			collection,
+			bid_pda(&bidder.pubkey(), &mint.unwrap_or(collection)),
+			SYSTEM_PROGRAM,
+			program_config_pda(),
+			mint,
+			amount,
+			expires_at,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Auctions `nft` from now until `end_time`, with a minimum increment of 0.001 SOL and no extension window.
+	pub async fn create_auction(context: &mut ProgramTestContext, collection: Pubkey, nft: &Nft, seller: &Keypair, reserve_price: u64, end_time: i64) -> Result<(), BanksClientError> {
+		let auction = auction_pda(&nft.mint);
+		let start_time = unix_timestamp(context).await;
+		let transaction = metaplex_nft_program_ix_interface::create_auction_ix_setup(
+			&context.payer,
+			seller,
+			nft.mint,
+			nft.metadata,
+			collection,
+			auction,
+			nft.token_account,
+			token_account_address(&auction, &nft.mint),
+			freeze_authority_pda(),
+			SYSTEM_PROGRAM,
+			TOKEN_PROGRAM,
+			TOKEN_PROGRAM,
+			ASSOCIATED_TOKEN_PROGRAM,
+			program_config_pda(),
+			reserve_price,
+			start_time,
+			end_time,
+			1_000_000,
+			0,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Bids `amount` in the auction of `mint`, crediting any outbid bidder's refund account.
+	pub async fn place_auction_bid(context: &mut ProgramTestContext, collection: Pubkey, mint: &Pubkey, bidder: &Keypair, amount: u64) -> Result<(), BanksClientError> {
+		let auction = auction_pda(mint);
+		let state: Auction = get_account(context, auction).await;
+		let previous_bid_refund = (state.highest_bid > 0).then(|| bid_refund_pda(&auction, &state.highest_bidder));
+		let transaction = metaplex_nft_program_ix_interface::place_auction_bid_ix_setup(
+			&context.payer,
+			bidder,
+			auction,
+			collection,
+			previous_bid_refund,
+			SYSTEM_PROGRAM,
+			program_config_pda(),
+			amount,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Offers `nft` for rent at `price_per_day` for up to `max_days`.
+	pub async fn create_rental_offer(context: &mut ProgramTestContext, collection: Pubkey, nft: &Nft, owner: &Keypair, price_per_day: u64, max_days: u32) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::create_rental_offer_ix_setup(
+			&context.payer,
+			owner,
+			nft.mint,
+			nft.metadata,
+			collection,
+			rental_offer_pda(&nft.mint, &owner.pubkey()),
+			SYSTEM_PROGRAM,
+			program_config_pda(),
+			price_per_day,
+			max_days,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Rents `nft` from `owner` for `days` on the offer's current terms.
+	pub async fn rent_nft(context: &mut ProgramTestContext, collection: Pubkey, nft: &Nft, owner: &Pubkey, renter: &Keypair, days: u32) -> Result<(), BanksClientError> {
+		let rental_offer = rental_offer_pda(&nft.mint, owner);
+		let offer: RentalOffer = get_account(context, rental_offer).await;
+		let transaction = metaplex_nft_program_ix_interface::rent_nft_ix_setup(
+			&context.payer,
+			renter,
+			*owner,
+			nft.metadata,
+			collection,
+			rental_offer,
+			SYSTEM_PROGRAM,
+			program_config_pda(),
+			days,
+			offer.price_per_day,
+			offer.max_days,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Creates the collection's stake pool with a new reward mint and returns the mint.
+	pub async fn create_stake_pool(context: &mut ProgramTestContext, collection: Pubkey, authority: &Keypair, reward_rate: u64) -> Pubkey {
+		let stake_pool = stake_pool_pda(&collection);
+		let reward_mint = create_mint(context, &stake_pool).await;
+		let transaction = metaplex_nft_program_ix_interface::create_stake_pool_ix_setup(
+			&context.payer,
+			collection,
+			authority,
+			stake_pool,
+			reward_mint,
+			SYSTEM_PROGRAM,
+			reward_rate,
+			context.last_blockhash,
+		);
+		process(context, transaction).await.unwrap();
+		reward_mint
+	}
+
+	/// Stakes `nft` in its collection's stake pool.
+	pub async fn stake_nft(context: &mut ProgramTestContext, collection: Pubkey, nft: &Nft, owner: &Keypair) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::stake_nft_ix_setup(
+			&context.payer,
+			owner,
+			nft.mint,
+			nft.metadata,
+			collection,
+			stake_pool_pda(&collection),
+			stake_entry_pda(&nft.mint),
+			nft.token_account,
+			freeze_authority_pda(),
+			rule_set_pda(&collection),
+			INSTRUCTIONS_SYSVAR,
+			SYSTEM_PROGRAM,
+			TOKEN_PROGRAM,
+			TOKEN_PROGRAM,
+			program_config_pda(),
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Locks `nft` in a vault and mints `total_shares` shares to `owner`.
+	pub async fn fractionalize_nft(context: &mut ProgramTestContext, collection: Pubkey, nft: &Nft, owner: &Keypair, total_shares: u64, reserve_price: u64) -> Result<(), BanksClientError> {
+		let vault = vault_pda(&nft.mint);
+		let share_mint = share_mint_pda(&vault);
+		let transaction = metaplex_nft_program_ix_interface::fractionalize_nft_ix_setup(
+			&context.payer,
+			owner,
+			nft.mint,
+			nft.metadata,
+			collection,
+			vault,
+			share_mint,
+			nft.token_account,
+			token_account_address(&vault, &nft.mint),
+			token_account_address(&owner.pubkey(), &share_mint),
+			freeze_authority_pda(),
+			SYSTEM_PROGRAM,
+			TOKEN_PROGRAM,
+			TOKEN_PROGRAM,
+			ASSOCIATED_TOKEN_PROGRAM,
+			program_config_pda(),
+			total_shares,
+			reserve_price,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Offers a loan of `amount` for `duration` seconds against any NFT of `collection`.
+	pub async fn create_loan_offer(context: &mut ProgramTestContext, collection: Pubkey, lender: &Keypair, offer_id: u64, amount: u64, duration: i64, interest: u64) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::create_loan_offer_ix_setup(
+			&context.payer,
+			lender,
+			collection,
+			loan_pda(&lender.pubkey(), offer_id),
+			SYSTEM_PROGRAM,
+			program_config_pda(),
+			offer_id,
+			amount,
+			duration,
+			interest,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Takes the loan at `loan` on its current terms, putting up `nft` as collateral.
+	pub async fn take_loan(context: &mut ProgramTestContext, collection: Pubkey, nft: &Nft, borrower: &Keypair, loan: Pubkey) -> Result<(), BanksClientError> {
+		let terms: Loan = get_account(context, loan).await;
+		let transaction = metaplex_nft_program_ix_interface::take_loan_ix_setup(
+			&context.payer,
+			borrower,
+			nft.mint,
+			nft.metadata,
+			collection,
+			loan,
+			nft.token_account,
+			token_account_address(&loan, &nft.mint),
+			freeze_authority_pda(),
+			SYSTEM_PROGRAM,
+			TOKEN_PROGRAM,
+			TOKEN_PROGRAM,
+			ASSOCIATED_TOKEN_PROGRAM,
+			program_config_pda(),
+			terms.amount,
+			terms.duration,
+			terms.interest,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Creates the multisig of `creator` numbered `nonce`.
+	pub async fn create_multisig(context: &mut ProgramTestContext, creator: &Keypair, nonce: u64, signers: &[Pubkey], threshold: u8) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::create_multisig_ix_setup(
+			&context.payer,
+			creator,
+			multisig_pda(&creator.pubkey(), nonce),
+			SYSTEM_PROGRAM,
+			nonce,
+			&signers.to_vec(),
+			threshold,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Builds an instruction of this program from its accounts and data, for a
+	/// multisig to propose.
+	pub fn program_instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+		Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None))
+	}
+
+	/// Proposes `instruction` to `multisig` and returns the proposal's address.
+	pub async fn create_proposal(context: &mut ProgramTestContext, multisig: Pubkey, proposer: &Keypair, instruction: &Instruction) -> Result<Pubkey, BanksClientError> {
+		let index = get_account::<Multisig>(context, multisig).await.proposal_count;
+		let proposal = proposal_pda(&multisig, index);
+		let accounts = instruction.accounts.iter()
+			.map(|meta| ProposalAccountMeta { pubkey: meta.pubkey, is_signer: meta.is_signer, is_writable: meta.is_writable })
+			.collect();
+		let transaction = metaplex_nft_program_ix_interface::create_proposal_ix_setup(
+			&context.payer,
+			proposer,
+			multisig,
+			proposal,
+			SYSTEM_PROGRAM,
+			&accounts,
+			&instruction.data,
+			context.last_blockhash,
+		);
+		process(context, transaction).await?;
+		Ok(proposal)
+	}
+
+	/// Approves `proposal` as `signer`.
+	pub async fn approve_proposal(context: &mut ProgramTestContext, multisig: Pubkey, proposal: Pubkey, signer: &Keypair) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::approve_proposal_ix_setup(
+			&context.payer,
+			signer,
+			multisig,
+			proposal,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Executes `proposal`, passing the accounts stored on it. Only the fee
+	/// payer signs the transaction; the multisig signs the inner instruction.
+	pub async fn execute_proposal(context: &mut ProgramTestContext, multisig: Pubkey, proposal: Pubkey) -> Result<(), BanksClientError> {
+		let fee_payer = context.payer.pubkey();
+		let remaining_accounts = get_account::<Proposal>(context, proposal).await.accounts.iter()
+			.map(|meta| AccountMeta {
+				pubkey: meta.pubkey,
+				is_signer: meta.is_signer && meta.pubkey == fee_payer,
+				is_writable: meta.is_writable,
+			})
+			.collect();
+		let transaction = metaplex_nft_program_ix_interface::execute_proposal_ix_setup(
+			&context.payer,
+			multisig,
+			proposal,
+			PROGRAM_ID,
+			remaining_accounts,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Hands `collection` over to `new_authority`.
+	pub async fn set_collection_authority(context: &mut ProgramTestContext, collection: Pubkey, authority: &Keypair, new_authority: Pubkey) -> Result<(), BanksClientError> {
+		let transaction = metaplex_nft_program_ix_interface::set_collection_authority_ix_setup(
+			&context.payer,
+			collection,
+			authority,
+			new_authority,
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+
+	/// Gifts `nft` from `owner` to `new_owner`, with the owner signing and paying.
+	///
+	/// The instruction moves the token into an existing account of the new
+	/// owner and creates an associated token account for a separate wallet.
+	pub async fn transfer_nft(context: &mut ProgramTestContext, collection: Pubkey, nft: &Nft, owner: &Keypair, new_owner: &Pubkey) -> Result<(), BanksClientError> {
+		transfer_nft_at_price(context, collection, nft, owner, new_owner, 0, &[]).await
+	}
+
+	/// Transfers `nft` declaring a sale at `price`, with the owner paying any royalty to `creators`.
+	pub async fn transfer_nft_at_price(context: &mut ProgramTestContext, collection: Pubkey, nft: &Nft, owner: &Keypair, new_owner: &Pubkey, price: u64, creators: &[Pubkey]) -> Result<(), BanksClientError> {
+		let destination = token_account_address(new_owner, &nft.mint);
+		if !account_exists(context, destination).await {
+			create_token_account(context, new_owner, &nft.mint).await;
+		}
+		let wallet = Keypair::new().pubkey();
+		let transaction = metaplex_nft_program_ix_interface::transfer_nft_ix_setup(
+			&context.payer,
+			nft.mint,
+			nft.metadata,
+			owner.pubkey(),
+			*new_owner,
+			owner,
+			token_account_address(&wallet, &nft.mint),
+			wallet,
+			SYSTEM_PROGRAM,
+			TOKEN_PROGRAM,
+			nft.token_account,
+			destination,
+			owner,
+			ASSOCIATED_TOKEN_PROGRAM,
+			TOKEN_PROGRAM,
+			collection,
+			freeze_authority_pda(),
+			rule_set_pda(&collection),
+			INSTRUCTIONS_SYSVAR,
+			program_config_pda(),
+			price,
+			creators.iter().map(|creator| AccountMeta::new(*creator, false)).collect(),
+			context.last_blockhash,
+		);
+		process(context, transaction).await
+	}
+}
+	
+pub mod metaplex_nft_program_ix_interface {
+
+	use {
+		solana_sdk::{
+			hash::Hash,
+			signature::{Keypair, Signer},
+			instruction::{AccountMeta, Instruction},
+			pubkey::Pubkey,
+			transaction::Transaction,
+		},
+		metaplex_nft_program::{
+			ID as PROGRAM_ID,
+			accounts as metaplex_nft_program_accounts,
+			instruction as metaplex_nft_program_instruction,
+			MintBatchEntry,
+			CollectionCreator,
+			RuleSetMode,
+			ProposalAccountMeta,
+			RevealEntry,
+		},
+		anchor_lang::{
+			prelude::*,
+			InstructionData,
+		}
+	};
+
+	pub fn initialize_collection_ix_setup(
+		fee_payer: &Keypair,
+		collection: Pubkey,
+		authority: &Keypair,
+		system_program: Pubkey,
+		name: &String,
+		symbol: &String,
+		uri: &String,
+		content_hash: Option<[u8; 32]>,
+		reveal_root: Option<[u8; 32]>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::InitializeCollection {
+			fee_payer: fee_payer.pubkey(),
+			collection: collection,
+			authority: authority.pubkey(),
+			system_program: system_program,
+		};
+
+		let data = 	metaplex_nft_program_instruction::InitializeCollection {
+				name: name.clone(),
+				symbol: symbol.clone(),
+				uri: uri.clone(),
+				content_hash,
+				reveal_root,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn mint_nft_ix_setup(
+		fee_payer: &Keypair,
+		collection: Pubkey,
+		mint: &Keypair,
+		metadata: Pubkey,
+		authority: &Keypair,
+		owner: &Keypair,
+		system_program: Pubkey,
+		funding: &Keypair,
+		assoc_token_account: Pubkey,
+		wallet: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		associated_token_program: Pubkey,
+		mint_config: Pubkey,
+		mint_receipt: Option<Pubkey>,
+		freeze_authority: Pubkey,
+		program_config: Pubkey,
+		treasury: Pubkey,
+		item_index: Pubkey,
+		name: &String,
+		symbol: &String,
+		uri: &String,
+		content_hash: Option<[u8; 32]>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::MintNft {
+			fee_payer: fee_payer.pubkey(),
+			collection: collection,
+			mint: mint.pubkey(),
+			metadata: metadata,
+			authority: authority.pubkey(),
+			owner: owner.pubkey(),
+			system_program: system_program,
+			funding: funding.pubkey(),
+			assoc_token_account: assoc_token_account,
+			wallet: wallet,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			associated_token_program: associated_token_program,
+			mint_config: mint_config,
+			mint_receipt: mint_receipt,
+			freeze_authority: freeze_authority,
+			program_config: program_config,
+			treasury: treasury,
+			item_index: item_index,
+		};
+
+		let data = 	metaplex_nft_program_instruction::MintNft {
+				name: name.clone(),
+				symbol: symbol.clone(),
+				uri: uri.clone(),
+				content_hash,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.partial_sign(&[
+			&fee_payer,
+			&mint,
+			&authority,
+			&owner,
+		], recent_blockhash);
+
+		transaction.partial_sign(&[
+			&funding,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn update_nft_metadata_ix_setup(
+		fee_payer: &Keypair,
+		collection: Pubkey,
+		metadata: Pubkey,
+		mint: Pubkey,
+		authority: &Keypair,
+		program_config: Pubkey,
+		history: Pubkey,
+		system_program: Pubkey,
+		name: &String,
+		uri: &String,
+		content_hash: Option<[u8; 32]>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::UpdateNftMetadata {
+			fee_payer: fee_payer.pubkey(),
+			collection: collection,
+			metadata: metadata,
+			mint: mint,
+			authority: authority.pubkey(),
+			program_config: program_config,
+			history: history,
+			system_program: system_program,
+		};
+
+		let data = 	metaplex_nft_program_instruction::UpdateNftMetadata {
+				name: name.clone(),
+				uri: uri.clone(),
+				content_hash,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn transfer_nft_ix_setup(
+		fee_payer: &Keypair,
+		mint: Pubkey,
+		metadata: Pubkey,
+		owner: Pubkey,
+		new_owner: Pubkey,
+		funding: &Keypair,
+		assoc_token_account: Pubkey,
+		wallet: Pubkey,
+		system_program: Pubkey,
+		token_program: Pubkey,
+		source: Pubkey,
+		destination: Pubkey,
+		authority: &Keypair,
+		associated_token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		collection: Pubkey,
+		freeze_authority: Pubkey,
+		rule_set: Pubkey,
+		instructions: Pubkey,
+		program_config: Pubkey,
+		price: u64,
+		remaining_accounts: Vec<AccountMeta>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::TransferNft {
+			fee_payer: fee_payer.pubkey(),
+			mint: mint,
+			metadata: metadata,
+			owner: owner,
+			new_owner: new_owner,
+			funding: funding.pubkey(),
+			assoc_token_account: assoc_token_account,
+			wallet: wallet,
+			system_program: system_program,
+			token_program: token_program,
+			source: source,
+			destination: destination,
+			authority: authority.pubkey(),
+			associated_token_program: associated_token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			collection: collection,
+			freeze_authority: freeze_authority,
+			rule_set: rule_set,
+			instructions: instructions,
+			program_config: program_config,
+		};
+
+		let data = 	metaplex_nft_program_instruction::TransferNft {
+				price,
+		};		let mut account_metas = accounts.to_account_metas(None);
+		account_metas.extend(remaining_accounts);
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&funding,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn mint_batch_ix_setup(
+		fee_payer: &Keypair,
+		collection: Pubkey,
+		authority: &Keypair,
+		freeze_authority: Pubkey,
+		system_program: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		csl_spl_assoc_token_v0_0_0: Pubkey,
+		program_config: Pubkey,
+		treasury: Pubkey,
+		mint_config: Pubkey,
+		entries: &Vec<MintBatchEntry>,
+		remaining_accounts: Vec<AccountMeta>,
+		remaining_signers: &[&Keypair],
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::MintBatch {
+			fee_payer: fee_payer.pubkey(),
+			collection: collection,
+			authority: authority.pubkey(),
+			freeze_authority: freeze_authority,
+			system_program: system_program,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			csl_spl_assoc_token_v0_0_0: csl_spl_assoc_token_v0_0_0,
+			program_config: program_config,
+			treasury: treasury,
+			mint_config: mint_config,
+		};
+
+		let data = 	metaplex_nft_program_instruction::MintBatch {
+				entries: entries.clone(),
+		};		let mut account_metas = accounts.to_account_metas(None);
+		account_metas.extend(remaining_accounts);
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.partial_sign(&[
+			&fee_payer,
+			&authority,
+		], recent_blockhash);
+
+		transaction.partial_sign(remaining_signers, recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_collection_royalties_ix_setup(
+		fee_payer: &Keypair,
+		collection: Pubkey,
+		authority: &Keypair,
+		seller_fee_basis_points: u16,
+		creators: &Vec<CollectionCreator>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::SetCollectionRoyalties {
+			fee_payer: fee_payer.pubkey(),
+			collection: collection,
+			authority: authority.pubkey(),
+		};
+
+		let data = 	metaplex_nft_program_instruction::SetCollectionRoyalties {
+				seller_fee_basis_points,
+				creators: creators.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn list_nft_ix_setup(
+		fee_payer: &Keypair,
+		seller: &Keypair,
+		mint: Pubkey,
+		metadata: Pubkey,
+		collection: Pubkey,
+		listing: Pubkey,
+		seller_token_account: Pubkey,
+		escrow_token_account: Pubkey,
+		freeze_authority: Pubkey,
+		system_program: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		associated_token_program: Pubkey,
+		program_config: Pubkey,
+		price: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::ListNft {
+			fee_payer: fee_payer.pubkey(),
+			seller: seller.pubkey(),
+			mint: mint,
+			metadata: metadata,
+			collection: collection,
+			listing: listing,
+			seller_token_account: seller_token_account,
+			escrow_token_account: escrow_token_account,
+			freeze_authority: freeze_authority,
+			system_program: system_program,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			associated_token_program: associated_token_program,
+			program_config: program_config,
+		};
+
+		let data = 	metaplex_nft_program_instruction::ListNft {
+				price,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&seller,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn delist_nft_ix_setup(
+		fee_payer: &Keypair,
+		seller: &Keypair,
+		mint: Pubkey,
+		metadata: Pubkey,
+		collection: Pubkey,
+		listing: Pubkey,
+		seller_token_account: Pubkey,
+		escrow_token_account: Pubkey,
+		freeze_authority: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::DelistNft {
+			fee_payer: fee_payer.pubkey(),
+			seller: seller.pubkey(),
+			mint: mint,
+			metadata: metadata,
+			collection: collection,
+			listing: listing,
+			seller_token_account: seller_token_account,
+			escrow_token_account: escrow_token_account,
+			freeze_authority: freeze_authority,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+		};
+
+		let data = metaplex_nft_program_instruction::DelistNft;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&seller,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn buy_nft_ix_setup(
+		fee_payer: &Keypair,
+		buyer: &Keypair,
+		seller: Pubkey,
+		mint: Pubkey,
+		metadata: Pubkey,
+		collection: Pubkey,
+		listing: Pubkey,
+		listing_token_account: Pubkey,
+		buyer_token_account: Pubkey,
+		freeze_authority: Pubkey,
+		system_program: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		associated_token_program: Pubkey,
+		program_config: Pubkey,
+		max_price: u64,
+		remaining_accounts: Vec<AccountMeta>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::BuyNft {
+			fee_payer: fee_payer.pubkey(),
+			buyer: buyer.pubkey(),
+			seller: seller,
+			mint: mint,
+			metadata: metadata,
+			collection: collection,
+			listing: listing,
+			listing_token_account: listing_token_account,
+			buyer_token_account: buyer_token_account,
+			freeze_authority: freeze_authority,
+			system_program: system_program,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			associated_token_program: associated_token_program,
+			program_config: program_config,
+		};
+
+		let data = 	metaplex_nft_program_instruction::BuyNft {
+				max_price,
+		};		let mut account_metas = accounts.to_account_metas(None);
+		account_metas.extend(remaining_accounts);
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&buyer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn list_nft_in_place_ix_setup(
+		fee_payer: &Keypair,
+		seller: &Keypair,
+		mint: Pubkey,
+		metadata: Pubkey,
+		collection: Pubkey,
+		listing: Pubkey,
+		seller_token_account: Pubkey,
+		freeze_authority: Pubkey,
+		rule_set: Pubkey,
+		instructions: Pubkey,
+		system_program: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		program_config: Pubkey,
+		price: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::ListNftInPlace {
+			fee_payer: fee_payer.pubkey(),
+			seller: seller.pubkey(),
+			mint: mint,
+			metadata: metadata,
+			collection: collection,
+			listing: listing,
+			seller_token_account: seller_token_account,
+			freeze_authority: freeze_authority,
+			rule_set: rule_set,
+			instructions: instructions,
+			system_program: system_program,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			program_config: program_config,
+		};
+
+		let data = 	metaplex_nft_program_instruction::ListNftInPlace {
+				price,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&seller,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn delist_nft_in_place_ix_setup(
+		fee_payer: &Keypair,
+		seller: &Keypair,
+		mint: Pubkey,
+		metadata: Pubkey,
+		listing: Pubkey,
+		collection: Pubkey,
+		seller_token_account: Pubkey,
+		freeze_authority: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::DelistNftInPlace {
+			fee_payer: fee_payer.pubkey(),
+			seller: seller.pubkey(),
+			mint: mint,
+			metadata: metadata,
+			listing: listing,
+			collection: collection,
+			seller_token_account: seller_token_account,
+			freeze_authority: freeze_authority,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+		};
+
+		let data = metaplex_nft_program_instruction::DelistNftInPlace;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&seller,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn place_bid_ix_setup(
+		fee_payer: &Keypair,
+		bidder: &Keypair,
+		collection: Pubkey,
+		bid: Pubkey,
+		system_program: Pubkey,
+		program_config: Pubkey,
+		mint: Option<Pubkey>,
+		amount: u64,
+		expires_at: i64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::PlaceBid {
+			fee_payer: fee_payer.pubkey(),
+			bidder: bidder.pubkey(),
+			collection: collection,
+			bid: bid,
+			system_program: system_program,
+			program_config: program_config,
+		};
+
+		let data = 	metaplex_nft_program_instruction::PlaceBid {
+				mint,
+				amount,
+				expires_at,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&bidder,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn cancel_bid_ix_setup(
+		fee_payer: &Keypair,
+		bidder: &Keypair,
+		bid: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::CancelBid {
+			fee_payer: fee_payer.pubkey(),
+			bidder: bidder.pubkey(),
+			bid: bid,
+		};
+
+		let data = metaplex_nft_program_instruction::CancelBid;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&bidder,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn accept_bid_ix_setup(
+		fee_payer: &Keypair,
+		seller: &Keypair,
+		bidder: Pubkey,
+		mint: Pubkey,
+		metadata: Pubkey,
+		collection: Pubkey,
+		bid: Pubkey,
+		seller_token_account: Pubkey,
+		bidder_token_account: Pubkey,
+		freeze_authority: Pubkey,
+		system_program: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		associated_token_program: Pubkey,
+		program_config: Pubkey,
+		min_amount: u64,
+		remaining_accounts: Vec<AccountMeta>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::AcceptBid {
+			fee_payer: fee_payer.pubkey(),
+			seller: seller.pubkey(),
+			bidder: bidder,
+			mint: mint,
+			metadata: metadata,
+			collection: collection,
+			bid: bid,
+			seller_token_account: seller_token_account,
+			bidder_token_account: bidder_token_account,
+			freeze_authority: freeze_authority,
+			system_program: system_program,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			associated_token_program: associated_token_program,
+			program_config: program_config,
+		};
+
+		let data = 	metaplex_nft_program_instruction::AcceptBid {
+				min_amount,
+		};		let mut account_metas = accounts.to_account_metas(None);
+		account_metas.extend(remaining_accounts);
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&seller,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_auction_ix_setup(
+		fee_payer: &Keypair,
+		seller: &Keypair,
+		mint: Pubkey,
+		metadata: Pubkey,
+		collection: Pubkey,
+		auction: Pubkey,
+		seller_token_account: Pubkey,
+		escrow_token_account: Pubkey,
+		freeze_authority: Pubkey,
+		system_program: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		associated_token_program: Pubkey,
+		program_config: Pubkey,
+		reserve_price: u64,
+		start_time: i64,
+		end_time: i64,
+		min_increment: u64,
+		extension_window: i64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::CreateAuction {
+			fee_payer: fee_payer.pubkey(),
+			seller: seller.pubkey(),
+			mint: mint,
+			metadata: metadata,
+			collection: collection,
+			auction: auction,
+			seller_token_account: seller_token_account,
+			escrow_token_account: escrow_token_account,
+			freeze_authority: freeze_authority,
+			system_program: system_program,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			associated_token_program: associated_token_program,
+			program_config: program_config,
+		};
+
+		let data = 	metaplex_nft_program_instruction::CreateAuction {
+				reserve_price,
+				start_time,
+				end_time,
+				min_increment,
+				extension_window,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&seller,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn place_auction_bid_ix_setup(
+		fee_payer: &Keypair,
+		bidder: &Keypair,
+		auction: Pubkey,
+		collection: Pubkey,
+		previous_bid_refund: Option<Pubkey>,
+		system_program: Pubkey,
+		program_config: Pubkey,
+		amount: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::PlaceAuctionBid {
+			fee_payer: fee_payer.pubkey(),
+			bidder: bidder.pubkey(),
+			auction: auction,
+			collection: collection,
+			previous_bid_refund: previous_bid_refund,
+			system_program: system_program,
+			program_config: program_config,
+		};
+
+		let data = 	metaplex_nft_program_instruction::PlaceAuctionBid {
+				amount,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&bidder,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn settle_auction_ix_setup(
+		fee_payer: &Keypair,
+		seller: Pubkey,
+		recipient: Pubkey,
+		mint: Pubkey,
+		metadata: Pubkey,
+		collection: Pubkey,
+		auction: Pubkey,
+		escrow_token_account: Pubkey,
+		recipient_token_account: Pubkey,
+		freeze_authority: Pubkey,
+		system_program: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		associated_token_program: Pubkey,
+		program_config: Pubkey,
+		remaining_accounts: Vec<AccountMeta>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::SettleAuction {
+			fee_payer: fee_payer.pubkey(),
+			seller: seller,
+			recipient: recipient,
+			mint: mint,
+			metadata: metadata,
+			collection: collection,
+			auction: auction,
+			escrow_token_account: escrow_token_account,
+			recipient_token_account: recipient_token_account,
+			freeze_authority: freeze_authority,
+			system_program: system_program,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			associated_token_program: associated_token_program,
+			program_config: program_config,
+		};
+
+		let data = metaplex_nft_program_instruction::SettleAuction;
+		let mut account_metas = accounts.to_account_metas(None);
+		account_metas.extend(remaining_accounts);
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_mint_config_ix_setup(
+		fee_payer: &Keypair,
+		collection: Pubkey,
+		mint_config: Pubkey,
+		authority: &Keypair,
+		system_program: Pubkey,
+		start_price: u64,
+		end_price: u64,
+		start_time: i64,
+		decay_interval: i64,
+		price_step: u64,
+		max_supply: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::SetMintConfig {
+			fee_payer: fee_payer.pubkey(),
+			collection: collection,
+			mint_config: mint_config,
+			authority: authority.pubkey(),
+			system_program: system_program,
+		};
+
+		let data = 	metaplex_nft_program_instruction::SetMintConfig {
+				start_price,
+				end_price,
+				start_time,
+				decay_interval,
+				price_step,
+				max_supply,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn claim_mint_rebate_ix_setup(
+		fee_payer: &Keypair,
+		minter: Pubkey,
+		mint_config: Pubkey,
+		mint_receipt: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::ClaimMintRebate {
+			fee_payer: fee_payer.pubkey(),
+			minter: minter,
+			mint_config: mint_config,
+			mint_receipt: mint_receipt,
+		};
+
+		let data = metaplex_nft_program_instruction::ClaimMintRebate;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn withdraw_mint_proceeds_ix_setup(
+		fee_payer: &Keypair,
+		collection: Pubkey,
+		authority: &Keypair,
+		mint_config: Pubkey,
+		destination: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::WithdrawMintProceeds {
+			fee_payer: fee_payer.pubkey(),
+			collection: collection,
+			authority: authority.pubkey(),
+			mint_config: mint_config,
+			destination: destination,
+		};
+
+		let data = metaplex_nft_program_instruction::WithdrawMintProceeds;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_rental_offer_ix_setup(
+		fee_payer: &Keypair,
+		owner: &Keypair,
+		mint: Pubkey,
+		metadata: Pubkey,
+		collection: Pubkey,
+		rental_offer: Pubkey,
+		system_program: Pubkey,
+		program_config: Pubkey,
+		price_per_day: u64,
+		max_days: u32,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::CreateRentalOffer {
+			fee_payer: fee_payer.pubkey(),
+			owner: owner.pubkey(),
+			mint: mint,
+			metadata: metadata,
+			collection: collection,
+			rental_offer: rental_offer,
+			system_program: system_program,
+			program_config: program_config,
+		};
+
+		let data = 	metaplex_nft_program_instruction::CreateRentalOffer {
+				price_per_day,
+				max_days,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&owner,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn rent_nft_ix_setup(
+		fee_payer: &Keypair,
+		renter: &Keypair,
+		owner: Pubkey,
+		metadata: Pubkey,
+		collection: Pubkey,
+		rental_offer: Pubkey,
+		system_program: Pubkey,
+		program_config: Pubkey,
+		days: u32,
+		expected_price_per_day: u64,
+		expected_max_days: u32,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::RentNft {
+			fee_payer: fee_payer.pubkey(),
+			renter: renter.pubkey(),
+			owner: owner,
+			metadata: metadata,
+			collection: collection,
+			rental_offer: rental_offer,
+			system_program: system_program,
+			program_config: program_config,
+		};
+
+		let data = 	metaplex_nft_program_instruction::RentNft {
+				days,
+				expected_price_per_day,
+				expected_max_days,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&renter,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn end_rental_ix_setup(
+		fee_payer: &Keypair,
+		metadata: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::EndRental {
+			fee_payer: fee_payer.pubkey(),
+			metadata: metadata,
+		};
+
+		let data = metaplex_nft_program_instruction::EndRental;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn close_rental_offer_ix_setup(
+		fee_payer: &Keypair,
+		owner: &Keypair,
+		rental_offer: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::CloseRentalOffer {
+			fee_payer: fee_payer.pubkey(),
+			owner: owner.pubkey(),
+			rental_offer: rental_offer,
+		};
+
+		let data = metaplex_nft_program_instruction::CloseRentalOffer;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&owner,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_stake_pool_ix_setup(
+		fee_payer: &Keypair,
+		collection: Pubkey,
+		authority: &Keypair,
+		stake_pool: Pubkey,
+		reward_mint: Pubkey,
+		system_program: Pubkey,
+		reward_rate: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::CreateStakePool {
+			fee_payer: fee_payer.pubkey(),
+			collection: collection,
+			authority: authority.pubkey(),
+			stake_pool: stake_pool,
+			reward_mint: reward_mint,
+			system_program: system_program,
+		};
+
+		let data = 	metaplex_nft_program_instruction::CreateStakePool {
+				reward_rate,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn stake_nft_ix_setup(
+		fee_payer: &Keypair,
+		owner: &Keypair,
+		mint: Pubkey,
+		metadata: Pubkey,
+		collection: Pubkey,
+		stake_pool: Pubkey,
+		stake_entry: Pubkey,
+		owner_token_account: Pubkey,
+		freeze_authority: Pubkey,
+		rule_set: Pubkey,
+		instructions: Pubkey,
+		system_program: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		program_config: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::StakeNft {
+			fee_payer: fee_payer.pubkey(),
+			owner: owner.pubkey(),
+			mint: mint,
+			metadata: metadata,
+			collection: collection,
+			stake_pool: stake_pool,
+			stake_entry: stake_entry,
+			owner_token_account: owner_token_account,
+			freeze_authority: freeze_authority,
+			rule_set: rule_set,
+			instructions: instructions,
+			system_program: system_program,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			program_config: program_config,
+		};
+
+		let data = metaplex_nft_program_instruction::StakeNft;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&owner,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn claim_stake_rewards_ix_setup(
+		fee_payer: &Keypair,
+		owner: &Keypair,
+		stake_pool: Pubkey,
+		collection: Pubkey,
+		stake_entry: Pubkey,
+		reward_mint: Pubkey,
+		owner_reward_account: Pubkey,
+		system_program: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		associated_token_program: Pubkey,
+		program_config: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::ClaimStakeRewards {
+			fee_payer: fee_payer.pubkey(),
+			owner: owner.pubkey(),
+			stake_pool: stake_pool,
+			collection: collection,
+			stake_entry: stake_entry,
+			reward_mint: reward_mint,
+			owner_reward_account: owner_reward_account,
+			system_program: system_program,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			associated_token_program: associated_token_program,
+			program_config: program_config,
+		};
+
+		let data = metaplex_nft_program_instruction::ClaimStakeRewards;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&owner,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn unstake_nft_ix_setup(
+		fee_payer: &Keypair,
+		owner: &Keypair,
+		mint: Pubkey,
+		metadata: Pubkey,
+		collection: Pubkey,
+		stake_pool: Pubkey,
+		stake_entry: Pubkey,
+		owner_token_account: Pubkey,
+		reward_mint: Pubkey,
+		owner_reward_account: Pubkey,
+		freeze_authority: Pubkey,
+		system_program: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		associated_token_program: Pubkey,
+		program_config: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::UnstakeNft {
+			fee_payer: fee_payer.pubkey(),
+			owner: owner.pubkey(),
+			mint: mint,
+			metadata: metadata,
+			collection: collection,
+			stake_pool: stake_pool,
+			stake_entry: stake_entry,
+			owner_token_account: owner_token_account,
+			reward_mint: reward_mint,
+			owner_reward_account: owner_reward_account,
+			freeze_authority: freeze_authority,
+			system_program: system_program,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			associated_token_program: associated_token_program,
+			program_config: program_config,
+		};
+
+		let data = metaplex_nft_program_instruction::UnstakeNft;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&owner,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn fractionalize_nft_ix_setup(
+		fee_payer: &Keypair,
+		owner: &Keypair,
+		mint: Pubkey,
+		metadata: Pubkey,
+		collection: Pubkey,
+		vault: Pubkey,
+		share_mint: Pubkey,
+		owner_token_account: Pubkey,
+		vault_token_account: Pubkey,
+		owner_share_account: Pubkey,
+		freeze_authority: Pubkey,
+		system_program: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		associated_token_program: Pubkey,
+		program_config: Pubkey,
+		total_shares: u64,
+		reserve_price: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::FractionalizeNft {
+			fee_payer: fee_payer.pubkey(),
+			owner: owner.pubkey(),
+			mint: mint,
+			metadata: metadata,
+			collection: collection,
+			vault: vault,
+			share_mint: share_mint,
+			owner_token_account: owner_token_account,
+			vault_token_account: vault_token_account,
+			owner_share_account: owner_share_account,
+			freeze_authority: freeze_authority,
+			system_program: system_program,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			associated_token_program: associated_token_program,
+			program_config: program_config,
+		};
+
+		let data = 	metaplex_nft_program_instruction::FractionalizeNft {
+				total_shares,
+				reserve_price,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&owner,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn buyout_vault_ix_setup(
+		fee_payer: &Keypair,
+		buyer: &Keypair,
+		mint: Pubkey,
+		metadata: Pubkey,
+		collection: Pubkey,
+		vault: Pubkey,
+		share_mint: Pubkey,
+		buyer_share_account: Pubkey,
+		vault_token_account: Pubkey,
+		buyer_token_account: Pubkey,
+		freeze_authority: Pubkey,
+		system_program: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		associated_token_program: Pubkey,
+		program_config: Pubkey,
+		remaining_accounts: Vec<AccountMeta>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::BuyoutVault {
+			fee_payer: fee_payer.pubkey(),
+			buyer: buyer.pubkey(),
+			mint: mint,
+			metadata: metadata,
+			collection: collection,
+			vault: vault,
+			share_mint: share_mint,
+			buyer_share_account: buyer_share_account,
+			vault_token_account: vault_token_account,
+			buyer_token_account: buyer_token_account,
+			freeze_authority: freeze_authority,
+			system_program: system_program,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			associated_token_program: associated_token_program,
+			program_config: program_config,
+		};
+
+		let data = metaplex_nft_program_instruction::BuyoutVault;
+		let mut account_metas = accounts.to_account_metas(None);
+		account_metas.extend(remaining_accounts);
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&buyer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn redeem_shares_ix_setup(
+		fee_payer: &Keypair,
+		holder: &Keypair,
+		vault: Pubkey,
+		share_mint: Pubkey,
+		holder_share_account: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::RedeemShares {
+			fee_payer: fee_payer.pubkey(),
+			holder: holder.pubkey(),
+			vault: vault,
+			share_mint: share_mint,
+			holder_share_account: holder_share_account,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+		};
+
+		let data = metaplex_nft_program_instruction::RedeemShares;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&holder,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_loan_offer_ix_setup(
+		fee_payer: &Keypair,
+		lender: &Keypair,
+		collection: Pubkey,
+		loan: Pubkey,
+		system_program: Pubkey,
+		program_config: Pubkey,
+		offer_id: u64,
+		amount: u64,
+		duration: i64,
+		interest: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::CreateLoanOffer {
+			fee_payer: fee_payer.pubkey(),
+			lender: lender.pubkey(),
+			collection: collection,
+			loan: loan,
+			system_program: system_program,
+			program_config: program_config,
+		};
+
+		let data = 	metaplex_nft_program_instruction::CreateLoanOffer {
+				offer_id,
+				amount,
+				duration,
+				interest,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&lender,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn cancel_loan_offer_ix_setup(
+		fee_payer: &Keypair,
+		lender: &Keypair,
+		loan: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::CancelLoanOffer {
+			fee_payer: fee_payer.pubkey(),
+			lender: lender.pubkey(),
+			loan: loan,
+		};
+
+		let data = metaplex_nft_program_instruction::CancelLoanOffer;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&lender,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn take_loan_ix_setup(
+		fee_payer: &Keypair,
+		borrower: &Keypair,
+		mint: Pubkey,
+		metadata: Pubkey,
+		collection: Pubkey,
+		loan: Pubkey,
+		borrower_token_account: Pubkey,
+		escrow_token_account: Pubkey,
+		freeze_authority: Pubkey,
+		system_program: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		associated_token_program: Pubkey,
+		program_config: Pubkey,
+		expected_amount: u64,
+		expected_duration: i64,
+		expected_interest: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::TakeLoan {
+			fee_payer: fee_payer.pubkey(),
+			borrower: borrower.pubkey(),
+			mint: mint,
+			metadata: metadata,
+			collection: collection,
+			loan: loan,
+			borrower_token_account: borrower_token_account,
+			escrow_token_account: escrow_token_account,
+			freeze_authority: freeze_authority,
+			system_program: system_program,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			associated_token_program: associated_token_program,
+			program_config: program_config,
+		};
+
+		let data = 	metaplex_nft_program_instruction::TakeLoan {
+				expected_amount,
+				expected_duration,
+				expected_interest,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&borrower,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn repay_loan_ix_setup(
+		fee_payer: &Keypair,
+		borrower: &Keypair,
+		lender: Pubkey,
+		mint: Pubkey,
+		metadata: Pubkey,
+		collection: Pubkey,
+		loan: Pubkey,
+		escrow_token_account: Pubkey,
+		borrower_token_account: Pubkey,
+		freeze_authority: Pubkey,
+		system_program: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::RepayLoan {
+			fee_payer: fee_payer.pubkey(),
+			borrower: borrower.pubkey(),
+			lender: lender,
+			mint: mint,
+			metadata: metadata,
+			collection: collection,
+			loan: loan,
+			escrow_token_account: escrow_token_account,
+			borrower_token_account: borrower_token_account,
+			freeze_authority: freeze_authority,
+			system_program: system_program,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+		};
+
+		let data = metaplex_nft_program_instruction::RepayLoan;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&borrower,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn foreclose_loan_ix_setup(
+		fee_payer: &Keypair,
+		lender: &Keypair,
+		mint: Pubkey,
+		metadata: Pubkey,
+		collection: Pubkey,
+		loan: Pubkey,
+		escrow_token_account: Pubkey,
+		lender_token_account: Pubkey,
+		freeze_authority: Pubkey,
+		system_program: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		associated_token_program: Pubkey,
+		program_config: Pubkey,
+		remaining_accounts: Vec<AccountMeta>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::ForecloseLoan {
+			fee_payer: fee_payer.pubkey(),
+			lender: lender.pubkey(),
+			mint: mint,
+			metadata: metadata,
+			collection: collection,
+			loan: loan,
+			escrow_token_account: escrow_token_account,
+			lender_token_account: lender_token_account,
+			freeze_authority: freeze_authority,
+			system_program: system_program,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			associated_token_program: associated_token_program,
+			program_config: program_config,
+		};
+
+		let data = metaplex_nft_program_instruction::ForecloseLoan;
+		let mut account_metas = accounts.to_account_metas(None);
+		account_metas.extend(remaining_accounts);
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&lender,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn enable_royalty_enforcement_ix_setup(
+		fee_payer: &Keypair,
+		collection: Pubkey,
+		authority: &Keypair,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::EnableRoyaltyEnforcement {
+			fee_payer: fee_payer.pubkey(),
+			collection: collection,
+			authority: authority.pubkey(),
+		};
+
+		let data = metaplex_nft_program_instruction::EnableRoyaltyEnforcement;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_rule_set_ix_setup(
+		fee_payer: &Keypair,
+		collection: Pubkey,
+		authority: &Keypair,
+		rule_set: Pubkey,
+		system_program: Pubkey,
+		mode: &RuleSetMode,
+		programs: &Vec<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::SetRuleSet {
+			fee_payer: fee_payer.pubkey(),
+			collection: collection,
+			authority: authority.pubkey(),
+			rule_set: rule_set,
+			system_program: system_program,
+		};
+
+		let data = 	metaplex_nft_program_instruction::SetRuleSet {
+				mode: mode.clone(),
+				programs: programs.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn make_collection_soulbound_ix_setup(
+		fee_payer: &Keypair,
+		collection: Pubkey,
+		authority: &Keypair,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::MakeCollectionSoulbound {
+			fee_payer: fee_payer.pubkey(),
+			collection: collection,
+			authority: authority.pubkey(),
+		};
+
+		let data = metaplex_nft_program_instruction::MakeCollectionSoulbound;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn burn_nft_ix_setup(
+		fee_payer: &Keypair,
+		owner: &Keypair,
+		mint: Pubkey,
+		metadata: Pubkey,
+		collection: Pubkey,
+		owner_token_account: Pubkey,
+		freeze_authority: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		program_config: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::BurnNft {
+			fee_payer: fee_payer.pubkey(),
+			owner: owner.pubkey(),
+			mint: mint,
+			metadata: metadata,
+			collection: collection,
+			owner_token_account: owner_token_account,
+			freeze_authority: freeze_authority,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			program_config: program_config,
+		};
+
+		let data = metaplex_nft_program_instruction::BurnNft;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&owner,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn approve_delegate_ix_setup(
+		fee_payer: &Keypair,
+		owner: &Keypair,
+		mint: Pubkey,
+		metadata: Pubkey,
+		owner_token_account: Pubkey,
+		delegate: Pubkey,
+		collection: Pubkey,
+		freeze_authority: Pubkey,
+		rule_set: Pubkey,
+		instructions: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		program_config: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::ApproveDelegate {
+			fee_payer: fee_payer.pubkey(),
+			owner: owner.pubkey(),
+			mint: mint,
+			metadata: metadata,
+			owner_token_account: owner_token_account,
+			delegate: delegate,
+			collection: collection,
+			freeze_authority: freeze_authority,
+			rule_set: rule_set,
+			instructions: instructions,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			program_config: program_config,
+		};
+
+		let data = metaplex_nft_program_instruction::ApproveDelegate;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&owner,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn revoke_delegate_ix_setup(
+		fee_payer: &Keypair,
+		owner: &Keypair,
+		mint: Pubkey,
+		metadata: Pubkey,
+		owner_token_account: Pubkey,
+		collection: Pubkey,
+		freeze_authority: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::RevokeDelegate {
+			fee_payer: fee_payer.pubkey(),
+			owner: owner.pubkey(),
+			mint: mint,
+			metadata: metadata,
+			owner_token_account: owner_token_account,
+			collection: collection,
+			freeze_authority: freeze_authority,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+		};
+
+		let data = metaplex_nft_program_instruction::RevokeDelegate;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&owner,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn freeze_nft_ix_setup(
+		fee_payer: &Keypair,
+		collection: Pubkey,
+		authority: &Keypair,
+		mint: Pubkey,
+		metadata: Pubkey,
+		holder: Pubkey,
+		holder_token_account: Pubkey,
+		freeze_authority: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::FreezeNft {
+			fee_payer: fee_payer.pubkey(),
+			collection: collection,
+			authority: authority.pubkey(),
+			mint: mint,
+			metadata: metadata,
+			holder: holder,
+			holder_token_account: holder_token_account,
+			freeze_authority: freeze_authority,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+		};
+
+		let data = metaplex_nft_program_instruction::FreezeNft;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn thaw_nft_ix_setup(
+		fee_payer: &Keypair,
+		collection: Pubkey,
+		authority: &Keypair,
+		mint: Pubkey,
+		metadata: Pubkey,
+		holder: Pubkey,
+		holder_token_account: Pubkey,
+		freeze_authority: Pubkey,
+		token_program: Pubkey,
+		csl_spl_token_v0_0_0: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::ThawNft {
+			fee_payer: fee_payer.pubkey(),
+			collection: collection,
+			authority: authority.pubkey(),
+			mint: mint,
+			metadata: metadata,
+			holder: holder,
+			holder_token_account: holder_token_account,
+			freeze_authority: freeze_authority,
+			token_program: token_program,
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+		};
+
+		let data = metaplex_nft_program_instruction::ThawNft;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn initialize_program_config_ix_setup(
+		fee_payer: &Keypair,
+		program_config: Pubkey,
+		treasury: Pubkey,
+		upgrade_authority: &Keypair,
+		program: Pubkey,
+		program_data: Pubkey,
+		system_program: Pubkey,
+		admin: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::InitializeProgramConfig {
+			fee_payer: fee_payer.pubkey(),
+			program_config: program_config,
+			treasury: treasury,
+			upgrade_authority: upgrade_authority.pubkey(),
+			program: program,
+			program_data: program_data,
+			system_program: system_program,
+		};
+
+		let data = 	metaplex_nft_program_instruction::InitializeProgramConfig {
+				admin,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&upgrade_authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_program_admin_ix_setup(
+		fee_payer: &Keypair,
+		program_config: Pubkey,
+		admin: &Keypair,
+		new_admin: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::SetProgramAdmin {
+			fee_payer: fee_payer.pubkey(),
+			program_config: program_config,
+			admin: admin.pubkey(),
+		};
+
+		let data = 	metaplex_nft_program_instruction::SetProgramAdmin {
+				new_admin,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&admin,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_program_paused_ix_setup(
+		fee_payer: &Keypair,
+		program_config: Pubkey,
+		admin: &Keypair,
+		paused: bool,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::SetProgramPaused {
+			fee_payer: fee_payer.pubkey(),
+			program_config: program_config,
+			admin: admin.pubkey(),
+		};
+
+		let data = 	metaplex_nft_program_instruction::SetProgramPaused {
+				paused,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&admin,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_collection_paused_ix_setup(
+		fee_payer: &Keypair,
+		collection: Pubkey,
+		authority: &Keypair,
+		paused: bool,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::SetCollectionPaused {
+			fee_payer: fee_payer.pubkey(),
+			collection: collection,
+			authority: authority.pubkey(),
+		};
+
+		let data = 	metaplex_nft_program_instruction::SetCollectionPaused {
+				paused,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_protocol_fee_ix_setup(
+		fee_payer: &Keypair,
+		program_config: Pubkey,
+		admin: &Keypair,
+		mint_fee_lamports: u64,
+		mint_fee_bps: u16,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::SetProtocolFee {
+			fee_payer: fee_payer.pubkey(),
+			program_config: program_config,
+			admin: admin.pubkey(),
+		};
+
+		let data = 	metaplex_nft_program_instruction::SetProtocolFee {
+				mint_fee_lamports,
+				mint_fee_bps,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&admin,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn withdraw_fees_ix_setup(
+		fee_payer: &Keypair,
+		program_config: Pubkey,
+		admin: &Keypair,
+		treasury: Pubkey,
+		destination: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::WithdrawFees {
+			fee_payer: fee_payer.pubkey(),
+			program_config: program_config,
+			admin: admin.pubkey(),
+			treasury: treasury,
+			destination: destination,
+		};
+
+		let data = metaplex_nft_program_instruction::WithdrawFees;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&admin,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_multisig_ix_setup(
+		fee_payer: &Keypair,
+		creator: &Keypair,
+		multisig: Pubkey,
+		system_program: Pubkey,
+		nonce: u64,
+		signers: &Vec<Pubkey>,
+		threshold: u8,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::CreateMultisig {
+			fee_payer: fee_payer.pubkey(),
+			creator: creator.pubkey(),
+			multisig: multisig,
+			system_program: system_program,
+		};
+
+		let data = 	metaplex_nft_program_instruction::CreateMultisig {
+				nonce,
+				signers: signers.clone(),
+				threshold,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&creator,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_multisig_signers_ix_setup(
+		fee_payer: &Keypair,
+		multisig: &Keypair,
+		signers: &Vec<Pubkey>,
+		threshold: u8,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::SetMultisigSigners {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig.pubkey(),
+		};
+
+		let data = 	metaplex_nft_program_instruction::SetMultisigSigners {
+				signers: signers.clone(),
+				threshold,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&multisig,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_proposal_ix_setup(
+		fee_payer: &Keypair,
+		proposer: &Keypair,
+		multisig: Pubkey,
+		proposal: Pubkey,
+		system_program: Pubkey,
+		proposal_accounts: &Vec<ProposalAccountMeta>,
+		data: &Vec<u8>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::CreateProposal {
+			fee_payer: fee_payer.pubkey(),
+			proposer: proposer.pubkey(),
+			multisig: multisig,
+			proposal: proposal,
+			system_program: system_program,
+		};
+
+		let data = 	metaplex_nft_program_instruction::CreateProposal {
+				accounts: proposal_accounts.clone(),
+				data: data.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&proposer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn approve_proposal_ix_setup(
+		fee_payer: &Keypair,
+		signer: &Keypair,
+		multisig: Pubkey,
+		proposal: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::ApproveProposal {
+			fee_payer: fee_payer.pubkey(),
+			signer: signer.pubkey(),
+			multisig: multisig,
+			proposal: proposal,
+		};
+
+		let data = metaplex_nft_program_instruction::ApproveProposal;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&signer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn execute_proposal_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		proposal: Pubkey,
+		program: Pubkey,
+		remaining_accounts: Vec<AccountMeta>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::ExecuteProposal {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			proposal: proposal,
+			program: program,
+		};
+
+		let data = metaplex_nft_program_instruction::ExecuteProposal;
+		let mut account_metas = accounts.to_account_metas(None);
+		account_metas.extend(remaining_accounts);
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn lock_metadata_ix_setup(
+		fee_payer: &Keypair,
+		collection: Pubkey,
+		authority: &Keypair,
+		metadata: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::LockMetadata {
+			fee_payer: fee_payer.pubkey(),
+			collection: collection,
+			authority: authority.pubkey(),
+			metadata: metadata,
+		};
+
+		let data = metaplex_nft_program_instruction::LockMetadata;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_uri_rules_ix_setup(
+		fee_payer: &Keypair,
+		collection: Pubkey,
+		authority: &Keypair,
+		max_uri_length: u16,
+		allowed_schemes: &Vec<String>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::SetUriRules {
+			fee_payer: fee_payer.pubkey(),
+			collection: collection,
+			authority: authority.pubkey(),
+		};
+
+		let data = 	metaplex_nft_program_instruction::SetUriRules {
+				max_uri_length,
+				allowed_schemes: allowed_schemes.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn reveal_ix_setup(
+		fee_payer: &Keypair,
+		collection: Pubkey,
+		authority: &Keypair,
+		program_config: Pubkey,
+		entries: &Vec<RevealEntry>,
+		remaining_accounts: Vec<AccountMeta>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::Reveal {
+			fee_payer: fee_payer.pubkey(),
+			collection: collection,
+			authority: authority.pubkey(),
+			program_config: program_config,
+		};
+
+		let data = 	metaplex_nft_program_instruction::Reveal {
+				entries: entries.clone(),
+		};		let mut account_metas = accounts.to_account_metas(None);
+		account_metas.extend(remaining_accounts);
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_base_uri_ix_setup(
+		fee_payer: &Keypair,
+		collection: Pubkey,
+		authority: &Keypair,
+		system_program: Pubkey,
+		base_uri: &String,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::SetBaseUri {
+			fee_payer: fee_payer.pubkey(),
+			collection: collection,
+			authority: authority.pubkey(),
+			system_program: system_program,
+		};
+
+		let data = 	metaplex_nft_program_instruction::SetBaseUri {
+				base_uri: base_uri.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn withdraw_bid_ix_setup(
+		fee_payer: &Keypair,
+		bidder: &Keypair,
+		bid_refund: Pubkey,
+		payer: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::WithdrawBid {
+			fee_payer: fee_payer.pubkey(),
+			bidder: bidder.pubkey(),
+			bid_refund: bid_refund,
+			payer: payer,
+		};
+
+		let data = metaplex_nft_program_instruction::WithdrawBid;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&bidder,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_collection_authority_ix_setup(
+		fee_payer: &Keypair,
+		collection: Pubkey,
+		authority: &Keypair,
+		new_authority: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = metaplex_nft_program_accounts::SetCollectionAuthority {
+			fee_payer: fee_payer.pubkey(),
+			collection: collection,
+			authority: authority.pubkey(),
+		};
+
+		let data = 	metaplex_nft_program_instruction::SetCollectionAuthority {
+				new_authority,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+}
@@ -0,0 +1,49 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, Auction, NftMint};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn create_auction_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let seller = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &seller).await;
+	let end_time = unix_timestamp(&mut context).await + 3_600;
+
+	create_auction(&mut context, collection, &nft, &seller, 1_000_000_000, end_time).await.unwrap();
+
+	let auction_address = auction_pda(&nft.mint);
+	let escrow = token_account_address(&auction_address, &nft.mint);
+	assert_eq!(token_account(&mut context, nft.token_account).await.amount, 0);
+	assert_eq!(token_account(&mut context, escrow).await.amount, 1);
+	let auction: Auction = get_account(&mut context, auction_address).await;
+	assert_eq!(auction.seller, seller.pubkey());
+	assert_eq!(auction.mint, nft.mint);
+	assert_eq!(auction.collection, collection);
+	assert_eq!(auction.reserve_price, 1_000_000_000);
+	assert_eq!(auction.end_time, end_time);
+	assert_eq!(auction.highest_bidder, Pubkey::default());
+	assert_eq!(auction.highest_bid, 0);
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert_eq!(metadata.owner, auction_address);
+}
+
+#[tokio::test]
+async fn create_auction_ix_rejects_past_end_time() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let seller = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &seller).await;
+	let end_time = unix_timestamp(&mut context).await;
+
+	let result = create_auction(&mut context, collection, &nft, &seller, 1_000_000_000, end_time).await;
+	assert_error(result, MetaplexNftProgramError::InvalidAuctionConfig);
+}
@@ -0,0 +1,44 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, Loan};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn create_loan_offer_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let lender = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+
+	create_loan_offer(&mut context, collection, &lender, 7, 1_000_000_000, 86_400, 50_000_000).await.unwrap();
+
+	let loan_address = loan_pda(&lender.pubkey(), 7);
+	let loan: Loan = get_account(&mut context, loan_address).await;
+	assert_eq!(loan.lender, lender.pubkey());
+	assert_eq!(loan.collection, collection);
+	assert_eq!(loan.offer_id, 7);
+	assert_eq!(loan.amount, 1_000_000_000);
+	assert_eq!(loan.duration, 86_400);
+	assert_eq!(loan.interest, 50_000_000);
+	assert_eq!(loan.borrower, Pubkey::default());
+	let account = context.banks_client.get_account(loan_address).await.unwrap().unwrap();
+	let rent = context.banks_client.get_rent().await.unwrap().minimum_balance(account.data.len());
+	assert_eq!(account.lamports, rent + 1_000_000_000);
+	assert_eq!(lamports(&mut context, lender.pubkey()).await, LAMPORTS - rent - 1_000_000_000);
+}
+
+#[tokio::test]
+async fn create_loan_offer_ix_rejects_zero_amount() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let lender = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+
+	let result = create_loan_offer(&mut context, collection, &lender, 7, 0, 86_400, 50_000_000).await;
+	assert_error(result, MetaplexNftProgramError::InvalidLoanTerms);
+}
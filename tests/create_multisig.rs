@@ -0,0 +1,48 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, Multisig};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn create_multisig_ix_success() {
+	let mut context = start().await;
+	let creator = Keypair::new();
+	let signers = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+
+	create_multisig(&mut context, &creator, 7, &signers, 2).await.unwrap();
+
+	let multisig: Multisig = get_account(&mut context, multisig_pda(&creator.pubkey(), 7)).await;
+	assert_eq!(multisig.creator, creator.pubkey());
+	assert_eq!(multisig.nonce, 7);
+	assert_eq!(multisig.signers, signers.to_vec());
+	assert_eq!(multisig.threshold, 2);
+	assert_eq!(multisig.proposal_count, 0);
+}
+
+#[tokio::test]
+async fn create_multisig_ix_rejects_unreachable_threshold() {
+	let mut context = start().await;
+	let creator = Keypair::new();
+	let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+	let result = create_multisig(&mut context, &creator, 7, &signers, 3).await;
+	assert_error(result, MetaplexNftProgramError::InvalidMultisig);
+
+	let result = create_multisig(&mut context, &creator, 7, &signers, 0).await;
+	assert_error(result, MetaplexNftProgramError::InvalidMultisig);
+}
+
+#[tokio::test]
+async fn create_multisig_ix_rejects_duplicate_signers() {
+	let mut context = start().await;
+	let creator = Keypair::new();
+	let signer = Pubkey::new_unique();
+
+	let result = create_multisig(&mut context, &creator, 7, &[signer, signer], 1).await;
+	assert_error(result, MetaplexNftProgramError::InvalidMultisig);
+}
@@ -0,0 +1,53 @@
+pub mod common;
+
+use metaplex_nft_program::{accounts, error::MetaplexNftProgramError, instruction, Multisig, Proposal};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::{instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+fn pause_instruction(fee_payer: Pubkey, collection: Pubkey, multisig: Pubkey) -> Instruction {
+	program_instruction(
+		accounts::SetCollectionPaused { fee_payer, collection, authority: multisig },
+		instruction::SetCollectionPaused { paused: true },
+	)
+}
+
+#[tokio::test]
+async fn create_proposal_ix_success() {
+	let mut context = start().await;
+	let creator = Keypair::new();
+	let member = Keypair::new();
+	let multisig = multisig_pda(&creator.pubkey(), 0);
+	create_multisig(&mut context, &creator, 0, &[member.pubkey(), Pubkey::new_unique()], 2).await.unwrap();
+
+	let instruction = pause_instruction(context.payer.pubkey(), Pubkey::new_unique(), multisig);
+	let address = create_proposal(&mut context, multisig, &member, &instruction).await.unwrap();
+
+	assert_eq!(address, proposal_pda(&multisig, 0));
+	let proposal: Proposal = get_account(&mut context, address).await;
+	assert_eq!(proposal.multisig, multisig);
+	assert_eq!(proposal.index, 0);
+	assert_eq!(proposal.proposer, member.pubkey());
+	assert_eq!(proposal.data, instruction.data);
+	assert_eq!(proposal.accounts.len(), 3);
+	assert!(proposal.accounts[2].pubkey == multisig && proposal.accounts[2].is_signer);
+	assert_eq!(proposal.approvals, vec![member.pubkey()]);
+	assert!(!proposal.executed);
+	let state: Multisig = get_account(&mut context, multisig).await;
+	assert_eq!(state.proposal_count, 1);
+}
+
+#[tokio::test]
+async fn create_proposal_ix_rejects_non_member() {
+	let mut context = start().await;
+	let creator = Keypair::new();
+	let outsider = Keypair::new();
+	let multisig = multisig_pda(&creator.pubkey(), 0);
+	create_multisig(&mut context, &creator, 0, &[Pubkey::new_unique()], 1).await.unwrap();
+
+	let instruction = pause_instruction(context.payer.pubkey(), Pubkey::new_unique(), multisig);
+	let result = create_proposal(&mut context, multisig, &outsider, &instruction).await.map(|_| ());
+	assert_error(result, MetaplexNftProgramError::NotMultisigSigner);
+}
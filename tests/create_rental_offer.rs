@@ -0,0 +1,38 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, RentalOffer};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::{signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn create_rental_offer_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	create_rental_offer(&mut context, collection, &nft, &owner, 10_000_000, 7).await.unwrap();
+
+	let offer: RentalOffer = get_account(&mut context, rental_offer_pda(&nft.mint, &owner.pubkey())).await;
+	assert_eq!(offer.owner, owner.pubkey());
+	assert_eq!(offer.mint, nft.mint);
+	assert_eq!(offer.price_per_day, 10_000_000);
+	assert_eq!(offer.max_days, 7);
+}
+
+#[tokio::test]
+async fn create_rental_offer_ix_rejects_zero_price() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	let result = create_rental_offer(&mut context, collection, &nft, &owner, 0, 7).await;
+	assert_error(result, MetaplexNftProgramError::InvalidPrice);
+}
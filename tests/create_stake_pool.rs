@@ -0,0 +1,47 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, StakePool};
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::tokio,
+	solana_sdk::{pubkey::Pubkey, signature::Keypair},
+};
+
+
+#[tokio::test]
+async fn create_stake_pool_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+
+	let reward_mint = create_stake_pool(&mut context, collection, &authority, 10).await;
+
+	let stake_pool: StakePool = get_account(&mut context, stake_pool_pda(&collection)).await;
+	assert_eq!(stake_pool.collection, collection);
+	assert_eq!(stake_pool.reward_mint, reward_mint);
+	assert_eq!(stake_pool.reward_rate, 10);
+}
+
+#[tokio::test]
+async fn create_stake_pool_ix_rejects_reward_mint_of_another_authority() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+	let reward_mint = create_mint(&mut context, &Pubkey::new_unique()).await;
+
+	let transaction = metaplex_nft_program_ix_interface::create_stake_pool_ix_setup(
+		&context.payer,
+		collection,
+		&authority,
+		stake_pool_pda(&collection),
+		reward_mint,
+		SYSTEM_PROGRAM,
+		10,
+		context.last_blockhash,
+	);
+	let result = process(&mut context, transaction).await;
+	assert_error(result, MetaplexNftProgramError::InvalidRewardMint);
+}
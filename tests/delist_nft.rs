@@ -0,0 +1,63 @@
+pub mod common;
+
+use anchor_lang::error::ErrorCode;
+use metaplex_nft_program::NftMint;
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::{tokio, ProgramTestContext},
+	solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction},
+};
+
+fn delist(context: &ProgramTestContext, seller: &Keypair, collection: Pubkey, nft: &Nft) -> Transaction {
+	let listing = listing_pda(&nft.mint);
+	metaplex_nft_program_ix_interface::delist_nft_ix_setup(
+		&context.payer,
+		seller,
+		nft.mint,
+		nft.metadata,
+		collection,
+		listing,
+		nft.token_account,
+		token_account_address(&listing, &nft.mint),
+		freeze_authority_pda(),
+		TOKEN_PROGRAM,
+		TOKEN_PROGRAM,
+		context.last_blockhash,
+	)
+}
+
+#[tokio::test]
+async fn delist_nft_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let seller = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &seller).await;
+	list_nft(&mut context, collection, &nft, &seller, 1_000_000).await.unwrap();
+
+	let transaction = delist(&context, &seller, collection, &nft);
+	process(&mut context, transaction).await.unwrap();
+
+	let listing = listing_pda(&nft.mint);
+	assert_eq!(token_account(&mut context, nft.token_account).await.amount, 1);
+	assert!(!account_exists(&mut context, listing).await);
+	assert!(!account_exists(&mut context, token_account_address(&listing, &nft.mint)).await);
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert_eq!(metadata.owner, seller.pubkey());
+}
+
+#[tokio::test]
+async fn delist_nft_ix_rejects_other_signer() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let seller = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &seller).await;
+	list_nft(&mut context, collection, &nft, &seller, 1_000_000).await.unwrap();
+
+	let transaction = delist(&context, &Keypair::new(), collection, &nft);
+	assert_error(process(&mut context, transaction).await, ErrorCode::ConstraintHasOne);
+}
@@ -0,0 +1,63 @@
+pub mod common;
+
+use anchor_lang::solana_program::program_option::COption;
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftMint};
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::{tokio, ProgramTestContext},
+	solana_sdk::{pubkey::Pubkey, signature::Keypair, transaction::Transaction},
+};
+
+fn delist_in_place(context: &ProgramTestContext, seller: &Keypair, collection: Pubkey, nft: &Nft) -> Transaction {
+	metaplex_nft_program_ix_interface::delist_nft_in_place_ix_setup(
+		&context.payer,
+		seller,
+		nft.mint,
+		nft.metadata,
+		listing_pda(&nft.mint),
+		collection,
+		nft.token_account,
+		freeze_authority_pda(),
+		TOKEN_PROGRAM,
+		TOKEN_PROGRAM,
+		context.last_blockhash,
+	)
+}
+
+#[tokio::test]
+async fn delist_nft_in_place_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let seller = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &seller).await;
+	list_nft_in_place(&mut context, collection, &nft, &seller, 1_000_000).await.unwrap();
+
+	let transaction = delist_in_place(&context, &seller, collection, &nft);
+	process(&mut context, transaction).await.unwrap();
+
+	let token = token_account(&mut context, nft.token_account).await;
+	assert_eq!(token.amount, 1);
+	assert_eq!(token.delegate, COption::None);
+	assert!(!token.is_frozen());
+	assert!(!account_exists(&mut context, listing_pda(&nft.mint)).await);
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert!(!metadata.listed);
+}
+
+#[tokio::test]
+async fn delist_nft_in_place_ix_rejects_escrow_listing() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let seller = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &seller).await;
+	list_nft(&mut context, collection, &nft, &seller, 1_000_000).await.unwrap();
+
+	let transaction = delist_in_place(&context, &seller, collection, &nft);
+	let result = process(&mut context, transaction).await;
+	assert_error(result, MetaplexNftProgramError::ListingModeMismatch);
+}
@@ -0,0 +1,62 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftCollection};
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::tokio,
+	solana_sdk::signature::Keypair,
+};
+
+
+#[tokio::test]
+async fn enable_royalty_enforcement_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+
+	enable_royalty_enforcement(&mut context, collection, &authority).await;
+
+	let state: NftCollection = get_account(&mut context, collection).await;
+	assert!(state.enforce_royalties);
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	assert!(token_account(&mut context, nft.token_account).await.is_frozen());
+}
+
+#[tokio::test]
+async fn enable_royalty_enforcement_ix_rejects_minted_collection() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	mint_nft(&mut context, collection, &authority, &owner).await;
+
+	let transaction = metaplex_nft_program_ix_interface::enable_royalty_enforcement_ix_setup(
+		&context.payer,
+		collection,
+		&authority,
+		context.last_blockhash,
+	);
+	let result = process(&mut context, transaction).await;
+	assert_error(result, MetaplexNftProgramError::RoyaltyEnforcementLocked);
+}
+
+#[tokio::test]
+async fn enable_royalty_enforcement_ix_rejects_other_authority() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let impostor = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+
+	let transaction = metaplex_nft_program_ix_interface::enable_royalty_enforcement_ix_setup(
+		&context.payer,
+		collection,
+		&impostor,
+		context.last_blockhash,
+	);
+	let result = process(&mut context, transaction).await;
+	assert_error(result, MetaplexNftProgramError::InvalidAuthority);
+}
@@ -0,0 +1,58 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftMint, SECONDS_PER_DAY};
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::tokio,
+	solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn end_rental_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let renter = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	create_rental_offer(&mut context, collection, &nft, &owner, 10_000_000, 7).await.unwrap();
+	let now = unix_timestamp(&mut context).await;
+	rent_nft(&mut context, collection, &nft, &owner.pubkey(), &renter, 1).await.unwrap();
+
+	set_unix_timestamp(&mut context, now + SECONDS_PER_DAY).await;
+	let transaction = metaplex_nft_program_ix_interface::end_rental_ix_setup(
+		&context.payer,
+		nft.metadata,
+		context.last_blockhash,
+	);
+	process(&mut context, transaction).await.unwrap();
+
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert_eq!(metadata.user, Pubkey::default());
+	assert_eq!(metadata.expires_at, 0);
+	transfer_nft(&mut context, collection, &nft, &owner, &Keypair::new().pubkey()).await.unwrap();
+}
+
+#[tokio::test]
+async fn end_rental_ix_rejects_running_rental() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let renter = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	create_rental_offer(&mut context, collection, &nft, &owner, 10_000_000, 7).await.unwrap();
+	rent_nft(&mut context, collection, &nft, &owner.pubkey(), &renter, 1).await.unwrap();
+
+	let transaction = metaplex_nft_program_ix_interface::end_rental_ix_setup(
+		&context.payer,
+		nft.metadata,
+		context.last_blockhash,
+	);
+	let result = process(&mut context, transaction).await;
+	assert_error(result, MetaplexNftProgramError::RentalNotExpired);
+}
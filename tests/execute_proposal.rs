@@ -0,0 +1,68 @@
+pub mod common;
+
+use metaplex_nft_program::{accounts, error::MetaplexNftProgramError, instruction, NftCollection, Proposal};
+use {
+	common::fixtures::*,
+	solana_program_test::{tokio, ProgramTestContext},
+	solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+/// Creates a collection owned by a 2-of-3 multisig of `members`, and returns
+/// the collection, the multisig and a proposal to pause the collection.
+async fn pause_proposal(context: &mut ProgramTestContext, members: &[Keypair; 3]) -> (Pubkey, Pubkey, Pubkey) {
+	let creator = Keypair::new();
+	let authority = Keypair::new();
+	let multisig = multisig_pda(&creator.pubkey(), 0);
+	let signers: Vec<Pubkey> = members.iter().map(|member| member.pubkey()).collect();
+	create_multisig(context, &creator, 0, &signers, 2).await.unwrap();
+	let collection = create_collection(context, &authority).await;
+	set_collection_authority(context, collection, &authority, multisig).await.unwrap();
+
+	let instruction = program_instruction(
+		accounts::SetCollectionPaused { fee_payer: context.payer.pubkey(), collection, authority: multisig },
+		instruction::SetCollectionPaused { paused: true },
+	);
+	let proposal = create_proposal(context, multisig, &members[0], &instruction).await.unwrap();
+	(collection, multisig, proposal)
+}
+
+#[tokio::test]
+async fn execute_proposal_ix_success() {
+	let mut context = start().await;
+	let members = [Keypair::new(), Keypair::new(), Keypair::new()];
+	let (collection, multisig, proposal) = pause_proposal(&mut context, &members).await;
+	approve_proposal(&mut context, multisig, proposal, &members[2]).await.unwrap();
+
+	execute_proposal(&mut context, multisig, proposal).await.unwrap();
+
+	let state: NftCollection = get_account(&mut context, collection).await;
+	assert!(state.paused);
+	let proposal: Proposal = get_account(&mut context, proposal).await;
+	assert!(proposal.executed);
+}
+
+#[tokio::test]
+async fn execute_proposal_ix_rejects_unapproved_proposal() {
+	let mut context = start().await;
+	let members = [Keypair::new(), Keypair::new(), Keypair::new()];
+	let (collection, multisig, proposal) = pause_proposal(&mut context, &members).await;
+
+	let result = execute_proposal(&mut context, multisig, proposal).await;
+	assert_error(result, MetaplexNftProgramError::ProposalNotApproved);
+	let state: NftCollection = get_account(&mut context, collection).await;
+	assert!(!state.paused);
+}
+
+#[tokio::test]
+async fn execute_proposal_ix_rejects_repeated_execution() {
+	let mut context = start().await;
+	let members = [Keypair::new(), Keypair::new(), Keypair::new()];
+	let (_, multisig, proposal) = pause_proposal(&mut context, &members).await;
+	approve_proposal(&mut context, multisig, proposal, &members[1]).await.unwrap();
+	execute_proposal(&mut context, multisig, proposal).await.unwrap();
+
+	// A fresh blockhash keeps the retry from being deduplicated as the same transaction.
+	context.get_new_latest_blockhash().await.unwrap();
+	let result = execute_proposal(&mut context, multisig, proposal).await;
+	assert_error(result, MetaplexNftProgramError::ProposalAlreadyExecuted);
+}
@@ -0,0 +1,71 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftMint};
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::{tokio, BanksClientError, ProgramTestContext},
+	solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+async fn foreclose(context: &mut ProgramTestContext, collection: Pubkey, nft: &Nft, lender: &Keypair, loan: Pubkey) -> Result<(), BanksClientError> {
+	let transaction = metaplex_nft_program_ix_interface::foreclose_loan_ix_setup(
+		&context.payer,
+		lender,
+		nft.mint,
+		nft.metadata,
+		collection,
+		loan,
+		token_account_address(&loan, &nft.mint),
+		token_account_address(&lender.pubkey(), &nft.mint),
+		freeze_authority_pda(),
+		SYSTEM_PROGRAM,
+		TOKEN_PROGRAM,
+		TOKEN_PROGRAM,
+		ASSOCIATED_TOKEN_PROGRAM,
+		program_config_pda(),
+		Vec::new(),
+		context.last_blockhash,
+	);
+	process(context, transaction).await
+}
+
+#[tokio::test]
+async fn foreclose_loan_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let lender = funded_keypair(&mut context);
+	let borrower = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &borrower).await;
+	create_loan_offer(&mut context, collection, &lender, 7, 1_000_000_000, 86_400, 50_000_000).await.unwrap();
+	let loan = loan_pda(&lender.pubkey(), 7);
+	let now = unix_timestamp(&mut context).await;
+	take_loan(&mut context, collection, &nft, &borrower, loan).await.unwrap();
+
+	set_unix_timestamp(&mut context, now + 86_401).await;
+	foreclose(&mut context, collection, &nft, &lender, loan).await.unwrap();
+
+	assert_eq!(token_account(&mut context, token_account_address(&lender.pubkey(), &nft.mint)).await.amount, 1);
+	assert!(!account_exists(&mut context, loan).await);
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert_eq!(metadata.owner, lender.pubkey());
+}
+
+#[tokio::test]
+async fn foreclose_loan_ix_rejects_running_loan() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let lender = funded_keypair(&mut context);
+	let borrower = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &borrower).await;
+	create_loan_offer(&mut context, collection, &lender, 7, 1_000_000_000, 86_400, 50_000_000).await.unwrap();
+	let loan = loan_pda(&lender.pubkey(), 7);
+	take_loan(&mut context, collection, &nft, &borrower, loan).await.unwrap();
+
+	let result = foreclose(&mut context, collection, &nft, &lender, loan).await;
+	assert_error(result, MetaplexNftProgramError::LoanNotExpired);
+}
@@ -0,0 +1,49 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftMint, Vault};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::{signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn fractionalize_nft_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	fractionalize_nft(&mut context, collection, &nft, &owner, 100, 1_000_000_000).await.unwrap();
+
+	let vault_address = vault_pda(&nft.mint);
+	let share_mint = share_mint_pda(&vault_address);
+	assert_eq!(token_account(&mut context, nft.token_account).await.amount, 0);
+	assert_eq!(token_account(&mut context, token_account_address(&vault_address, &nft.mint)).await.amount, 1);
+	assert_eq!(token_account(&mut context, token_account_address(&owner.pubkey(), &share_mint)).await.amount, 100);
+	let shares = mint_account(&mut context, share_mint).await;
+	assert_eq!(shares.supply, 100);
+	assert!(shares.mint_authority.is_none());
+	let vault: Vault = get_account(&mut context, vault_address).await;
+	assert_eq!(vault.curator, owner.pubkey());
+	assert_eq!(vault.share_mint, share_mint);
+	assert_eq!(vault.total_shares, 100);
+	assert_eq!(vault.reserve_price, 1_000_000_000);
+	assert!(!vault.bought_out);
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert_eq!(metadata.owner, vault_address);
+}
+
+#[tokio::test]
+async fn fractionalize_nft_ix_rejects_zero_shares() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	let result = fractionalize_nft(&mut context, collection, &nft, &owner, 0, 1_000_000_000).await;
+	assert_error(result, MetaplexNftProgramError::InvalidShareSupply);
+}
@@ -0,0 +1,74 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftMint};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn freeze_nft_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	freeze_nft(&mut context, collection, &authority, &nft, &owner.pubkey()).await.unwrap();
+
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert!(metadata.frozen);
+	assert!(token_account(&mut context, nft.token_account).await.is_frozen());
+}
+
+#[tokio::test]
+async fn freeze_nft_ix_blocks_transfers() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	freeze_nft(&mut context, collection, &authority, &nft, &owner.pubkey()).await.unwrap();
+
+	let result = transfer_nft(&mut context, collection, &nft, &owner, &Keypair::new().pubkey()).await;
+	assert_error(result, MetaplexNftProgramError::NftFrozen);
+
+	let result = list_nft(&mut context, collection, &nft, &owner, 1_000_000_000).await;
+	assert_error(result, MetaplexNftProgramError::NftFrozen);
+
+	let result = burn_nft(&mut context, collection, &nft, &owner).await;
+	assert_error(result, MetaplexNftProgramError::NftFrozen);
+
+	let result = update_nft_metadata(&mut context, collection, &authority, &nft, "Renamed", "https://example.com/renamed.json").await;
+	assert_error(result, MetaplexNftProgramError::NftFrozen);
+}
+
+#[tokio::test]
+async fn freeze_nft_ix_survives_revoking_a_delegate() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	approve_delegate(&mut context, collection, &nft, &owner, Pubkey::new_unique()).await.unwrap();
+	freeze_nft(&mut context, collection, &authority, &nft, &owner.pubkey()).await.unwrap();
+
+	revoke_delegate(&mut context, collection, &nft, &owner).await.unwrap();
+
+	assert!(token_account(&mut context, nft.token_account).await.is_frozen());
+}
+
+#[tokio::test]
+async fn freeze_nft_ix_rejects_other_authority() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let impostor = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	let result = freeze_nft(&mut context, collection, &impostor, &nft, &owner.pubkey()).await;
+	assert_error(result, MetaplexNftProgramError::InvalidAuthority);
+}
@@ -1,104 +1,50 @@
 pub mod common;
 
-use std::str::FromStr;
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftCollection};
 use {
-    common::{
-		get_program_test,
+	common::{
+		fixtures::*,
 		metaplex_nft_program_ix_interface,
-		csl_spl_token_ix_interface,
-		csl_spl_assoc_token_ix_interface,
 	},
-    solana_program_test::tokio,
-    solana_sdk::{
-        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
-    },
+	solana_program_test::tokio,
+	solana_sdk::{signature::Keypair, signer::Signer},
 };
 
 
 #[tokio::test]
 async fn initialize_collection_ix_success() {
-	let mut program_test = get_program_test();
-
-	// PROGRAMS
-	program_test.prefer_bpf(true);
+	let mut context = start().await;
+	let authority = Keypair::new();
+
+	let collection = create_collection(&mut context, &authority).await;
+
+	let state: NftCollection = get_account(&mut context, collection).await;
+	assert_eq!(state.authority, authority.pubkey());
+	assert_eq!(state.seed_authority, authority.pubkey());
+	assert_eq!(state.name, "Collection");
+	assert_eq!(state.symbol, "COL");
+	assert_eq!(state.uri, "https://example.com/collection.json");
+	assert_eq!(state.nft_count, 0);
+	assert!(state.is_mutable);
+	assert_eq!(state.allowed_uri_schemes, vec!["https", "ipfs", "ar"]);
+}
 
-	program_test.add_program(
-		"account_compression",
-		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+#[tokio::test]
+async fn initialize_collection_ix_rejects_uri_without_scheme() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+
+	let transaction = metaplex_nft_program_ix_interface::initialize_collection_ix_setup(
+		&context.payer,
+		collection_pda(&authority.pubkey()),
+		&authority,
+		SYSTEM_PROGRAM,
+		&"Collection".to_string(),
+		&"COL".to_string(),
+		&"example.com/collection.json".to_string(),
 		None,
-	);
-
-	program_test.add_program(
-		"noop",
-		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
 		None,
+		context.last_blockhash,
 	);
-
-	// DATA
-	let name: String = Default::default();
-	let symbol: String = Default::default();
-	let uri: String = Default::default();
-
-	// KEYPAIR
-	let fee_payer_keypair = Keypair::new();
-	let authority_keypair = Keypair::new();
-
-	// PUBKEY
-	let fee_payer_pubkey = fee_payer_keypair.pubkey();
-	let authority_pubkey = authority_keypair.pubkey();
-
-	// EXECUTABLE PUBKEY
-	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
-
-	// PDA
-	let (collection_pda, _collection_pda_bump) = Pubkey::find_program_address(
-		&[
-			b"collection",
-			authority_pubkey.as_ref(),
-		],
-		&metaplex_nft_program::ID,
-	);
-
-	// ACCOUNT PROGRAM TEST SETUP
-	program_test.add_account(
-		fee_payer_pubkey,
-		Account {
-			lamports: 1_000_000_000_000,
-			data: vec![],
-			owner: system_program::ID,
-			executable: false,
-			rent_epoch: 0,
-		},
-	);
-
-	program_test.add_account(
-		authority_pubkey,
-		Account {
-			lamports: 0,
-			data: vec![],
-			owner: system_program::ID,
-			executable: false,
-			rent_epoch: 0,
-		},
-	);
-
-	// INSTRUCTIONS
-	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
-
-	let ix = metaplex_nft_program_ix_interface::initialize_collection_ix_setup(
-		&fee_payer_keypair,
-		collection_pda,
-		&authority_keypair,
-		system_program_pubkey,
-		&name,
-		&symbol,
-		&uri,
-		recent_blockhash,
-	);
-
-	let result = banks_client.process_transaction(ix).await;
-
-	// ASSERTIONS
-	assert!(result.is_ok());
-
+	assert_error(process(&mut context, transaction).await, MetaplexNftProgramError::UriMissingScheme);
 }
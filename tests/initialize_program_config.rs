@@ -0,0 +1,44 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, ID as PROGRAM_ID};
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::tokio,
+	solana_sdk::{account::AccountSharedData, pubkey, pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+const UPGRADEABLE_LOADER: Pubkey = pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
+
+// The success path needs the program deployed through the upgradeable
+// loader, which a natively loaded program is not; the other tests start
+// from the state it writes through `initialize_program_config`.
+#[tokio::test]
+async fn initialize_program_config_ix_rejects_unverified_upgrade_authority() {
+	let mut context = start().await;
+	let upgrade_authority = Keypair::new();
+
+	// Program data naming the signer as upgrade authority does not help while
+	// the program account does not point at it.
+	let program_data = Pubkey::find_program_address(&[PROGRAM_ID.as_ref()], &UPGRADEABLE_LOADER).0;
+	let mut account = AccountSharedData::new(LAMPORTS, 45, &UPGRADEABLE_LOADER);
+	account.set_data_from_slice(&[&3u32.to_le_bytes()[..], &0u64.to_le_bytes(), &[1], upgrade_authority.pubkey().as_ref()].concat());
+	context.set_account(&program_data, &account);
+
+	let transaction = metaplex_nft_program_ix_interface::initialize_program_config_ix_setup(
+		&context.payer,
+		program_config_pda(),
+		treasury_pda(),
+		&upgrade_authority,
+		PROGRAM_ID,
+		program_data,
+		SYSTEM_PROGRAM,
+		upgrade_authority.pubkey(),
+		context.last_blockhash,
+	);
+	let result = process(&mut context, transaction).await;
+	assert_error(result, MetaplexNftProgramError::InvalidAuthority);
+	assert!(!account_exists(&mut context, program_config_pda()).await);
+}
@@ -0,0 +1,45 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, Listing, NftMint};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::{signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn list_nft_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let seller = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &seller).await;
+
+	list_nft(&mut context, collection, &nft, &seller, 1_000_000).await.unwrap();
+
+	let listing_address = listing_pda(&nft.mint);
+	let escrow = token_account_address(&listing_address, &nft.mint);
+	assert_eq!(token_account(&mut context, nft.token_account).await.amount, 0);
+	assert_eq!(token_account(&mut context, escrow).await.amount, 1);
+
+	let listing: Listing = get_account(&mut context, listing_address).await;
+	assert_eq!(listing.seller, seller.pubkey());
+	assert_eq!(listing.price, 1_000_000);
+	assert_eq!(listing.token_account, escrow);
+	assert!(!listing.escrowless);
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert_eq!(metadata.owner, listing_address);
+}
+
+#[tokio::test]
+async fn list_nft_ix_rejects_zero_price() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let seller = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &seller).await;
+
+	let result = list_nft(&mut context, collection, &nft, &seller, 0).await;
+	assert_error(result, MetaplexNftProgramError::InvalidPrice);
+}
@@ -0,0 +1,50 @@
+pub mod common;
+
+use anchor_lang::solana_program::program_option::COption;
+use metaplex_nft_program::{error::MetaplexNftProgramError, Listing, NftMint};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::{signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn list_nft_in_place_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let seller = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &seller).await;
+
+	list_nft_in_place(&mut context, collection, &nft, &seller, 1_000_000).await.unwrap();
+
+	let listing_address = listing_pda(&nft.mint);
+	let token = token_account(&mut context, nft.token_account).await;
+	assert_eq!(token.amount, 1);
+	assert_eq!(token.delegate, COption::Some(listing_address));
+	assert_eq!(token.delegated_amount, 1);
+	assert!(token.is_frozen());
+
+	let listing: Listing = get_account(&mut context, listing_address).await;
+	assert_eq!(listing.seller, seller.pubkey());
+	assert_eq!(listing.price, 1_000_000);
+	assert_eq!(listing.token_account, nft.token_account);
+	assert!(listing.escrowless);
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert_eq!(metadata.owner, seller.pubkey());
+	assert!(metadata.listed);
+}
+
+#[tokio::test]
+async fn list_nft_in_place_ix_blocks_transfers() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let seller = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &seller).await;
+	list_nft_in_place(&mut context, collection, &nft, &seller, 1_000_000).await.unwrap();
+
+	let result = transfer_nft(&mut context, collection, &nft, &seller, &Keypair::new().pubkey()).await;
+	assert_error(result, MetaplexNftProgramError::NftListed);
+}
@@ -0,0 +1,83 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftCollection, NftMint};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::signature::Keypair,
+};
+
+
+#[tokio::test]
+async fn lock_metadata_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let locked = mint_nft(&mut context, collection, &authority, &owner).await;
+	let open = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	lock_metadata(&mut context, collection, &authority, Some(locked.metadata)).await.unwrap();
+
+	let metadata: NftMint = get_account(&mut context, locked.metadata).await;
+	assert!(!metadata.is_mutable);
+	let result = update_nft_metadata(&mut context, collection, &authority, &locked, "Renamed", "https://example.com/renamed.json").await;
+	assert_error(result, MetaplexNftProgramError::MetadataImmutable);
+	update_nft_metadata(&mut context, collection, &authority, &open, "Renamed", "https://example.com/renamed.json").await.unwrap();
+}
+
+#[tokio::test]
+async fn lock_metadata_ix_locks_whole_collection() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	lock_metadata(&mut context, collection, &authority, None).await.unwrap();
+
+	let state: NftCollection = get_account(&mut context, collection).await;
+	assert!(!state.is_mutable);
+	let result = update_nft_metadata(&mut context, collection, &authority, &nft, "Renamed", "https://example.com/renamed.json").await;
+	assert_error(result, MetaplexNftProgramError::MetadataImmutable);
+}
+
+#[tokio::test]
+async fn lock_metadata_ix_rejects_locked_nft() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	lock_metadata(&mut context, collection, &authority, Some(nft.metadata)).await.unwrap();
+
+	context.get_new_latest_blockhash().await.unwrap();
+	let result = lock_metadata(&mut context, collection, &authority, Some(nft.metadata)).await;
+	assert_error(result, MetaplexNftProgramError::MetadataImmutable);
+}
+
+#[tokio::test]
+async fn lock_metadata_ix_rejects_other_authority() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	let result = lock_metadata(&mut context, collection, &Keypair::new(), Some(nft.metadata)).await;
+	assert_error(result, MetaplexNftProgramError::InvalidAuthority);
+}
+
+#[tokio::test]
+async fn lock_metadata_ix_rejects_nft_of_other_collection() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let other_authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let other_collection = create_collection(&mut context, &other_authority).await;
+	let nft = mint_nft(&mut context, other_collection, &other_authority, &owner).await;
+
+	let result = lock_metadata(&mut context, collection, &authority, Some(nft.metadata)).await;
+	assert_error(result, MetaplexNftProgramError::CollectionNotFound);
+}
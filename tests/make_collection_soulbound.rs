@@ -0,0 +1,63 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftCollection, NftMint};
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::tokio,
+	solana_sdk::{signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn make_collection_soulbound_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+
+	make_collection_soulbound(&mut context, collection, &authority).await;
+
+	let state: NftCollection = get_account(&mut context, collection).await;
+	assert!(state.soulbound);
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert!(metadata.soulbound);
+	assert!(token_account(&mut context, nft.token_account).await.is_frozen());
+}
+
+#[tokio::test]
+async fn make_collection_soulbound_ix_blocks_transfers() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	make_collection_soulbound(&mut context, collection, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	let result = transfer_nft(&mut context, collection, &nft, &owner, &Keypair::new().pubkey()).await;
+	assert_error(result, MetaplexNftProgramError::Soulbound);
+
+	let result = list_nft(&mut context, collection, &nft, &owner, 1_000_000_000).await;
+	assert_error(result, MetaplexNftProgramError::Soulbound);
+}
+
+#[tokio::test]
+async fn make_collection_soulbound_ix_rejects_minted_collection() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	mint_nft(&mut context, collection, &authority, &owner).await;
+
+	let transaction = metaplex_nft_program_ix_interface::make_collection_soulbound_ix_setup(
+		&context.payer,
+		collection,
+		&authority,
+		context.last_blockhash,
+	);
+	let result = process(&mut context, transaction).await;
+	assert_error(result, MetaplexNftProgramError::SoulboundLocked);
+}
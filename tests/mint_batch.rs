@@ -0,0 +1,83 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, ItemIndex, NftCollection, NftMint};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::{account::AccountSharedData, pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn mint_batch_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+	let first = mint_nft(&mut context, collection, &authority, &Keypair::new()).await;
+
+	let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+	let (mint_a, mint_b) = (Keypair::new(), Keypair::new());
+	let entries = vec![batch_entry("a", alice), batch_entry("b", bob)];
+	let accounts = batch_accounts(&collection, 1, &[&mint_a, &mint_b], &[alice, bob]);
+	mint_batch(&mut context, collection, &authority, &entries, accounts, &[&mint_a, &mint_b]).await.unwrap();
+
+	for (item_number, mint, owner, name) in [(1, &mint_a, alice, "a"), (2, &mint_b, bob, "b")] {
+		let token = token_account(&mut context, token_account_address(&owner, &mint.pubkey())).await;
+		assert_eq!(token.amount, 1);
+		assert!(mint_account(&mut context, mint.pubkey()).await.mint_authority.is_none());
+
+		let metadata: NftMint = get_account(&mut context, metadata_pda(&mint.pubkey())).await;
+		assert_eq!(metadata.owner, owner);
+		assert_eq!(metadata.name, name);
+		assert_eq!(metadata.item_number, item_number);
+
+		let index: ItemIndex = get_account(&mut context, item_index_pda(&collection, item_number)).await;
+		assert_eq!(index.mint, mint.pubkey());
+	}
+	let index: ItemIndex = get_account(&mut context, item_index_pda(&collection, 0)).await;
+	assert_eq!(index.mint, first.mint);
+	let collection: NftCollection = get_account(&mut context, collection).await;
+	assert_eq!(collection.nft_count, 3);
+}
+
+#[tokio::test]
+async fn mint_batch_ix_rejects_mismatched_recipient() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+
+	let mint = Keypair::new();
+	let wallet = Pubkey::new_unique();
+	let entries = vec![batch_entry("a", Pubkey::new_unique())];
+	let accounts = batch_accounts(&collection, 0, &[&mint], &[wallet]);
+	let result = mint_batch(&mut context, collection, &authority, &entries, accounts, &[&mint]).await;
+	assert_error(result, MetaplexNftProgramError::InvalidBatchAccounts);
+	assert!(!account_exists(&mut context, mint.pubkey()).await);
+}
+
+#[tokio::test]
+async fn mint_batch_ix_succeeds_with_prefunded_accounts() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+
+	// Lamports sent to the PDAs ahead of the mint must not block it, whether
+	// or not they already cover the rent.
+	let recipient = Pubkey::new_unique();
+	let mint = Keypair::new();
+	context.set_account(&metadata_pda(&mint.pubkey()), &AccountSharedData::new(1, 0, &SYSTEM_PROGRAM));
+	context.set_account(&item_index_pda(&collection, 0), &AccountSharedData::new(LAMPORTS, 0, &SYSTEM_PROGRAM));
+	let entries = vec![batch_entry("a", recipient)];
+	let accounts = batch_accounts(&collection, 0, &[&mint], &[recipient]);
+	mint_batch(&mut context, collection, &authority, &entries, accounts, &[&mint]).await.unwrap();
+
+	let rent = context.banks_client.get_rent().await.unwrap();
+	let metadata_account = context.banks_client.get_account(metadata_pda(&mint.pubkey())).await.unwrap().unwrap();
+	assert_eq!(metadata_account.owner, metaplex_nft_program::ID);
+	assert_eq!(metadata_account.lamports, rent.minimum_balance(metadata_account.data.len()));
+	let metadata: NftMint = get_account(&mut context, metadata_pda(&mint.pubkey())).await;
+	assert_eq!(metadata.owner, recipient);
+	let index: ItemIndex = get_account(&mut context, item_index_pda(&collection, 0)).await;
+	assert_eq!(index.mint, mint.pubkey());
+	assert_eq!(lamports(&mut context, item_index_pda(&collection, 0)).await, LAMPORTS);
+}
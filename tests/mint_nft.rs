@@ -1,196 +1,81 @@
 pub mod common;
 
-use std::str::FromStr;
+use metaplex_nft_program::{error::MetaplexNftProgramError, ItemIndex, NftCollection, NftMint};
 use {
-    common::{
-		get_program_test,
+	common::{
+		fixtures::*,
 		metaplex_nft_program_ix_interface,
-		csl_spl_token_ix_interface,
-		csl_spl_assoc_token_ix_interface,
 	},
-    solana_program_test::tokio,
-    solana_sdk::{
-        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
-    },
+	solana_program_test::tokio,
+	solana_sdk::{signature::Keypair, signer::Signer},
 };
 
 
 #[tokio::test]
 async fn mint_nft_ix_success() {
-	let mut program_test = get_program_test();
-
-	// PROGRAMS
-	program_test.prefer_bpf(true);
-
-	program_test.add_program(
-		"account_compression",
-		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
-		None,
-	);
-
-	program_test.add_program(
-		"noop",
-		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
-		None,
-	);
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	let token_account = token_account(&mut context, nft.token_account).await;
+	assert_eq!(token_account.amount, 1);
+	assert_eq!(token_account.owner, owner.pubkey());
+	let mint = mint_account(&mut context, nft.mint).await;
+	assert_eq!(mint.supply, 1);
+	assert_eq!(mint.decimals, 0);
+	assert!(mint.mint_authority.is_none());
+
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert_eq!(metadata.mint, nft.mint);
+	assert_eq!(metadata.owner, owner.pubkey());
+	assert_eq!(metadata.collection, collection);
+	assert_eq!(metadata.name, "NFT #0");
+	assert_eq!(metadata.uri, "https://example.com/0.json");
+	assert_eq!(metadata.item_number, 0);
+
+	let item_index: ItemIndex = get_account(&mut context, item_index_pda(&collection, 0)).await;
+	assert_eq!(item_index.mint, nft.mint);
+	let collection: NftCollection = get_account(&mut context, collection).await;
+	assert_eq!(collection.nft_count, 1);
+}
 
-	program_test.add_program(
-		"csl_spl_token",
-		Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap(),
+#[tokio::test]
+async fn mint_nft_ix_rejects_other_authority() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let impostor = Keypair::new();
+	let owner = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+
+	let mint = Keypair::new();
+	let transaction = metaplex_nft_program_ix_interface::mint_nft_ix_setup(
+		&context.payer,
+		collection,
+		&mint,
+		metadata_pda(&mint.pubkey()),
+		&impostor,
+		&owner,
+		SYSTEM_PROGRAM,
+		&context.payer,
+		token_account_address(&owner.pubkey(), &mint.pubkey()),
+		owner.pubkey(),
+		TOKEN_PROGRAM,
+		TOKEN_PROGRAM,
+		ASSOCIATED_TOKEN_PROGRAM,
+		mint_config_pda(&collection),
 		None,
-	);
-
-	program_test.add_program(
-		"csl_spl_assoc_token",
-		Pubkey::from_str("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL").unwrap(),
+		freeze_authority_pda(),
+		program_config_pda(),
+		treasury_pda(),
+		item_index_pda(&collection, 0),
+		&"NFT".to_string(),
+		&"COL".to_string(),
+		&"https://example.com/0.json".to_string(),
 		None,
+		context.last_blockhash,
 	);
-
-	// DATA
-	let name: String = Default::default();
-	let symbol: String = Default::default();
-	let uri: String = Default::default();
-
-	// KEYPAIR
-	let fee_payer_keypair = Keypair::new();
-	let mint_keypair = Keypair::new();
-	let authority_keypair = Keypair::new();
-	let owner_keypair = Keypair::new();
-	let funding_keypair = Keypair::new();
-
-	// PUBKEY
-	let fee_payer_pubkey = fee_payer_keypair.pubkey();
-	let mint_pubkey = mint_keypair.pubkey();
-	let authority_pubkey = authority_keypair.pubkey();
-	let owner_pubkey = owner_keypair.pubkey();
-	let funding_pubkey = funding_keypair.pubkey();
-	let wallet_pubkey = Pubkey::new_unique();
-	let token_program_pubkey = csl_spl_token_ix_interface::ID;
-
-	// EXECUTABLE PUBKEY
-	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
-	let csl_spl_token_v0_0_0_pubkey = Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap();
-	let csl_spl_assoc_token_v0_0_0_pubkey = Pubkey::from_str("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL").unwrap();
-
-	// PDA
-	let (collection_pda, _collection_pda_bump) = Pubkey::find_program_address(
-		&[
-			b"collection",
-			authority_pubkey.as_ref(),
-		],
-		&metaplex_nft_program::ID,
-	);
-
-	let (metadata_pda, _metadata_pda_bump) = Pubkey::find_program_address(
-		&[
-			b"metadata",
-			mint_pubkey.as_ref(),
-		],
-		&metaplex_nft_program::ID,
-	);
-
-	let (assoc_token_account_pda, _assoc_token_account_pda_bump) = Pubkey::find_program_address(
-		&[
-			wallet_pubkey.as_ref(),
-			token_program_pubkey.as_ref(),
-			mint_pubkey.as_ref(),
-		],
-		&csl_spl_token_ix_interface::ID,
-	);
-
-	// ACCOUNT PROGRAM TEST SETUP
-	program_test.add_account(
-		mint_pubkey,
-		Account {
-			lamports: 0,
-			data: vec![],
-			owner: system_program::ID,
-			executable: false,
-			rent_epoch: 0,
-		},
-	);
-
-	program_test.add_account(
-		fee_payer_pubkey,
-		Account {
-			lamports: 1_000_000_000_000,
-			data: vec![],
-			owner: system_program::ID,
-			executable: false,
-			rent_epoch: 0,
-		},
-	);
-
-	program_test.add_account(
-		mint_pubkey,
-		Account {
-			lamports: 0,
-			data: vec![],
-			owner: system_program::ID,
-			executable: false,
-			rent_epoch: 0,
-		},
-	);
-
-	program_test.add_account(
-		authority_pubkey,
-		Account {
-			lamports: 0,
-			data: vec![],
-			owner: system_program::ID,
-			executable: false,
-			rent_epoch: 0,
-		},
-	);
-
-	program_test.add_account(
-		owner_pubkey,
-		Account {
-			lamports: 0,
-			data: vec![],
-			owner: system_program::ID,
-			executable: false,
-			rent_epoch: 0,
-		},
-	);
-
-	program_test.add_account(
-		funding_pubkey,
-		Account {
-			lamports: 1_000_000_000_000,
-			data: vec![],
-			owner: system_program::ID,
-			executable: false,
-			rent_epoch: 0,
-		},
-	);
-
-	// INSTRUCTIONS
-	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
-
-	let ix = metaplex_nft_program_ix_interface::mint_nft_ix_setup(
-		&fee_payer_keypair,
-		collection_pda,
-		&mint_keypair,
-		metadata_pda,
-		&authority_keypair,
-		&owner_keypair,
-		system_program_pubkey,
-		&funding_keypair,
-		assoc_token_account_pda,
-		wallet_pubkey,
-		token_program_pubkey,
-		csl_spl_token_v0_0_0_pubkey,
-		csl_spl_assoc_token_v0_0_0_pubkey,
-		&name,
-		&symbol,
-		&uri,
-		recent_blockhash,
-	);
-
-	let result = banks_client.process_transaction(ix).await;
-
-	// ASSERTIONS
-	assert!(result.is_ok());
-
+	assert_error(process(&mut context, transaction).await, MetaplexNftProgramError::InvalidAuthority);
 }
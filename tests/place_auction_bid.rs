@@ -0,0 +1,69 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, Auction, BidRefund};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::{signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn place_auction_bid_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let seller = funded_keypair(&mut context);
+	let first_bidder = funded_keypair(&mut context);
+	let second_bidder = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &seller).await;
+	let end_time = unix_timestamp(&mut context).await + 3_600;
+	create_auction(&mut context, collection, &nft, &seller, 1_000_000_000, end_time).await.unwrap();
+
+	place_auction_bid(&mut context, collection, &nft.mint, &first_bidder, 1_000_000_000).await.unwrap();
+	place_auction_bid(&mut context, collection, &nft.mint, &second_bidder, 1_100_000_000).await.unwrap();
+
+	let auction_address = auction_pda(&nft.mint);
+	let auction: Auction = get_account(&mut context, auction_address).await;
+	assert_eq!(auction.highest_bidder, second_bidder.pubkey());
+	assert_eq!(auction.highest_bid, 1_100_000_000);
+	let refund: BidRefund = get_account(&mut context, bid_refund_pda(&auction_address, &first_bidder.pubkey())).await;
+	assert_eq!(refund.auction, auction_address);
+	assert_eq!(refund.bidder, first_bidder.pubkey());
+	assert_eq!(refund.amount, 1_000_000_000);
+	assert_eq!(refund.payer, second_bidder.pubkey());
+	assert_eq!(lamports(&mut context, first_bidder.pubkey()).await, LAMPORTS - 1_000_000_000);
+}
+
+#[tokio::test]
+async fn place_auction_bid_ix_rejects_bid_below_increment() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let seller = funded_keypair(&mut context);
+	let first_bidder = funded_keypair(&mut context);
+	let second_bidder = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &seller).await;
+	let end_time = unix_timestamp(&mut context).await + 3_600;
+	create_auction(&mut context, collection, &nft, &seller, 1_000_000_000, end_time).await.unwrap();
+	place_auction_bid(&mut context, collection, &nft.mint, &first_bidder, 1_000_000_000).await.unwrap();
+
+	let result = place_auction_bid(&mut context, collection, &nft.mint, &second_bidder, 1_000_500_000).await;
+	assert_error(result, MetaplexNftProgramError::BidTooLow);
+}
+
+#[tokio::test]
+async fn place_auction_bid_ix_rejects_ended_auction() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let seller = funded_keypair(&mut context);
+	let bidder = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &seller).await;
+	let end_time = unix_timestamp(&mut context).await + 3_600;
+	create_auction(&mut context, collection, &nft, &seller, 1_000_000_000, end_time).await.unwrap();
+
+	set_unix_timestamp(&mut context, end_time).await;
+	let result = place_auction_bid(&mut context, collection, &nft.mint, &bidder, 1_000_000_000).await;
+	assert_error(result, MetaplexNftProgramError::AuctionEnded);
+}
@@ -0,0 +1,43 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, Bid};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::{signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn place_bid_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let bidder = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let expires_at = unix_timestamp(&mut context).await + 3_600;
+
+	place_bid(&mut context, collection, &bidder, None, 1_000_000, expires_at).await.unwrap();
+
+	let bid_address = bid_pda(&bidder.pubkey(), &collection);
+	let bid: Bid = get_account(&mut context, bid_address).await;
+	assert_eq!(bid.bidder, bidder.pubkey());
+	assert_eq!(bid.collection, collection);
+	assert_eq!(bid.mint, None);
+	assert_eq!(bid.amount, 1_000_000);
+	assert_eq!(bid.expires_at, expires_at);
+	let rent = context.banks_client.get_rent().await.unwrap().minimum_balance(122);
+	assert_eq!(lamports(&mut context, bid_address).await, rent + 1_000_000);
+	assert_eq!(lamports(&mut context, bidder.pubkey()).await, LAMPORTS - rent - 1_000_000);
+}
+
+#[tokio::test]
+async fn place_bid_ix_rejects_past_expiration() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let bidder = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let expires_at = unix_timestamp(&mut context).await;
+
+	let result = place_bid(&mut context, collection, &bidder, None, 1_000_000, expires_at).await;
+	assert_error(result, MetaplexNftProgramError::InvalidExpiration);
+}
@@ -0,0 +1,85 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, Vault};
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::{tokio, BanksClientError, ProgramTestContext},
+	solana_sdk::{signature::Keypair, signer::Signer},
+};
+
+async fn redeem(context: &mut ProgramTestContext, nft: &Nft, holder: &Keypair) -> Result<(), BanksClientError> {
+	let vault = vault_pda(&nft.mint);
+	let share_mint = share_mint_pda(&vault);
+	let transaction = metaplex_nft_program_ix_interface::redeem_shares_ix_setup(
+		&context.payer,
+		holder,
+		vault,
+		share_mint,
+		token_account_address(&holder.pubkey(), &share_mint),
+		TOKEN_PROGRAM,
+		TOKEN_PROGRAM,
+		context.last_blockhash,
+	);
+	process(context, transaction).await
+}
+
+#[tokio::test]
+async fn redeem_shares_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let curator = funded_keypair(&mut context);
+	let buyer = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &curator).await;
+	fractionalize_nft(&mut context, collection, &nft, &curator, 100, 1_000_000_000).await.unwrap();
+	let vault_address = vault_pda(&nft.mint);
+	let share_mint = share_mint_pda(&vault_address);
+	transfer_tokens(&mut context, &share_mint, &curator, &buyer.pubkey(), 60).await;
+	let transaction = metaplex_nft_program_ix_interface::buyout_vault_ix_setup(
+		&context.payer,
+		&buyer,
+		nft.mint,
+		nft.metadata,
+		collection,
+		vault_address,
+		share_mint,
+		token_account_address(&buyer.pubkey(), &share_mint),
+		token_account_address(&vault_address, &nft.mint),
+		token_account_address(&buyer.pubkey(), &nft.mint),
+		freeze_authority_pda(),
+		SYSTEM_PROGRAM,
+		TOKEN_PROGRAM,
+		TOKEN_PROGRAM,
+		ASSOCIATED_TOKEN_PROGRAM,
+		program_config_pda(),
+		Vec::new(),
+		context.last_blockhash,
+	);
+	process(&mut context, transaction).await.unwrap();
+
+	let curator_before = lamports(&mut context, curator.pubkey()).await;
+	redeem(&mut context, &nft, &curator).await.unwrap();
+
+	assert_eq!(lamports(&mut context, curator.pubkey()).await, curator_before + 400_000_000);
+	assert_eq!(token_account(&mut context, token_account_address(&curator.pubkey(), &share_mint)).await.amount, 0);
+	assert_eq!(mint_account(&mut context, share_mint).await.supply, 0);
+	let vault: Vault = get_account(&mut context, vault_address).await;
+	assert_eq!(vault.outstanding_shares, 0);
+	assert_eq!(vault.proceeds, 0);
+}
+
+#[tokio::test]
+async fn redeem_shares_ix_rejects_vault_not_bought_out() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let curator = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &curator).await;
+	fractionalize_nft(&mut context, collection, &nft, &curator, 100, 1_000_000_000).await.unwrap();
+
+	let result = redeem(&mut context, &nft, &curator).await;
+	assert_error(result, MetaplexNftProgramError::VaultNotBoughtOut);
+}
@@ -0,0 +1,77 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftMint, SECONDS_PER_DAY};
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::tokio,
+	solana_sdk::{signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn rent_nft_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let renter = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	create_rental_offer(&mut context, collection, &nft, &owner, 10_000_000, 7).await.unwrap();
+
+	let owner_before = lamports(&mut context, owner.pubkey()).await;
+	let now = unix_timestamp(&mut context).await;
+	rent_nft(&mut context, collection, &nft, &owner.pubkey(), &renter, 3).await.unwrap();
+
+	assert_eq!(lamports(&mut context, owner.pubkey()).await, owner_before + 30_000_000);
+	assert_eq!(lamports(&mut context, renter.pubkey()).await, LAMPORTS - 30_000_000);
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert_eq!(metadata.owner, owner.pubkey());
+	assert_eq!(metadata.user, renter.pubkey());
+	assert_eq!(metadata.expires_at, now + 3 * SECONDS_PER_DAY);
+}
+
+#[tokio::test]
+async fn rent_nft_ix_blocks_transfers() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let renter = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	create_rental_offer(&mut context, collection, &nft, &owner, 10_000_000, 7).await.unwrap();
+	rent_nft(&mut context, collection, &nft, &owner.pubkey(), &renter, 3).await.unwrap();
+
+	let result = transfer_nft(&mut context, collection, &nft, &owner, &Keypair::new().pubkey()).await;
+	assert_error(result, MetaplexNftProgramError::RentalActive);
+}
+
+#[tokio::test]
+async fn rent_nft_ix_rejects_changed_terms() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let renter = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	create_rental_offer(&mut context, collection, &nft, &owner, 10_000_000, 7).await.unwrap();
+
+	let transaction = metaplex_nft_program_ix_interface::rent_nft_ix_setup(
+		&context.payer,
+		&renter,
+		owner.pubkey(),
+		nft.metadata,
+		collection,
+		rental_offer_pda(&nft.mint, &owner.pubkey()),
+		SYSTEM_PROGRAM,
+		program_config_pda(),
+		3,
+		5_000_000,
+		7,
+		context.last_blockhash,
+	);
+	let result = process(&mut context, transaction).await;
+	assert_error(result, MetaplexNftProgramError::RentalTermsChanged);
+}
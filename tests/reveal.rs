@@ -0,0 +1,120 @@
+pub mod common;
+
+use anchor_lang::solana_program::hash::hashv;
+use metaplex_nft_program::{error::MetaplexNftProgramError, reveal_leaf, NftMint, RevealEntry};
+use {
+	common::fixtures::*,
+	solana_program_test::{tokio, ProgramTestContext},
+	solana_sdk::{pubkey::Pubkey, signature::Keypair},
+};
+
+
+fn final_metadata() -> [(String, String, Option<[u8; 32]>); 2] {
+	[
+		("Zero".to_string(), "https://example.com/0.json".to_string(), Some([7; 32])),
+		("One".to_string(), "https://example.com/1.json".to_string(), None),
+	]
+}
+
+/// Reveal entries of the two NFTs of `final_metadata`, each proven by the other's leaf.
+fn reveal_entries() -> ([u8; 32], [RevealEntry; 2]) {
+	let leaves = final_metadata()
+		.iter()
+		.enumerate()
+		.map(|(index, (name, uri, content_hash))| reveal_leaf(index as u64, name, uri, *content_hash))
+		.collect::<Vec<_>>();
+	let (left, right) = if leaves[0] <= leaves[1] { (leaves[0], leaves[1]) } else { (leaves[1], leaves[0]) };
+	let root = hashv(&[&[1], &left, &right]).to_bytes();
+	let entries = final_metadata().map(|(name, uri, content_hash)| RevealEntry { name, uri, content_hash, proof: vec![] });
+	let [mut zero, mut one] = entries;
+	zero.proof = vec![leaves[1]];
+	one.proof = vec![leaves[0]];
+	(root, [zero, one])
+}
+
+/// Creates a placeholder collection committed to `reveal_entries` and mints its two NFTs.
+async fn placeholder_launch(context: &mut ProgramTestContext, authority: &Keypair) -> (Pubkey, [Nft; 2], [RevealEntry; 2]) {
+	let owner = funded_keypair(context);
+	let (root, entries) = reveal_entries();
+	let collection = create_placeholder_collection(context, authority, root).await;
+	let nfts = [
+		mint_nft(context, collection, authority, &owner).await,
+		mint_nft(context, collection, authority, &owner).await,
+	];
+	(collection, nfts, entries)
+}
+
+#[tokio::test]
+async fn reveal_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let (collection, nfts, entries) = placeholder_launch(&mut context, &authority).await;
+	let metadata: NftMint = get_account(&mut context, nfts[0].metadata).await;
+	assert!(!metadata.revealed);
+
+	reveal(&mut context, collection, &authority, &entries, &[nfts[0].metadata, nfts[1].metadata]).await.unwrap();
+
+	for (nft, (name, uri, content_hash)) in nfts.iter().zip(final_metadata()) {
+		let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+		assert!(metadata.revealed);
+		assert_eq!(metadata.name, name);
+		assert_eq!(metadata.uri, uri);
+		assert_eq!(metadata.content_hash, content_hash);
+	}
+}
+
+#[tokio::test]
+async fn reveal_ix_rejects_wrong_content_hash() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let (collection, nfts, mut entries) = placeholder_launch(&mut context, &authority).await;
+	entries[0].content_hash = Some([8; 32]);
+
+	let result = reveal(&mut context, collection, &authority, &entries[..1], &[nfts[0].metadata]).await;
+	assert_error(result, MetaplexNftProgramError::InvalidRevealProof);
+}
+
+#[tokio::test]
+async fn reveal_ix_rejects_revealed_nft() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let (collection, nfts, entries) = placeholder_launch(&mut context, &authority).await;
+	reveal(&mut context, collection, &authority, &entries[..1], &[nfts[0].metadata]).await.unwrap();
+
+	context.get_new_latest_blockhash().await.unwrap();
+	let result = reveal(&mut context, collection, &authority, &entries[..1], &[nfts[0].metadata]).await;
+	assert_error(result, MetaplexNftProgramError::AlreadyRevealed);
+}
+
+#[tokio::test]
+async fn reveal_ix_rejects_frozen_nft() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let (collection, nfts, entries) = placeholder_launch(&mut context, &authority).await;
+	let holder = token_account(&mut context, nfts[0].token_account).await.owner;
+	freeze_nft(&mut context, collection, &authority, &nfts[0], &holder).await.unwrap();
+
+	let result = reveal(&mut context, collection, &authority, &entries[..1], &[nfts[0].metadata]).await;
+	assert_error(result, MetaplexNftProgramError::NftFrozen);
+}
+
+#[tokio::test]
+async fn reveal_ix_rejects_locked_metadata() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let (collection, nfts, entries) = placeholder_launch(&mut context, &authority).await;
+	lock_metadata(&mut context, collection, &authority, Some(nfts[0].metadata)).await.unwrap();
+
+	let result = reveal(&mut context, collection, &authority, &entries[..1], &[nfts[0].metadata]).await;
+	assert_error(result, MetaplexNftProgramError::MetadataImmutable);
+}
+
+#[tokio::test]
+async fn reveal_ix_rejects_other_authority() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let (collection, nfts, entries) = placeholder_launch(&mut context, &authority).await;
+
+	let result = reveal(&mut context, collection, &Keypair::new(), &entries[..1], &[nfts[0].metadata]).await;
+	assert_error(result, MetaplexNftProgramError::InvalidAuthority);
+}
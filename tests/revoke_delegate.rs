@@ -0,0 +1,57 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftMint};
+use {
+	anchor_lang::solana_program::program_option::COption,
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn revoke_delegate_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	approve_delegate(&mut context, collection, &nft, &owner, Keypair::new().pubkey()).await.unwrap();
+
+	revoke_delegate(&mut context, collection, &nft, &owner).await.unwrap();
+
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert_eq!(metadata.delegate, Pubkey::default());
+	let token = token_account(&mut context, nft.token_account).await;
+	assert_eq!(token.delegate, COption::None);
+	assert!(!token.is_frozen());
+}
+
+#[tokio::test]
+async fn revoke_delegate_ix_refreezes_enforced_nft() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	enable_royalty_enforcement(&mut context, collection, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	approve_delegate(&mut context, collection, &nft, &owner, Keypair::new().pubkey()).await.unwrap();
+
+	revoke_delegate(&mut context, collection, &nft, &owner).await.unwrap();
+
+	let token = token_account(&mut context, nft.token_account).await;
+	assert_eq!(token.delegate, COption::None);
+	assert!(token.is_frozen());
+}
+
+#[tokio::test]
+async fn revoke_delegate_ix_rejects_missing_delegate() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	let result = revoke_delegate(&mut context, collection, &nft, &owner).await;
+	assert_error(result, MetaplexNftProgramError::NoDelegate);
+}
@@ -0,0 +1,108 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftCollection, NftMint};
+use {
+	common::fixtures::*,
+	solana_program_test::{tokio, ProgramTestContext},
+	solana_sdk::{pubkey::Pubkey, signature::Keypair},
+};
+
+
+/// Asserts `address` holds exactly `space` bytes and the rent they need.
+async fn assert_sized(context: &mut ProgramTestContext, address: Pubkey, space: usize) {
+	let account = context.banks_client.get_account(address).await.unwrap().unwrap();
+	let rent = context.banks_client.get_rent().await.unwrap();
+	assert_eq!(account.data.len(), space);
+	assert_eq!(account.lamports, rent.minimum_balance(space));
+}
+
+#[tokio::test]
+async fn set_base_uri_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	assert_sized(&mut context, collection, NftCollection::SPACE).await;
+
+	set_base_uri(&mut context, collection, &authority, "https://example.com/items/").await.unwrap();
+	assert_sized(&mut context, collection, NftCollection::SPACE + "https://example.com/items/".len()).await;
+
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	let state: NftCollection = get_account(&mut context, collection).await;
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert_eq!(context.banks_client.get_account(nft.metadata).await.unwrap().unwrap().data.len(), NftMint::space(&state));
+	assert!(NftMint::space(&state) < NftMint::SPACE);
+	assert_eq!(state.resolve_uri(&metadata.uri, metadata.item_number), "https://example.com/items/0.json");
+
+	// Moving to a shorter base re-points every NFT and refunds the spare rent.
+	set_base_uri(&mut context, collection, &authority, "ar://items/").await.unwrap();
+	assert_sized(&mut context, collection, NftCollection::SPACE + "ar://items/".len()).await;
+	let state: NftCollection = get_account(&mut context, collection).await;
+	assert_eq!(state.resolve_uri(&metadata.uri, metadata.item_number), "ar://items/0.json");
+}
+
+#[tokio::test]
+async fn set_base_uri_ix_rejects_adding_base_after_mint() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	mint_nft(&mut context, collection, &authority, &owner).await;
+
+	let result = set_base_uri(&mut context, collection, &authority, "https://example.com/items/").await;
+	assert_error(result, MetaplexNftProgramError::BaseUriLocked);
+}
+
+#[tokio::test]
+async fn set_base_uri_ix_rejects_change_while_nft_locked() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	set_base_uri(&mut context, collection, &authority, "https://example.com/items/").await.unwrap();
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	lock_metadata(&mut context, collection, &authority, Some(nft.metadata)).await.unwrap();
+	let state: NftCollection = get_account(&mut context, collection).await;
+	assert_eq!(state.locked_nft_count, 1);
+
+	let result = set_base_uri(&mut context, collection, &authority, "ar://items/").await;
+	assert_error(result, MetaplexNftProgramError::BaseUriLocked);
+
+	// Burning the locked NFT releases the base URI.
+	burn_nft(&mut context, collection, &nft, &owner).await.unwrap();
+	let state: NftCollection = get_account(&mut context, collection).await;
+	assert_eq!(state.locked_nft_count, 0);
+	context.get_new_latest_blockhash().await.unwrap();
+	set_base_uri(&mut context, collection, &authority, "ar://items/").await.unwrap();
+}
+
+#[tokio::test]
+async fn set_base_uri_ix_rejects_locked_collection() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+	lock_metadata(&mut context, collection, &authority, None).await.unwrap();
+
+	let result = set_base_uri(&mut context, collection, &authority, "https://example.com/items/").await;
+	assert_error(result, MetaplexNftProgramError::MetadataImmutable);
+}
+
+#[tokio::test]
+async fn set_base_uri_ix_rejects_invalid_base() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+
+	let result = set_base_uri(&mut context, collection, &authority, "htps://example.com/items/").await;
+	assert_error(result, MetaplexNftProgramError::UriSchemeNotAllowed);
+}
+
+#[tokio::test]
+async fn set_base_uri_ix_rejects_other_authority() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+
+	let result = set_base_uri(&mut context, collection, &Keypair::new(), "https://example.com/items/").await;
+	assert_error(result, MetaplexNftProgramError::InvalidAuthority);
+}
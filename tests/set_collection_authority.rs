@@ -0,0 +1,58 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftCollection};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn set_collection_authority_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let new_authority = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+
+	set_collection_authority(&mut context, collection, &authority, new_authority.pubkey()).await.unwrap();
+
+	let state: NftCollection = get_account(&mut context, collection).await;
+	assert_eq!(state.authority, new_authority.pubkey());
+	assert_eq!(state.seed_authority, authority.pubkey());
+	let result = set_collection_paused(&mut context, collection, &authority, true).await;
+	assert_error(result, MetaplexNftProgramError::InvalidAuthority);
+	set_collection_paused(&mut context, collection, &new_authority, true).await.unwrap();
+}
+
+#[tokio::test]
+async fn set_collection_authority_ix_rejects_multisig_member_acting_alone() {
+	let mut context = start().await;
+	let creator = Keypair::new();
+	let authority = Keypair::new();
+	let member = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let multisig = multisig_pda(&creator.pubkey(), 0);
+	create_multisig(&mut context, &creator, 0, &[member.pubkey(), Pubkey::new_unique()], 2).await.unwrap();
+	let collection = create_collection(&mut context, &authority).await;
+	set_collection_authority(&mut context, collection, &authority, multisig).await.unwrap();
+
+	// Neither the previous authority nor a single member can act for the multisig.
+	let result = try_mint_nft(&mut context, collection, &authority, &owner).await.map(|_| ());
+	assert_error(result, MetaplexNftProgramError::InvalidAuthority);
+	let result = try_mint_nft(&mut context, collection, &member, &owner).await.map(|_| ());
+	assert_error(result, MetaplexNftProgramError::InvalidAuthority);
+	let result = set_collection_paused(&mut context, collection, &member, true).await;
+	assert_error(result, MetaplexNftProgramError::InvalidAuthority);
+}
+
+#[tokio::test]
+async fn set_collection_authority_ix_rejects_other_authority() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let impostor = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+
+	let result = set_collection_authority(&mut context, collection, &impostor, impostor.pubkey()).await;
+	assert_error(result, MetaplexNftProgramError::InvalidAuthority);
+}
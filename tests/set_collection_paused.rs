@@ -0,0 +1,60 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftCollection};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::{signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn set_collection_paused_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+
+	set_collection_paused(&mut context, collection, &authority, true).await.unwrap();
+
+	let state: NftCollection = get_account(&mut context, collection).await;
+	assert!(state.paused);
+
+	set_collection_paused(&mut context, collection, &authority, false).await.unwrap();
+
+	let state: NftCollection = get_account(&mut context, collection).await;
+	assert!(!state.paused);
+}
+
+#[tokio::test]
+async fn set_collection_paused_ix_blocks_only_its_collection() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let other_authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let other_collection = create_collection(&mut context, &other_authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	set_collection_paused(&mut context, collection, &authority, true).await.unwrap();
+
+	let result = try_mint_nft(&mut context, collection, &authority, &owner).await.map(|_| ());
+	assert_error(result, MetaplexNftProgramError::CollectionPaused);
+
+	let result = transfer_nft(&mut context, collection, &nft, &owner, &Keypair::new().pubkey()).await;
+	assert_error(result, MetaplexNftProgramError::CollectionPaused);
+
+	let result = update_nft_metadata(&mut context, collection, &authority, &nft, "Renamed", "https://example.com/renamed.json").await;
+	assert_error(result, MetaplexNftProgramError::CollectionPaused);
+
+	mint_nft(&mut context, other_collection, &other_authority, &owner).await;
+}
+
+#[tokio::test]
+async fn set_collection_paused_ix_rejects_other_authority() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let impostor = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+
+	let result = set_collection_paused(&mut context, collection, &impostor, true).await;
+	assert_error(result, MetaplexNftProgramError::InvalidAuthority);
+}
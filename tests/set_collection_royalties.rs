@@ -0,0 +1,49 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, CollectionCreator, NftCollection};
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::tokio,
+	solana_sdk::{pubkey::Pubkey, signature::Keypair},
+};
+
+
+#[tokio::test]
+async fn set_collection_royalties_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+	let creators = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+	set_royalties(&mut context, collection, &authority, 500, &creators).await;
+
+	let state: NftCollection = get_account(&mut context, collection).await;
+	assert_eq!(state.seller_fee_basis_points, 500);
+	assert_eq!(state.creators.len(), 2);
+	assert_eq!(state.creators[0].address, creators[0]);
+	assert_eq!(state.creators[1].share, 50);
+}
+
+#[tokio::test]
+async fn set_collection_royalties_ix_rejects_shares_not_totalling_100() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+
+	let creators = vec![
+		CollectionCreator { address: Pubkey::new_unique(), share: 60 },
+		CollectionCreator { address: Pubkey::new_unique(), share: 30 },
+	];
+	let transaction = metaplex_nft_program_ix_interface::set_collection_royalties_ix_setup(
+		&context.payer,
+		collection,
+		&authority,
+		500,
+		&creators,
+		context.last_blockhash,
+	);
+	assert_error(process(&mut context, transaction).await, MetaplexNftProgramError::InvalidRoyaltyConfig);
+}
@@ -0,0 +1,45 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, MintConfig};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::signature::Keypair,
+};
+
+
+#[tokio::test]
+async fn set_mint_config_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+	let now = unix_timestamp(&mut context).await;
+
+	set_mint_config(&mut context, collection, &authority, 1_000_000_000, 500_000_000, now, 100_000_000, 10).await.unwrap();
+
+	let mint_config: MintConfig = get_account(&mut context, mint_config_pda(&collection)).await;
+	assert_eq!(mint_config.collection, collection);
+	assert_eq!(mint_config.start_price, 1_000_000_000);
+	assert_eq!(mint_config.end_price, 500_000_000);
+	assert_eq!(mint_config.start_time, now);
+	assert_eq!(mint_config.decay_interval, 100);
+	assert_eq!(mint_config.price_step, 100_000_000);
+	assert_eq!(mint_config.max_supply, 10);
+	assert_eq!(mint_config.minted, 0);
+	assert_eq!(mint_config.paid_mints, 0);
+	assert_eq!(mint_config.last_price, 1_000_000_000);
+}
+
+#[tokio::test]
+async fn set_mint_config_ix_rejects_change_after_first_mint() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let minter = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let now = unix_timestamp(&mut context).await;
+	set_mint_config(&mut context, collection, &authority, 1_000_000_000, 500_000_000, now, 100_000_000, 10).await.unwrap();
+	mint_nft_paid(&mut context, collection, &authority, &minter).await.unwrap();
+
+	let result = set_mint_config(&mut context, collection, &authority, 2_000_000_000, 500_000_000, now, 100_000_000, 10).await;
+	assert_error(result, MetaplexNftProgramError::MintConfigLocked);
+}
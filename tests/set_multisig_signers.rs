@@ -0,0 +1,64 @@
+pub mod common;
+
+use metaplex_nft_program::{accounts, error::MetaplexNftProgramError, instruction, Multisig};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction},
+};
+
+
+#[tokio::test]
+async fn set_multisig_signers_ix_success() {
+	let mut context = start().await;
+	let creator = Keypair::new();
+	let members = [Keypair::new(), Keypair::new()];
+	let newcomer = Keypair::new();
+	let multisig = multisig_pda(&creator.pubkey(), 0);
+	create_multisig(&mut context, &creator, 0, &[members[0].pubkey(), members[1].pubkey()], 2).await.unwrap();
+
+	let signers = vec![members[0].pubkey(), newcomer.pubkey()];
+	let instruction = program_instruction(
+		accounts::SetMultisigSigners { fee_payer: context.payer.pubkey(), multisig },
+		instruction::SetMultisigSigners { signers: signers.clone(), threshold: 1 },
+	);
+	let proposal = create_proposal(&mut context, multisig, &members[0], &instruction).await.unwrap();
+	approve_proposal(&mut context, multisig, proposal, &members[1]).await.unwrap();
+	execute_proposal(&mut context, multisig, proposal).await.unwrap();
+
+	let state: Multisig = get_account(&mut context, multisig).await;
+	assert_eq!(state.signers, signers);
+	assert_eq!(state.threshold, 1);
+	assert_eq!(state.proposal_count, 1);
+
+	// The rotated-out member no longer counts.
+	let instruction = program_instruction(
+		accounts::SetMultisigSigners { fee_payer: context.payer.pubkey(), multisig },
+		instruction::SetMultisigSigners { signers: vec![newcomer.pubkey()], threshold: 1 },
+	);
+	let result = create_proposal(&mut context, multisig, &members[1], &instruction).await.map(|_| ());
+	assert_error(result, MetaplexNftProgramError::NotMultisigSigner);
+}
+
+#[tokio::test]
+async fn set_multisig_signers_ix_rejects_direct_call() {
+	let mut context = start().await;
+	let creator = Keypair::new();
+	let multisig = multisig_pda(&creator.pubkey(), 0);
+	create_multisig(&mut context, &creator, 0, &[Pubkey::new_unique()], 1).await.unwrap();
+
+	// The multisig cannot sign outside a proposal, so the call goes out without its signature.
+	let mut instruction = program_instruction(
+		accounts::SetMultisigSigners { fee_payer: context.payer.pubkey(), multisig },
+		instruction::SetMultisigSigners { signers: vec![context.payer.pubkey()], threshold: 1 },
+	);
+	instruction.accounts[1].is_signer = false;
+	let transaction = Transaction::new_signed_with_payer(
+		&[instruction],
+		Some(&context.payer.pubkey()),
+		&[&context.payer],
+		context.last_blockhash,
+	);
+	let result = process(&mut context, transaction).await;
+	assert_error(result, MetaplexNftProgramError::InvalidAuthority);
+}
@@ -0,0 +1,49 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, ProgramConfig};
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::{tokio, BanksClientError, ProgramTestContext},
+	solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+async fn set_program_admin(context: &mut ProgramTestContext, admin: &Keypair, new_admin: Pubkey) -> Result<(), BanksClientError> {
+	let transaction = metaplex_nft_program_ix_interface::set_program_admin_ix_setup(
+		&context.payer,
+		program_config_pda(),
+		admin,
+		new_admin,
+		context.last_blockhash,
+	);
+	process(context, transaction).await
+}
+
+#[tokio::test]
+async fn set_program_admin_ix_success() {
+	let mut context = start().await;
+	let admin = Keypair::new();
+	let new_admin = Keypair::new();
+	initialize_program_config(&mut context, &admin.pubkey()).await;
+
+	set_program_admin(&mut context, &admin, new_admin.pubkey()).await.unwrap();
+
+	let config: ProgramConfig = get_account(&mut context, program_config_pda()).await;
+	assert_eq!(config.admin, new_admin.pubkey());
+	let result = set_program_paused(&mut context, &admin, true).await;
+	assert_error(result, MetaplexNftProgramError::InvalidAuthority);
+	set_program_paused(&mut context, &new_admin, true).await.unwrap();
+}
+
+#[tokio::test]
+async fn set_program_admin_ix_rejects_non_admin() {
+	let mut context = start().await;
+	let admin = Keypair::new();
+	let impostor = Keypair::new();
+	initialize_program_config(&mut context, &admin.pubkey()).await;
+
+	let result = set_program_admin(&mut context, &impostor, impostor.pubkey()).await;
+	assert_error(result, MetaplexNftProgramError::InvalidAuthority);
+}
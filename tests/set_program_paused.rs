@@ -0,0 +1,65 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, ProgramConfig};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn set_program_paused_ix_success() {
+	let mut context = start().await;
+	let admin = Keypair::new();
+	initialize_program_config(&mut context, &admin.pubkey()).await;
+
+	set_program_paused(&mut context, &admin, true).await.unwrap();
+
+	let config: ProgramConfig = get_account(&mut context, program_config_pda()).await;
+	assert!(config.paused);
+
+	set_program_paused(&mut context, &admin, false).await.unwrap();
+
+	let config: ProgramConfig = get_account(&mut context, program_config_pda()).await;
+	assert!(!config.paused);
+}
+
+#[tokio::test]
+async fn set_program_paused_ix_blocks_every_collection() {
+	let mut context = start().await;
+	let admin = Keypair::new();
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	initialize_program_config(&mut context, &admin.pubkey()).await;
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	approve_delegate(&mut context, collection, &nft, &owner, Pubkey::new_unique()).await.unwrap();
+	set_program_paused(&mut context, &admin, true).await.unwrap();
+
+	let result = try_mint_nft(&mut context, collection, &authority, &owner).await.map(|_| ());
+	assert_error(result, MetaplexNftProgramError::ProgramPaused);
+
+	let result = transfer_nft(&mut context, collection, &nft, &owner, &Keypair::new().pubkey()).await;
+	assert_error(result, MetaplexNftProgramError::ProgramPaused);
+
+	let result = update_nft_metadata(&mut context, collection, &authority, &nft, "Renamed", "https://example.com/renamed.json").await;
+	assert_error(result, MetaplexNftProgramError::ProgramPaused);
+
+	// Exits that only hand back the caller's own position stay open.
+	revoke_delegate(&mut context, collection, &nft, &owner).await.unwrap();
+
+	set_program_paused(&mut context, &admin, false).await.unwrap();
+	transfer_nft(&mut context, collection, &nft, &owner, &Keypair::new().pubkey()).await.unwrap();
+}
+
+#[tokio::test]
+async fn set_program_paused_ix_rejects_non_admin() {
+	let mut context = start().await;
+	let admin = Keypair::new();
+	let impostor = Keypair::new();
+	initialize_program_config(&mut context, &admin.pubkey()).await;
+
+	let result = set_program_paused(&mut context, &impostor, true).await;
+	assert_error(result, MetaplexNftProgramError::InvalidAuthority);
+}
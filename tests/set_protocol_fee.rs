@@ -0,0 +1,66 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, ProgramConfig};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::{signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn set_protocol_fee_ix_success() {
+	let mut context = start().await;
+	let admin = Keypair::new();
+	initialize_program_config(&mut context, &admin.pubkey()).await;
+
+	set_protocol_fee(&mut context, &admin, 1_000_000, 500).await.unwrap();
+
+	let config: ProgramConfig = get_account(&mut context, program_config_pda()).await;
+	assert_eq!(config.mint_fee_lamports, 1_000_000);
+	assert_eq!(config.mint_fee_bps, 500);
+}
+
+#[tokio::test]
+async fn set_protocol_fee_ix_charges_mints() {
+	let mut context = start().await;
+	let admin = Keypair::new();
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let minter = funded_keypair(&mut context);
+	initialize_program_config(&mut context, &admin.pubkey()).await;
+	set_protocol_fee(&mut context, &admin, 1_000_000, 500).await.unwrap();
+	let collection = create_collection(&mut context, &authority).await;
+	let treasury_before = lamports(&mut context, treasury_pda()).await;
+
+	// A free mint pays the flat fee only.
+	mint_nft(&mut context, collection, &authority, &owner).await;
+	assert_eq!(lamports(&mut context, treasury_pda()).await, treasury_before + 1_000_000);
+
+	// A paid mint also pays the share of its price.
+	let now = unix_timestamp(&mut context).await;
+	set_mint_config(&mut context, collection, &authority, 1_000_000_000, 1_000_000_000, now, 0, 10).await.unwrap();
+	mint_nft_paid(&mut context, collection, &authority, &minter).await.unwrap();
+	assert_eq!(lamports(&mut context, treasury_pda()).await, treasury_before + 1_000_000 + 51_000_000);
+}
+
+#[tokio::test]
+async fn set_protocol_fee_ix_rejects_fee_above_price() {
+	let mut context = start().await;
+	let admin = Keypair::new();
+	initialize_program_config(&mut context, &admin.pubkey()).await;
+
+	let result = set_protocol_fee(&mut context, &admin, 0, 10_001).await;
+	assert_error(result, MetaplexNftProgramError::InvalidProtocolFee);
+}
+
+#[tokio::test]
+async fn set_protocol_fee_ix_rejects_non_admin() {
+	let mut context = start().await;
+	let admin = Keypair::new();
+	let impostor = Keypair::new();
+	initialize_program_config(&mut context, &admin.pubkey()).await;
+
+	let result = set_protocol_fee(&mut context, &impostor, 1_000_000, 0).await;
+	assert_error(result, MetaplexNftProgramError::InvalidAuthority);
+}
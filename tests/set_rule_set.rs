@@ -0,0 +1,97 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftMint, RuleSet, RuleSetMode};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::{account::AccountSharedData, pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn set_rule_set_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let marketplace = Pubkey::new_unique();
+	let collection = create_collection(&mut context, &authority).await;
+
+	set_rule_set(&mut context, collection, &authority, RuleSetMode::Deny, vec![marketplace]).await.unwrap();
+
+	let rule_set: RuleSet = get_account(&mut context, rule_set_pda(&collection)).await;
+	assert_eq!(rule_set.collection, collection);
+	assert!(rule_set.mode == RuleSetMode::Deny);
+	assert_eq!(rule_set.programs, vec![marketplace]);
+
+	set_rule_set(&mut context, collection, &authority, RuleSetMode::Allow, Vec::new()).await.unwrap();
+
+	let rule_set: RuleSet = get_account(&mut context, rule_set_pda(&collection)).await;
+	assert!(rule_set.mode == RuleSetMode::Allow);
+	assert!(rule_set.programs.is_empty());
+}
+
+#[tokio::test]
+async fn set_rule_set_ix_rejects_too_many_programs() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+
+	let programs = (0..11).map(|_| Pubkey::new_unique()).collect();
+	let result = set_rule_set(&mut context, collection, &authority, RuleSetMode::Deny, programs).await;
+	assert_error(result, MetaplexNftProgramError::TooManyRuleSetPrograms);
+}
+
+#[tokio::test]
+async fn set_rule_set_ix_rejects_other_authority() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let impostor = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+
+	let result = set_rule_set(&mut context, collection, &impostor, RuleSetMode::Deny, Vec::new()).await;
+	assert_error(result, MetaplexNftProgramError::InvalidAuthority);
+}
+
+#[tokio::test]
+async fn set_rule_set_ix_denies_listed_delegates() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let marketplace = Pubkey::new_unique();
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	set_rule_set(&mut context, collection, &authority, RuleSetMode::Deny, vec![marketplace]).await.unwrap();
+
+	let result = approve_delegate(&mut context, collection, &nft, &owner, marketplace).await;
+	assert_error(result, MetaplexNftProgramError::ProgramNotPermitted);
+
+	// An account the denied program owns is caught by its owner.
+	let marketplace_account = Pubkey::new_unique();
+	context.set_account(&marketplace_account, &AccountSharedData::new(1_000_000, 0, &marketplace));
+	let result = approve_delegate(&mut context, collection, &nft, &owner, marketplace_account).await;
+	assert_error(result, MetaplexNftProgramError::ProgramNotPermitted);
+
+	let wallet = Pubkey::new_unique();
+	approve_delegate(&mut context, collection, &nft, &owner, wallet).await.unwrap();
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert_eq!(metadata.delegate, wallet);
+}
+
+#[tokio::test]
+async fn set_rule_set_ix_allows_only_listed_delegates() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let marketplace = Pubkey::new_unique();
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	set_rule_set(&mut context, collection, &authority, RuleSetMode::Allow, vec![marketplace]).await.unwrap();
+
+	let result = approve_delegate(&mut context, collection, &nft, &owner, Keypair::new().pubkey()).await;
+	assert_error(result, MetaplexNftProgramError::ProgramNotPermitted);
+
+	let marketplace_account = Pubkey::new_unique();
+	context.set_account(&marketplace_account, &AccountSharedData::new(1_000_000, 0, &marketplace));
+	approve_delegate(&mut context, collection, &nft, &owner, marketplace_account).await.unwrap();
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert_eq!(metadata.delegate, marketplace_account);
+}
@@ -0,0 +1,73 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftCollection, MAX_URI_LENGTH};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::signature::Keypair,
+};
+
+
+#[tokio::test]
+async fn set_uri_rules_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	set_uri_rules(&mut context, collection, &authority, 32, &["ipfs", "ar"]).await.unwrap();
+
+	let state: NftCollection = get_account(&mut context, collection).await;
+	assert_eq!(state.max_uri_length, 32);
+	assert_eq!(state.allowed_uri_schemes, vec!["ipfs".to_string(), "ar".to_string()]);
+	update_nft_metadata(&mut context, collection, &authority, &nft, "Renamed", "ar://renamed").await.unwrap();
+	let result = update_nft_metadata(&mut context, collection, &authority, &nft, "Renamed", "https://example.com/1").await;
+	assert_error(result, MetaplexNftProgramError::UriSchemeNotAllowed);
+	let result = update_nft_metadata(&mut context, collection, &authority, &nft, "Renamed", "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi").await;
+	assert_error(result, MetaplexNftProgramError::UriTooLong);
+}
+
+#[tokio::test]
+async fn set_uri_rules_ix_rejects_malformed_uris() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	let result = update_nft_metadata(&mut context, collection, &authority, &nft, "Renamed", "https://example.com/a b").await;
+	assert_error(result, MetaplexNftProgramError::UriInvalidCharacter);
+	let result = update_nft_metadata(&mut context, collection, &authority, &nft, "Renamed", "example.com/1").await;
+	assert_error(result, MetaplexNftProgramError::UriMissingScheme);
+	let result = update_nft_metadata(&mut context, collection, &authority, &nft, "Renamed", "htps://example.com/1").await;
+	assert_error(result, MetaplexNftProgramError::UriSchemeNotAllowed);
+}
+
+#[tokio::test]
+async fn set_uri_rules_ix_rejects_invalid_rules() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+
+	let result = set_uri_rules(&mut context, collection, &authority, 0, &["https"]).await;
+	assert_error(result, MetaplexNftProgramError::InvalidUriRules);
+	let result = set_uri_rules(&mut context, collection, &authority, MAX_URI_LENGTH as u16 + 1, &["https"]).await;
+	assert_error(result, MetaplexNftProgramError::InvalidUriRules);
+	let result = set_uri_rules(&mut context, collection, &authority, 64, &[]).await;
+	assert_error(result, MetaplexNftProgramError::InvalidUriRules);
+	let result = set_uri_rules(&mut context, collection, &authority, 64, &["HTTPS"]).await;
+	assert_error(result, MetaplexNftProgramError::InvalidUriRules);
+	let result = set_uri_rules(&mut context, collection, &authority, 64, &["https", "ipfs", "ar", "data", "ftp"]).await;
+	assert_error(result, MetaplexNftProgramError::InvalidUriRules);
+}
+
+#[tokio::test]
+async fn set_uri_rules_ix_rejects_other_authority() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+
+	let result = set_uri_rules(&mut context, collection, &Keypair::new(), 64, &["https"]).await;
+	assert_error(result, MetaplexNftProgramError::InvalidAuthority);
+}
@@ -0,0 +1,106 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftMint};
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::{tokio, BanksClientError, ProgramTestContext},
+	solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+async fn settle(
+	context: &mut ProgramTestContext,
+	seller: Pubkey,
+	recipient: Pubkey,
+	collection: Pubkey,
+	nft: &Nft,
+	creators: &[Pubkey],
+) -> Result<(), BanksClientError> {
+	let auction = auction_pda(&nft.mint);
+	let transaction = metaplex_nft_program_ix_interface::settle_auction_ix_setup(
+		&context.payer,
+		seller,
+		recipient,
+		nft.mint,
+		nft.metadata,
+		collection,
+		auction,
+		token_account_address(&auction, &nft.mint),
+		token_account_address(&recipient, &nft.mint),
+		freeze_authority_pda(),
+		SYSTEM_PROGRAM,
+		TOKEN_PROGRAM,
+		TOKEN_PROGRAM,
+		ASSOCIATED_TOKEN_PROGRAM,
+		program_config_pda(),
+		creators.iter().map(|creator| AccountMeta::new(*creator, false)).collect(),
+		context.last_blockhash,
+	);
+	process(context, transaction).await
+}
+
+#[tokio::test]
+async fn settle_auction_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let seller = funded_keypair(&mut context);
+	let bidder = funded_keypair(&mut context);
+	let creator = funded_keypair(&mut context).pubkey();
+	let collection = create_collection(&mut context, &authority).await;
+	set_royalties(&mut context, collection, &authority, 500, &[creator]).await;
+	let nft = mint_nft(&mut context, collection, &authority, &seller).await;
+	let end_time = unix_timestamp(&mut context).await + 3_600;
+	create_auction(&mut context, collection, &nft, &seller, 1_000_000_000, end_time).await.unwrap();
+	place_auction_bid(&mut context, collection, &nft.mint, &bidder, 1_000_000_000).await.unwrap();
+
+	let auction = auction_pda(&nft.mint);
+	let rent = lamports(&mut context, auction).await - 1_000_000_000
+		+ lamports(&mut context, token_account_address(&auction, &nft.mint)).await;
+	let seller_before = lamports(&mut context, seller.pubkey()).await;
+	set_unix_timestamp(&mut context, end_time).await;
+	settle(&mut context, seller.pubkey(), bidder.pubkey(), collection, &nft, &[creator]).await.unwrap();
+
+	assert_eq!(lamports(&mut context, creator).await, LAMPORTS + 50_000_000);
+	assert_eq!(lamports(&mut context, seller.pubkey()).await, seller_before + 950_000_000 + rent);
+	let bidder_token_account = token_account_address(&bidder.pubkey(), &nft.mint);
+	assert_eq!(token_account(&mut context, bidder_token_account).await.amount, 1);
+	assert!(!account_exists(&mut context, auction).await);
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert_eq!(metadata.owner, bidder.pubkey());
+}
+
+#[tokio::test]
+async fn settle_auction_ix_returns_unsold_nft_to_seller() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let seller = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &seller).await;
+	let end_time = unix_timestamp(&mut context).await + 3_600;
+	create_auction(&mut context, collection, &nft, &seller, 1_000_000_000, end_time).await.unwrap();
+
+	set_unix_timestamp(&mut context, end_time).await;
+	settle(&mut context, seller.pubkey(), seller.pubkey(), collection, &nft, &[]).await.unwrap();
+
+	assert_eq!(token_account(&mut context, nft.token_account).await.amount, 1);
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert_eq!(metadata.owner, seller.pubkey());
+}
+
+#[tokio::test]
+async fn settle_auction_ix_rejects_running_auction() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let seller = funded_keypair(&mut context);
+	let bidder = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &seller).await;
+	let end_time = unix_timestamp(&mut context).await + 3_600;
+	create_auction(&mut context, collection, &nft, &seller, 1_000_000_000, end_time).await.unwrap();
+	place_auction_bid(&mut context, collection, &nft.mint, &bidder, 1_000_000_000).await.unwrap();
+
+	let result = settle(&mut context, seller.pubkey(), bidder.pubkey(), collection, &nft, &[]).await;
+	assert_error(result, MetaplexNftProgramError::AuctionNotEnded);
+}
@@ -0,0 +1,51 @@
+pub mod common;
+
+use anchor_lang::solana_program::program_option::COption;
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftMint, StakeEntry};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::{signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn stake_nft_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	create_stake_pool(&mut context, collection, &authority, 10).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	let now = unix_timestamp(&mut context).await;
+	stake_nft(&mut context, collection, &nft, &owner).await.unwrap();
+
+	let stake_entry_address = stake_entry_pda(&nft.mint);
+	let token = token_account(&mut context, nft.token_account).await;
+	assert_eq!(token.amount, 1);
+	assert_eq!(token.delegate, COption::Some(stake_entry_address));
+	assert!(token.is_frozen());
+	let stake_entry: StakeEntry = get_account(&mut context, stake_entry_address).await;
+	assert_eq!(stake_entry.owner, owner.pubkey());
+	assert_eq!(stake_entry.mint, nft.mint);
+	assert_eq!(stake_entry.stake_pool, stake_pool_pda(&collection));
+	assert_eq!(stake_entry.staked_at, now);
+	assert_eq!(stake_entry.last_claimed_at, now);
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert!(metadata.staked);
+}
+
+#[tokio::test]
+async fn stake_nft_ix_blocks_transfers() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	create_stake_pool(&mut context, collection, &authority, 10).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	stake_nft(&mut context, collection, &nft, &owner).await.unwrap();
+
+	let result = transfer_nft(&mut context, collection, &nft, &owner, &Keypair::new().pubkey()).await;
+	assert_error(result, MetaplexNftProgramError::NftStaked);
+}
@@ -0,0 +1,72 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, Loan, NftMint};
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::tokio,
+	solana_sdk::{signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn take_loan_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let lender = funded_keypair(&mut context);
+	let borrower = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &borrower).await;
+	create_loan_offer(&mut context, collection, &lender, 7, 1_000_000_000, 86_400, 50_000_000).await.unwrap();
+
+	let loan_address = loan_pda(&lender.pubkey(), 7);
+	let now = unix_timestamp(&mut context).await;
+	take_loan(&mut context, collection, &nft, &borrower, loan_address).await.unwrap();
+
+	assert_eq!(lamports(&mut context, borrower.pubkey()).await, LAMPORTS + 1_000_000_000);
+	assert_eq!(token_account(&mut context, nft.token_account).await.amount, 0);
+	assert_eq!(token_account(&mut context, token_account_address(&loan_address, &nft.mint)).await.amount, 1);
+	let loan: Loan = get_account(&mut context, loan_address).await;
+	assert_eq!(loan.borrower, borrower.pubkey());
+	assert_eq!(loan.mint, nft.mint);
+	assert_eq!(loan.start_time, now);
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert_eq!(metadata.owner, loan_address);
+}
+
+#[tokio::test]
+async fn take_loan_ix_rejects_changed_terms() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let lender = funded_keypair(&mut context);
+	let borrower = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &borrower).await;
+	create_loan_offer(&mut context, collection, &lender, 7, 1_000_000_000, 86_400, 50_000_000).await.unwrap();
+
+	let loan = loan_pda(&lender.pubkey(), 7);
+	let transaction = metaplex_nft_program_ix_interface::take_loan_ix_setup(
+		&context.payer,
+		&borrower,
+		nft.mint,
+		nft.metadata,
+		collection,
+		loan,
+		nft.token_account,
+		token_account_address(&loan, &nft.mint),
+		freeze_authority_pda(),
+		SYSTEM_PROGRAM,
+		TOKEN_PROGRAM,
+		TOKEN_PROGRAM,
+		ASSOCIATED_TOKEN_PROGRAM,
+		program_config_pda(),
+		1_000_000_000,
+		86_400,
+		10_000_000,
+		context.last_blockhash,
+	);
+	let result = process(&mut context, transaction).await;
+	assert_error(result, MetaplexNftProgramError::LoanTermsChanged);
+}
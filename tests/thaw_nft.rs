@@ -0,0 +1,56 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftMint};
+use {
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::{signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn thaw_nft_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let recipient = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	freeze_nft(&mut context, collection, &authority, &nft, &owner.pubkey()).await.unwrap();
+
+	thaw_nft(&mut context, collection, &authority, &nft, &owner.pubkey()).await.unwrap();
+
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert!(!metadata.frozen);
+	assert!(!token_account(&mut context, nft.token_account).await.is_frozen());
+	transfer_nft(&mut context, collection, &nft, &owner, &recipient.pubkey()).await.unwrap();
+}
+
+#[tokio::test]
+async fn thaw_nft_ix_keeps_enforced_tokens_frozen() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	enable_royalty_enforcement(&mut context, collection, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	freeze_nft(&mut context, collection, &authority, &nft, &owner.pubkey()).await.unwrap();
+
+	thaw_nft(&mut context, collection, &authority, &nft, &owner.pubkey()).await.unwrap();
+
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert!(!metadata.frozen);
+	assert!(token_account(&mut context, nft.token_account).await.is_frozen());
+}
+
+#[tokio::test]
+async fn thaw_nft_ix_rejects_unfrozen_nft() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	let result = thaw_nft(&mut context, collection, &authority, &nft, &owner.pubkey()).await;
+	assert_error(result, MetaplexNftProgramError::NftNotFrozen);
+}
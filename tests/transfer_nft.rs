@@ -1,163 +1,78 @@
 pub mod common;
 
-use std::str::FromStr;
+use metaplex_nft_program::{error::MetaplexNftProgramError, NftMint};
 use {
-    common::{
-		get_program_test,
-		metaplex_nft_program_ix_interface,
-		csl_spl_token_ix_interface,
-		csl_spl_assoc_token_ix_interface,
-	},
-    solana_program_test::tokio,
-    solana_sdk::{
-        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
-    },
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
 };
 
 
 #[tokio::test]
 async fn transfer_nft_ix_success() {
-	let mut program_test = get_program_test();
-
-	// PROGRAMS
-	program_test.prefer_bpf(true);
-
-	program_test.add_program(
-		"account_compression",
-		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
-		None,
-	);
-
-	program_test.add_program(
-		"noop",
-		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
-		None,
-	);
-
-	program_test.add_program(
-		"csl_spl_assoc_token",
-		Pubkey::from_str("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL").unwrap(),
-		None,
-	);
-
-	program_test.add_program(
-		"csl_spl_token",
-		Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap(),
-		None,
-	);
-
-	// KEYPAIR
-	let fee_payer_keypair = Keypair::new();
-	let owner_keypair = Keypair::new();
-	let funding_keypair = Keypair::new();
-	let authority_keypair = Keypair::new();
-	let mint_keypair = Keypair::new();
-
-	// PUBKEY
-	let fee_payer_pubkey = fee_payer_keypair.pubkey();
-	let owner_pubkey = owner_keypair.pubkey();
-	let funding_pubkey = funding_keypair.pubkey();
-	let authority_pubkey = authority_keypair.pubkey();
-	let mint_pubkey = mint_keypair.pubkey();
-	let new_owner_pubkey = Pubkey::new_unique();
-	let wallet_pubkey = Pubkey::new_unique();
-	let token_program_pubkey = csl_spl_token_ix_interface::ID;
-	let source_pubkey = Pubkey::new_unique();
-	let destination_pubkey = Pubkey::new_unique();
-	let system_program_pubkey = Pubkey::new_unique();
-
-	// EXECUTABLE PUBKEY
-	let csl_spl_assoc_token_v0_0_0_pubkey = Pubkey::from_str("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL").unwrap();
-	let csl_spl_token_v0_0_0_pubkey = Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap();
-
-	// PDA
-	let (metadata_pda, _metadata_pda_bump) = Pubkey::find_program_address(
-		&[
-			b"metadata",
-			mint_pubkey.as_ref(),
-		],
-		&metaplex_nft_program::ID,
-	);
-
-	let (assoc_token_account_pda, _assoc_token_account_pda_bump) = Pubkey::find_program_address(
-		&[
-			wallet_pubkey.as_ref(),
-			token_program_pubkey.as_ref(),
-			mint_pubkey.as_ref(),
-		],
-		&csl_spl_token_ix_interface::ID,
-	);
-
-	// ACCOUNT PROGRAM TEST SETUP
-	program_test.add_account(
-		fee_payer_pubkey,
-		Account {
-			lamports: 1_000_000_000_000,
-			data: vec![],
-			owner: system_program::ID,
-			executable: false,
-			rent_epoch: 0,
-		},
-	);
-
-	program_test.add_account(
-		owner_pubkey,
-		Account {
-			lamports: 0,
-			data: vec![],
-			owner: system_program::ID,
-			executable: false,
-			rent_epoch: 0,
-		},
-	);
-
-	program_test.add_account(
-		funding_pubkey,
-		Account {
-			lamports: 1_000_000_000_000,
-			data: vec![],
-			owner: system_program::ID,
-			executable: false,
-			rent_epoch: 0,
-		},
-	);
-
-	program_test.add_account(
-		authority_pubkey,
-		Account {
-			lamports: 0,
-			data: vec![],
-			owner: system_program::ID,
-			executable: false,
-			rent_epoch: 0,
-		},
-	);
-
-	// INSTRUCTIONS
-	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
-
-	let ix = metaplex_nft_program_ix_interface::transfer_nft_ix_setup(
-		&fee_payer_keypair,
-		mint_pubkey,
-		metadata_pda,
-		&owner_keypair,
-		new_owner_pubkey,
-		&funding_keypair,
-		assoc_token_account_pda,
-		wallet_pubkey,
-		system_program_pubkey,
-		token_program_pubkey,
-		source_pubkey,
-		destination_pubkey,
-		&authority_keypair,
-		csl_spl_assoc_token_v0_0_0_pubkey,
-		csl_spl_token_v0_0_0_pubkey,
-		recent_blockhash,
-	);
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let recipient = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	transfer_nft(&mut context, collection, &nft, &owner, &recipient.pubkey()).await.unwrap();
+
+	let destination = token_account_address(&recipient.pubkey(), &nft.mint);
+	assert_eq!(token_account(&mut context, nft.token_account).await.amount, 0);
+	assert_eq!(token_account(&mut context, destination).await.amount, 1);
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert_eq!(metadata.owner, recipient.pubkey());
+}
 
-	let result = banks_client.process_transaction(ix).await;
+#[tokio::test]
+async fn transfer_nft_ix_pays_enforced_royalties() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let recipient = Keypair::new();
+	let creators = [Pubkey::new_unique(), Pubkey::new_unique()];
+	let collection = create_collection(&mut context, &authority).await;
+	set_royalties(&mut context, collection, &authority, 500, &creators).await;
+	enable_royalty_enforcement(&mut context, collection, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	transfer_nft_at_price(&mut context, collection, &nft, &owner, &recipient.pubkey(), 1_000_000_000, &creators).await.unwrap();
+
+	assert_eq!(lamports(&mut context, creators[0]).await, 25_000_000);
+	assert_eq!(lamports(&mut context, creators[1]).await, 25_000_000);
+	let destination = token_account(&mut context, token_account_address(&recipient.pubkey(), &nft.mint)).await;
+	assert_eq!(destination.amount, 1);
+	assert!(destination.is_frozen());
+}
 
-	// ASSERTIONS
-	assert!(result.is_ok());
+#[tokio::test]
+async fn transfer_nft_ix_rejects_unpriced_enforced_transfer() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let recipient = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+	set_royalties(&mut context, collection, &authority, 500, &[Pubkey::new_unique()]).await;
+	enable_royalty_enforcement(&mut context, collection, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	let result = transfer_nft(&mut context, collection, &nft, &owner, &recipient.pubkey()).await;
+	assert_error(result, MetaplexNftProgramError::RoyaltyRequired);
+}
 
+#[tokio::test]
+async fn transfer_nft_ix_rejects_missing_creators() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let recipient = Keypair::new();
+	let collection = create_collection(&mut context, &authority).await;
+	set_royalties(&mut context, collection, &authority, 500, &[Pubkey::new_unique()]).await;
+	enable_royalty_enforcement(&mut context, collection, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	let result = transfer_nft_at_price(&mut context, collection, &nft, &owner, &recipient.pubkey(), 1_000_000_000, &[]).await;
+	assert_error(result, MetaplexNftProgramError::InvalidCreatorAccount);
 }
@@ -0,0 +1,75 @@
+pub mod common;
+
+use anchor_lang::{error::ErrorCode, solana_program::program_option::COption};
+use metaplex_nft_program::NftMint;
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::{tokio, BanksClientError, ProgramTestContext},
+	solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+async fn unstake(context: &mut ProgramTestContext, collection: Pubkey, reward_mint: Pubkey, nft: &Nft, owner: &Keypair) -> Result<(), BanksClientError> {
+	let transaction = metaplex_nft_program_ix_interface::unstake_nft_ix_setup(
+		&context.payer,
+		owner,
+		nft.mint,
+		nft.metadata,
+		collection,
+		stake_pool_pda(&collection),
+		stake_entry_pda(&nft.mint),
+		nft.token_account,
+		reward_mint,
+		token_account_address(&owner.pubkey(), &reward_mint),
+		freeze_authority_pda(),
+		SYSTEM_PROGRAM,
+		TOKEN_PROGRAM,
+		TOKEN_PROGRAM,
+		ASSOCIATED_TOKEN_PROGRAM,
+		program_config_pda(),
+		context.last_blockhash,
+	);
+	process(context, transaction).await
+}
+
+#[tokio::test]
+async fn unstake_nft_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let reward_mint = create_stake_pool(&mut context, collection, &authority, 10).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	let now = unix_timestamp(&mut context).await;
+	stake_nft(&mut context, collection, &nft, &owner).await.unwrap();
+
+	set_unix_timestamp(&mut context, now + 100).await;
+	unstake(&mut context, collection, reward_mint, &nft, &owner).await.unwrap();
+
+	let rewards = token_account(&mut context, token_account_address(&owner.pubkey(), &reward_mint)).await;
+	assert_eq!(rewards.amount, 1_000);
+	let token = token_account(&mut context, nft.token_account).await;
+	assert_eq!(token.amount, 1);
+	assert_eq!(token.delegate, COption::None);
+	assert!(!token.is_frozen());
+	assert!(!account_exists(&mut context, stake_entry_pda(&nft.mint)).await);
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert!(!metadata.staked);
+}
+
+#[tokio::test]
+async fn unstake_nft_ix_rejects_another_owner() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let other = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let reward_mint = create_stake_pool(&mut context, collection, &authority, 10).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+	stake_nft(&mut context, collection, &nft, &owner).await.unwrap();
+
+	let result = unstake(&mut context, collection, reward_mint, &nft, &other).await;
+	assert_error(result, ErrorCode::ConstraintHasOne);
+}
@@ -1,110 +1,54 @@
 pub mod common;
 
-use std::str::FromStr;
+use metaplex_nft_program::{error::MetaplexNftProgramError, MetadataHistory, NftMint};
 use {
-    common::{
-		get_program_test,
-		metaplex_nft_program_ix_interface,
-		csl_spl_token_ix_interface,
-		csl_spl_assoc_token_ix_interface,
-	},
-    solana_program_test::tokio,
-    solana_sdk::{
-        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
-    },
+	common::fixtures::*,
+	solana_program_test::tokio,
+	solana_sdk::signature::Keypair,
 };
 
 
 #[tokio::test]
 async fn update_nft_metadata_ix_success() {
-	let mut program_test = get_program_test();
-
-	// PROGRAMS
-	program_test.prefer_bpf(true);
-
-	program_test.add_program(
-		"account_compression",
-		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
-		None,
-	);
-
-	program_test.add_program(
-		"noop",
-		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
-		None,
-	);
-
-	// DATA
-	let name: String = Default::default();
-	let uri: String = Default::default();
-
-	// KEYPAIR
-	let fee_payer_keypair = Keypair::new();
-	let authority_keypair = Keypair::new();
-	let mint_keypair = Keypair::new();
-
-	// PUBKEY
-	let fee_payer_pubkey = fee_payer_keypair.pubkey();
-	let authority_pubkey = authority_keypair.pubkey();
-	let mint_pubkey = mint_keypair.pubkey();
-
-	// PDA
-	let (collection_pda, _collection_pda_bump) = Pubkey::find_program_address(
-		&[
-			b"collection",
-			authority_pubkey.as_ref(),
-		],
-		&metaplex_nft_program::ID,
-	);
-
-	let (metadata_pda, _metadata_pda_bump) = Pubkey::find_program_address(
-		&[
-			b"metadata",
-			mint_pubkey.as_ref(),
-		],
-		&metaplex_nft_program::ID,
-	);
-
-	// ACCOUNT PROGRAM TEST SETUP
-	program_test.add_account(
-		fee_payer_pubkey,
-		Account {
-			lamports: 1_000_000_000_000,
-			data: vec![],
-			owner: system_program::ID,
-			executable: false,
-			rent_epoch: 0,
-		},
-	);
-
-	program_test.add_account(
-		authority_pubkey,
-		Account {
-			lamports: 0,
-			data: vec![],
-			owner: system_program::ID,
-			executable: false,
-			rent_epoch: 0,
-		},
-	);
-
-	// INSTRUCTIONS
-	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
-
-	let ix = metaplex_nft_program_ix_interface::update_nft_metadata_ix_setup(
-		&fee_payer_keypair,
-		collection_pda,
-		metadata_pda,
-		mint_pubkey,
-		&authority_keypair,
-		&name,
-		&uri,
-		recent_blockhash,
-	);
-
-	let result = banks_client.process_transaction(ix).await;
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	update_nft_metadata(&mut context, collection, &authority, &nft, "Renamed", "https://example.com/renamed.json").await.unwrap();
+
+	let metadata: NftMint = get_account(&mut context, nft.metadata).await;
+	assert_eq!(metadata.name, "Renamed");
+	assert_eq!(metadata.uri, "https://example.com/renamed.json");
+	assert_eq!(metadata.revision, 1);
+	let history: MetadataHistory = get_account(&mut context, history_pda(&nft.mint)).await;
+	assert_eq!(history.total, 1);
+	assert_eq!(history.entries[0].name, "NFT #0");
+	assert_eq!(history.entries[0].uri, "https://example.com/0.json");
+}
 
-	// ASSERTIONS
-	assert!(result.is_ok());
+#[tokio::test]
+async fn update_nft_metadata_ix_rejects_other_authority() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let impostor = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	let result = update_nft_metadata(&mut context, collection, &impostor, &nft, "Renamed", "https://example.com/renamed.json").await;
+	assert_error(result, MetaplexNftProgramError::InvalidAuthority);
+}
 
+#[tokio::test]
+async fn update_nft_metadata_ix_rejects_invalid_uri() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &owner).await;
+
+	let result = update_nft_metadata(&mut context, collection, &authority, &nft, "Renamed", "https://example.com/re named.json").await;
+	assert_error(result, MetaplexNftProgramError::UriInvalidCharacter);
 }
@@ -0,0 +1,68 @@
+pub mod common;
+
+use anchor_lang::error::ErrorCode;
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::tokio,
+	solana_sdk::{signature::Keypair, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn withdraw_bid_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let seller = funded_keypair(&mut context);
+	let first_bidder = funded_keypair(&mut context);
+	let second_bidder = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &seller).await;
+	let end_time = unix_timestamp(&mut context).await + 3_600;
+	create_auction(&mut context, collection, &nft, &seller, 1_000_000_000, end_time).await.unwrap();
+	place_auction_bid(&mut context, collection, &nft.mint, &first_bidder, 1_000_000_000).await.unwrap();
+	place_auction_bid(&mut context, collection, &nft.mint, &second_bidder, 1_100_000_000).await.unwrap();
+
+	let refund = bid_refund_pda(&auction_pda(&nft.mint), &first_bidder.pubkey());
+	let refund_rent = lamports(&mut context, refund).await - 1_000_000_000;
+	let second_bidder_before = lamports(&mut context, second_bidder.pubkey()).await;
+	let transaction = metaplex_nft_program_ix_interface::withdraw_bid_ix_setup(
+		&context.payer,
+		&first_bidder,
+		refund,
+		second_bidder.pubkey(),
+		context.last_blockhash,
+	);
+	process(&mut context, transaction).await.unwrap();
+
+	assert!(!account_exists(&mut context, refund).await);
+	assert_eq!(lamports(&mut context, first_bidder.pubkey()).await, LAMPORTS);
+	assert_eq!(lamports(&mut context, second_bidder.pubkey()).await, second_bidder_before + refund_rent);
+}
+
+#[tokio::test]
+async fn withdraw_bid_ix_rejects_another_rent_payer() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let seller = funded_keypair(&mut context);
+	let first_bidder = funded_keypair(&mut context);
+	let second_bidder = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let nft = mint_nft(&mut context, collection, &authority, &seller).await;
+	let end_time = unix_timestamp(&mut context).await + 3_600;
+	create_auction(&mut context, collection, &nft, &seller, 1_000_000_000, end_time).await.unwrap();
+	place_auction_bid(&mut context, collection, &nft.mint, &first_bidder, 1_000_000_000).await.unwrap();
+	place_auction_bid(&mut context, collection, &nft.mint, &second_bidder, 1_100_000_000).await.unwrap();
+
+	let transaction = metaplex_nft_program_ix_interface::withdraw_bid_ix_setup(
+		&context.payer,
+		&first_bidder,
+		bid_refund_pda(&auction_pda(&nft.mint), &first_bidder.pubkey()),
+		first_bidder.pubkey(),
+		context.last_blockhash,
+	);
+	let result = process(&mut context, transaction).await;
+	assert_error(result, ErrorCode::ConstraintHasOne);
+}
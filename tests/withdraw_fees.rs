@@ -0,0 +1,69 @@
+pub mod common;
+
+use metaplex_nft_program::error::MetaplexNftProgramError;
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::{tokio, BanksClientError, ProgramTestContext},
+	solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+async fn withdraw_fees(context: &mut ProgramTestContext, admin: &Keypair, destination: Pubkey) -> Result<(), BanksClientError> {
+	let transaction = metaplex_nft_program_ix_interface::withdraw_fees_ix_setup(
+		&context.payer,
+		program_config_pda(),
+		admin,
+		treasury_pda(),
+		destination,
+		context.last_blockhash,
+	);
+	process(context, transaction).await
+}
+
+#[tokio::test]
+async fn withdraw_fees_ix_success() {
+	let mut context = start().await;
+	let admin = Keypair::new();
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	let destination = funded_keypair(&mut context).pubkey();
+	initialize_program_config(&mut context, &admin.pubkey()).await;
+	set_protocol_fee(&mut context, &admin, 1_000_000, 0).await.unwrap();
+	let collection = create_collection(&mut context, &authority).await;
+	let treasury_rent = lamports(&mut context, treasury_pda()).await;
+	mint_nft(&mut context, collection, &authority, &owner).await;
+	mint_nft(&mut context, collection, &authority, &owner).await;
+
+	withdraw_fees(&mut context, &admin, destination).await.unwrap();
+
+	assert_eq!(lamports(&mut context, destination).await, LAMPORTS + 2_000_000);
+	assert_eq!(lamports(&mut context, treasury_pda()).await, treasury_rent);
+}
+
+#[tokio::test]
+async fn withdraw_fees_ix_rejects_empty_treasury() {
+	let mut context = start().await;
+	let admin = Keypair::new();
+	initialize_program_config(&mut context, &admin.pubkey()).await;
+
+	let result = withdraw_fees(&mut context, &admin, admin.pubkey()).await;
+	assert_error(result, MetaplexNftProgramError::NoFeesToWithdraw);
+}
+
+#[tokio::test]
+async fn withdraw_fees_ix_rejects_non_admin() {
+	let mut context = start().await;
+	let admin = Keypair::new();
+	let impostor = Keypair::new();
+	let authority = Keypair::new();
+	let owner = funded_keypair(&mut context);
+	initialize_program_config(&mut context, &admin.pubkey()).await;
+	set_protocol_fee(&mut context, &admin, 1_000_000, 0).await.unwrap();
+	let collection = create_collection(&mut context, &authority).await;
+	mint_nft(&mut context, collection, &authority, &owner).await;
+
+	let result = withdraw_fees(&mut context, &impostor, impostor.pubkey()).await;
+	assert_error(result, MetaplexNftProgramError::InvalidAuthority);
+}
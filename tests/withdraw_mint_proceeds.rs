@@ -0,0 +1,82 @@
+pub mod common;
+
+use metaplex_nft_program::{error::MetaplexNftProgramError, MintConfig};
+use {
+	common::{
+		fixtures::*,
+		metaplex_nft_program_ix_interface,
+	},
+	solana_program_test::{tokio, BanksClientError, ProgramTestContext},
+	solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+async fn withdraw(context: &mut ProgramTestContext, collection: Pubkey, authority: &Keypair, destination: Pubkey) -> Result<(), BanksClientError> {
+	let transaction = metaplex_nft_program_ix_interface::withdraw_mint_proceeds_ix_setup(
+		&context.payer,
+		collection,
+		authority,
+		mint_config_pda(&collection),
+		destination,
+		context.last_blockhash,
+	);
+	process(context, transaction).await
+}
+
+#[tokio::test]
+async fn withdraw_mint_proceeds_ix_success() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let minter = funded_keypair(&mut context);
+	let destination = Pubkey::new_unique();
+	let collection = create_collection(&mut context, &authority).await;
+	let now = unix_timestamp(&mut context).await;
+	set_mint_config(&mut context, collection, &authority, 1_000_000_000, 500_000_000, now, 100_000_000, 10).await.unwrap();
+	let nft = mint_nft_paid(&mut context, collection, &authority, &minter).await.unwrap();
+
+	// Batch mints count towards the supply but are not paid for.
+	let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+	let (mint_a, mint_b) = (Keypair::new(), Keypair::new());
+	let entries = vec![batch_entry("a", alice), batch_entry("b", bob)];
+	let accounts = batch_accounts(&collection, 1, &[&mint_a, &mint_b], &[alice, bob]);
+	mint_batch(&mut context, collection, &authority, &entries, accounts, &[&mint_a, &mint_b]).await.unwrap();
+
+	let mint_config_address = mint_config_pda(&collection);
+	let rent = lamports(&mut context, mint_config_address).await - 1_000_000_000;
+	set_unix_timestamp(&mut context, now + 500).await;
+	withdraw(&mut context, collection, &authority, destination).await.unwrap();
+
+	assert_eq!(lamports(&mut context, destination).await, 500_000_000);
+	let mint_config: MintConfig = get_account(&mut context, mint_config_address).await;
+	assert_eq!(mint_config.minted, 3);
+	assert_eq!(mint_config.paid_mints, 1);
+	assert_eq!(mint_config.withdrawn, 500_000_000);
+
+	// The minter's rebate is still covered after the withdrawal.
+	let minter_before = lamports(&mut context, minter.pubkey()).await;
+	let receipt = mint_receipt_pda(&nft.mint);
+	let receipt_rent = lamports(&mut context, receipt).await;
+	let transaction = metaplex_nft_program_ix_interface::claim_mint_rebate_ix_setup(
+		&context.payer,
+		minter.pubkey(),
+		mint_config_address,
+		receipt,
+		context.last_blockhash,
+	);
+	process(&mut context, transaction).await.unwrap();
+	assert_eq!(lamports(&mut context, minter.pubkey()).await, minter_before + 500_000_000 + receipt_rent);
+	assert_eq!(lamports(&mut context, mint_config_address).await, rent);
+}
+
+#[tokio::test]
+async fn withdraw_mint_proceeds_ix_rejects_running_drop() {
+	let mut context = start().await;
+	let authority = Keypair::new();
+	let minter = funded_keypair(&mut context);
+	let collection = create_collection(&mut context, &authority).await;
+	let now = unix_timestamp(&mut context).await;
+	set_mint_config(&mut context, collection, &authority, 1_000_000_000, 500_000_000, now, 100_000_000, 10).await.unwrap();
+	mint_nft_paid(&mut context, collection, &authority, &minter).await.unwrap();
+
+	let result = withdraw(&mut context, collection, &authority, Pubkey::new_unique()).await;
+	assert_error(result, MetaplexNftProgramError::MintNotConcluded);
+}
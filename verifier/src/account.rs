@@ -0,0 +1,181 @@
+//! Reads the committed URI and content hash from program accounts over JSON-RPC.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use borsh::BorshDeserialize;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+use crate::{Error, Result};
+
+/// The URI and content hash stored on an `NftMint` or `NftCollection` account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommittedMetadata {
+	pub uri: String,
+	pub content_hash: Option<[u8; 32]>,
+}
+
+// Borsh mirrors of the program accounts, up to the last field read here.
+// Fields after it are left unread, so appending fields on chain does not
+// break them.
+
+#[derive(BorshDeserialize)]
+struct NftMint {
+	_mint: [u8; 32],
+	_owner: [u8; 32],
+	_name: String,
+	_symbol: String,
+	uri: String,
+	collection: [u8; 32],
+	_user: [u8; 32],
+	_expires_at: i64,
+	_staked: bool,
+	_soulbound: bool,
+	_delegate: [u8; 32],
+	_frozen: bool,
+	_is_mutable: bool,
+	content_hash: Option<[u8; 32]>,
+	_revision: u32,
+	index: u64,
+}
+
+#[derive(BorshDeserialize)]
+struct CollectionCreator {
+	_address: [u8; 32],
+	_share: u8,
+}
+
+#[derive(BorshDeserialize)]
+struct NftCollection {
+	_authority: [u8; 32],
+	_name: String,
+	_symbol: String,
+	uri: String,
+	_verified: bool,
+	_nft_count: u64,
+	_seller_fee_basis_points: u16,
+	_creators: Vec<CollectionCreator>,
+	_enforce_royalties: bool,
+	_soulbound: bool,
+	_paused: bool,
+	_is_mutable: bool,
+	_max_uri_length: u16,
+	_allowed_uri_schemes: Vec<String>,
+	content_hash: Option<[u8; 32]>,
+	_reveal_root: Option<[u8; 32]>,
+	base_uri: String,
+}
+
+/// Anchor account discriminator: the first 8 bytes of `sha256("account:<Name>")`.
+fn discriminator(name: &str) -> [u8; 8] {
+	let hash = Sha256::digest(format!("account:{}", name));
+	let mut discriminator = [0u8; 8];
+	discriminator.copy_from_slice(&hash[..8]);
+	discriminator
+}
+
+/// The fields of a program account that verification needs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetadataAccount {
+	Nft {
+		uri: String,
+		content_hash: Option<[u8; 32]>,
+		collection: [u8; 32],
+		index: u64,
+	},
+	Collection {
+		uri: String,
+		content_hash: Option<[u8; 32]>,
+		base_uri: String,
+	},
+}
+
+/// Decodes raw `NftMint` or `NftCollection` account data.
+pub fn decode_account(data: &[u8]) -> Result<MetadataAccount> {
+	if data.len() < 8 {
+		return Err(Error::UnknownAccount);
+	}
+	let (head, mut body) = data.split_at(8);
+
+	if head == discriminator("NftMint") {
+		let account = NftMint::deserialize(&mut body).map_err(|_| Error::UnknownAccount)?;
+		Ok(MetadataAccount::Nft {
+			uri: account.uri,
+			content_hash: account.content_hash,
+			collection: account.collection,
+			index: account.index,
+		})
+	} else if head == discriminator("NftCollection") {
+		let account = NftCollection::deserialize(&mut body).map_err(|_| Error::UnknownAccount)?;
+		Ok(MetadataAccount::Collection {
+			uri: account.uri,
+			content_hash: account.content_hash,
+			base_uri: account.base_uri,
+		})
+	} else {
+		Err(Error::UnknownAccount)
+	}
+}
+
+/// Full URI of the NFT at `index` storing `uri`, mirroring
+/// `NftCollection::resolve_uri`: with a base URI, NFTs store only a suffix,
+/// or nothing to use `<index>.json`.
+pub fn resolve_uri(base_uri: &str, uri: &str, index: u64) -> String {
+	if base_uri.is_empty() {
+		uri.to_string()
+	} else if uri.is_empty() {
+		format!("{}{}.json", base_uri, index)
+	} else {
+		format!("{}{}", base_uri, uri)
+	}
+}
+
+/// Fetches an `NftMint` or `NftCollection` account and returns its committed
+/// hash and full URI. For NFTs, the collection is read too, to resolve URIs
+/// stored relative to its base URI.
+pub fn fetch_committed_metadata(rpc_url: &str, address: &str, program_id: &str) -> Result<CommittedMetadata> {
+	match decode_account(&fetch_account_data(rpc_url, address, program_id)?)? {
+		MetadataAccount::Collection { uri, content_hash, .. } => Ok(CommittedMetadata { uri, content_hash }),
+		MetadataAccount::Nft { uri, content_hash, collection, index } => {
+			let collection = bs58::encode(collection).into_string();
+			let MetadataAccount::Collection { base_uri, .. } =
+				decode_account(&fetch_account_data(rpc_url, &collection, program_id)?)?
+			else {
+				return Err(Error::UnknownAccount);
+			};
+			Ok(CommittedMetadata { uri: resolve_uri(&base_uri, &uri, index), content_hash })
+		}
+	}
+}
+
+/// Fetches the data of a program-owned account with `getAccountInfo`.
+fn fetch_account_data(rpc_url: &str, address: &str, program_id: &str) -> Result<Vec<u8>> {
+	if bs58::decode(address).into_vec().map(|bytes| bytes.len()) != Ok(32) {
+		return Err(Error::AccountNotFound(address.to_string()));
+	}
+
+	let response: Value = ureq::post(rpc_url)
+		.send_json(json!({
+			"jsonrpc": "2.0",
+			"id": 1,
+			"method": "getAccountInfo",
+			"params": [address, { "encoding": "base64" }],
+		}))
+		.map_err(|error| Error::Network(error.to_string()))?
+		.into_json()
+		.map_err(|error| Error::Network(error.to_string()))?;
+
+	if let Some(error) = response.get("error") {
+		return Err(Error::Network(error.to_string()));
+	}
+	let value = &response["result"]["value"];
+	if value.is_null() || value["owner"].as_str() != Some(program_id) {
+		return Err(Error::AccountNotFound(address.to_string()));
+	}
+
+	let encoded = value["data"][0]
+		.as_str()
+		.ok_or_else(|| Error::Network("malformed getAccountInfo response".to_string()))?;
+	STANDARD
+		.decode(encoded)
+		.map_err(|error| Error::Network(error.to_string()))
+}
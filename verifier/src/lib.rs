@@ -0,0 +1,69 @@
+//! Checks off-chain NFT metadata against the `content_hash` that the
+//! metaplex_nft_program commits on `NftMint` and `NftCollection` accounts.
+
+use std::fmt;
+
+use sha2::{Digest, Sha256};
+
+pub mod account;
+pub mod source;
+
+pub use account::{fetch_committed_metadata, CommittedMetadata};
+pub use source::{fetch_content, Gateways};
+
+/// Program ID of the deployed metaplex_nft_program.
+pub const PROGRAM_ID: &str = "4mAMsimURXatxBJNW6AydZBmHsmXrksPJDPafNAfHAeT";
+
+#[derive(Debug)]
+pub enum Error {
+	/// The RPC node or a content gateway could not be reached or answered with an error.
+	Network(String),
+	/// A local file could not be read.
+	Io(std::io::Error),
+	/// The account does not exist or is not owned by the program.
+	AccountNotFound(String),
+	/// The account data is not an `NftMint` or `NftCollection`.
+	UnknownAccount,
+	/// A hash argument is not 32 hex-encoded bytes.
+	InvalidHash(String),
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Error::Network(message) => write!(f, "request failed: {}", message),
+			Error::Io(error) => write!(f, "could not read file: {}", error),
+			Error::AccountNotFound(address) => write!(f, "account {} not found or not owned by the program", address),
+			Error::UnknownAccount => write!(f, "account is neither an NftMint nor an NftCollection"),
+			Error::InvalidHash(hash) => write!(f, "invalid hash {:?}: expected 64 hex characters", hash),
+		}
+	}
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+	fn from(error: std::io::Error) -> Self {
+		Error::Io(error)
+	}
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// SHA-256 hash of `content`, as committed on chain.
+pub fn content_hash(content: &[u8]) -> [u8; 32] {
+	Sha256::digest(content).into()
+}
+
+/// Whether `content` hashes to `expected`.
+pub fn verify(content: &[u8], expected: &[u8; 32]) -> bool {
+	content_hash(content) == *expected
+}
+
+/// Parses a hex-encoded 32-byte hash.
+pub fn parse_hash(hash: &str) -> Result<[u8; 32]> {
+	let mut bytes = [0u8; 32];
+	hex::decode_to_slice(hash.trim_start_matches("0x"), &mut bytes)
+		.map_err(|_| Error::InvalidHash(hash.to_string()))?;
+	Ok(bytes)
+}
@@ -0,0 +1,86 @@
+use std::process::ExitCode;
+
+use clap::Parser;
+use metadata_verifier::{fetch_committed_metadata, fetch_content, parse_hash, verify, Gateways, PROGRAM_ID};
+
+/// Checks NFT metadata against its on-chain content hash.
+///
+/// Given an `NftMint` or `NftCollection` account, the committed hash and URI
+/// are read from chain, and the URI, or SOURCE if given, is fetched and
+/// hashed. With `--hash`, no account is read and SOURCE is required.
+///
+/// Exits with 0 if the content matches, 1 if it does not and 2 on errors.
+#[derive(Parser)]
+#[command(name = "metadata-verifier", version)]
+struct Args {
+	/// Address of the NftMint or NftCollection account
+	#[arg(required_unless_present = "hash")]
+	account: Option<String>,
+
+	/// Local file or URI to hash instead of the committed URI
+	#[arg(long)]
+	source: Option<String>,
+
+	/// Expected SHA-256 hash, hex-encoded, instead of reading an account
+	#[arg(long, conflicts_with = "account", requires = "source")]
+	hash: Option<String>,
+
+	/// JSON-RPC endpoint
+	#[arg(long, default_value = "https://api.mainnet-beta.solana.com")]
+	rpc: String,
+
+	/// Program that owns the account
+	#[arg(long, default_value = PROGRAM_ID)]
+	program_id: String,
+
+	/// Gateway used for ipfs:// URIs
+	#[arg(long, default_value = "https://ipfs.io/ipfs/")]
+	ipfs_gateway: String,
+
+	/// Gateway used for ar:// URIs
+	#[arg(long, default_value = "https://arweave.net/")]
+	arweave_gateway: String,
+}
+
+fn run(args: Args) -> metadata_verifier::Result<bool> {
+	let (expected, committed_uri) = match (&args.hash, &args.account) {
+		(Some(hash), _) => (parse_hash(hash)?, None),
+		(None, Some(account)) => {
+			let committed = fetch_committed_metadata(&args.rpc, account, &args.program_id)?;
+			let Some(hash) = committed.content_hash else {
+				eprintln!("{} has no committed content hash", account);
+				return Ok(false);
+			};
+			(hash, Some(committed.uri))
+		}
+		(None, None) => unreachable!("clap requires an account or a hash"),
+	};
+
+	let source = args.source.or(committed_uri).expect("clap requires a source with --hash");
+	let gateways = Gateways { ipfs: args.ipfs_gateway, arweave: args.arweave_gateway };
+	let content = fetch_content(&source, &gateways)?;
+
+	let matches = verify(&content, &expected);
+	if matches {
+		println!("OK {} matches {}", source, hex::encode(expected));
+	} else {
+		println!(
+			"MISMATCH {} hashes to {}, expected {}",
+			source,
+			hex::encode(metadata_verifier::content_hash(&content)),
+			hex::encode(expected)
+		);
+	}
+	Ok(matches)
+}
+
+fn main() -> ExitCode {
+	match run(Args::parse()) {
+		Ok(true) => ExitCode::SUCCESS,
+		Ok(false) => ExitCode::from(1),
+		Err(error) => {
+			eprintln!("error: {}", error);
+			ExitCode::from(2)
+		}
+	}
+}
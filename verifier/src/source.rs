@@ -0,0 +1,61 @@
+//! Loads metadata content from a local file or a `https`, `http`, `ipfs` or `ar` URI.
+
+use std::io::Read;
+
+use crate::{Error, Result};
+
+/// Upper bound on fetched content, to avoid reading unbounded responses.
+const MAX_CONTENT_LENGTH: u64 = 64 * 1024 * 1024;
+
+/// HTTP gateways used to resolve `ipfs://` and `ar://` URIs.
+#[derive(Debug, Clone)]
+pub struct Gateways {
+	pub ipfs: String,
+	pub arweave: String,
+}
+
+impl Default for Gateways {
+	fn default() -> Self {
+		Self {
+			ipfs: "https://ipfs.io/ipfs/".to_string(),
+			arweave: "https://arweave.net/".to_string(),
+		}
+	}
+}
+
+impl Gateways {
+	/// The HTTP URL `source` is fetched from, or `None` if it is a local path.
+	pub fn resolve(&self, source: &str) -> Option<String> {
+		let (scheme, rest) = source.split_once("://")?;
+		match scheme.to_ascii_lowercase().as_str() {
+			"https" | "http" => Some(source.to_string()),
+			"ipfs" => Some(join(&self.ipfs, rest.trim_start_matches("ipfs/"))),
+			"ar" => Some(join(&self.arweave, rest)),
+			_ => None,
+		}
+	}
+}
+
+fn join(base: &str, path: &str) -> String {
+	format!("{}/{}", base.trim_end_matches('/'), path.trim_start_matches('/'))
+}
+
+/// Reads `source`, fetching it over HTTP when it is a URI and reading it
+/// from disk otherwise. The bytes are returned exactly as served, since the
+/// committed hash covers the raw body.
+pub fn fetch_content(source: &str, gateways: &Gateways) -> Result<Vec<u8>> {
+	let Some(url) = gateways.resolve(source) else {
+		return Ok(std::fs::read(source.strip_prefix("file://").unwrap_or(source))?);
+	};
+
+	let response = ureq::get(&url)
+		.call()
+		.map_err(|error| Error::Network(error.to_string()))?;
+	let mut content = Vec::new();
+	response
+		.into_reader()
+		.take(MAX_CONTENT_LENGTH)
+		.read_to_end(&mut content)
+		.map_err(|error| Error::Network(error.to_string()))?;
+	Ok(content)
+}
@@ -0,0 +1,80 @@
+use metadata_verifier::account::{decode_account, resolve_uri, MetadataAccount};
+use metadata_verifier::{content_hash, parse_hash, verify, Error, Gateways};
+use sha2::{Digest, Sha256};
+
+const METADATA: &[u8] = br#"{"name":"Item #1","image":"ipfs://bafy/1.png"}"#;
+
+fn nft_mint_data(uri: &str, hash: Option<[u8; 32]>) -> Vec<u8> {
+	let mut data = Sha256::digest("account:NftMint")[..8].to_vec();
+	data.extend([1u8; 32]); // mint
+	data.extend([2u8; 32]); // owner
+	data.extend(borsh::to_vec(&("Item #1".to_string(), "ITEM".to_string(), uri.to_string())).unwrap());
+	data.extend([3u8; 32]); // collection
+	data.extend([0u8; 32]); // user
+	data.extend(0i64.to_le_bytes()); // expires_at
+	data.extend([0u8, 0]); // staked, soulbound
+	data.extend([0u8; 32]); // delegate
+	data.extend([0u8, 1]); // frozen, is_mutable
+	data.extend(borsh::to_vec(&hash).unwrap());
+	data.extend(0u32.to_le_bytes()); // revision
+	data.extend(7u64.to_le_bytes()); // index
+	data.extend([0u8; 16]); // fields appended later
+	data
+}
+
+#[test]
+fn verifies_matching_content() {
+	let hash = content_hash(METADATA);
+
+	assert!(verify(METADATA, &hash));
+	assert!(!verify(b"{}", &hash));
+}
+
+#[test]
+fn parses_hex_hashes() {
+	let hash = content_hash(METADATA);
+
+	assert_eq!(parse_hash(&hex::encode(hash)).unwrap(), hash);
+	assert_eq!(parse_hash(&format!("0x{}", hex::encode(hash))).unwrap(), hash);
+	assert!(matches!(parse_hash("abcd"), Err(Error::InvalidHash(_))));
+}
+
+#[test]
+fn decodes_nft_mint_accounts() {
+	let hash = content_hash(METADATA);
+
+	assert_eq!(
+		decode_account(&nft_mint_data("ar://tx", Some(hash))).unwrap(),
+		MetadataAccount::Nft { uri: "ar://tx".to_string(), content_hash: Some(hash), collection: [3u8; 32], index: 7 }
+	);
+	assert!(matches!(
+		decode_account(&nft_mint_data("ar://tx", None)).unwrap(),
+		MetadataAccount::Nft { content_hash: None, .. }
+	));
+}
+
+#[test]
+fn resolves_base_uris() {
+	assert_eq!(resolve_uri("", "ar://tx", 7), "ar://tx");
+	assert_eq!(resolve_uri("https://example.com/items/", "", 7), "https://example.com/items/7.json");
+	assert_eq!(resolve_uri("https://example.com/items/", "rare/7.json", 7), "https://example.com/items/rare/7.json");
+}
+
+#[test]
+fn rejects_other_accounts() {
+	let mut data = nft_mint_data("ar://tx", None);
+	data[0] ^= 1;
+
+	assert!(matches!(decode_account(&data), Err(Error::UnknownAccount)));
+}
+
+#[test]
+fn resolves_gateway_uris() {
+	let gateways = Gateways::default();
+
+	assert_eq!(gateways.resolve("ipfs://bafy/1.json").as_deref(), Some("https://ipfs.io/ipfs/bafy/1.json"));
+	assert_eq!(gateways.resolve("ipfs://ipfs/bafy").as_deref(), Some("https://ipfs.io/ipfs/bafy"));
+	assert_eq!(gateways.resolve("ar://tx").as_deref(), Some("https://arweave.net/tx"));
+	assert_eq!(gateways.resolve("https://example.com/1.json").as_deref(), Some("https://example.com/1.json"));
+	assert_eq!(gateways.resolve("metadata/1.json"), None);
+}